@@ -59,4 +59,22 @@ public class AsyncTests
         Assert.Equal("Pass", res.GetValue<string>(0,"status")); // Alice
         Assert.Equal("Fail", res.GetValue<string>(1,"status")); // Bob
     }
-}
\ No newline at end of file
+
+    [Fact]
+    public async Task Test_CollectAsync_Native_Background()
+    {
+        using var df = new DataFrame(new Series("v", Enumerable.Range(0, 1000).ToArray()));
+
+        // 多个查询同时在后台线程上执行
+        var tasks = Enumerable.Range(0, 4)
+            .Select(i => df.Lazy().Filter(Col("v") < (i + 1) * 100).CollectAsync())
+            .ToArray();
+        var results = await Task.WhenAll(tasks);
+        Assert.Equal(new long[] { 100, 200, 300, 400 }, results.Select(r => (long)r.Height).ToArray());
+        foreach (var r in results) r.Dispose();
+
+        // 查询失败时 Task 以异常结束
+        var bad = df.Lazy().Select(Col("missing"));
+        await Assert.ThrowsAsync<Exception>(() => bad.CollectAsync());
+    }
+}
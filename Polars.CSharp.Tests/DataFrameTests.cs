@@ -475,6 +475,28 @@ B,5";
         Assert.True(res.GetValue<bool>(1,"has_3"));
     }
     [Fact]
+    public void Test_List_Contains_PerRow_Item_Column()
+    {
+        // list.contains 逐行比较：第 i 行的 item 只在第 i 行的 list 里找
+        using var tags = Series.From("tags", new List<List<int>>
+        {
+            new() { 1, 2 },
+            new() { 3, 4 },
+            new() { 5 }
+        });
+        using var item = new Series("item", new int[] { 2, 1, 5 });
+        using var df = new DataFrame(tags, item);
+
+        using var res = df.Select(
+            Col("tags").List.Contains(Col("item")).Alias("hit")
+        );
+
+        // 1 在第 0 行的 list 里，但第 1 行的 list 是 [3, 4]，所以为 false
+        Assert.True(res.GetValue<bool>(0, "hit"));
+        Assert.False(res.GetValue<bool>(1, "hit"));
+        Assert.True(res.GetValue<bool>(2, "hit"));
+    }
+    [Fact]
     public void Test_DataFrame_From_Records_With_Decimal()
     {
         // 1. 准备数据
@@ -686,4 +708,278 @@ B,5";
             Assert.Equal(2, sorted2["b"][0]); // a=2 tied, b asc -> 2
             Assert.Equal(8, sorted2["b"][1]); // a=2 tied, b asc -> 8
         }
-}
\ No newline at end of file
+    [Fact]
+    public void Test_ApproxNUniqueHll_And_CardinalityReport()
+    {
+        // 10000 行，id 有 5000 个不同值，flag 只有 2 个
+        var ids = Enumerable.Range(0, 10000).Select(i => i % 5000).ToArray();
+        var flags = Enumerable.Range(0, 10000).Select(i => i % 2 == 0 ? "a" : null).ToArray();
+        using var sId = new Series("id", ids);
+        using var sFlag = new Series("flag", flags);
+        using var df = new DataFrame(sId, sFlag);
+
+        // 1. Expr 聚合：precision = 14 时误差约 0.8%，这里放宽到 5%
+        using var agg = df.Select(Col("id").ApproxNUniqueHll(14).Alias("n"));
+        var estimate = agg.GetValue<long>(0, "n");
+        Assert.InRange(estimate, 4750, 5250);
+
+        // 2. 报告：每列一行
+        using var report = df.CardinalityReport();
+        Assert.Equal(2, report.Height);
+        Assert.Equal("id", report.GetValue<string>(0, "column"));
+        Assert.Equal("flag", report.GetValue<string>(1, "column"));
+        Assert.Equal(0, report.GetValue<long>(0, "null_count"));
+        Assert.Equal(5000, report.GetValue<long>(1, "null_count"));
+        Assert.InRange(report.GetValue<long>(0, "approx_n_unique"), 4750, 5250);
+
+        // 3. precision 越界直接报错
+        Assert.Throws<Exception>(() => Col("id").ApproxNUniqueHll(3));
+        Assert.Throws<Exception>(() => df.CardinalityReport(19));
+    }
+    [Fact]
+    public void Test_DataFrameRegistry_Put_Get_Remove()
+    {
+        var name = $"shared_{Guid.NewGuid()}";
+        using var s = new Series("v", new int[] { 1, 2, 3 });
+        using var df = new DataFrame(s);
+
+        DataFrameRegistry.Put(name, df);
+
+        // 1. 取回的是独立句柄，释放它不影响注册表
+        using (var got = DataFrameRegistry.Get(name))
+        {
+            Assert.Equal(3, got.Height);
+            Assert.Equal(2, got.GetValue<int>(1, "v"));
+        }
+        using (var again = DataFrameRegistry.Get(name))
+        {
+            Assert.Equal(3, again.Height);
+        }
+
+        // 2. 同名覆盖
+        using var s2 = new Series("v", new int[] { 9 });
+        using var df2 = new DataFrame(s2);
+        DataFrameRegistry.Put(name, df2);
+        using (var replaced = DataFrameRegistry.Get(name))
+        {
+            Assert.Equal(9, replaced.GetValue<int>(0, "v"));
+        }
+
+        // 3. Remove 返回是否确实删除
+        Assert.True(DataFrameRegistry.Remove(name));
+        Assert.False(DataFrameRegistry.Remove(name));
+        Assert.Throws<Exception>(() => DataFrameRegistry.Get(name));
+
+        // 原 df 仍然可用
+        Assert.Equal(3, df.Height);
+    }
+    [Fact]
+    public void Test_Filter_With_Boolean_Mask()
+    {
+        using var id = new Series("id", new[] { 1, 2, 3, 4 });
+        using var df = new DataFrame(id);
+
+        // 第 3 个值为 Null，应按 false 处理
+        using var mask = new Series("m", new[] { true, false, true, true }, new[] { true, true, false, true });
+        using var filtered = df.Filter(mask);
+
+        Assert.Equal(2, filtered.Height);
+        Assert.Equal(1, filtered.GetValue<int>(0, "id"));
+        Assert.Equal(4, filtered.GetValue<int>(1, "id"));
+
+        // 长度不一致 / 非布尔类型都会报错
+        using var shortMask = new Series("m", new[] { true, false });
+        Assert.Throws<Exception>(() => df.Filter(shortMask));
+        Assert.Throws<Exception>(() => df.Filter(id));
+    }
+    [Fact]
+    public void Test_GroupSizes()
+    {
+        using var city = new Series("city", new string?[] { "Oslo", "Rome", "Rome", "Paris", "Rome", "Paris" });
+        using var df = new DataFrame(city);
+
+        // 不排序: 按首次出现顺序
+        using var stable = df.GroupSizes(["city"]);
+        Assert.Equal(3, stable.Height);
+        Assert.Equal("Oslo", stable.GetValue<string>(0, "city"));
+        Assert.Equal(1, stable.GetValue<int>(0, "count"));
+        Assert.Equal("Rome", stable.GetValue<string>(1, "city"));
+
+        // 排序: count 降序
+        using var sorted = df.GroupSizes(["city"], sort: true);
+        Assert.Equal("Rome", sorted.GetValue<string>(0, "city"));
+        Assert.Equal(3, sorted.GetValue<int>(0, "count"));
+        Assert.Equal("Paris", sorted.GetValue<string>(1, "city"));
+        Assert.Equal("Oslo", sorted.GetValue<string>(2, "city"));
+
+        Assert.Throws<Exception>(() => df.GroupSizes(["missing"]));
+    }
+    [Fact]
+    public void Test_List_Transforms()
+    {
+        using var vals = Series.From("vals", new List<List<int?>>
+        {
+            new() { 3, 1, 3, null, 2 },
+            new() { 5, 5 }
+        });
+        using var off = new Series("off", new[] { 1L, 0L });
+        using var df = new DataFrame(vals, off);
+
+        using var res = df.Select(
+            Col("vals").List.Unique(maintainOrder: true).Alias("uniq"),
+            Col("vals").List.Slice(1, 2).Alias("slice"),
+            // offset 逐行取自另一列，length 为空 -> 取到末尾
+            Col("vals").List.Slice(Col("off")).Alias("slice_col"),
+            Col("vals").List.Head(2).Alias("head"),
+            Col("vals").List.Tail(1).Alias("tail"),
+            Col("vals").List.Reverse().Alias("rev"),
+            Col("vals").List.DropNulls().Alias("no_null")
+        );
+
+        Assert.Equal(new List<int?> { 3, 1, null, 2 }, res["uniq"].GetValue<List<int?>>(0));
+        Assert.Equal(new List<int?> { 5 }, res["uniq"].GetValue<List<int?>>(1));
+        Assert.Equal(new List<int?> { 1, 3 }, res["slice"].GetValue<List<int?>>(0));
+        Assert.Equal(new List<int?> { 1, 3, null, 2 }, res["slice_col"].GetValue<List<int?>>(0));
+        Assert.Equal(new List<int?> { 5, 5 }, res["slice_col"].GetValue<List<int?>>(1));
+        Assert.Equal(new List<int?> { 3, 1 }, res["head"].GetValue<List<int?>>(0));
+        Assert.Equal(new List<int?> { 2 }, res["tail"].GetValue<List<int?>>(0));
+        Assert.Equal(new List<int?> { 2, null, 3, 1, 3 }, res["rev"].GetValue<List<int?>>(0));
+        Assert.Equal(new List<int?> { 3, 1, 3, 2 }, res["no_null"].GetValue<List<int?>>(0));
+    }
+    [Fact]
+    public void Test_List_Std_ArgMin_ArgMax()
+    {
+        using var vals = Series.From("vals", new List<List<int?>>
+        {
+            new() { 2, 4, 4, 4, 5, 5, 7, 9 },
+            new() { 3, null, 1 }
+        });
+        using var df = new DataFrame(vals);
+
+        using var res = df.Select(
+            Col("vals").List.Std(0).Alias("pop_std"),
+            Col("vals").List.ArgMin().Alias("argmin"),
+            Col("vals").List.ArgMax().Alias("argmax")
+        );
+
+        // 总体标准差 (ddof = 0)
+        Assert.Equal(2.0, res.GetValue<double>(0, "pop_std"), 10);
+        // 第一次出现的位置; Null 被跳过
+        Assert.Equal(0L, Convert.ToInt64(res[0, "argmin"]));
+        Assert.Equal(7L, Convert.ToInt64(res[0, "argmax"]));
+        Assert.Equal(2L, Convert.ToInt64(res[1, "argmin"]));
+        Assert.Equal(0L, Convert.ToInt64(res[1, "argmax"]));
+
+        // 默认 ddof = 1
+        using var sample = df.Select(Col("vals").List.Std().Alias("s"));
+        Assert.Equal(Math.Sqrt(32.0 / 7.0), sample.GetValue<double>(0, "s"), 10);
+    }
+    [Fact]
+    public void Test_DataFrame_Empty_From_Schema()
+    {
+        using var df = DataFrame.Empty(new Dictionary<string, DataType>
+        {
+            ["id"] = DataType.Int64,
+            ["tags"] = DataType.List(DataType.String),
+            ["ts"] = DataType.Datetime(TimeUnit.Milliseconds, "UTC")
+        });
+
+        Assert.Equal(0, df.Height);
+        Assert.Equal(new[] { "id", "tags", "ts" }, df.Columns);
+        Assert.Equal(DataTypeKind.Int64, df.Schema["id"].Kind);
+        // 嵌套类型完整保留
+        Assert.Equal(DataTypeKind.List, df.Schema["tags"].Kind);
+        Assert.Equal(DataTypeKind.String, df.Schema["tags"].InnerType!.Kind);
+        Assert.Equal(TimeUnit.Milliseconds, df.Schema["ts"].Unit);
+        Assert.Equal("UTC", df.Schema["ts"].TimeZone);
+
+        // 空 Schema -> 0 列
+        using var none = DataFrame.Empty(new Dictionary<string, DataType>());
+        Assert.Equal(0, none.Width);
+    }
+    [Fact]
+    public void Test_List_Set_Operations()
+    {
+        using var a = Series.From("a", new List<List<int>>
+        {
+            new() { 1, 2, 3 },
+            new() { 5 }
+        });
+        using var b = Series.From("b", new List<List<int>>
+        {
+            new() { 2, 3, 4 },
+            new() { 6 }
+        });
+        using var df = new DataFrame(a, b);
+
+        using var res = df.Select(
+            Col("a").List.Union(Col("b")).Alias("union"),
+            Col("a").List.SetIntersection(Col("b")).Alias("inter"),
+            Col("a").List.SetDifference(Col("b")).Alias("diff"),
+            Col("a").List.SetSymmetricDifference(Col("b")).Alias("sym")
+        );
+
+        // 逐行比较: 第 i 行只和第 i 行运算
+        Assert.Equal(new List<int> { 1, 2, 3, 4 }, res["union"].GetValue<List<int>>(0).OrderBy(x => x).ToList());
+        Assert.Equal(new List<int> { 2, 3 }, res["inter"].GetValue<List<int>>(0).OrderBy(x => x).ToList());
+        Assert.Equal(new List<int> { 1 }, res["diff"].GetValue<List<int>>(0));
+        Assert.Equal(new List<int> { 1, 4 }, res["sym"].GetValue<List<int>>(0).OrderBy(x => x).ToList());
+
+        Assert.Equal(new List<int> { 5, 6 }, res["union"].GetValue<List<int>>(1).OrderBy(x => x).ToList());
+        Assert.Empty(res["inter"].GetValue<List<int>>(1));
+    }
+    [Fact]
+    public void Test_DataFrame_Generate()
+    {
+        Dictionary<string, DataType> Schema() => new()
+        {
+            ["id"] = DataType.Int64,
+            ["name"] = DataType.String,
+            ["tags"] = DataType.List(DataType.Int32),
+            ["ts"] = DataType.Datetime(TimeUnit.Microseconds)
+        };
+
+        using var a = DataFrame.Generate(Schema(), 50, seed: 42);
+        using var b = DataFrame.Generate(Schema(), 50, seed: 42);
+        using var c = DataFrame.Generate(Schema(), 50, seed: 7);
+
+        Assert.Equal(50, a.Height);
+        Assert.Equal(DataTypeKind.List, a.Schema["tags"].Kind);
+        Assert.Equal(DataTypeKind.Datetime, a.Schema["ts"].Kind);
+
+        // 相同 seed -> 相同数据
+        Assert.Equal(a["id"].ToArray<long>(), b["id"].ToArray<long>());
+        Assert.Equal(a["name"].ToArray<string>(), b["name"].ToArray<string>());
+        Assert.NotEqual(a["id"].ToArray<long>(), c["id"].ToArray<long>());
+
+        // 默认不生成 Null，数值落在 [-1000, 1000]
+        Assert.Equal(0, a["id"].NullCount);
+        Assert.All(a["id"].ToArray<long>(), v => Assert.InRange(v, -1000, 1000));
+
+        // nullRatio = 1 -> 全部为 Null
+        using var allNull = DataFrame.Generate(Schema(), 10, nullRatio: 1.0);
+        Assert.Equal(10, allNull["id"].NullCount);
+
+        Assert.Throws<Exception>(() => DataFrame.Generate(Schema(), 10, nullRatio: 1.5));
+    }
+    [Fact]
+    public void Test_Shutdown_Clears_Registry()
+    {
+        // Registry 是进程级的，与 Registry 的其他测试放在同一个类里，避免并行执行互相干扰
+        using var s = new Series("x", new[] { 1, 2 });
+        using var df = new DataFrame(s);
+        DataFrameRegistry.Put("shutdown_a", df);
+        DataFrameRegistry.Put("shutdown_b", df);
+
+        // 返回仍存活的条目数
+        Assert.Equal(2, Shutdown());
+        Assert.Throws<Exception>(() => DataFrameRegistry.Get("shutdown_a"));
+
+        // 再次调用没有可清理的条目，也不会残留错误信息
+        Assert.Equal(0, Shutdown());
+        Assert.False(DataFrameRegistry.Remove("shutdown_b"));
+
+        // 调用方持有的 DataFrame 不受影响
+        Assert.Equal(2, df.Height);
+    }
+}
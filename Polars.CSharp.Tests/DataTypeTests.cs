@@ -455,4 +455,178 @@ public class DataTypeTests
         var valNaive = (DateTime)df4["ts_naive"][0];
         Assert.Equal(18, valNaive.Hour);
     }
-}
\ No newline at end of file
+
+    [Fact]
+    public void Test_SupportedConversion_Int_To_Decimal_Is_Lossy()
+    {
+        Assert.Equal(CastSupport.Lossy, DataType.SupportedConversion(DataTypeKind.Int32, DataTypeKind.Decimal));
+
+        // Decimal(5,2) 装不下 10000，非严格 cast 会变成 null
+        using var s = new Series("a", new int[] { 1, 10000 });
+        using var dec = s.Cast(DataType.Decimal(5, 2));
+        Assert.Equal(1, dec.NullCount);
+    }
+
+    [Fact]
+    public void Test_SupportedConversion_Float_To_String_RoundTrip()
+    {
+        Assert.Equal(CastSupport.Lossless, DataType.SupportedConversion(DataTypeKind.Float64, DataTypeKind.String));
+
+        var data = new double[] { double.NaN, 1e-7, 0.1, double.PositiveInfinity, double.Epsilon, -1.5e300 };
+        using var s = new Series("f", data);
+        using var str = s.Cast(DataType.String);
+        using var back = str.Cast(DataType.Float64);
+
+        Assert.Equal(0, back.NullCount);
+        Assert.True(double.IsNaN(back.GetValue<double>(0)));
+        for (int i = 1; i < data.Length; i++)
+        {
+            Assert.Equal(data[i], back.GetValue<double>(i));
+        }
+    }
+
+    [Fact]
+    public void Test_SupportedConversion_Int64_To_Float64_Is_Lossy()
+    {
+        Assert.Equal(CastSupport.Lossy, DataType.SupportedConversion(DataTypeKind.Int64, DataTypeKind.Float64));
+
+        // 2^53 + 1 无法用 f64 精确表示
+        long big = (1L << 53) + 1;
+        using var s = new Series("i", new long[] { big });
+        using var f = s.Cast(DataType.Float64);
+        using var back = f.Cast(DataType.Int64);
+        Assert.NotEqual(big, back.GetValue<long>(0));
+    }
+
+    [Fact]
+    public void Test_SupportedConversion_Temporal_Pairs()
+    {
+        // Time -> Duration: Polars 支持，但 Duration 回不到 Time (有损)
+        Assert.Equal(CastSupport.Lossy, DataType.SupportedConversion(DataTypeKind.Time, DataTypeKind.Duration));
+        using var t = new Series("t", new TimeOnly[] { new(1, 2, 3), new(23, 59, 59) });
+        using var d = t.Cast(DataType.Duration(TimeUnit.Nanoseconds));
+        Assert.Equal(2, d.Length);
+        Assert.Equal(0, d.NullCount);
+
+        // Datetime -> Duration: Polars 直接拒绝
+        Assert.Equal(CastSupport.Unsupported, DataType.SupportedConversion(DataTypeKind.Datetime, DataTypeKind.Duration));
+        using var dt = new Series("dt", new DateTime[] { new(2024, 1, 1) });
+        Assert.Throws<Exception>(() => dt.Cast(DataType.Duration(TimeUnit.Microseconds)));
+
+        // Duration -> String 同样不支持
+        Assert.Equal(CastSupport.Unsupported, DataType.SupportedConversion(DataTypeKind.Duration, DataTypeKind.String));
+        Assert.Throws<Exception>(() => d.Cast(DataType.String));
+    }
+
+    [Fact]
+    public void Test_SupportedConversion_Invalid_Kind_Throws()
+    {
+        Assert.Throws<Exception>(() => DataType.SupportedConversion((DataTypeKind)99, DataTypeKind.Int32));
+    }
+    [Fact]
+    public void Test_Dt_Utc_And_Dst_Offsets()
+    {
+        using var s = new Series("ts", new DateTime[] { new(2024, 1, 15, 12, 0, 0), new(2024, 7, 1, 12, 0, 0) });
+        using var df = new DataFrame(s);
+
+        using var res = df
+            .Select(Col("ts").Dt.ReplaceTimeZone("Europe/Berlin").Alias("ts"))
+            .Select(
+                // Duration(ms) -> Duration(us)，便于按 TimeSpan 读取
+                Col("ts").Dt.BaseUtcOffset().Cast(DataType.Duration(TimeUnit.Microseconds)).Alias("base"),
+                Col("ts").Dt.DstOffset().Cast(DataType.Duration(TimeUnit.Microseconds)).Alias("dst")
+            );
+
+        // 冬季: +1h / 0；夏季: +1h / +1h
+        Assert.Equal(TimeSpan.FromHours(1), res.GetValue<TimeSpan>(0, "base"));
+        Assert.Equal(TimeSpan.Zero, res.GetValue<TimeSpan>(0, "dst"));
+        Assert.Equal(TimeSpan.FromHours(1), res.GetValue<TimeSpan>(1, "base"));
+        Assert.Equal(TimeSpan.FromHours(1), res.GetValue<TimeSpan>(1, "dst"));
+    }
+    [Fact]
+    public void Test_Array_Type_And_Ops()
+    {
+        using var vals = Series.From("vals", new List<List<long>>
+        {
+            new() { 1, 2, 3 },
+            new() { 4, 5, 6 }
+        });
+        using var df = new DataFrame(vals);
+
+        using var arrType = DataType.Array(DataType.Int64, 3);
+        Assert.Equal(DataTypeKind.Array, arrType.Kind);
+        Assert.Equal(DataTypeKind.Int64, arrType.InnerType!.Kind);
+
+        using var arr = df.Select(Col("vals").Cast(arrType));
+        // 从 Schema 读回也能识别为 Array
+        Assert.Equal(DataTypeKind.Array, arr.Schema["vals"].Kind);
+        Assert.Contains("array", arr.Schema["vals"].ToString());
+
+        using var res = arr.Select(
+            Col("vals").Arr.Len().Alias("len"),
+            Col("vals").Arr.Sum().Alias("sum"),
+            Col("vals").Arr.Min().Alias("min"),
+            Col("vals").Arr.Max().Alias("max"),
+            Col("vals").Arr.Mean().Alias("mean"),
+            Col("vals").Arr.Get(-1).Alias("last"),
+            Col("vals").Arr.Get(10).Alias("oob"),
+            Col("vals").Arr.Contains(5).Alias("has5"),
+            Col("vals").Arr.ToList().Alias("as_list")
+        );
+
+        Assert.Equal(3L, Convert.ToInt64(res[0, "len"]));
+        Assert.Equal(15L, res.GetValue<long>(1, "sum"));
+        Assert.Equal(1L, res.GetValue<long>(0, "min"));
+        Assert.Equal(6L, res.GetValue<long>(1, "max"));
+        Assert.Equal(2.0, res.GetValue<double>(0, "mean"));
+        Assert.Equal(3L, res.GetValue<long>(0, "last"));
+        Assert.Null(res[0, "oob"]);
+        Assert.False(res.GetValue<bool>(0, "has5"));
+        Assert.True(res.GetValue<bool>(1, "has5"));
+        Assert.Equal(DataTypeKind.List, res.Schema["as_list"].Kind);
+        Assert.Equal(new List<long> { 4, 5, 6 }, res["as_list"].GetValue<List<long>>(1));
+
+        // 宽度不匹配时 Cast 报错
+        using var wrongWidth = DataType.Array(DataType.Int64, 2);
+        Assert.Throws<Exception>(() => df.Select(Col("vals").Cast(wrongWidth)));
+    }
+    [Fact]
+    public void Test_Categorical_Ops()
+    {
+        using var df = new DataFrame(
+            new Series("fruit", new[] { "apple", "banana", "apple", "cherry" })
+        );
+        using var cat = df.Select(Col("fruit").Cast(DataType.Categorical));
+        Assert.Equal(DataTypeKind.Categorical, cat.Schema["fruit"].Kind);
+
+        using var res = cat.Select(
+            Col("fruit").Cat.LenBytes().Alias("bytes"),
+            Col("fruit").Cat.LenChars().Alias("chars"),
+            Col("fruit").Cat.StartsWith("ap").Alias("ap"),
+            Col("fruit").Cat.EndsWith("rry").Alias("rry"),
+            Col("fruit").Cat.Slice(1, 3).Alias("mid"),
+            Col("fruit").Cat.Slice(-2).Alias("tail")
+        );
+
+        Assert.Equal(6L, Convert.ToInt64(res[1, "bytes"]));
+        Assert.Equal(5L, Convert.ToInt64(res[0, "chars"]));
+        Assert.True(res.GetValue<bool>(2, "ap"));
+        Assert.False(res.GetValue<bool>(1, "ap"));
+        Assert.True(res.GetValue<bool>(3, "rry"));
+        Assert.Equal("ppl", res.GetValue<string>(0, "mid"));
+        Assert.Equal("ry", res.GetValue<string>(3, "tail"));
+
+        // 类别字典：Expr 与 Series 两条路径结果一致
+        using var cats = cat.Select(Col("fruit").Cat.GetCategories().Alias("cats"));
+        var fromExpr = cats["cats"].ToArray<string>().OrderBy(x => x).ToArray();
+        Assert.Equal(new[] { "apple", "banana", "cherry" }, fromExpr);
+
+        using var catsSeries = cat["fruit"].GetCategories();
+        Assert.Equal(DataTypeKind.String, catsSeries.DataType.Kind);
+        var fromSeries = catsSeries.ToArray<string>().OrderBy(x => x).ToArray();
+        Assert.Equal(fromExpr, fromSeries);
+
+        // 非 Categorical 列没有类别字典
+        Assert.Throws<Exception>(() => df["fruit"].GetCategories());
+    }
+}
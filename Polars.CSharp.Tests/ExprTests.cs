@@ -740,4 +740,849 @@ TooShort,1990-05-20,1.60";
         Assert.Equal(10, result[1, "DiffValue"]); // 20 - 10 = 10
         Assert.Equal(100, result[4, "DiffValue"]); // 200 - 100 = 100
     }
-}
\ No newline at end of file
+    [Fact]
+    public void Test_String_ExtractAll_Groups_CountMatches()
+    {
+        using var s = new Series("log", new string?[] { "GET /a 200 12ms", "POST /b 500 3ms", null });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            Col("log").Str.ExtractAll(@"\d+").Alias("nums"),
+            Col("log").Str.ExtractGroups(@"(?<method>[A-Z]+) (?<path>\S+)").Alias("req"),
+            Col("log").Str.CountMatches(@"\d").Alias("digits"),
+            // literal = true 时 "." 只匹配字面量
+            Col("log").Str.CountMatches(".", literal: true).Alias("dots")
+        );
+
+        // 1. extract_all -> List<String>
+        var nums = res["nums"].GetValue<List<string>>(0);
+        Assert.Equal(new List<string> { "200", "12" }, nums);
+
+        // 2. extract_groups -> Struct，字段名为命名组
+        using var fields = res.Select(
+            Col("req").Struct.Field("method").Alias("method"),
+            Col("req").Struct.Field("path").Alias("path")
+        );
+        Assert.Equal("POST", fields.GetValue<string>(1, "method"));
+        Assert.Equal("/b", fields.GetValue<string>(1, "path"));
+
+        // 3. count_matches
+        Assert.Equal(5, res.GetValue<int>(0, "digits"));
+        Assert.Equal(0, res.GetValue<int>(0, "dots"));
+        Assert.Null(res.GetValue<int?>(2, "digits"));
+    }
+
+    [Fact]
+    public void Test_String_ExtractGroups_Invalid_Regex_Throws()
+    {
+        // 非法正则在构建表达式时就应该报错
+        Assert.Throws<Exception>(() => Col("log").Str.ExtractGroups("(unclosed"));
+    }
+    [Fact]
+    public void Test_String_Split_Exact_SplitN_Inclusive()
+    {
+        using var s = new Series("path", new string?[] { "a/b/c", "x" });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            Col("path").Str.Split("/", inclusive: true).Alias("incl"),
+            Col("path").Str.SplitExact("/", 1).Alias("exact"),
+            Col("path").Str.SplitN("/", 2).Alias("n")
+        );
+
+        // 1. inclusive: 分隔符保留在每段末尾
+        Assert.Equal(new List<string> { "a/", "b/", "c" }, res["incl"].GetValue<List<string>>(0));
+
+        // 2. split_exact(1) -> 2 个字段，"b/c" 中的第二个 "/" 被丢弃；不足时补 null
+        using var exact = res.Select(
+            Col("exact").Struct.Field("field_0").Alias("f0"),
+            Col("exact").Struct.Field("field_1").Alias("f1")
+        );
+        Assert.Equal("a", exact.GetValue<string>(0, "f0"));
+        Assert.Equal("b", exact.GetValue<string>(0, "f1"));
+        Assert.Equal("x", exact.GetValue<string>(1, "f0"));
+        Assert.Null(exact.GetValue<string?>(1, "f1"));
+
+        // 3. splitn(2): 剩余部分保留在最后一个字段
+        using var n = res.Select(Col("n").Struct.Field("field_1").Alias("rest"));
+        Assert.Equal("b/c", n.GetValue<string>(0, "rest"));
+        Assert.Null(n.GetValue<string?>(1, "rest"));
+    }
+    [Fact]
+    public void Test_String_Titlecase_Pad_Zfill()
+    {
+        using var s = new Series("s", new string?[] { "hello wORLD-foo", "-42", "abcdef" });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            Col("s").Str.ToTitlecase().Alias("title"),
+            Col("s").Str.PadStart(5, '*').Alias("left"),
+            Col("s").Str.PadEnd(5).Alias("right"),
+            Col("s").Str.Zfill(5).Alias("z")
+        );
+
+        // 非字母数字字符后重新大写
+        Assert.Equal("Hello World-Foo", res.GetValue<string>(0, "title"));
+
+        Assert.Equal("**-42", res.GetValue<string>(1, "left"));
+        Assert.Equal("-42  ", res.GetValue<string>(1, "right"));
+        // 超过长度的不截断
+        Assert.Equal("abcdef", res.GetValue<string>(2, "left"));
+
+        // zfill 保留符号
+        Assert.Equal("-0042", res.GetValue<string>(1, "z"));
+
+        // 孤立的代理项不是合法 code point
+        Assert.Throws<Exception>(() => Col("s").Str.PadStart(5, '\uD800'));
+    }
+    [Fact]
+    public void Test_String_Strptime_ToTime_ToDatetime_Options()
+    {
+        using var s = new Series("raw", new string?[] { "2024-03-01 08:30:15", "bad" });
+        using var t = new Series("clock", new string?[] { "08:30:15", "25:99:00" });
+        using var df = new DataFrame(s, t);
+
+        using var res = df.Select(
+            Col("raw").Str.ToDatetime("%Y-%m-%d %H:%M:%S", TimeUnit.Milliseconds, "UTC").Alias("dt"),
+            Col("clock").Str.ToTime("%H:%M:%S").Alias("time"),
+            // strict = false: 解析失败变成 null
+            Col("raw").Str.Strptime(DataType.Date, "%Y-%m-%d", strict: false, exact: false).Alias("date")
+        );
+
+        // 1. to_datetime 带单位和时区
+        var dtType = res["dt"].DataType;
+        Assert.Equal(DataTypeKind.Datetime, dtType.Kind);
+        Assert.Equal(TimeUnit.Milliseconds, dtType.Unit);
+        Assert.Equal("UTC", dtType.TimeZone);
+        Assert.Null(res.GetValue<DateTime?>(1, "dt"));
+
+        // 2. to_time
+        Assert.Equal(new TimeOnly(8, 30, 15), res.GetValue<TimeOnly>(0, "time"));
+        Assert.Null(res.GetValue<TimeOnly?>(1, "time"));
+
+        // 3. strptime: exact = false 允许只匹配前缀部分
+        Assert.Equal(new DateOnly(2024, 3, 1), res.GetValue<DateOnly>(0, "date"));
+        Assert.Null(res.GetValue<DateOnly?>(1, "date"));
+
+        // 4. strict = true 时解析失败在 collect 时报错
+        Assert.Throws<Exception>(() =>
+            df.Select(Col("raw").Str.Strptime(DataType.Date, "%Y-%m-%d", strict: true, exact: false)));
+
+        // 5. 目标类型必须是时间类型
+        Assert.Throws<Exception>(() => Col("raw").Str.Strptime(DataType.Int64));
+
+        // 6. 非法时区在构建表达式时就报错
+        Assert.Throws<Exception>(() => Col("raw").Str.ToDatetime(null, TimeUnit.Microseconds, "Mars/Olympus"));
+    }
+    [Fact]
+    public void Test_String_JsonDecode_And_JsonPathMatch()
+    {
+        using var s = new Series("payload", new string?[]
+        {
+            @"{""a"":1,""b"":""x""}",
+            @"{""a"":2,""b"":""y"",""c"":true}",
+            null
+        });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            // 1. 自动推断 (扫描全部行，所以能看到第二行才出现的 c)
+            Col("payload").Str.JsonDecode(inferSchemaLen: 0).Alias("inferred"),
+            // 2. 指定类型：只保留 a
+            Col("payload").Str.JsonDecode(DataType.Struct(["a"], [DataType.Int64])).Alias("typed"),
+            // 3. JSONPath 提取为字符串
+            Col("payload").Str.JsonPathMatch("$.b").Alias("b")
+        );
+
+        Assert.Equal(DataTypeKind.Struct, res["inferred"].DataType.Kind);
+        using var fields = res.Select(
+            Col("inferred").Struct.Field("c").Alias("c"),
+            Col("typed").Struct.Field("a").Alias("a")
+        );
+        Assert.Null(fields.GetValue<bool?>(0, "c"));
+        Assert.True(fields.GetValue<bool>(1, "c"));
+        Assert.Equal(2L, fields.GetValue<long>(1, "a"));
+
+        Assert.Equal("x", res.GetValue<string>(0, "b"));
+        Assert.Equal("y", res.GetValue<string>(1, "b"));
+        Assert.Null(res.GetValue<string?>(2, "b"));
+    }
+    [Fact]
+    public void Test_String_Head_Tail_LenChars_Reverse()
+    {
+        using var s = new Series("s", new string?[] { "héllo", "ab" });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            Col("s").Str.LenBytes().Alias("bytes"),
+            Col("s").Str.LenChars().Alias("chars"),
+            Col("s").Str.Head(2).Alias("head"),
+            Col("s").Str.Head(-1).Alias("head_neg"),
+            Col("s").Str.Tail(-1).Alias("tail_neg"),
+            Col("s").Str.Reverse().Alias("rev")
+        );
+
+        // é 在 UTF-8 下占 2 个字节
+        Assert.Equal(6, res.GetValue<int>(0, "bytes"));
+        Assert.Equal(5, res.GetValue<int>(0, "chars"));
+
+        // head / tail 按字符截取，不会切断多字节字符
+        Assert.Equal("hé", res.GetValue<string>(0, "head"));
+        Assert.Equal("héll", res.GetValue<string>(0, "head_neg"));
+        Assert.Equal("éllo", res.GetValue<string>(0, "tail_neg"));
+        Assert.Equal("olléh", res.GetValue<string>(0, "rev"));
+        Assert.Equal("ab", res.GetValue<string>(1, "head"));
+    }
+    [Fact]
+    public void Test_ConcatStr_And_Str_Join()
+    {
+        using var a = new Series("a", new string?[] { "x", "y", "x" });
+        using var b = new Series("b", new string?[] { "1", null, "3" });
+        using var df = new DataFrame(a, b);
+
+        // 横向拼接
+        using var horizontal = df.Select(
+            ConcatStr([Col("a"), Col("b")], "-").Alias("strict"),
+            ConcatStr([Col("a"), Col("b")], "-", ignoreNulls: true).Alias("loose")
+        );
+        Assert.Equal("x-1", horizontal.GetValue<string>(0, "strict"));
+        Assert.Null(horizontal.GetValue<string>(1, "strict"));
+        Assert.Equal("y", horizontal.GetValue<string>(1, "loose"));
+
+        // 纵向聚合
+        using var whole = df.Select(
+            Col("b").Str.Join(",").Alias("skip"),
+            Col("b").Str.Join(",", ignoreNulls: false).Alias("keep")
+        );
+        Assert.Equal("1,3", whole.GetValue<string>(0, "skip"));
+        Assert.Null(whole.GetValue<string>(0, "keep"));
+
+        // group_by 中每组拼一个字符串
+        using var grouped = df
+            .GroupBy(Col("a"))
+            .Agg(Col("b").Str.Join("|").Alias("joined"))
+            .Sort(Col("a"));
+        Assert.Equal("1|3", grouped.GetValue<string>(0, "joined"));
+        Assert.Equal("", grouped.GetValue<string>(1, "joined"));
+    }
+    [Fact]
+    public void Temporal_Week_And_Quarter()
+    {
+        var csvContent = "d\n2024-01-01\n2023-01-01\n2024-12-30\n2024-05-15";
+        using var csv = new DisposableFile(csvContent, ".csv");
+        using var df = DataFrame.ReadCsv(csv.Path, tryParseDates: true);
+
+        using var res = df.Select(
+            Col("d").Dt.Week().Alias("week"),
+            Col("d").Dt.Quarter().Alias("q")
+        );
+
+        // ISO 周: 2023-01-01 属于 2022 年第 52 周，2024-12-30 属于 2025 年第 1 周
+        Assert.Equal(1, res.GetValue<int>(0, "week"));
+        Assert.Equal(52, res.GetValue<int>(1, "week"));
+        Assert.Equal(1, res.GetValue<int>(2, "week"));
+        Assert.Equal(20, res.GetValue<int>(3, "week"));
+
+        Assert.Equal(1, res.GetValue<int>(0, "q"));
+        Assert.Equal(1, res.GetValue<int>(1, "q"));
+        Assert.Equal(4, res.GetValue<int>(2, "q"));
+        Assert.Equal(2, res.GetValue<int>(3, "q"));
+    }
+    [Fact]
+    public void Test_Dt_Truncate_Round_With_Expr_Interval()
+    {
+        using var ts = new Series("ts", ["2023-01-01 10:37:55", "2023-01-01 10:45:10"]);
+        using var bucket = new Series("bucket", ["15m", "1h"]);
+        using var df = new DataFrame(ts, bucket);
+
+        using var res = df
+            .Select(
+                Col("ts").Str.ToDatetime("%Y-%m-%d %H:%M:%S").Alias("ts"),
+                Col("bucket")
+            )
+            .Select(
+                // 每行使用自己的分桶粒度
+                Col("ts").Dt.Truncate(Col("bucket")).Alias("trunc"),
+                Col("ts").Dt.Round(Lit("15m")).Alias("round")
+            );
+
+        Assert.Equal(new DateTime(2023, 1, 1, 10, 30, 0), res.GetValue<DateTime>(0, "trunc"));
+        Assert.Equal(new DateTime(2023, 1, 1, 10, 0, 0), res.GetValue<DateTime>(1, "trunc"));
+        Assert.Equal(new DateTime(2023, 1, 1, 10, 45, 0), res.GetValue<DateTime>(0, "round"));
+        Assert.Equal(new DateTime(2023, 1, 1, 10, 45, 0), res.GetValue<DateTime>(1, "round"));
+    }
+    [Fact]
+    public void Test_Dt_OffsetBy_Calendar_Durations()
+    {
+        using var d = new Series("d", new DateOnly[] { new(2024, 1, 31), new(2024, 2, 29) });
+        using var ts = new Series("ts", new DateTime[] { new(2024, 1, 1, 8, 0, 0), new(2024, 3, 10, 20, 30, 0) });
+        using var df = new DataFrame(d, ts);
+
+        using var res = df.Select(
+            Col("d").Dt.OffsetBy("1mo").Alias("plus_month"),
+            Col("d").Dt.OffsetBy("1y").Alias("plus_year"),
+            Col("d").Dt.OffsetBy("-2w").Alias("minus_2w"),
+            Col("ts").Dt.OffsetBy("3d12h").Alias("plus_3d12h")
+        );
+
+        // 按月/年偏移会钳制到月末
+        Assert.Equal(new DateOnly(2024, 2, 29), res.GetValue<DateOnly>(0, "plus_month"));
+        Assert.Equal(new DateOnly(2024, 3, 29), res.GetValue<DateOnly>(1, "plus_month"));
+        Assert.Equal(new DateOnly(2025, 2, 28), res.GetValue<DateOnly>(1, "plus_year"));
+        Assert.Equal(new DateOnly(2024, 1, 17), res.GetValue<DateOnly>(0, "minus_2w"));
+        Assert.Equal(new DateTime(2024, 1, 4, 20, 0, 0), res.GetValue<DateTime>(0, "plus_3d12h"));
+
+        // 非法 Duration 在构建表达式时就报错
+        Assert.Throws<Exception>(() => Col("d").Dt.OffsetBy("1xyz"));
+    }
+    [Fact]
+    public void Test_Dt_Strftime()
+    {
+        using var d = new Series("d", new DateOnly[] { new(2024, 1, 2) });
+        using var ts = new Series("ts", new DateTime[] { new(2024, 1, 2, 3, 4, 5) });
+        using var df = new DataFrame(d, ts);
+
+        using var res = df.Select(
+            Col("d").Dt.Strftime().Alias("d_iso"),
+            Col("ts").Dt.Strftime("%d/%m/%Y %H:%M").Alias("ts_custom"),
+            Col("ts").Dt.Strftime("iso:strict").Alias("ts_strict")
+        );
+
+        Assert.Equal("2024-01-02", res.GetValue<string>(0, "d_iso"));
+        Assert.Equal("02/01/2024 03:04", res.GetValue<string>(0, "ts_custom"));
+        // 小数秒位数取决于时间单位，这里只校验 'T' 分隔
+        Assert.StartsWith("2024-01-02T03:04:05", res.GetValue<string>(0, "ts_strict"));
+    }
+    [Fact]
+    public void Test_Dt_Total_Duration_Units()
+    {
+        var elapsed = new TimeSpan(1, 2, 30, 15, 250);
+        using var s = new Series("dur", new[] { elapsed, TimeSpan.FromMinutes(-90) });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            Col("dur").Dt.TotalDays().Alias("days"),
+            Col("dur").Dt.TotalHours().Alias("hours"),
+            Col("dur").Dt.TotalMinutes().Alias("minutes"),
+            Col("dur").Dt.TotalSeconds().Alias("seconds"),
+            Col("dur").Dt.TotalMilliseconds().Alias("ms"),
+            Col("dur").Dt.TotalMicroseconds().Alias("us"),
+            Col("dur").Dt.TotalNanoseconds().Alias("ns")
+        );
+
+        Assert.Equal(1, res.GetValue<long>(0, "days"));
+        Assert.Equal(26, res.GetValue<long>(0, "hours"));
+        Assert.Equal(26 * 60 + 30, res.GetValue<long>(0, "minutes"));
+        Assert.Equal((long)elapsed.TotalSeconds, res.GetValue<long>(0, "seconds"));
+        Assert.Equal((long)elapsed.TotalMilliseconds, res.GetValue<long>(0, "ms"));
+        Assert.Equal((long)elapsed.TotalMilliseconds * 1000, res.GetValue<long>(0, "us"));
+        Assert.Equal((long)elapsed.TotalMilliseconds * 1_000_000, res.GetValue<long>(0, "ns"));
+
+        // 向零截断: -90 分钟 = -1 小时
+        Assert.Equal(-1, res.GetValue<long>(1, "hours"));
+        Assert.Equal(-90, res.GetValue<long>(1, "minutes"));
+    }
+    [Fact]
+    public void Test_IsOutlierIqr_And_ZScore()
+    {
+        using var s = new Series("v", new[] { 1.0, 2.0, 3.0, 4.0, 100.0 });
+        using var df = new DataFrame(s);
+
+        using var res = df.Select(
+            Col("v").IsOutlierIqr().Alias("outlier"),
+            Col("v").IsOutlierIqr(100).Alias("loose"),
+            Col("v").ZScore().Alias("z")
+        );
+
+        // Q1=2, Q3=4, IQR=2 -> 上界 7
+        Assert.False(res.GetValue<bool>(3, "outlier"));
+        Assert.True(res.GetValue<bool>(4, "outlier"));
+        Assert.False(res.GetValue<bool>(4, "loose"));
+
+        using var z = df.Select(Col("v").ZScore().Alias("z"));
+        using var stats = z.Select(
+            Col("z").Mean().Alias("mean"),
+            Col("z").Std().Alias("std")
+        );
+        Assert.Equal(0.0, stats.GetValue<double>(0, "mean"), 9);
+        Assert.Equal(1.0, stats.GetValue<double>(0, "std"), 9);
+        Assert.True(res.GetValue<double>(4, "z") > 1.5);
+    }
+    [Fact]
+    public void Test_Weighted_Mean_Sum_And_Dot()
+    {
+        using var px = new Series("px", new[] { 10.0, 20.0, 0.0 }, new[] { true, true, false });
+        using var w = new Series("w", new[] { 1.0, 3.0, 100.0 });
+        using var df = new DataFrame(px, w);
+
+        using var res = df.Select(
+            Col("px").WeightedMean(Col("w")).Alias("wmean"),
+            Col("px").WeightedSum(Col("w")).Alias("wsum"),
+            Col("w").Dot(Col("w")).Alias("dot")
+        );
+
+        // 第 3 行 px 为 Null，其权重 100 不计入分母: (10*1 + 20*3) / (1 + 3)
+        Assert.Equal(17.5, res.GetValue<double>(0, "wmean"));
+        Assert.Equal(70.0, res.GetValue<double>(0, "wsum"));
+        Assert.Equal(1.0 + 9.0 + 10000.0, res.GetValue<double>(0, "dot"));
+
+        // group_by 中按组计算
+        using var g = new Series("g", new string?[] { "a", "a", "b" });
+        using var grouped = new DataFrame(g, w)
+            .GroupBy(Col("g"))
+            .Agg(Col("w").WeightedMean(Col("w")).Alias("self_weighted"))
+            .Sort(Col("g"));
+        Assert.Equal(10.0 / 4.0, grouped.GetValue<double>(0, "self_weighted"));
+    }
+    [Fact]
+    public void Test_Fraction_Of_Total()
+    {
+        using var g = new Series("Group", new string?[] { "A", "A", "B", "B" });
+        using var v = new Series("Value", new[] { 10, 30, 50, 150 });
+        using var df = new DataFrame(g, v);
+
+        using var res = df.Select(
+            Col("Value").FractionOfTotal(Col("Group")).Alias("InGroup"),
+            // 无分组列: 按全表求和
+            Col("Value").FractionOfTotal().Alias("InTable")
+        );
+
+        // Int32 输入也返回 Float64
+        Assert.Equal(0.25, res.GetValue<double>(0, "InGroup"));
+        Assert.Equal(0.75, res.GetValue<double>(1, "InGroup"));
+        Assert.Equal(0.25, res.GetValue<double>(2, "InGroup"));
+        Assert.Equal(0.75, res.GetValue<double>(3, "InGroup"));
+
+        Assert.Equal(10.0 / 240.0, res.GetValue<double>(0, "InTable"), 10);
+        Assert.Equal(150.0 / 240.0, res.GetValue<double>(3, "InTable"), 10);
+    }
+    [Fact]
+    public void Test_Streaks()
+    {
+        using var active = new Series("active", new[] { true, true, false, true, true, true }, new[] { true, true, true, true, false, true });
+        using var price = new Series("price", new[] { 1, 2, 3, 3, 2, 1 });
+        using var df = new DataFrame(active, price);
+
+        using var res = df.Select(
+            Col("active").StreakLength().Alias("run"),
+            Col("price").IncreasingStreak().Alias("up"),
+            Col("price").DecreasingStreak().Alias("down")
+        );
+
+        // 第 5 行 active 为 Null，视为中断
+        Assert.Equal(new uint[] { 1, 2, 0, 1, 0, 1 }, res.Column("run").ToArray<uint>());
+        // 第 1 行没有前值 -> 0; 持平 (3 -> 3) 也归零
+        Assert.Equal(new uint[] { 0, 1, 2, 0, 0, 0 }, res.Column("up").ToArray<uint>());
+        Assert.Equal(new uint[] { 0, 0, 0, 0, 1, 2 }, res.Column("down").ToArray<uint>());
+    }
+    [Fact]
+    public void Test_Struct_Fields_And_WithFields()
+    {
+        using var a = new Series("a", new[] { 1, 2 });
+        using var b = new Series("b", new string?[] { "x", "y" });
+        using var df = new DataFrame(a, b);
+
+        using var packed = df.Select(AsStruct(Col("a"), Col("b")).Alias("s"));
+
+        // 一次取多个字段 -> 多列
+        using var picked = packed.Select(Col("s").Struct.Fields("a", "b"));
+        Assert.Equal(new[] { "a", "b" }, picked.Columns);
+        Assert.Equal("y", picked.GetValue<string>(1, "b"));
+
+        // "*" 取全部字段
+        using var all = packed.Select(Col("s").Struct.Fields("*"));
+        Assert.Equal(new[] { "a", "b" }, all.Columns);
+
+        // WithFields: 新增字段 + 覆盖同名字段，Field() 引用当前 struct 的字段
+        using var modified = packed.Select(
+            Col("s").Struct.WithFields(
+                (Field("a") * 10).Alias("a10"),
+                Lit("z").Alias("b")
+            ).Alias("s")
+        ).Select(Col("s").Struct.Fields("*"));
+
+        Assert.Equal(new[] { "a", "b", "a10" }, modified.Columns);
+        Assert.Equal(20, modified.GetValue<int>(1, "a10"));
+        Assert.Equal("z", modified.GetValue<string>(0, "b"));
+
+        // 不存在的字段报错
+        Assert.Throws<Exception>(() => packed.Select(Col("s").Struct.Fields("missing")));
+    }
+    [Fact]
+    public void Test_Row_Json_Encode()
+    {
+        using var id = new Series("id", new[] { 1, 2 });
+        using var name = new Series("name", new string?[] { "a", null });
+        using var df = new DataFrame(id, name);
+
+        using var res = df.Select(RowJsonEncode(Col("id"), Col("name")).Alias("payload"));
+
+        Assert.Equal(DataTypeKind.String, res.Schema["payload"].Kind);
+        Assert.Equal("{\"id\":1,\"name\":\"a\"}", res.GetValue<string>(0, "payload"));
+        // Null 字段编码为 JSON null
+        Assert.Equal("{\"id\":2,\"name\":null}", res.GetValue<string>(1, "payload"));
+
+        // 不 Alias 时沿用第一个表达式的列名
+        using var unnamed = df.Select(RowJsonEncode(Col("id"), Col("name")));
+        Assert.Equal(new[] { "id" }, unnamed.Columns);
+    }
+    [Fact]
+    public void Test_Name_Ops()
+    {
+        using var df = new DataFrame(
+            new Series("Price", new[] { 1, 2 }),
+            new Series("Qty", new[] { 3, 4 })
+        );
+
+        using var res = df.Select(
+            (Col("Price") * 2).Alias("tmp").Name.Keep(),
+            Col("Price").Name.ToLowercase(),
+            Col("Qty").Name.ToUppercase(),
+            Col("Qty").Name.Prefix("p_"),
+            Col("Qty").Name.Suffix("_s"),
+            Col("Qty").Name.Map(n => $"{n}_{n.Length}")
+        );
+
+        Assert.Equal(new[] { "Price", "price", "QTY", "p_Qty", "Qty_s", "Qty_3" }, res.Columns);
+        Assert.Equal(4, res.GetValue<int>(1, "Price"));
+
+        // 回调抛异常时错误传回 C#
+        Assert.Throws<Exception>(() =>
+            df.Select(Col("Qty").Name.Map(_ => throw new InvalidOperationException("bad name"))));
+    }
+    [Fact]
+    public void Test_Expr_Meta()
+    {
+        using var e = (Col("a") + Col("b")).Alias("total");
+
+        Assert.Equal("total", e.Meta.OutputName());
+        Assert.Equal(new[] { "a", "b" }, e.Meta.RootNames().OrderBy(x => x).ToArray());
+        Assert.Empty(Lit(1).Meta.RootNames());
+
+        var tree = e.Meta.TreeFormat();
+        Assert.Contains("col(a)", tree);
+        Assert.StartsWith("graph", e.Meta.TreeFormat(asDot: true));
+
+        // 结构相等
+        Assert.True(e.Meta.Eq((Col("a") + Col("b")).Alias("total")));
+        Assert.False(e.Meta.Eq((Col("a") - Col("b")).Alias("total")));
+
+        // Meta 只借用，表达式仍可使用
+        using var df = new DataFrame(new Series("a", new[] { 1 }), new Series("b", new[] { 2 }));
+        Assert.Equal(3, df.Select(e).GetValue<int>(0, "total"));
+
+        // 没有确定输出名
+        Assert.Throws<Exception>(() => All().ToExpr().Meta.OutputName());
+    }
+    [Fact]
+    public void Test_Expr_Serialize_RoundTrip()
+    {
+        using var e = (Col("a") * 2).Alias("b");
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3 }));
+
+        // 二进制
+        var bytes = e.Serialize();
+        Assert.NotEmpty(bytes);
+        using var fromBin = Expr.Deserialize(bytes);
+        Assert.True(e.Meta.Eq(fromBin));
+
+        // JSON
+        var json = e.SerializeJson();
+        Assert.Contains("\"Column\":\"a\"", json);
+        using var fromJson = Expr.DeserializeJson(json);
+        using var res = df.Select(fromJson);
+        Assert.Equal("b", res.Columns[0]);
+        Assert.Equal(6, res.GetValue<int>(2, "b"));
+
+        Assert.Throws<Exception>(() => Expr.DeserializeJson("not json"));
+    }
+    [Fact]
+    public void Test_Typed_Literals()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2 }));
+
+        using var res = df.Select(
+            Col("a"),
+            Lit(new DateOnly(2024, 2, 29)).Alias("date"),
+            Lit(new TimeOnly(13, 45, 10)).Alias("time"),
+            Lit(TimeSpan.FromMinutes(90)).Alias("dur"),
+            LitDatetime(new DateTime(2024, 1, 1, 8, 0, 0), TimeUnit.Milliseconds, "UTC").Alias("dt"),
+            Lit(12.34m).Alias("dec"),
+            Lit(-12345678901234567890.12m).Alias("wide"),
+            Lit(new byte[] { 1, 2, 3 }).Alias("bin"),
+            LitNull(DataType.Int64).Alias("nul"),
+            (Col("a") + Lit(0.5m)).Alias("sum")
+        );
+
+        Assert.Equal(DataTypeKind.Date, res.Schema["date"].Kind);
+        Assert.Equal(DataTypeKind.Time, res.Schema["time"].Kind);
+        Assert.Equal(DataTypeKind.Duration, res.Schema["dur"].Kind);
+        Assert.Equal(DataTypeKind.Datetime, res.Schema["dt"].Kind);
+        Assert.Contains("UTC", res.Schema["dt"].ToString());
+        Assert.Equal(DataTypeKind.Decimal, res.Schema["dec"].Kind);
+        Assert.Equal(DataTypeKind.Binary, res.Schema["bin"].Kind);
+        // 带类型的 Null 不会被推断成 Null 类型
+        Assert.Equal(DataTypeKind.Int64, res.Schema["nul"].Kind);
+
+        Assert.Equal(new DateOnly(2024, 2, 29), res.GetValue<DateOnly>(1, "date"));
+        Assert.Equal(new TimeOnly(13, 45, 10), res.GetValue<TimeOnly>(0, "time"));
+        Assert.Equal(TimeSpan.FromMinutes(90), res.GetValue<TimeSpan>(0, "dur"));
+        Assert.Equal(12.34m, res.GetValue<decimal>(0, "dec"));
+        // 放大后超过 64 位的负数，高位和符号位都要完整传过去
+        Assert.Equal(-12345678901234567890.12m, res.GetValue<decimal>(0, "wide"));
+        Assert.Equal(2.5m, res.GetValue<decimal>(1, "sum"));
+        Assert.Null(res[0, "nul"]);
+
+        // Datetime 字面量只支持 ns / us / ms
+        Assert.Throws<ArgumentOutOfRangeException>(() => LitDatetime(DateTime.UnixEpoch, TimeUnit.Second));
+    }
+    [Fact]
+    public void Test_Lit_Series_And_IsIn()
+    {
+        using var df = new DataFrame(new Series("x", new string?[] { "a", "b", "c", null }));
+        using var allow = new Series("allow", new string?[] { "b", "c", null });
+
+        using var res = df.Select(
+            Col("x").IsIn(allow).Alias("plain"),
+            Col("x").IsIn(allow, nullsEqual: true).Alias("nulls"),
+            Col("x").IsIn(Lit(allow).Implode()).Alias("by_expr")
+        );
+
+        Assert.False(res.GetValue<bool>(0, "plain"));
+        Assert.True(res.GetValue<bool>(1, "plain"));
+        Assert.Null(res[3, "plain"]);
+        Assert.True(res.GetValue<bool>(3, "nulls"));
+        Assert.True(res.GetValue<bool>(2, "by_expr"));
+
+        // Lit(Series) 直接当作一列使用，原 Series 仍然可用
+        using var asCol = df.Select(Lit(new Series("y", new[] { 1, 2, 3, 4 })));
+        Assert.Equal(new[] { "y" }, asCol.Columns);
+        Assert.Equal(4, asCol.GetValue<int>(3, "y"));
+        Assert.Equal(3, allow.Length);
+    }
+    [Fact]
+    public void Test_Horizontal_And_Coalesce()
+    {
+        using var df = new DataFrame(
+            new Series("a", new[] { 1, 0, 5 }, new[] { true, false, true }),
+            new Series("b", new[] { 4, 2, 0 }, new[] { true, true, false }),
+            new Series("p", new[] { true, false, false }),
+            new Series("q", new[] { false, false, true })
+        );
+
+        using var res = df.Select(
+            MinHorizontal(Col("a"), Col("b")).Alias("min"),
+            MaxHorizontal(Col("a"), Col("b")).Alias("max"),
+            SumHorizontal(Col("a"), Col("b")).Alias("sum"),
+            SumHorizontal(new[] { Col("a"), Col("b") }, ignoreNulls: false).Alias("sum_strict"),
+            MeanHorizontal(Col("a"), Col("b")).Alias("mean"),
+            Coalesce(Col("a"), Col("b")).Alias("co"),
+            AnyHorizontal(Col("p"), Col("q")).Alias("any"),
+            AllHorizontal(Col("p"), Col("q")).Alias("all")
+        );
+
+        Assert.Equal(1, res.GetValue<int>(0, "min"));
+        Assert.Equal(4, res.GetValue<int>(0, "max"));
+        Assert.Equal(2, res.GetValue<int>(1, "sum"));
+        Assert.Equal(5, res.GetValue<int>(0, "sum_strict"));
+        Assert.Null(res[1, "sum_strict"]);
+        Assert.Equal(2.5, res.GetValue<double>(0, "mean"));
+        Assert.Equal(2, res.GetValue<int>(1, "co"));
+        Assert.Equal(5, res.GetValue<int>(2, "co"));
+        Assert.True(res.GetValue<bool>(2, "any"));
+        Assert.False(res.GetValue<bool>(1, "any"));
+        Assert.False(res.GetValue<bool>(0, "all"));
+    }
+    [Fact]
+    public void Test_Cast_Wrap_Numerical()
+    {
+        using var df = new DataFrame(new Series("x", new[] { 10, 300 }));
+
+        using var wrapped = df.Select(Col("x").Cast(DataType.UInt8, strict: true, wrapNumerical: true));
+        Assert.Equal(DataTypeKind.UInt8, wrapped.Schema["x"].Kind);
+        Assert.Equal(44L, Convert.ToInt64(wrapped[1, "x"]));
+
+        using var lenient = df.Select(Col("x").Cast(DataType.UInt8, strict: false, wrapNumerical: false));
+        Assert.Equal(10L, Convert.ToInt64(lenient[0, "x"]));
+        Assert.Null(lenient[1, "x"]);
+
+        Assert.Throws<Exception>(() => df.Select(Col("x").Cast(DataType.UInt8, strict: true, wrapNumerical: false)));
+    }
+    [Fact]
+    public void Test_Len_NullCount_HasNulls()
+    {
+        using var df = new DataFrame(
+            new Series("g", new[] { "a", "a", "b" }),
+            new Series("x", new[] { 1, 0, 3 }, new[] { true, false, true })
+        );
+
+        using var res = df.Select(
+            Len(),
+            Col("x").Count().Alias("count"),
+            Col("x").NullCount().Alias("nulls"),
+            Col("x").HasNulls().Alias("has_nulls"),
+            Col("g").HasNulls().Alias("g_has_nulls")
+        );
+        Assert.Equal(3L, Convert.ToInt64(res[0, "len"]));
+        Assert.Equal(2L, Convert.ToInt64(res[0, "count"]));
+        Assert.Equal(1L, Convert.ToInt64(res[0, "nulls"]));
+        Assert.True(res.GetValue<bool>(0, "has_nulls"));
+        Assert.False(res.GetValue<bool>(0, "g_has_nulls"));
+
+        // group_by 中 Len() 为每组大小
+        using var agg = df.GroupBy(Col("g")).Agg(Len(), Col("x").HasNulls().Alias("has_nulls")).Sort("g");
+        Assert.Equal(2L, Convert.ToInt64(agg[0, "len"]));
+        Assert.True(agg.GetValue<bool>(0, "has_nulls"));
+        Assert.False(agg.GetValue<bool>(1, "has_nulls"));
+    }
+    [Fact]
+    public void Test_Flatten_DropNulls_Filter()
+    {
+        using var df = new DataFrame(
+            new Series("g", new[] { "a", "a", "b", "b" }),
+            new Series("x", new[] { 1, 0, 3, 4 }, new[] { true, false, true, true }),
+            new Series("y", new[] { 5, -1, -2, 6 })
+        );
+
+        using var dropped = df.Select(Col("x").DropNulls());
+        Assert.Equal(3, dropped.Height);
+
+        // 条件聚合：只对 y > 0 的行求和
+        using var agg = df
+            .GroupBy(Col("g"))
+            .Agg(
+                Col("x").Filter(Col("y") > 0).Sum().Alias("pos_sum"),
+                Col("x").Alias("xs")
+            )
+            .Sort("g");
+        Assert.Equal(1, agg.GetValue<int>(0, "pos_sum"));
+        Assert.Equal(4, agg.GetValue<int>(1, "pos_sum"));
+
+        // Flatten 把 List 展开回多行
+        using var flat = agg.Select(Col("xs").Flatten());
+        Assert.Equal(4, flat.Height);
+        Assert.Equal(3, flat.GetValue<int>(2, "xs"));
+    }
+    [Fact]
+    public void Test_Cut_And_QCut()
+    {
+        using var df = new DataFrame(new Series("x", new[] { 1.0, 2.0, 3.0, 4.0, 5.0, 6.0 }));
+
+        using var res = df.Select(
+            Col("x").Cut(new[] { 2.0, 4.0 }).Alias("cut"),
+            Col("x").Cut(new[] { 2.0, 4.0 }, new[] { "lo", "mid", "hi" }, leftClosed: true).Alias("lab"),
+            Col("x").QCut(2).Alias("q2"),
+            Col("x").QCut(new[] { 0.5 }, new[] { "low", "high" }).Alias("q")
+        );
+        Assert.Equal(DataTypeKind.Categorical, res.Schema["cut"].Kind);
+
+        using var str = res.Select(All().ToExpr().Cast(DataType.String));
+        Assert.Equal("(-inf, 2]", str.GetValue<string>(0, "cut"));
+        Assert.Equal("(4, inf]", str.GetValue<string>(5, "cut"));
+        Assert.Equal("mid", str.GetValue<string>(1, "lab"));
+        Assert.Equal("(3.5, inf]", str.GetValue<string>(3, "q2"));
+        Assert.Equal("high", str.GetValue<string>(5, "q"));
+
+        // 标签个数必须是 breaks + 1
+        Assert.Throws<Exception>(() => df.Select(Col("x").Cut(new[] { 2.0 }, new[] { "only" })));
+    }
+    [Fact]
+    public void Test_Skew_Kurtosis_Entropy()
+    {
+        using var df = new DataFrame(
+            new Series("x", new[] { 1.0, 2.0, 3.0, 4.0 }),
+            new Series("p", new[] { 1.0, 1.0, 1.0, 1.0 })
+        );
+
+        using var res = df.Select(
+            Col("x").Skew().Alias("skew"),
+            Col("x").Kurtosis().Alias("kurt"),
+            Col("x").Kurtosis(fisher: false).Alias("kurt_pearson"),
+            Col("p").Entropy(logBase: 2).Alias("entropy")
+        );
+
+        // 对称分布偏度为 0
+        Assert.Equal(0.0, res.GetValue<double>(0, "skew"), 9);
+        // m4 / m2^2 = 2.5625 / 1.5625
+        Assert.Equal(1.64, res.GetValue<double>(0, "kurt_pearson"), 9);
+        Assert.Equal(-1.36, res.GetValue<double>(0, "kurt"), 9);
+        // 4 个等概率事件 -> 2 bit
+        Assert.Equal(2.0, res.GetValue<double>(0, "entropy"), 9);
+    }
+    [Fact]
+    public void Test_Corr_And_Cov()
+    {
+        using var df = new DataFrame(
+            new Series("x", new[] { 1.0, 2.0, 3.0, 4.0 }),
+            new Series("y", new[] { 2.0, 4.0, 6.0, 8.0 }),
+            new Series("z", new[] { 1.0, 8.0, 27.0, 64.0 })
+        );
+
+        using var res = df.Select(
+            Corr(Col("x"), Col("y")).Alias("pearson"),
+            SpearmanRankCorr(Col("x"), Col("z")).Alias("spearman"),
+            Cov(Col("x"), Col("y")).Alias("cov")
+        );
+        Assert.Equal(1.0, res.GetValue<double>(0, "pearson"), 9);
+        // 单调但非线性，秩相关仍为 1
+        Assert.Equal(1.0, res.GetValue<double>(0, "spearman"), 9);
+        // var(x) = 5/3，cov(x, 2x) = 10/3
+        Assert.Equal(10.0 / 3.0, res.GetValue<double>(0, "cov"), 9);
+
+        using var rolling = df.Select(
+            RollingCov(Col("x"), Col("y"), 2).Alias("rcov"),
+            RollingCorr(Col("x"), Col("y"), 3, minPeriods: 2).Alias("rcorr")
+        );
+        Assert.Null(rolling[0, "rcov"]);
+        Assert.Equal(1.0, rolling.GetValue<double>(1, "rcov"), 9);
+        Assert.Equal(1.0, rolling.GetValue<double>(1, "rcorr"), 9);
+    }
+    [Fact]
+    public void Test_Distinct_Ops()
+    {
+        using var df = new DataFrame(new Series("x", new[] { "b", "a", "b", "c", "a", "b" }));
+
+        using var flags = df.Select(
+            Col("x").IsUnique().Alias("unique"),
+            Col("x").IsDuplicated().Alias("dup"),
+            Col("x").IsFirstDistinct().Alias("first"),
+            Col("x").IsLastDistinct().Alias("last")
+        );
+        Assert.True(flags.GetValue<bool>(3, "unique"));
+        Assert.True(flags.GetValue<bool>(0, "dup"));
+        Assert.True(flags.GetValue<bool>(0, "first"));
+        Assert.False(flags.GetValue<bool>(2, "first"));
+        Assert.True(flags.GetValue<bool>(5, "last"));
+        Assert.False(flags.GetValue<bool>(0, "last"));
+
+        using var n = df.Select(Col("x").NUnique());
+        Assert.Equal(3L, Convert.ToInt64(n[0, "x"]));
+
+        // 保持首次出现顺序: b, a, c
+        using var uniq = df.Select(
+            Col("x").Unique(maintainOrder: true).Alias("u"),
+            Col("x").ArgUnique().Alias("idx"),
+            Col("x").UniqueCounts().Alias("counts")
+        );
+        Assert.Equal(new[] { "b", "a", "c" }, uniq["u"].ToArray<string>());
+        Assert.Equal(new[] { 0L, 1L, 3L }, uniq["idx"].ToArray<uint>().Select(v => (long)v).ToArray());
+        Assert.Equal(new[] { 3L, 2L, 1L }, uniq["counts"].ToArray<uint>().Select(v => (long)v).ToArray());
+    }
+
+    [Fact]
+    public void Test_Repeat_And_ExtendConstant()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1L, 2L }));
+
+        using var rep = df.Select(Repeat(Lit(7L), 3));
+        Assert.Equal(3, rep.Height);
+        Assert.Equal("repeat", rep.Columns[0]);
+        Assert.Equal(7L, Convert.ToInt64(rep[2, "repeat"]));
+
+        using var casted = df.Select(Repeat(Lit(1), 2, DataType.Float64));
+        Assert.Equal(1.0, casted.GetValue<double>(1, "repeat"));
+
+        using var ext = df.Select(Col("a").ExtendConstant(Lit(0L), 2));
+        Assert.Equal(new[] { 1L, 2L, 0L, 0L }, ext["a"].ToArray<long>());
+    }
+}
@@ -514,6 +514,386 @@ namespace Polars.CSharp.Tests
             // 验证 null 处理
             Assert.Equal(DBNull.Value, targetTable.Rows[1]["Date"]);
         }
+        [Fact]
+        public void Test_Parquet_Descriptions_RoundTrip()
+        {
+            using var s1 = new Series("id", new int[]{1, 2});
+            using var s2 = new Series("name", new string[]{"a", "b"});
+            using var df = new DataFrame(s1, s2);
+
+            using var f = new DisposableFile(".parquet");
+            df.WriteParquet(f.Path, "用户表", new Dictionary<string, string>
+            {
+                ["id"] = "主键",
+                ["name"] = "用户名"
+            });
+
+            // 1. 描述读回来
+            var (table, columns) = DataFrame.ReadParquetDescriptions(f.Path);
+            Assert.Equal("用户表", table);
+            Assert.Equal("主键", columns["id"]);
+            Assert.Equal("用户名", columns["name"]);
+
+            // 2. 原始 key_value_metadata 里能看到表描述
+            var kv = DataFrame.ReadParquetMetadata(f.Path);
+            Assert.Equal("用户表", kv["polars_net.description"]);
+
+            // 3. 数据本身不受影响
+            using var dfRead = DataFrame.ReadParquet(f.Path);
+            Assert.Equal(2, dfRead.Height);
+            Assert.Equal("b", dfRead.GetValue<string>(1, "name"));
+        }
+
+        [Fact]
+        public void Test_Parquet_Descriptions_Missing_Column_Throws()
+        {
+            using var s = new Series("id", new int[]{1});
+            using var df = new DataFrame(s);
+            using var f = new DisposableFile(".parquet");
+
+            // 描述不存在的列应该报错，而不是静默写入
+            Assert.Throws<Exception>(() =>
+                df.WriteParquet(f.Path, null, new Dictionary<string, string> { ["nope"] = "x" }));
+        }
+
+        [Fact]
+        public void Test_Parquet_Descriptions_Absent()
+        {
+            using var s = new Series("id", new int[]{1});
+            using var df = new DataFrame(s);
+            using var f = new DisposableFile(".parquet");
+            df.WriteParquet(f.Path);
+
+            var (table, columns) = DataFrame.ReadParquetDescriptions(f.Path);
+            Assert.Null(table);
+            Assert.Empty(columns);
+        }
+        [Fact]
+        public void Test_Csv_Read_With_Options()
+        {
+            // 注释行、分号分隔、引号内分隔符、自定义 null、小数逗号、缺字段
+            var csvContent = "# exported\nid;name;score\n1;\"a;b\";1,5\n2;NA;2,5\n3;c\n";
+            using var csv = new DisposableFile(csvContent, ".csv");
+
+            var options = new CsvReadOptions
+            {
+                Separator = ';',
+                CommentPrefix = "#",
+                NullValues = new[] { "NA" },
+                DecimalComma = true
+            };
+
+            using var df = DataFrame.ReadCsv(csv.Path, options);
+            Assert.Equal(new[] { "id", "name", "score" }, df.Columns);
+            Assert.Equal(3, df.Height);
+            Assert.Equal("a;b", df.GetValue<string>(0, "name"));
+            Assert.Null(df.GetValue<string>(1, "name"));
+            Assert.Equal(2.5, df.GetValue<double>(1, "score"));
+            Assert.Null(df.GetValue<double?>(2, "score"));
+
+            // Scan + NRows
+            options.NRows = 2;
+            using var scanned = LazyFrame.ScanCsv(csv.Path, options).Collect();
+            Assert.Equal(2, scanned.Height);
+            Assert.Equal(new long[] { 1, 2 }, scanned["id"].ToArray<long>());
+
+            // 显式 schema 覆盖推断
+            var schema = new Dictionary<string, DataType> { ["id"] = DataType.Int32 };
+            using var typed = DataFrame.ReadCsv(csv.Path, options, schema);
+            Assert.Equal(DataTypeKind.Int32, typed.Schema["id"].Kind);
+        }
+        [Fact]
+        public void Test_Csv_Write_With_Options()
+        {
+            using var df = new DataFrame(
+                new Series("a", new[] { 1, 2 }),
+                new Series("b", new[] { "x", null }),
+                new Series("f", new[] { 1.5, 2.25 })
+            );
+
+            var options = new CsvWriteOptions
+            {
+                Separator = ';',
+                QuoteStyle = QuoteStyle.NonNumeric,
+                FloatPrecision = 1,
+                NullValue = "NULL",
+                IncludeBom = true
+            };
+
+            using var f = new DisposableFile(".csv");
+            df.WriteCsv(f.Path, options);
+
+            var bytes = File.ReadAllBytes(f.Path);
+            Assert.Equal(new byte[] { 0xEF, 0xBB, 0xBF }, bytes.Take(3).ToArray());
+            // ReadAllText 会去掉 BOM
+            Assert.Equal("\"a\";\"b\";\"f\"\n1;\"x\";1.5\n2;NULL;2.2\n", File.ReadAllText(f.Path));
+
+            // Sink：不写表头
+            options.IncludeHeader = false;
+            options.IncludeBom = false;
+            using var sinkFile = new DisposableFile(".csv");
+            df.Lazy().SinkCsv(sinkFile.Path, options);
+            Assert.Equal("1;\"x\";1.5\n2;NULL;2.2\n", File.ReadAllText(sinkFile.Path));
+        }
+        [Fact]
+        public void Test_Scan_Multiple_Files()
+        {
+            using var p1 = new DisposableFile(".parquet");
+            using var p2 = new DisposableFile(".parquet");
+            using (var a = new DataFrame(new Series("a", new long[] { 1, 2 }), new Series("b", new[] { "x", "y" })))
+                a.WriteParquet(p1.Path);
+            using (var b = new DataFrame(new Series("a", new long[] { 3 })))
+                b.WriteParquet(p2.Path);
+
+            // 第二个文件缺列 b：允许时补 Null，并记录来源文件
+            using var df = LazyFrame.ScanParquet(new[] { p1.Path, p2.Path }, allowMissingColumns: true, includeFilePaths: "src").Collect();
+            Assert.Equal(new long[] { 1, 2, 3 }, df["a"].ToArray<long>());
+            Assert.Null(df.GetValue<string>(2, "b"));
+            Assert.Equal(p1.Path, df.GetValue<string>(0, "src"));
+            Assert.Equal(p2.Path, df.GetValue<string>(2, "src"));
+
+            Assert.Throws<Exception>(() => LazyFrame.ScanParquet(new[] { p1.Path, p2.Path }).Collect());
+
+            // CSV 多文件共用同一套选项
+            using var c1 = new DisposableFile("a;b\n1;x\n", ".csv");
+            using var c2 = new DisposableFile("a;b\n2;y\n", ".csv");
+            using var csv = LazyFrame.ScanCsv(new[] { c1.Path, c2.Path }, new CsvReadOptions { Separator = ';' }).Collect();
+            Assert.Equal(new long[] { 1, 2 }, csv["a"].ToArray<long>());
+
+            // NDJSON
+            using var j1 = new DisposableFile("{\"a\":1}\n", ".ndjson");
+            using var j2 = new DisposableFile("{\"a\":2}\n{\"a\":3}\n", ".ndjson");
+            using var ndjson = LazyFrame.ScanNdjson(new[] { j1.Path, j2.Path }).Collect();
+            Assert.Equal(3, ndjson.Height);
+
+            Assert.Throws<ArgumentException>(() => LazyFrame.ScanIpc(Array.Empty<string>()));
+        }
+        [Fact]
+        public void Test_Parquet_Read_With_Options()
+        {
+            using var file = new DisposableFile(".parquet");
+            using (var src = new DataFrame(new Series("a", new long[] { 1, 2, 3 }), new Series("b", new[] { "x", "y", "z" })))
+                src.WriteParquet(file.Path);
+
+            var options = new ParquetReadOptions
+            {
+                Columns = new[] { "b" },
+                NRows = 2,
+                RowIndexName = "idx",
+                RowIndexOffset = 10
+            };
+
+            // 行号列排在最前
+            using var df = DataFrame.ReadParquet(file.Path, options);
+            Assert.Equal(new[] { "idx", "b" }, df.Columns);
+            Assert.Equal(new uint[] { 10, 11 }, df["idx"].ToArray<uint>());
+            Assert.Equal(new[] { "x", "y" }, df["b"].ToArray<string>());
+
+            options.Parallel = ParallelStrategy.RowGroups;
+            using var scanned = LazyFrame.ScanParquet(file.Path, options).Collect();
+            Assert.Equal(new[] { "idx", "b" }, scanned.Columns);
+            Assert.Equal(2, scanned.Height);
+
+            Assert.Throws<Exception>(() => DataFrame.ReadParquet(file.Path, new ParquetReadOptions { Parallel = (ParallelStrategy)99 }));
+        }
+        [Fact]
+        public void Test_Parquet_Write_With_Options()
+        {
+            var values = Enumerable.Range(0, 10_000).Select(i => (long)(i % 100)).ToArray();
+            using var df = new DataFrame(new Series("a", values));
+
+            using var plain = new DisposableFile(".parquet");
+            using var zstd = new DisposableFile(".parquet");
+            df.WriteParquet(plain.Path, new ParquetWriteOptions { Compression = ParquetCompression.Uncompressed });
+            df.WriteParquet(zstd.Path, new ParquetWriteOptions
+            {
+                Compression = ParquetCompression.Zstd,
+                CompressionLevel = 19,
+                Statistics = ParquetStatistics.Full,
+                RowGroupSize = 1000
+            });
+            Assert.True(new FileInfo(zstd.Path).Length < new FileInfo(plain.Path).Length);
+
+            using var back = DataFrame.ReadParquet(zstd.Path);
+            Assert.Equal(values, back["a"].ToArray<long>());
+
+            // 越界级别必须报错而不是截断
+            using var bad = new DisposableFile(".parquet");
+            Assert.Throws<Exception>(() => df.WriteParquet(bad.Path, new ParquetWriteOptions
+            {
+                Compression = ParquetCompression.Gzip,
+                CompressionLevel = 265
+            }));
+
+            // Sink
+            using var sunk = new DisposableFile(".parquet");
+            df.Lazy().SinkParquet(sunk.Path, new ParquetWriteOptions { Compression = ParquetCompression.Snappy, Statistics = ParquetStatistics.None });
+            using var sunkBack = DataFrame.ReadParquet(sunk.Path);
+            Assert.Equal(10_000, sunkBack.Height);
+        }
+        [Fact]
+        public void Test_Ipc_Compression_And_Stream()
+        {
+            var values = Enumerable.Range(0, 10_000).Select(i => (long)(i % 100)).ToArray();
+            using var df = new DataFrame(new Series("a", values));
+
+            using var plain = new DisposableFile(".arrow");
+            using var zstd = new DisposableFile(".arrow");
+            df.WriteIpc(plain.Path);
+            df.WriteIpc(zstd.Path, IpcCompression.Zstd);
+            Assert.True(new FileInfo(zstd.Path).Length < new FileInfo(plain.Path).Length);
+            using (var back = DataFrame.ReadIpc(zstd.Path))
+                Assert.Equal(values, back["a"].ToArray<long>());
+
+            using var sunk = new DisposableFile(".arrow");
+            df.Lazy().SinkIpc(sunk.Path, IpcCompression.LZ4);
+            using (var back = DataFrame.ReadIpc(sunk.Path))
+                Assert.Equal(10_000, back.Height);
+
+            // Stream 格式往返；IPC File 读取器不认 Stream 格式
+            using var stream = new DisposableFile(".arrows");
+            df.WriteIpcStream(stream.Path, IpcCompression.LZ4);
+            using (var back = DataFrame.ReadIpcStream(stream.Path))
+                Assert.Equal(values, back["a"].ToArray<long>());
+            Assert.Throws<Exception>(() => DataFrame.ReadIpc(stream.Path));
+
+            using var bad = new DisposableFile(".arrow");
+            Assert.Throws<Exception>(() => df.WriteIpc(bad.Path, (IpcCompression)7));
+        }
+        [Fact]
+        public void Test_Ipc_Read_Memory_Mapped()
+        {
+            using var plain = new DisposableFile(".arrow");
+            using var compressed = new DisposableFile(".arrow");
+            using (var src = new DataFrame(new Series("a", new long[] { 1, 2, 3 }), new Series("b", new[] { "x", "y", "z" })))
+            {
+                src.WriteIpc(plain.Path);
+                src.WriteIpc(compressed.Path, IpcCompression.Zstd);
+            }
+
+            // Windows 上映射期间无法删除文件，DataFrame 要先于文件释放
+            using (var mapped = DataFrame.ReadIpc(plain.Path, memoryMap: true))
+            {
+                Assert.Equal(new long[] { 1, 2, 3 }, mapped["a"].ToArray<long>());
+                Assert.Equal("z", mapped.GetValue<string>(2, "b"));
+            }
+
+            // 压缩文件自动退回普通读取
+            using (var fallback = DataFrame.ReadIpc(compressed.Path, memoryMap: true))
+                Assert.Equal(3, fallback.Height);
+
+            Assert.Throws<FileNotFoundException>(() => DataFrame.ReadIpc("missing.arrow", memoryMap: true));
+        }
+        [Fact]
+        public void Test_Avro_RoundTrip()
+        {
+            using var df = new DataFrame(
+                new Series("a", new long[] { 1, 2, 3 }),
+                new Series("b", new[] { "x", null, "z" }),
+                new Series("f", new[] { 1.5, 2.5, 3.5 })
+            );
+
+            foreach (var compression in new[] { AvroCompression.None, AvroCompression.Deflate, AvroCompression.Snappy })
+            {
+                using var file = new DisposableFile(".avro");
+                df.WriteAvro(file.Path, compression);
+
+                using var back = DataFrame.ReadAvro(file.Path);
+                Assert.Equal(new[] { "a", "b", "f" }, back.Columns);
+                Assert.Equal(new long[] { 1, 2, 3 }, back["a"].ToArray<long>());
+                Assert.Null(back.GetValue<string>(1, "b"));
+                Assert.Equal(3.5, back.GetValue<double>(2, "f"));
+            }
+
+            using var bad = new DisposableFile(".avro");
+            Assert.Throws<Exception>(() => df.WriteAvro(bad.Path, (AvroCompression)9));
+        }
+        [Fact]
+        public void Test_Ndjson_Write_And_Sink()
+        {
+            using var df = new DataFrame(
+                new Series("a", new long[] { 1, 2 }),
+                new Series("b", new[] { "x", null })
+            );
+
+            using var file = new DisposableFile(".ndjson");
+            df.WriteNdjson(file.Path);
+            Assert.Equal("{\"a\":1,\"b\":\"x\"}\n{\"a\":2,\"b\":null}\n", File.ReadAllText(file.Path));
+
+            using (var back = LazyFrame.ScanNdjson(file.Path).Collect())
+            {
+                Assert.Equal(new long[] { 1, 2 }, back["a"].ToArray<long>());
+                Assert.Null(back.GetValue<string>(1, "b"));
+            }
+
+            using var sunk = new DisposableFile(".ndjson");
+            df.Lazy().SinkNdjson(sunk.Path);
+            Assert.Equal(2, File.ReadAllLines(sunk.Path).Length);
+        }
+        [Fact]
+        public void Test_Ndjson_Scan_With_Options()
+        {
+            // 第二行的 a 无法按推断出的 Int64 解析
+            using var bad = new DisposableFile("{\"a\":1,\"b\":\"x\"}\n{\"a\":\"oops\",\"b\":\"y\"}\n{\"a\":3,\"b\":\"z\"}\n", ".ndjson");
+            var options = new NdjsonReadOptions { InferSchemaLength = 1 };
+            Assert.Throws<Exception>(() => LazyFrame.ScanNdjson(bad.Path, options).Collect());
+
+            options.IgnoreErrors = true;
+            using (var lenient = LazyFrame.ScanNdjson(bad.Path, options).Collect())
+            {
+                Assert.Equal(3, lenient.Height);
+                Assert.Null(lenient.GetValue<long?>(1, "a"));
+            }
+
+            using var good = new DisposableFile("{\"a\":1,\"b\":\"x\"}\n{\"a\":2,\"b\":\"y\"}\n{\"a\":3,\"b\":\"z\"}\n", ".ndjson");
+
+            // 只覆盖 a 的类型，其余列仍然推断
+            var overwrite = new Dictionary<string, DataType> { ["a"] = DataType.Float64 };
+            using (var df = LazyFrame.ScanNdjson(good.Path, new NdjsonReadOptions { NRows = 2 }, schemaOverwrite: overwrite).Collect())
+            {
+                Assert.Equal(2, df.Height);
+                Assert.Equal(DataTypeKind.Float64, df.Schema["a"].Kind);
+                Assert.Equal(DataTypeKind.String, df.Schema["b"].Kind);
+            }
+
+            // 完整 schema 跳过推断
+            var schema = new Dictionary<string, DataType> { ["a"] = DataType.Int32, ["b"] = DataType.String };
+            using (var df = LazyFrame.ScanNdjson(good.Path, new NdjsonReadOptions(), schema).Collect())
+                Assert.Equal(new[] { 1, 2, 3 }, df["a"].ToArray<int>());
+        }
+        [Fact]
+        public void Test_Read_From_Bytes()
+        {
+            using var df = new DataFrame(
+                new Series("a", new long[] { 1, 2, 3 }),
+                new Series("b", new[] { "x", "y", "z" })
+            );
+
+            // Parquet / IPC: 先写文件再把字节读进内存
+            using var pq = new DisposableFile(".parquet");
+            df.WriteParquet(pq.Path);
+            using (var back = DataFrame.ReadParquet(File.ReadAllBytes(pq.Path)))
+                Assert.Equal(new long[] { 1, 2, 3 }, back["a"].ToArray<long>());
+
+            using var ipc = new DisposableFile(".arrow");
+            df.WriteIpc(ipc.Path);
+            using (var back = DataFrame.ReadIpc(File.ReadAllBytes(ipc.Path)))
+                Assert.Equal(new[] { "x", "y", "z" }, back["b"].ToArray<string>());
+
+            // CSV 复用 CsvReadOptions
+            var csvBytes = System.Text.Encoding.UTF8.GetBytes("a;b\n1;x\n2;NA\n");
+            using (var csv = DataFrame.ReadCsv(csvBytes, new CsvReadOptions { Separator = ';', NullValues = new[] { "NA" } }))
+            {
+                Assert.Equal(new long[] { 1, 2 }, csv["a"].ToArray<long>());
+                Assert.Null(csv.GetValue<string>(1, "b"));
+            }
+
+            var jsonBytes = System.Text.Encoding.UTF8.GetBytes("[{\"a\":1},{\"a\":2}]");
+            using (var json = DataFrame.ReadJson(jsonBytes))
+                Assert.Equal(new long[] { 1, 2 }, json["a"].ToArray<long>());
+
+            Assert.Throws<Exception>(() => DataFrame.ReadParquet(new byte[] { 1, 2, 3 }));
+        }
     }
     public class CsvSchemaTests
     {
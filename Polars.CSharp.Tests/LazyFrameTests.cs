@@ -479,4 +479,637 @@ HR,50";
             Console.WriteLine($"Column: {kvp.Key.PadRight(10)} | Kind: {dt.Kind.ToString().PadRight(10)} | {dt} {extraInfo}");
         }
     }
-}
\ No newline at end of file
+    [Fact]
+    public void Test_LazyFrame_Join_Allow_Parallel()
+    {
+        using var sKey = new Series("id", new int[] { 1, 2, 3 });
+        using var sVal = new Series("v", new string?[] { "a", "b", "c" });
+        using var left = new DataFrame(sKey, sVal);
+
+        using var rKey = new Series("id", new int[] { 2, 3, 4 });
+        using var rVal = new Series("w", new double[] { 2.0, 3.0, 4.0 });
+        using var right = new DataFrame(rKey, rVal);
+
+        // 串行与并行的结果必须一致，只影响执行方式
+        using var parallel = left.Lazy()
+            .Join(right.Lazy(), [Col("id")], [Col("id")], JoinType.Inner, allowParallel: true)
+            .Sort("id")
+            .Collect();
+        using var serial = left.Lazy()
+            .Join(right.Lazy(), [Col("id")], [Col("id")], JoinType.Inner, allowParallel: false)
+            .Sort("id")
+            .Collect();
+
+        Assert.Equal(2, serial.Height);
+        Assert.Equal(parallel.Height, serial.Height);
+        Assert.Equal("b", serial.GetValue<string>(0, "v"));
+        Assert.Equal(3.0, serial.GetValue<double>(1, "w"));
+    }
+    [Fact]
+    public void Test_LazyFrame_CollectSpooled()
+    {
+        var spoolDir = Path.Combine(Path.GetTempPath(), $"spool_{Guid.NewGuid()}");
+        try
+        {
+            using var s = new Series("x", Enumerable.Range(0, 1000).ToArray());
+            using var df = new DataFrame(s);
+
+            // 1. 结果落盘，返回的是对 IPC 文件的 scan
+            using (var spooled = df.Lazy().Filter(Col("x") > Lit(499)).CollectSpooled(spoolDir))
+            {
+                var files = Directory.GetFiles(spoolDir, "polars_spool_*.arrow");
+                Assert.Single(files);
+
+                // 2. 可以继续在上面构建查询
+                using var res = spooled.Select(Col("x").Sum().Alias("total")).Collect();
+                Assert.Equal(Enumerable.Range(500, 500).Sum(), res.GetValue<long>(0, "total"));
+            }
+
+            // 3. 同一目录再落盘一次，文件名不冲突
+            using (var again = df.Lazy().CollectSpooled(spoolDir))
+            {
+                Assert.Equal(2, Directory.GetFiles(spoolDir, "polars_spool_*.arrow").Length);
+                using var all = again.Collect();
+                Assert.Equal(1000, all.Height);
+            }
+        }
+        finally
+        {
+            try { Directory.Delete(spoolDir, true); } catch { }
+        }
+    }
+    [Fact]
+    public void Test_WithRollingAggregates()
+    {
+        using var x = new Series("x", new[] { 1.0, 2.0, 3.0, 4.0 });
+        using var y = new Series("y", new[] { 10.0, 20.0, 30.0, 40.0 });
+        using var tag = new Series("tag", new string?[] { "a", "b", "c", "d" });
+        using var df = new DataFrame(x, y, tag);
+        using var lf = df.Lazy();
+
+        using var sel = Numeric();
+        using var res = lf.WithRollingAggregates(sel, [RollingOp.Mean, RollingOp.Max], "2").Collect();
+
+        // 2 个数值列 x 2 种聚合，字符串列不参与
+        Assert.Equal(3 + 4, (int)res.Width);
+        Assert.Contains("x_rolling_mean", res.Columns);
+        Assert.DoesNotContain("tag_rolling_mean", res.Columns);
+
+        // min_periods = 1: 第一行只有一个值
+        Assert.Equal(1.0, res.GetValue<double>(0, "x_rolling_mean"));
+        Assert.Equal(1.5, res.GetValue<double>(1, "x_rolling_mean"));
+        Assert.Equal(35.0, res.GetValue<double>(3, "y_rolling_mean"));
+        Assert.Equal(40.0, res.GetValue<double>(3, "y_rolling_max"));
+
+        // 自定义后缀
+        using var custom = lf.WithRollingAggregates(sel, [RollingOp.Sum], "3i", "_w").Collect();
+        Assert.Equal(9.0, custom.GetValue<double>(3, "x_w_sum"));
+
+        // 未知聚合 / 非行数窗口报错
+        Assert.Throws<Exception>(() => lf.WithRollingAggregates(sel, [(RollingOp)99], "2"));
+        Assert.Throws<Exception>(() => lf.WithRollingAggregates(sel, [RollingOp.Mean], "1h"));
+    }
+    [Fact]
+    public void Test_Lazy_CastColumns()
+    {
+        using var a = new Series("a", new string?[] { "1", "2", "x" });
+        using var b = new Series("b", new[] { 1, 2, 3 });
+        using var df = new DataFrame(a, b);
+
+        var dtypes = new Dictionary<string, DataType>
+        {
+            ["a"] = DataType.Int64,
+            ["b"] = DataType.Float64
+        };
+
+        // 非严格模式: 无法解析的值变为 Null
+        using var res = df.Lazy().CastColumns(dtypes, strict: false).Collect();
+        Assert.Equal(DataTypeKind.Int64, res.Schema["a"].Kind);
+        Assert.Equal(DataTypeKind.Float64, res.Schema["b"].Kind);
+        Assert.Equal(2L, res.GetValue<long>(1, "a"));
+        Assert.Null(res["a"][2]);
+        Assert.Equal(3.0, res.GetValue<double>(2, "b"));
+
+        // 严格模式: 执行时报错
+        Assert.Throws<Exception>(() => df.Lazy().CastColumns(dtypes).Collect());
+    }
+    [Fact]
+    public void Test_Lazy_Benchmark()
+    {
+        using var s = new Series("x", new[] { 1, 2, 3, 4 });
+        using var df = new DataFrame(s);
+        using var lf = df.Lazy().Filter(Col("x") > 1);
+
+        var report = lf.Benchmark(3);
+
+        Assert.Equal(3, report.Iterations);
+        Assert.Equal(3, report.Rows);
+        Assert.Equal(3, report.WallMs.Length);
+        Assert.True(report.MinMs <= report.MeanMs && report.MeanMs <= report.MaxMs);
+        // 峰值内存只在 Linux 上有值
+        if (OperatingSystem.IsLinux()) Assert.True(report.PeakRssBytes > 0);
+
+        Assert.Throws<ArgumentOutOfRangeException>(() => lf.Benchmark(0));
+
+        // Benchmark 不消费 LazyFrame
+        using var res = lf.Collect();
+        Assert.Equal(3, res.Height);
+    }
+    [Fact]
+    public void Test_Shared_LazyFrame_Across_Threads()
+    {
+        using var s = new Series("x", new[] { 1, 2, 3, 4, 5, 6 });
+        using var df = new DataFrame(s);
+        using var lf = df.Lazy();
+
+        using var shared = lf.Share();
+        Assert.Equal(1, shared.RefCount);
+
+        // 每个线程 Retain 自己的句柄，再 ClonePlan 出独立的 LazyFrame 继续构建
+        var results = new long[4];
+        Parallel.For(0, 4, i =>
+        {
+            using var mine = shared.Retain();
+            using var plan = mine.ClonePlan();
+            using var res = plan.Filter(Col("x") > i).Collect();
+            results[i] = res.Height;
+        });
+        Assert.Equal(new long[] { 6, 5, 4, 3 }, results);
+
+        // 线程内的句柄都已释放
+        Assert.Equal(1, shared.RefCount);
+        using (var extra = shared.Retain())
+        {
+            Assert.Equal(2, shared.RefCount);
+        }
+        Assert.Equal(1, shared.RefCount);
+
+        // 原 LazyFrame 不受影响
+        using var original = lf.Collect();
+        Assert.Equal(6, original.Height);
+    }
+    [Fact]
+    public void Test_Lazy_Sort_Exprs_NullsLast()
+    {
+        using var df = new DataFrame(
+            new Series("a", new[] { 1, 0, 1, 2 }, new[] { true, false, true, true }),
+            new Series("b", new[] { 3, 1, 2, 0 })
+        );
+        using var lf = df.Lazy();
+
+        // a 降序且 Null 放最后，b 升序
+        using var res = lf.Sort(
+            new[] { Col("a"), Col("b") },
+            descending: new[] { true, false },
+            nullsLast: new[] { true, false },
+            maintainOrder: true
+        ).Collect();
+
+        Assert.Equal(2, res.GetValue<int?>(0, "a"));
+        Assert.Null(res.GetValue<int?>(3, "a"));
+        Assert.Equal(new[] { 0, 2, 3, 1 }, res["b"].ToArray<int>());
+
+        Assert.Throws<ArgumentException>(() => lf.Sort(new[] { Col("a") }, new[] { true, false }, null));
+
+        // Sort 不消费原 LazyFrame
+        using var original = lf.Collect();
+        Assert.Equal(4, original.Height);
+    }
+    [Fact]
+    public void Test_LazyFrame_Join_With_Args()
+    {
+        using var left = new DataFrame(
+            new Series("id", new[] { 1, 2, 0 }, new[] { true, true, false }),
+            new Series("v", new[] { 10, 20, 30 })
+        );
+        using var right = new DataFrame(
+            new Series("id", new[] { 1, 0 }, new[] { true, false }),
+            new Series("v", new[] { 100, 300 })
+        );
+        using var lLf = left.Lazy();
+        using var rLf = right.Lazy();
+
+        // 默认: Null 键不匹配，重名列加 "_right"
+        using var basic = lLf.Join(rLf, new[] { Col("id") }, new[] { Col("id") }, JoinType.Inner, suffix: null).Collect();
+        Assert.Equal(1, basic.Height);
+        Assert.Equal(new[] { "id", "v", "v_right" }, basic.Columns);
+
+        // Null 键视为相等，保留两侧键列，自定义后缀
+        using var full = lLf.Join(
+            rLf, new[] { Col("id") }, new[] { Col("id") }, JoinType.Inner,
+            suffix: "_x", joinNulls: true, coalesce: JoinCoalesce.KeepColumns
+        ).Collect();
+        Assert.Equal(2, full.Height);
+        Assert.Equal(new[] { "id", "v", "id_x", "v_x" }, full.Columns);
+        Assert.Equal(300, full.GetValue<int>(1, "v_x"));
+
+        // 左表键重复，1:1 校验在 collect 时报错
+        using var dup = new DataFrame(new Series("id", new[] { 1, 1 }));
+        using var dupLf = dup.Lazy();
+        using var invalid = dupLf.Join(rLf, new[] { Col("id") }, new[] { Col("id") }, JoinType.Inner, null, JoinValidation.OneToOne);
+        Assert.Throws<Exception>(() => invalid.Collect());
+    }
+    [Fact]
+    public void Test_LazyFrame_JoinWhere()
+    {
+        using var events = new DataFrame(
+            new Series("t", new[] { 1, 5, 10 }),
+            new Series("v", new[] { 1, 2, 3 })
+        );
+        using var windows = new DataFrame(
+            new Series("start", new[] { 0, 4 }),
+            new Series("end", new[] { 3, 8 }),
+            new Series("v", new[] { 7, 8 })
+        );
+        using var lf = events.Lazy();
+        using var other = windows.Lazy();
+
+        using var res = lf.JoinWhere(other, Col("t") >= Col("start"), Col("t") < Col("end")).Collect();
+        Assert.Equal(2, res.Height);
+        Assert.Contains("v_right", res.Columns);
+
+        // 谓词可以引用带后缀的右表列
+        using var filtered = lf.JoinWhere(
+            other, Col("t") >= Col("start"), Col("t") < Col("end"), Col("v_right") > Lit(7)
+        ).Collect();
+        Assert.Equal(1, filtered.Height);
+        Assert.Equal(5, filtered.GetValue<int>(0, "t"));
+
+        Assert.Throws<ArgumentException>(() => lf.JoinWhere(other));
+    }
+    [Fact]
+    public void Test_LazyFrame_CrossJoin()
+    {
+        using var left = new DataFrame(new Series("t", new[] { 1, 5, 10 }), new Series("v", new[] { 1, 2, 3 }));
+        using var right = new DataFrame(new Series("v", new[] { 7, 8 }));
+        using var lf = left.Lazy();
+        using var other = right.Lazy();
+
+        using var res = lf.CrossJoin(other).Collect();
+        Assert.Equal(6, res.Height);
+        Assert.Equal(new[] { "t", "v", "v_right" }, res.Columns);
+
+        using var custom = lf.CrossJoin(other, "_x").Collect();
+        Assert.Equal(new[] { "t", "v", "v_x" }, custom.Columns);
+        Assert.Equal(new[] { 7, 8, 7, 8, 7, 8 }, custom["v_x"].ToArray<int>());
+    }
+    [Fact]
+    public void Test_Lazy_Rename_And_Drop()
+    {
+        using var df = new DataFrame(
+            new Series("a", new[] { 1L }),
+            new Series("b", new[] { 2 }),
+            new Series("c", new[] { "x" })
+        );
+        using var lf = df.Lazy();
+
+        using var renamed = lf.Rename(new Dictionary<string, string> { ["a"] = "A", ["c"] = "C" }).Collect();
+        Assert.Equal(new[] { "A", "b", "C" }, renamed.Columns);
+
+        // 非严格模式忽略不存在的列
+        using var lenient = lf.Rename(new[] { "a", "zz" }, new[] { "A", "ZZ" }, strict: false).Collect();
+        Assert.Equal(new[] { "A", "b", "c" }, lenient.Columns);
+        using var badRename = lf.Rename(new[] { "zz" }, new[] { "ZZ" });
+        Assert.Throws<Exception>(() => badRename.Collect());
+        Assert.Throws<ArgumentException>(() => lf.Rename(new[] { "a" }, new[] { "A", "B" }));
+
+        using var dropped = lf.Drop("a", "c").Collect();
+        Assert.Equal(new[] { "b" }, dropped.Columns);
+
+        using var droppedLenient = lf.Drop(new[] { "a", "zz" }, strict: false).Collect();
+        Assert.Equal(new[] { "b", "c" }, droppedLenient.Columns);
+        using var badDrop = lf.Drop("zz");
+        Assert.Throws<Exception>(() => badDrop.Collect());
+
+        using var noNumeric = lf.Drop(Numeric()).Collect();
+        Assert.Equal(new[] { "c" }, noNumeric.Columns);
+    }
+    [Fact]
+    public void Test_Lazy_Unique()
+    {
+        using var df = new DataFrame(
+            new Series("a", new[] { 1, 1, 2, 2, 3 }),
+            new Series("b", new[] { 10, 11, 20, 20, 30 })
+        );
+        using var lf = df.Lazy();
+
+        // 整行去重: (2, 20) 重复
+        using var all = lf.Unique(maintainOrder: true).Collect();
+        Assert.Equal(new[] { 10, 11, 20, 30 }, all["b"].ToArray<int>());
+
+        var byA = Polars.Selectors.StartsWith("a");
+        using var first = lf.Unique(byA, UniqueKeepStrategy.First, maintainOrder: true).Collect();
+        Assert.Equal(new[] { 10, 20, 30 }, first["b"].ToArray<int>());
+
+        using var last = lf.Unique(byA, UniqueKeepStrategy.Last, maintainOrder: true).Collect();
+        Assert.Equal(new[] { 11, 20, 30 }, last["b"].ToArray<int>());
+
+        // None: 重复的键全部丢弃
+        using var none = lf.Unique(byA, UniqueKeepStrategy.None, maintainOrder: true).Collect();
+        Assert.Equal(new[] { 30 }, none["b"].ToArray<int>());
+
+        using var any = lf.Unique(byA, UniqueKeepStrategy.Any).Collect();
+        Assert.Equal(3, any.Height);
+    }
+    [Fact]
+    public void Test_Lazy_FillNull_FillNan()
+    {
+        using var df = new DataFrame(
+            new Series("a", new[] { 1, 0 }, new[] { true, false }),
+            new Series("f", new[] { double.NaN, 0.0 }, new[] { true, false })
+        );
+        using var lf = df.Lazy();
+
+        using var nullFilled = lf.FillNull(0).Collect();
+        Assert.Equal(new[] { 1, 0 }, nullFilled["a"].ToArray<int>());
+        Assert.True(double.IsNaN(nullFilled.GetValue<double>(0, "f")));
+        Assert.Equal(0.0, nullFilled.GetValue<double>(1, "f"));
+
+        // FillNan 只影响浮点列里的 NaN，Null 保持不变
+        using var nanFilled = lf.FillNan(Lit(0.5)).Collect();
+        Assert.Equal(0.5, nanFilled.GetValue<double>(0, "f"));
+        Assert.Null(nanFilled.GetValue<double?>(1, "f"));
+        Assert.Null(nanFilled.GetValue<int?>(1, "a"));
+    }
+    [Fact]
+    public void Test_Lazy_Shift_Reverse_GatherEvery()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3, 4, 5 }));
+        using var lf = df.Lazy();
+
+        using var down = lf.Shift(2).Collect();
+        Assert.Null(down.GetValue<int?>(0, "a"));
+        Assert.Null(down.GetValue<int?>(1, "a"));
+        Assert.Equal(1, down.GetValue<int>(2, "a"));
+
+        using var up = lf.Shift(-1, Lit(0)).Collect();
+        Assert.Equal(new[] { 2, 3, 4, 5, 0 }, up["a"].ToArray<int>());
+
+        using var rev = lf.Reverse().Collect();
+        Assert.Equal(new[] { 5, 4, 3, 2, 1 }, rev["a"].ToArray<int>());
+
+        using var every = lf.GatherEvery(2, offset: 1).Collect();
+        Assert.Equal(new[] { 2, 4 }, every["a"].ToArray<int>());
+
+        Assert.Throws<Exception>(() => lf.GatherEvery(0));
+    }
+    [Fact]
+    public void Test_Lazy_Slice()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3, 4, 5 }));
+        using var lf = df.Lazy();
+
+        using var page = lf.Slice(1, 2).Collect();
+        Assert.Equal(new[] { 2, 3 }, page["a"].ToArray<int>());
+
+        // 负数 offset 从末尾倒数
+        using var tail = lf.Slice(-2, 10).Collect();
+        Assert.Equal(new[] { 4, 5 }, tail["a"].ToArray<int>());
+
+        using var empty = lf.Slice(10, 3).Collect();
+        Assert.Equal(0, empty.Height);
+    }
+    [Fact]
+    public void Test_Lazy_WithContext()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3 }));
+        using var paramsDf = new DataFrame(new Series("factor", new[] { 10 }));
+        using var lf = df.Lazy();
+        using var paramsLf = paramsDf.Lazy();
+
+        using var res = lf.WithContext(paramsLf)
+            .Select((Col("a") * Col("factor").Max()).Alias("scaled"))
+            .Collect();
+        Assert.Equal(new[] { 10, 20, 30 }, res["scaled"].ToArray<int>());
+
+        Assert.Throws<ArgumentException>(() => lf.WithContext());
+
+        // 辅助 LazyFrame 不被消费
+        using var stillThere = paramsLf.Collect();
+        Assert.Equal(1, stillThere.Height);
+    }
+    [Fact]
+    public void Test_Lazy_Fetch()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3, 4, 5 }));
+        using var lf = df.Lazy();
+
+        using var fetched = lf.Fetch(2);
+        Assert.Equal(new[] { 1, 2 }, fetched["a"].ToArray<int>());
+
+        // 截断发生在数据源，后续 filter 只能看到前两行
+        using var filtered = lf.Filter(Col("a") > 2);
+        using var partial = filtered.Fetch(2);
+        Assert.Equal(0, partial.Height);
+        using var full = filtered.Collect();
+        Assert.Equal(3, full.Height);
+
+        // 行数按 64 位传递：超过 IdxSize (u32) 的值报错，而不是被截断成 2
+        Assert.Throws<Exception>(() => lf.Fetch((1UL << 32) + 2));
+    }
+    [Fact]
+    public void Test_Lazy_Profile()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3, 4, 5 }));
+        using var lf = df.Lazy().Filter(Col("a") > 2);
+
+        var (result, timings) = lf.Profile();
+        using (result)
+        using (timings)
+        {
+            Assert.Equal(3, result.Height);
+            Assert.Equal(new[] { "node", "start", "end" }, timings.Columns);
+            Assert.True(timings.Height > 0);
+            Assert.True(Convert.ToInt64(timings[0, "end"]) >= Convert.ToInt64(timings[0, "start"]));
+        }
+
+        // 出错时抛异常
+        using var bad = df.Lazy().Select(Col("missing"));
+        Assert.Throws<Exception>(() => bad.Profile());
+    }
+    [Fact]
+    public void Test_Lazy_ExplainTree_And_ToDot()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3 }));
+        using var lf = df.Lazy().Filter(Col("a") > 1);
+
+        var tree = lf.ExplainTree();
+        Assert.Contains("FILTER", tree);
+        Assert.Contains("│", tree);
+
+        var dot = lf.ToDot(optimized: false);
+        Assert.StartsWith("graph", dot);
+
+        // 只借用，LazyFrame 仍可执行
+        using var res = lf.Collect();
+        Assert.Equal(2, res.Height);
+    }
+    [Fact]
+    public void Test_Lazy_CollectAll()
+    {
+        using var df = new DataFrame(
+            new Series("g", new[] { "x", "y", "x" }),
+            new Series("v", new[] { 1, 2, 3 })
+        );
+        using var source = df.Lazy();
+        using var big = source.Filter(Col("v") > 1);
+        using var total = source.Select(Col("v").Sum().Alias("total"));
+
+        var results = LazyFrame.CollectAll(new[] { big, total });
+        Assert.Equal(2, results.Length);
+        using (results[0])
+        using (results[1])
+        {
+            Assert.Equal(new[] { 2, 3 }, results[0]["v"].ToArray<int>());
+            Assert.Equal(6, results[1].GetValue<int>(0, "total"));
+        }
+
+        Assert.Empty(LazyFrame.CollectAll(Array.Empty<LazyFrame>()));
+
+        // 出错时抛异常
+        using var bad = source.Select(Col("missing"));
+        Assert.Throws<Exception>(() => LazyFrame.CollectAll(new[] { big, bad }));
+    }
+    [Fact]
+    public void Test_Lazy_Unnest()
+    {
+        using var df = new DataFrame(
+            new Series("id", new[] { 1, 2 }),
+            new Series("a", new[] { 3, 4 }),
+            new Series("b", new[] { "x", "y" })
+        );
+        using var lf = df.Lazy().Select(Col("id"), AsStruct(Col("a"), Col("b")).Alias("s_ab"));
+
+        // 按列名展开，字段按原位置插入
+        using var byName = lf.Unnest("s_ab").Collect();
+        Assert.Equal(new[] { "id", "a", "b" }, byName.Columns);
+        Assert.Equal(new[] { 3, 4 }, byName["a"].ToArray<int>());
+        Assert.Equal("y", byName.GetValue<string>(1, "b"));
+
+        // 按 selector 展开
+        using var bySelector = lf.Unnest(Polars.Selectors.StartsWith("s_")).Collect();
+        Assert.Equal(new[] { "id", "a", "b" }, bySelector.Columns);
+
+        // 列不存在时 collect 报错
+        using var missing = lf.Unnest("nope");
+        Assert.Throws<Exception>(() => missing.Collect());
+    }
+    [Fact]
+    public void Test_Lazy_MergeSorted()
+    {
+        using var left = new DataFrame(
+            new Series("k", new[] { 1, 3, 5 }),
+            new Series("v", new[] { "a", "c", "e" })
+        ).Lazy();
+        using var right = new DataFrame(
+            new Series("k", new[] { 2, 4, 6 }),
+            new Series("v", new[] { "b", "d", "f" })
+        ).Lazy();
+
+        using var res = left.MergeSorted(right, "k").Collect();
+        Assert.Equal(new[] { 1, 2, 3, 4, 5, 6 }, res["k"].ToArray<int>());
+        Assert.Equal(new[] { "a", "b", "c", "d", "e", "f" }, res["v"].ToArray<string>());
+
+        // key 不存在时 collect 报错
+        using var bad = left.MergeSorted(right, "missing");
+        Assert.Throws<Exception>(() => bad.Collect());
+    }
+    [Fact]
+    public void Test_Lazy_Count()
+    {
+        using var df = new DataFrame(new Series("v", new[] { 1, 2, 3, 4, 5 }));
+        using var lf = df.Lazy();
+
+        Assert.Equal(5UL, lf.Count());
+        Assert.Equal(2UL, lf.Filter(Col("v") > 3).Count());
+        Assert.Equal(0UL, lf.Filter(Col("v") > 10).Count());
+
+        // 文件扫描
+        using var f = new DisposableFile(".parquet");
+        df.WriteParquet(f.Path);
+        using var scan = LazyFrame.ScanParquet(f.Path);
+        Assert.Equal(5UL, scan.Count());
+
+        // LazyFrame 不被消费
+        using var res = lf.Collect();
+        Assert.Equal(5, res.Height);
+    }
+    [Fact]
+    public void Test_Lazy_Concat_With_Args()
+    {
+        using var a = new DataFrame(new Series("a", new[] { 1, 2 }));
+        using var b = new DataFrame(new Series("a", new long[] { 3 }));
+        using var c = new DataFrame(
+            new Series("a", new long[] { 3 }),
+            new Series("b", new[] { "x" })
+        );
+
+        // 类型不一致：默认报错，toSupertypes 时提升为 Int64
+        using var strict = LazyFrame.Concat(new[] { a.Lazy(), b.Lazy() });
+        Assert.Throws<Exception>(() => strict.Collect());
+
+        using var relaxed = LazyFrame.Concat(new[] { a.Lazy(), b.Lazy() }, toSupertypes: true).Collect();
+        Assert.Equal(new long[] { 1, 2, 3 }, relaxed["a"].ToArray<long>());
+
+        // DiagonalRelaxed：对齐列 + 提升类型，缺失补 null
+        using var diag = LazyFrame.Concat(new[] { a.Lazy(), c.Lazy() }, ConcatType.DiagonalRelaxed).Collect();
+        Assert.Equal(new[] { "a", "b" }, diag.Columns);
+        Assert.Equal(new long[] { 1, 2, 3 }, diag["a"].ToArray<long>());
+        Assert.Null(diag.GetValue<string>(0, "b"));
+        Assert.Equal("x", diag.GetValue<string>(2, "b"));
+
+        // 关闭 maintainOrder 后内容不变
+        using var unordered = LazyFrame.Concat(new[] { a.Lazy(), a.Lazy() }, maintainOrder: false).Collect();
+        Assert.Equal(new[] { 1, 1, 2, 2 }, unordered["a"].ToArray<int>().OrderBy(x => x));
+
+        Assert.Throws<ArgumentException>(() => LazyFrame.Concat(Array.Empty<LazyFrame>()));
+    }
+    [Fact]
+    public void Test_Lazy_GroupBy_MaintainOrder()
+    {
+        using var df = new DataFrame(
+            new Series("k", new[] { "b", "a", "b", "c", "a" }),
+            new Series("v", new[] { 1, 2, 3, 4, 5 })
+        );
+        using var lf = df.Lazy();
+
+        // 组按首次出现的顺序输出
+        using var stable = lf.GroupBy(new[] { Col("k") }, maintainOrder: true).Agg(Col("v").Sum()).Collect();
+        Assert.Equal(new[] { "b", "a", "c" }, stable["k"].ToArray<string>());
+        Assert.Equal(new[] { 4, 7, 4 }, stable["v"].ToArray<int>());
+
+        // 顺序不定，内容一致
+        using var fast = lf.GroupBy(new[] { Col("k") }, maintainOrder: false).Agg(Col("v").Sum())
+            .Sort(Col("k")).Collect();
+        Assert.Equal(new[] { "a", "b", "c" }, fast["k"].ToArray<string>());
+        Assert.Equal(new[] { 7, 4, 4 }, fast["v"].ToArray<int>());
+    }
+    [Fact]
+    public void Test_LazyFrame_Join_On()
+    {
+        using var left = new DataFrame(
+            new Series("k", new[] { 1, 2, 3 }),
+            new Series("x", new[] { 10, 20, 30 })
+        ).Lazy();
+        using var right = new DataFrame(
+            new Series("k", new[] { 2, 3, 4 }),
+            new Series("x", new[] { 200, 300, 400 })
+        ).Lazy();
+
+        // 同名键只保留一列，右表重名列加后缀
+        using var inner = left.Join(right, new[] { Col("k") }, suffix: "_r").Collect();
+        Assert.Equal(new[] { "k", "x", "x_r" }, inner.Columns);
+        Assert.Equal(new[] { 2, 3 }, inner["k"].ToArray<int>());
+        Assert.Equal(new[] { 200, 300 }, inner["x_r"].ToArray<int>());
+
+        // Semi / Anti 只保留左表列
+        using var semi = left.Join(right, new[] { Col("k") }, JoinType.Semi).Collect();
+        Assert.Equal(new[] { "k", "x" }, semi.Columns);
+        Assert.Equal(new[] { 2, 3 }, semi["k"].ToArray<int>());
+
+        using var anti = left.Join(right, new[] { Col("k") }, JoinType.Anti).Collect();
+        Assert.Equal(new[] { 1 }, anti["k"].ToArray<int>());
+
+        Assert.Throws<ArgumentException>(() => left.Join(right, Array.Empty<Expr>()));
+    }
+}
@@ -451,4 +451,156 @@ public class SeriesTests
         using var isInf = s.IsInfinite();
         Assert.True(isInf.GetValue<bool>(2));
     }
-}
\ No newline at end of file
+    [Fact]
+    public void Test_Series_FromArrow_Keeps_Extension_Type()
+    {
+        var builder = new StringArray.Builder();
+        builder.Append("{\"a\": 1}");
+        builder.AppendNull();
+        using var storage = builder.Build();
+
+        var field = new Field("payload", StringType.Default, true, new Dictionary<string, string>
+        {
+            ["ARROW:extension:name"] = "arrow.json",
+            ["ARROW:extension:metadata"] = "{\"encoding\":\"utf8\"}",
+        });
+
+        // 扩展类型按存储类型 (String) 导入，扩展信息单独保留
+        using var s = Series.FromArrow(field, storage);
+        Assert.Equal("payload", s.Name);
+        Assert.Equal("arrow.json", s.ExtensionName);
+        Assert.Equal("{\"encoding\":\"utf8\"}", s.ExtensionMetadata);
+        Assert.Equal("{\"a\": 1}", s.GetValue<string>(0));
+        Assert.Equal(1, s.NullCount);
+
+        // 普通 Series 没有扩展类型
+        using var plain = new Series("plain", new[] { 1, 2 });
+        Assert.Null(plain.ExtensionName);
+        Assert.Null(plain.ExtensionMetadata);
+    }
+    [Fact]
+    public void Test_Wkb_Point_Roundtrip()
+    {
+        // little-endian 2D Point (1.5, -2.25)
+        var le = new byte[21];
+        le[0] = 1;
+        System.Buffers.Binary.BinaryPrimitives.WriteUInt32LittleEndian(le.AsSpan(1), 1);
+        System.Buffers.Binary.BinaryPrimitives.WriteDoubleLittleEndian(le.AsSpan(5), 1.5);
+        System.Buffers.Binary.BinaryPrimitives.WriteDoubleLittleEndian(le.AsSpan(13), -2.25);
+
+        // big-endian EWKB Point 带 SRID=4326 (10, 20)
+        var ewkb = new byte[25];
+        ewkb[0] = 0;
+        System.Buffers.Binary.BinaryPrimitives.WriteUInt32BigEndian(ewkb.AsSpan(1), 0x2000_0001);
+        System.Buffers.Binary.BinaryPrimitives.WriteUInt32BigEndian(ewkb.AsSpan(5), 4326);
+        System.Buffers.Binary.BinaryPrimitives.WriteDoubleBigEndian(ewkb.AsSpan(9), 10.0);
+        System.Buffers.Binary.BinaryPrimitives.WriteDoubleBigEndian(ewkb.AsSpan(17), 20.0);
+
+        // LineString 头 (非 Point) 与截断数据都应得到 Null
+        var lineString = new byte[] { 1, 2, 0, 0, 0 };
+        var truncated = new byte[] { 1, 1, 0, 0, 0, 0 };
+
+        var builder = new BinaryArray.Builder();
+        builder.Append(le.AsSpan());
+        builder.Append(ewkb.AsSpan());
+        builder.Append(lineString.AsSpan());
+        builder.Append(truncated.AsSpan());
+        builder.AppendNull();
+        using var arr = builder.Build();
+
+        using var geom = Series.FromArrow("geom", arr);
+        using var df = new DataFrame(geom);
+
+        using var decoded = df
+            .Select(Polars.Col("geom").Bin.WkbToXy().Alias("pt"))
+            .Select(
+                Polars.Col("pt").Struct.Field("x").Alias("x"),
+                Polars.Col("pt").Struct.Field("y").Alias("y")
+            );
+
+        Assert.Equal(1.5, decoded.GetValue<double>(0, "x"));
+        Assert.Equal(-2.25, decoded.GetValue<double>(0, "y"));
+        Assert.Equal(10.0, decoded.GetValue<double>(1, "x"));
+        Assert.Equal(20.0, decoded.GetValue<double>(1, "y"));
+        Assert.Null(decoded.GetValue<double?>(2, "x"));
+        Assert.Null(decoded.GetValue<double?>(3, "x"));
+        Assert.Null(decoded.GetValue<double?>(4, "x"));
+
+        // x/y -> WKB -> x/y 往返
+        using var roundtrip = decoded
+            .Select(Polars.XyToWkb(Polars.Col("x"), Polars.Col("y")).Alias("wkb"))
+            .Select(Polars.Col("wkb").Bin.WkbToXy().Struct.Field("y").Alias("y"));
+
+        Assert.Equal(-2.25, roundtrip.GetValue<double>(0, "y"));
+        Assert.Equal(20.0, roundtrip.GetValue<double>(1, "y"));
+        Assert.Null(roundtrip.GetValue<double?>(2, "y"));
+
+        // 字面量坐标广播到整列，每行一个点
+        using var broadcast = decoded
+            .Select(
+                Polars.XyToWkb(Polars.Col("x"), Polars.Lit(0.0)).Alias("a"),
+                Polars.XyToWkb(Polars.Lit(7.0), Polars.Col("y")).Alias("b")
+            )
+            .Select(
+                Polars.Col("a").Bin.WkbToXy().Struct.Field("y").Alias("ay"),
+                Polars.Col("b").Bin.WkbToXy().Struct.Field("x").Alias("bx")
+            );
+
+        Assert.Equal(5, broadcast.Height);
+        Assert.Equal(0.0, broadcast.GetValue<double>(0, "ay"));
+        Assert.Equal(0.0, broadcast.GetValue<double>(1, "ay"));
+        Assert.Null(broadcast.GetValue<double?>(2, "ay"));
+        Assert.Equal(7.0, broadcast.GetValue<double>(0, "bx"));
+        Assert.Equal(7.0, broadcast.GetValue<double>(1, "bx"));
+
+        // 长度不一致 (且都不是 1) 时报错，而不是按短的一侧截断
+        Assert.Throws<Exception>(() =>
+            decoded.Select(Polars.XyToWkb(Polars.Col("x"), Polars.Col("y").DropNulls())));
+    }
+    [Fact]
+    public void Test_Binary_Encode_Decode_And_Match()
+    {
+        var builder = new BinaryArray.Builder();
+        builder.Append(System.Text.Encoding.UTF8.GetBytes("hello").AsSpan());
+        builder.Append(new byte[] { 0x00, 0xFF }.AsSpan());
+        builder.AppendNull();
+        using var arr = builder.Build();
+        using var raw = Series.FromArrow("raw", arr);
+        using var df = new DataFrame(raw);
+
+        using var res = df.Select(
+            Polars.Col("raw").Bin.Size().Alias("size"),
+            Polars.Col("raw").Bin.Encode("hex").Alias("hex"),
+            Polars.Col("raw").Bin.Encode("base64").Alias("b64"),
+            Polars.Col("raw").Bin.Contains("ell").Alias("has_ell"),
+            Polars.Col("raw").Bin.StartsWith("he").Alias("sw"),
+            Polars.Col("raw").Bin.EndsWith("lo").Alias("ew")
+        );
+
+        Assert.Equal(5L, Convert.ToInt64(res[0, "size"]));
+        Assert.Equal("68656c6c6f", res.GetValue<string>(0, "hex"));
+        Assert.Equal("00ff", res.GetValue<string>(1, "hex"));
+        Assert.Equal("AP8=", res.GetValue<string>(1, "b64"));
+        Assert.True(res.GetValue<bool>(0, "has_ell"));
+        Assert.False(res.GetValue<bool>(1, "has_ell"));
+        Assert.True(res.GetValue<bool>(0, "sw"));
+        Assert.True(res.GetValue<bool>(0, "ew"));
+        Assert.Null(res[2, "hex"]);
+
+        // Decode: 输入是编码后的 Binary
+        var encoded = new BinaryArray.Builder();
+        encoded.Append(System.Text.Encoding.ASCII.GetBytes("68656c6c6f").AsSpan());
+        encoded.Append(System.Text.Encoding.ASCII.GetBytes("zz").AsSpan());
+        using var encArr = encoded.Build();
+        using var enc = Series.FromArrow("enc", encArr);
+        using var encDf = new DataFrame(enc);
+
+        using var decoded = encDf.Select(Polars.Col("enc").Bin.Decode("hex", strict: false).Alias("dec"));
+        Assert.Equal(System.Text.Encoding.UTF8.GetBytes("hello"), decoded.GetValue<byte[]>(0, "dec"));
+        Assert.Null(decoded[1, "dec"]);
+
+        // strict 模式遇到非法编码报错；未知编码在构建表达式时就报错
+        Assert.Throws<Exception>(() => encDf.Select(Polars.Col("enc").Bin.Decode("hex")));
+        Assert.Throws<Exception>(() => Polars.Col("enc").Bin.Encode("base32"));
+    }
+}
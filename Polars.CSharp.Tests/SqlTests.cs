@@ -71,4 +71,42 @@ public class SqlTests
         Assert.Equal("IT", deptCol.GetValue<string>(1));
         Assert.Equal(3000, salaryCol.GetValue<long>(1));
     }
-}
\ No newline at end of file
+
+    [Fact]
+    public void Test_Sql_Snapshot_And_Restore()
+    {
+        var dir = Path.Combine(Path.GetTempPath(), $"sql_catalog_{Guid.NewGuid()}");
+        try
+        {
+            var data = new[]
+            {
+                new { Dept = "IT", Salary = 1000 },
+                new { Dept = "HR", Salary = 1500 }
+            };
+            using var df = DataFrame.From(data);
+
+            using (var ctx = new SqlContext())
+            {
+                ctx.Register("employees", df);
+                ctx.Snapshot(dir);
+            }
+
+            Assert.True(File.Exists(Path.Combine(dir, "catalog.json")));
+            Assert.True(File.Exists(Path.Combine(dir, "table_0.arrow")));
+
+            // 新的 Context 从磁盘恢复，原 Context 已释放
+            using var restored = SqlContext.Restore(dir);
+            using var res = restored.Execute("SELECT Dept FROM employees WHERE Salary > 1200").Collect();
+
+            Assert.Equal(1, res.Height);
+            Assert.Equal("HR", res.Column("Dept").GetValue<string>(0));
+
+            // 缺少清单时报错
+            Assert.Throws<Exception>(() => SqlContext.Restore(Path.Combine(dir, "missing")));
+        }
+        finally
+        {
+            if (Directory.Exists(dir)) Directory.Delete(dir, true);
+        }
+    }
+}
@@ -521,4 +521,129 @@ public class StreamingTests
         Assert.Equal(2, df2.Height);
         Assert.Equal(2, df2[1, "Id"]);
     }
+    [Fact]
+    public void Test_Lazy_CollectBatches()
+    {
+        using var df = new DataFrame(new Series("v", Enumerable.Range(0, 10).ToArray()));
+        using var lf = df.Lazy();
+
+        // 按行号重新排好后切分，最后一批可以不满
+        var sizes = new List<int>();
+        var values = new List<int>();
+        foreach (var batch in lf.CollectBatches(batchSize: 3))
+        {
+            using (batch)
+            {
+                sizes.Add(batch.Length);
+                var col = (Int32Array)batch.Column("v");
+                for (int i = 0; i < col.Length; i++) values.Add(col.GetValue(i)!.Value);
+            }
+        }
+        Assert.Equal(new[] { 3, 3, 3, 1 }, sizes);
+        Assert.Equal(Enumerable.Range(0, 10), values);
+
+        // 提前结束枚举会释放迭代器
+        using (var first = lf.CollectBatches(batchSize: 2).First())
+        {
+            Assert.Equal(2, first.Length);
+        }
+
+        // 查询出错时在枚举中抛出
+        using var bad = lf.Select(Col("missing"));
+        Assert.Throws<Exception>(() => bad.CollectBatches().ToList());
+
+        // LazyFrame 不被消费
+        using var res = lf.Collect();
+        Assert.Equal(10, res.Height);
+    }
+    [Fact]
+    public void Test_Lazy_Collect_With_Engine()
+    {
+        using var df = new DataFrame(new Series("v", new[] { 1, 2, 3 }));
+
+        foreach (var engine in new[] { Engine.Auto, Engine.InMemory, Engine.Streaming, Engine.Gpu })
+        {
+            using var lf = df.Lazy().Filter(Col("v") > 1);
+            using var res = lf.Collect(engine);
+            Assert.Equal(new[] { 2, 3 }, res["v"].ToArray<int>());
+        }
+
+        using var unknown = df.Lazy();
+        Assert.Throws<Exception>(() => unknown.Collect((Engine)99));
+    }
+    [Fact]
+    public void Test_Lazy_ScanRecordBatches_Pushdown()
+    {
+        var schema = new Schema.Builder()
+            .Field(new Field("a", Int32Type.Default, true))
+            .Field(new Field("b", Int32Type.Default, true))
+            .Build();
+
+        string[]? seenColumns = null;
+        long? seenLimit = null;
+
+        // 工厂忽略下推提示，始终返回全部列和行，由 Polars 负责裁剪
+        IEnumerable<RecordBatch> Factory(string[]? columns, long? nRows)
+        {
+            seenColumns = columns;
+            seenLimit = nRows;
+            yield return new RecordBatch(schema, [
+                new Int32Array.Builder().AppendRange([1, 2, 3, 4]).Build(),
+                new Int32Array.Builder().AppendRange([5, 6, 7, 8]).Build()
+            ], 4);
+        }
+
+        using var lf = LazyFrame.ScanRecordBatches(Factory, schema);
+
+        using var projected = lf.Select(Col("b")).Limit(2).Collect();
+        Assert.Equal(new[] { "b" }, seenColumns);
+        Assert.Equal((long?)2, seenLimit);
+        Assert.Equal(new[] { "b" }, projected.ColumnNames);
+        Assert.Equal(new[] { 5, 6 }, projected["b"].ToArray<int>());
+
+        // 无下推时收到 null
+        using var full = lf.Collect();
+        Assert.Null(seenColumns);
+        Assert.Null(seenLimit);
+        Assert.Equal(4, full.Height);
+        Assert.Equal(new[] { 1, 2, 3, 4 }, full["a"].ToArray<int>());
+    }
+    [Fact]
+    public void Test_Lazy_Sink_Callbacks()
+    {
+        using var df = new DataFrame(new Series("v", Enumerable.Range(0, 10).ToArray()));
+        using var lf = df.Lazy().Filter(Col("v") >= 2);
+
+        var events = new List<string>();
+        var values = new List<int>();
+        ulong total = 0;
+        lf.Sink(
+            schema =>
+            {
+                Assert.Empty(values); // schema 先于数据到达
+                events.Add("schema:" + string.Join(",", schema.FieldsList.Select(f => f.Name)));
+            },
+            batch =>
+            {
+                using (batch)
+                {
+                    var col = (Int32Array)batch.Column("v");
+                    for (int i = 0; i < col.Length; i++) values.Add(col.GetValue(i)!.Value);
+                }
+            },
+            rows =>
+            {
+                events.Add("finish");
+                total = rows;
+            });
+
+        Assert.Equal(new[] { "schema:v", "finish" }, events);
+        Assert.Equal(Enumerable.Range(2, 8), values);
+        Assert.Equal(8UL, total);
+
+        // 回调抛出的异常中止查询并传回调用方
+        var ex = Assert.Throws<Exception>(() =>
+            lf.Sink(_ => { }, _ => throw new InvalidOperationException("sink failed")));
+        Assert.Contains("sink failed", ex.Message);
+    }
 }
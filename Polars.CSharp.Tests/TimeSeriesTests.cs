@@ -129,4 +129,82 @@ public class TimeSeriesTests
         // [修复] 这里用 uint 或者 10u
         Assert.Equal(10, res.GetValue<int>(0, "Count"));
     }
-}
\ No newline at end of file
+    [Fact]
+    public void Test_Temporal_Ranges()
+    {
+        using var bounds = new DataFrame(
+            new Series("t0", new TimeOnly[] { new(9, 0) }),
+            new Series("t1", new TimeOnly[] { new(10, 0) })
+        );
+
+        // date_range: 按月偏移钳制到月末
+        using var dates = bounds.Select(
+            DateRange(Lit(new DateTime(2024, 1, 31)), Lit(new DateTime(2024, 4, 30)), "1mo").Alias("d")
+        );
+        Assert.Equal(4, dates.Height);
+        Assert.Equal(new DateOnly(2024, 2, 29), dates.GetValue<DateOnly>(1, "d"));
+        Assert.Equal(new DateOnly(2024, 4, 30), dates.GetValue<DateOnly>(3, "d"));
+
+        // datetime_range: closed="none" 去掉两端，显式指定单位和时区
+        using var dts = bounds.Select(
+            DatetimeRange(Lit(new DateTime(2024, 1, 31)), Lit(new DateTime(2024, 4, 30)), "1mo",
+                closed: "none", unit: TimeUnit.Milliseconds, timeZone: "UTC").Alias("dt")
+        );
+        Assert.Equal(2, dts.Height);
+        Assert.Equal(TimeUnit.Milliseconds, dts.Schema["dt"].Unit);
+        Assert.Equal("UTC", dts.Schema["dt"].TimeZone);
+
+        // time_range: 左闭右开
+        using var times = bounds.Select(
+            TimeRange(Col("t0"), Col("t1"), "15m", closed: "left").Alias("t")
+        );
+        Assert.Equal(4, times.Height);
+        Assert.Equal(new TimeOnly(9, 45), times.GetValue<TimeOnly>(3, "t"));
+
+        // date_ranges: 每行一个 List
+        using var spans = new DataFrame(
+            new Series("s", new DateOnly[] { new(2024, 1, 1), new(2024, 2, 1) }),
+            new Series("e", new DateOnly[] { new(2024, 1, 3), new(2024, 2, 2) })
+        );
+        using var perRow = spans.Select(
+            DateRanges(Col("s"), Col("e")).List.Len().Alias("n")
+        );
+        Assert.Equal(3, perRow.GetValue<int>(0, "n"));
+        Assert.Equal(2, perRow.GetValue<int>(1, "n"));
+
+        // 非法 interval 在构建表达式时报错
+        Assert.Throws<Exception>(() => DateRange(Lit(new DateTime(2024, 1, 1)), Lit(new DateTime(2024, 2, 1)), "1xyz"));
+    }
+    [Fact]
+    public void Test_Lazy_Rolling_GroupBy()
+    {
+        using var df = new DataFrame(
+            new Series("t", new[] { 1L, 2L, 3L, 5L }),
+            new Series("v", new[] { 1, 1, 1, 1 })
+        );
+        using var lf = df.Lazy();
+
+        // 默认窗口 (t - 2, t]
+        using var res = lf.Rolling("t", "2i").Agg(Col("v").Sum().Alias("s")).Collect();
+        Assert.Equal(new[] { 1, 2, 2, 1 }, res["s"].ToArray<int>());
+
+        // 两端闭合 [t - 2, t]
+        using var both = lf.Rolling("t", "2i", closedWindow: ClosedWindow.Both)
+            .Agg(Col("v").Sum().Alias("s"))
+            .Collect();
+        Assert.Equal(new[] { 1, 2, 3, 2 }, both["s"].ToArray<int>());
+
+        // 时间索引
+        var start = new DateTime(2024, 1, 1);
+        using var tdf = DataFrame.FromColumns(new
+        {
+            Time = new[] { start, start.AddDays(1), start.AddDays(3) },
+            Val = new[] { 1, 2, 3 }
+        });
+        using var tres = tdf.Lazy()
+            .Rolling("Time", TimeSpan.FromDays(2))
+            .Agg(Col("Val").Sum().Alias("s"))
+            .Collect();
+        Assert.Equal(new[] { 1, 3, 3 }, tres["s"].ToArray<int>());
+    }
+}
@@ -310,4 +310,101 @@ public class UdfTests
         Assert.Equal("Value:10", res.Column("status").GetValue<string>(0));
         Assert.Equal("FoundNull", res.Column("status").GetValue<string>(1)); // 成功捕获了 Null 输入！
     }
+    [Fact]
+    public void Test_UDF_MapElements()
+    {
+        using var df = new DataFrame(
+            new Series("g", new[] { "a", "a", "b" }),
+            new Series("v", new long[] { 1, 2, 3 })
+        );
+
+        // 普通 Select：逐元素
+        using var res = df.Select(
+            Col("v").MapElements<long, long>(x => x * 10, DataType.Int64).Alias("v10"),
+            Col("v").MapElements<long, string>(x => $"#{x}", DataType.String).Alias("tag")
+        );
+        Assert.Equal(30L, res.GetValue<long>(2, "v10"));
+        Assert.Equal("#1", res.GetValue<string>(0, "tag"));
+
+        // group_by 中仍然按元素作用，每组得到同样长度的结果
+        using var agg = df
+            .GroupBy(Col("g"))
+            .Agg(Col("v").MapElements<long, long>(x => x + 100, DataType.Int64).Alias("mapped"))
+            .Sort("g");
+        Assert.Equal(new List<long> { 101, 102 }, agg["mapped"].GetValue<List<long>>(0));
+        Assert.Equal(new List<long> { 103 }, agg["mapped"].GetValue<List<long>>(1));
+
+        // 输出长度与输入不一致时报错
+        Func<IArrowArray, IArrowArray> shrink = arr => new Int64Array.Builder().Append(1).Build();
+        Assert.Throws<Exception>(() => df.Select(Col("v").MapElements(shrink, DataType.Int64)));
+    }
+    [Fact]
+    public void Test_UDF_MapBatches()
+    {
+        using var df = new DataFrame(
+            new Series("g", new[] { "a", "a", "b", "b" }),
+            new Series("v", new long[] { 1, 3, 2, 2 })
+        );
+
+        // 依赖整批数据：每个值除以整批总和
+        Func<IArrowArray, IArrowArray> share = arr =>
+        {
+            var ints = (Int64Array)arr;
+            double total = 0;
+            for (int i = 0; i < ints.Length; i++) total += ints.GetValue(i) ?? 0;
+            var b = new DoubleArray.Builder();
+            for (int i = 0; i < ints.Length; i++) b.Append((ints.GetValue(i) ?? 0) / total);
+            return b.Build();
+        };
+
+        using var whole = df.Select(Col("v").MapBatches(share, DataType.Float64).Alias("share"));
+        Assert.Equal(0.125, whole.GetValue<double>(0, "share"));
+
+        // 非 elementwise：group_by 中按组调用，每组各自归一化
+        using var agg = df
+            .GroupBy(Col("g"))
+            .Agg(Col("v").MapBatches(share, DataType.Float64).Alias("share"))
+            .Sort("g");
+        Assert.Equal(new List<double> { 0.25, 0.75 }, agg["share"].GetValue<List<double>>(0));
+        Assert.Equal(new List<double> { 0.5, 0.5 }, agg["share"].GetValue<List<double>>(1));
+
+        // elementwise 的 UDF 错误照样传回 C#
+        Func<IArrowArray, IArrowArray> fail = UdfLogic.AlwaysFail;
+        Assert.Throws<Exception>(() => df.Select(Col("v").MapBatches(fail, DataType.SameAsInput, isElementwise: true)));
+    }
+    [Fact]
+    public void Test_Lazy_MapBatches_Transform()
+    {
+        using var df = new DataFrame(new Series("a", new[] { 1, 2, 3 }));
+
+        // 声明输出 schema 后，后续算子可以引用新列
+        var schema = new Dictionary<string, DataType> { ["a"] = DataType.Int32, ["b"] = DataType.Int32 };
+        using var lf = df.Lazy()
+            .MapBatches(d => d.WithColumns((Col("a") * 10).Alias("b")), schema, validateOutput: true)
+            .Filter(Col("b") > 10);
+        using var res = lf.Collect();
+        Assert.Equal(new[] { "a", "b" }, res.ColumnNames);
+        Assert.Equal(new[] { 2, 3 }, res["a"].ToArray<int>());
+        Assert.Equal(new[] { 20, 30 }, res["b"].ToArray<int>());
+
+        // 不给 schema：输出与输入相同
+        using var same = df.Lazy().MapBatches(d => d.Filter(Col("a") != 2)).Collect();
+        Assert.Equal(new[] { 1, 3 }, same["a"].ToArray<int>());
+
+        // 输出与声明不符时报错
+        using var mismatch = df.Lazy().MapBatches(d => d.Select(Col("a").Cast(DataType.Int64)), validateOutput: true);
+        Assert.Throws<Exception>(() => mismatch.Collect());
+
+        // C# 端异常传回 Rust
+        using var failing = df.Lazy().MapBatches(_ => throw new InvalidOperationException("boom"));
+        var ex = Assert.Throws<Exception>(() => failing.Collect());
+        Assert.Contains("boom", ex.Message);
+
+        // 同一个计划反复执行：每批的 Arrow 结构体用完即释放，不随计划存活累积
+        for (int i = 0; i < 100; i++)
+        {
+            using var again = lf.Collect();
+            Assert.Equal(2, again.Height);
+        }
+    }
 }
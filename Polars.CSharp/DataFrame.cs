@@ -6,6 +6,7 @@ using Polars.NET.Core.Data;
 using System.Collections.Concurrent;
 using System.Collections;
 using System.Text;
+using System.Text.Json;
 
 namespace Polars.CSharp;
 
@@ -58,6 +59,24 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         }
     }
     /// <summary>
+    /// Build a cardinality report with one row per column:
+    /// column, dtype, approx_n_unique (HyperLogLog estimate) and null_count.
+    /// </summary>
+    /// <param name="precision">HyperLogLog register bits, between 4 and 18.</param>
+    public DataFrame CardinalityReport(byte precision = 14)
+    {
+        return new DataFrame(PolarsWrapper.CardinalityReport(Handle, precision));
+    }
+    /// <summary>
+    /// Count rows per group. Returns the key columns plus a "count" column.
+    /// </summary>
+    /// <param name="by">Key column names.</param>
+    /// <param name="sort">Sort by count descending (ties keep first-seen order). Otherwise groups keep first-seen order.</param>
+    public DataFrame GroupSizes(string[] by, bool sort = false)
+    {
+        return new DataFrame(PolarsWrapper.GroupSizes(Handle, by, sort));
+    }
+    /// <summary>
     /// Prints the schema to the console in a tree format.
     /// Useful for debugging column names and data types.
     /// </summary>
@@ -111,6 +130,55 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         return new DataFrame(handle);
     }
     /// <summary>
+    /// Reads a CSV file into a DataFrame with the full set of parser options.
+    /// </summary>
+    /// <param name="path">Path to the CSV file.</param>
+    /// <param name="options">Parser options.</param>
+    /// <param name="schema">Optional column types that override inference.</param>
+    public static DataFrame ReadCsv(string path, CsvReadOptions options, Dictionary<string, DataType>? schema = null)
+    {
+        var schemaHandles = schema?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new DataFrame(PolarsWrapper.ReadCsv(
+            path, schemaHandles, options.ToNative(), options.CommentPrefix, options.NullValues));
+    }
+    /// <summary>
+    /// Read CSV data that is already in memory (e.g. an HTTP response body).
+    /// </summary>
+    /// <param name="data">CSV bytes.</param>
+    /// <param name="options">Parser options (defaults when null).</param>
+    /// <param name="schema">Optional column types that override inference.</param>
+    public static DataFrame ReadCsv(byte[] data, CsvReadOptions? options = null, Dictionary<string, DataType>? schema = null)
+    {
+        options ??= new CsvReadOptions();
+        var schemaHandles = schema?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new DataFrame(PolarsWrapper.ReadCsv(
+            data, schemaHandles, options.ToNative(), options.CommentPrefix, options.NullValues));
+    }
+    /// <summary>
+    /// Read Parquet data that is already in memory.
+    /// </summary>
+    /// <param name="data">Parquet file bytes.</param>
+    public static DataFrame ReadParquet(byte[] data)
+    {
+        return new DataFrame(PolarsWrapper.ReadParquet(data));
+    }
+    /// <summary>
+    /// Read IPC (Feather) data that is already in memory.
+    /// </summary>
+    /// <param name="data">IPC file bytes.</param>
+    public static DataFrame ReadIpc(byte[] data)
+    {
+        return new DataFrame(PolarsWrapper.ReadIpc(data));
+    }
+    /// <summary>
+    /// Read JSON array data that is already in memory.
+    /// </summary>
+    /// <param name="data">UTF-8 JSON bytes.</param>
+    public static DataFrame ReadJson(byte[] data)
+    {
+        return new DataFrame(PolarsWrapper.ReadJson(data));
+    }
+    /// <summary>
     /// Read Parquet File
     /// </summary>
     /// <param name="path"></param>
@@ -121,6 +189,48 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         return new DataFrame(PolarsWrapper.ReadParquet(path));
     }
     /// <summary>
+    /// Read a Parquet file with column selection, row limit and row index options.
+    /// </summary>
+    /// <param name="path">Path to the Parquet file.</param>
+    /// <param name="options">Read options.</param>
+    public static DataFrame ReadParquet(string path, ParquetReadOptions options)
+    {
+        return new DataFrame(PolarsWrapper.ReadParquet(
+            path, options.ToNative(), options.Columns, options.RowIndexName));
+    }
+    /// <summary>
+    /// Read the file-level key/value metadata of a Parquet file.
+    /// Entries without a value are returned as null.
+    /// </summary>
+    /// <param name="path">Path to the Parquet file.</param>
+    public static Dictionary<string, string?> ReadParquetMetadata(string path)
+    {
+        var json = PolarsWrapper.ReadParquetKeyValueMetadata(path);
+        return JsonSerializer.Deserialize<Dictionary<string, string?>>(json) ?? new();
+    }
+    /// <summary>
+    /// Read the table and column descriptions written by <see cref="WriteParquet(string, string?, Dictionary{string, string}?)"/>.
+    /// </summary>
+    /// <param name="path">Path to the Parquet file.</param>
+    /// <returns>The table description (null if absent) and the column descriptions.</returns>
+    public static (string? Table, Dictionary<string, string> Columns) ReadParquetDescriptions(string path)
+    {
+        var json = PolarsWrapper.ReadParquetDescriptions(path);
+        using var doc = JsonDocument.Parse(json);
+        var root = doc.RootElement;
+
+        string? table = root.GetProperty("table").ValueKind == JsonValueKind.Null
+            ? null
+            : root.GetProperty("table").GetString();
+
+        var columns = new Dictionary<string, string>();
+        foreach (var prop in root.GetProperty("columns").EnumerateObject())
+        {
+            columns[prop.Name] = prop.Value.GetString() ?? "";
+        }
+        return (table, columns);
+    }
+    /// <summary>
     /// Read JSON File
     /// </summary>
     /// <param name="path"></param>
@@ -140,6 +250,33 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         //
         return new DataFrame(PolarsWrapper.ReadIpc(path));
     }
+    /// <summary>
+    /// Read IPC File, optionally memory-mapping it instead of copying it into memory.
+    /// Memory mapping only applies to uncompressed files; compressed files are read normally.
+    /// The file must not be modified while the returned DataFrame is alive.
+    /// </summary>
+    /// <param name="path">Path to the IPC file.</param>
+    /// <param name="memoryMap">Map the file instead of reading it.</param>
+    public static DataFrame ReadIpc(string path, bool memoryMap)
+    {
+        return new DataFrame(PolarsWrapper.ReadIpc(path, memoryMap));
+    }
+    /// <summary>
+    /// Read an Avro file.
+    /// </summary>
+    /// <param name="path">Path to the Avro file.</param>
+    public static DataFrame ReadAvro(string path)
+    {
+        return new DataFrame(PolarsWrapper.ReadAvro(path));
+    }
+    /// <summary>
+    /// Read a file in the Arrow IPC stream format (as written by <see cref="WriteIpcStream"/>).
+    /// </summary>
+    /// <param name="path">Path to the stream file.</param>
+    public static DataFrame ReadIpcStream(string path)
+    {
+        return new DataFrame(PolarsWrapper.ReadIpcStream(path));
+    }
 
     /// <summary>
     /// Create DataFrame from Apache Arrow RecordBatch.
@@ -311,6 +448,15 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         return new DataFrame(PolarsWrapper.Filter(Handle, h));
     }
     /// <summary>
+    /// Filter rows with a boolean mask Series of the same length. Null mask values drop the row.
+    /// </summary>
+    /// <param name="mask"></param>
+    /// <returns></returns>
+    public DataFrame Filter(Series mask)
+    {
+        return new DataFrame(PolarsWrapper.FilterMask(Handle, mask.Handle));
+    }
+    /// <summary>
     /// Filter rows based on a boolean expression. 
     /// </summary>
     /// <param name="exprs"></param>
@@ -593,6 +739,17 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         PolarsWrapper.WriteCsv(Handle, path);
     }
     /// <summary>
+    /// Write DataFrame to CSV File with writer options
+    /// </summary>
+    /// <param name="path"></param>
+    /// <param name="options"></param>
+    public void WriteCsv(string path, CsvWriteOptions options)
+    {
+        PolarsWrapper.WriteCsv(
+            Handle, path, options.ToNative(),
+            options.LineTerminator, options.DateFormat, options.TimeFormat, options.DatetimeFormat, options.NullValue);
+    }
+    /// <summary>
     /// Write DataFrame to Parquet File
     /// </summary>
     /// <param name="path"></param>
@@ -601,6 +758,26 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         PolarsWrapper.WriteParquet(Handle, path);
     }
     /// <summary>
+    /// Write DataFrame to Parquet File with compression, statistics and page options.
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    /// <param name="options">Write options.</param>
+    public void WriteParquet(string path, ParquetWriteOptions options)
+    {
+        PolarsWrapper.WriteParquet(Handle, path, options.ToNative());
+    }
+    /// <summary>
+    /// Write DataFrame to Parquet File with a table description and per-column descriptions
+    /// stored in the file-level key/value metadata.
+    /// </summary>
+    /// <param name="path"></param>
+    /// <param name="tableDescription">Description of the whole table. Null to skip.</param>
+    /// <param name="columnDescriptions">Column name -> description. Every column must exist.</param>
+    public void WriteParquet(string path, string? tableDescription, Dictionary<string, string>? columnDescriptions = null)
+    {
+        PolarsWrapper.WriteParquetWithDescriptions(Handle, path, tableDescription, columnDescriptions);
+    }
+    /// <summary>
     /// Write DataFrame to IPC File    
     /// </summary>
     /// <param name="path"></param>
@@ -609,6 +786,34 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         PolarsWrapper.WriteIpc(Handle, path);
     }
     /// <summary>
+    /// Write DataFrame to IPC File with body compression.
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    /// <param name="compression">Compression codec.</param>
+    public void WriteIpc(string path, IpcCompression compression)
+    {
+        PolarsWrapper.WriteIpc(Handle, path, compression.ToNative());
+    }
+    /// <summary>
+    /// Write DataFrame to Avro File.
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    /// <param name="compression">Compression codec.</param>
+    public void WriteAvro(string path, AvroCompression compression = AvroCompression.None)
+    {
+        PolarsWrapper.WriteAvro(Handle, path, compression.ToNative());
+    }
+    /// <summary>
+    /// Write DataFrame in the Arrow IPC stream format.
+    /// Unlike the IPC file format there is no footer, so readers can consume it while it is written.
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    /// <param name="compression">Compression codec.</param>
+    public void WriteIpcStream(string path, IpcCompression compression = IpcCompression.None)
+    {
+        PolarsWrapper.WriteIpcStream(Handle, path, compression.ToNative());
+    }
+    /// <summary>
     /// Write DataFrame to JSON File
     /// </summary>
     /// <param name="path"></param>
@@ -617,6 +822,14 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         PolarsWrapper.WriteJson(Handle, path);
     }
     /// <summary>
+    /// Write DataFrame to NDJSON File (one JSON object per line).
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    public void WriteNdjson(string path)
+    {
+        PolarsWrapper.WriteNdjson(Handle, path);
+    }
+    /// <summary>
     /// 将 DataFrame 的数据按 Batch 导出（零拷贝）。
     /// 这是实现自定义 Eager Sink (如 WriteDatabase) 的基础。
     /// </summary>
@@ -832,6 +1045,28 @@ public class DataFrame : IDisposable,IEnumerable<Series>
         Handle = PolarsWrapper.DataFrameNew(handles);
     }
     /// <summary>
+    /// Create a DataFrame with zero rows and the given columns.
+    /// Nested types such as List and Struct are kept as declared.
+    /// </summary>
+    public static DataFrame Empty(Dictionary<string, DataType> schema)
+    {
+        var handles = schema.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new DataFrame(PolarsWrapper.DataFrameEmptyFromSchema(handles));
+    }
+    /// <summary>
+    /// Generate random data for the given schema, for fixtures and benchmarks.
+    /// The same seed and schema always produce the same data.
+    /// </summary>
+    /// <param name="schema">Column names and types, nested types included.</param>
+    /// <param name="rows">Number of rows to generate.</param>
+    /// <param name="seed">Random seed.</param>
+    /// <param name="nullRatio">Probability (0 to 1) that a value is null.</param>
+    public static DataFrame Generate(Dictionary<string, DataType> schema, ulong rows, ulong seed = 0, double nullRatio = 0.0)
+    {
+        var handles = schema.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new DataFrame(PolarsWrapper.DataFrameGenerate(handles, rows, seed, nullRatio));
+    }
+    /// <summary>
     /// [High Performance] Stream data into Polars using Arrow C Stream Interface.
     /// This method supports datasets larger than available RAM by streaming chunks directly to Polars.
     /// </summary>
@@ -0,0 +1,37 @@
+using Polars.NET.Core;
+
+namespace Polars.CSharp;
+
+/// <summary>
+/// Process-global registry that lets independent components exchange DataFrames by name.
+/// Frames are copy-on-write, so Put and Get do not copy the data.
+/// </summary>
+public static class DataFrameRegistry
+{
+    /// <summary>
+    /// Register a DataFrame under a name, replacing any frame already registered with it.
+    /// The caller keeps ownership of <paramref name="df"/>.
+    /// </summary>
+    public static void Put(string name, DataFrame df)
+    {
+        PolarsWrapper.RegistryPut(name, df.Handle);
+    }
+
+    /// <summary>
+    /// Get the DataFrame registered under a name.
+    /// Throws if nothing is registered under it.
+    /// </summary>
+    public static DataFrame Get(string name)
+    {
+        return new DataFrame(PolarsWrapper.RegistryGet(name));
+    }
+
+    /// <summary>
+    /// Remove a DataFrame from the registry.
+    /// </summary>
+    /// <returns>True if an entry was removed, false if the name was not registered.</returns>
+    public static bool Remove(string name)
+    {
+        return PolarsWrapper.RegistryRemove(name);
+    }
+}
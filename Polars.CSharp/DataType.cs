@@ -80,14 +80,14 @@ public class DataType : IDisposable
         return _displayString;
     }
     /// <summary>
-    /// 如果是 List 类型，返回内部元素的类型。否则返回 null。
+    /// 如果是 List / Array 类型，返回内部元素的类型。否则返回 null。
     /// </summary>
     public DataType? InnerType 
     {
         get 
         {
             // 1. 快速检查，避免不必要的 FFI 调用
-            if (Kind != DataTypeKind.List) return null;
+            if (Kind != DataTypeKind.List && Kind != DataTypeKind.Array) return null;
 
             // 2. 调用 Wrapper 获取内部 Handle (Rust Clone 的新对象)
             var innerHandle = PolarsWrapper.GetListInnerType(Handle);
@@ -114,6 +114,18 @@ public class DataType : IDisposable
         _ => false
     };
 
+    /// <summary>
+    /// Returns whether Polars can cast values of kind <paramref name="from"/> to kind <paramref name="to"/>,
+    /// and whether that cast is lossless or lossy.
+    /// </summary>
+    public static CastSupport SupportedConversion(DataTypeKind from, DataTypeKind to)
+        => (CastSupport)PolarsWrapper.SupportedConversion((int)from, (int)to);
+
+    /// <summary>
+    /// Returns how a cast from this type to <paramref name="target"/> behaves.
+    /// </summary>
+    public CastSupport SupportedConversionTo(DataType target) => SupportedConversion(Kind, target.Kind);
+
     // ==========================================
     // Primitive Factories (Static Properties)
     // ==========================================
@@ -182,10 +194,19 @@ public class DataType : IDisposable
         // Wrapper 负责调用 Rust 创建 List<inner>
         return new DataType(PolarsWrapper.NewListType(innerType.Handle), DataTypeKind.List);
     }
+    /// <summary>
+    /// Creates a fixed-width Array type.
+    /// Usage: DataType.Array(DataType.Float64, 3)
+    /// </summary>
+    public static DataType Array(DataType innerType, int width)
+    {
+        if (width < 0) throw new ArgumentOutOfRangeException(nameof(width));
+        return new DataType(PolarsWrapper.NewArrayType(innerType.Handle, (ulong)width), DataTypeKind.Array);
+    }
     public static DataType Struct(string[] names, DataType[] types)
     {
         // 提取 Handles
-        var handles = Array.ConvertAll(types, t => t.Handle);
+        var handles = System.Array.ConvertAll(types, t => t.Handle);
         
         // 调用新 Wrapper
         var h = PolarsWrapper.NewStructType(names, handles);
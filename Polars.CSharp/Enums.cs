@@ -10,6 +10,48 @@ public enum JoinType
     Inner,Left, Outer,Cross,Semi,Anti
 }
 /// <summary>
+/// Uniqueness check on join keys. A violation raises an error on collect.
+/// </summary>
+public enum JoinValidation
+{
+    ManyToMany = 0,
+    OneToOne = 1,
+    OneToMany = 2,
+    ManyToOne = 3
+}
+/// <summary>
+/// Whether the left and right key columns are merged into one.
+/// JoinSpecific follows the join type (same as Python's default).
+/// </summary>
+public enum JoinCoalesce
+{
+    JoinSpecific = 0,
+    CoalesceColumns = 1,
+    KeepColumns = 2
+}
+/// <summary>
+/// Which row to keep among duplicates.
+/// Any is the fastest and keeps an arbitrary row; None drops every duplicated row.
+/// </summary>
+public enum UniqueKeepStrategy
+{
+    First = 0,
+    Last = 1,
+    Any = 2,
+    None = 3
+}
+/// <summary>
+/// Execution engine used by collect.
+/// Gpu falls back to InMemory when no GPU executor is compiled in.
+/// </summary>
+public enum Engine
+{
+    Auto = 0,
+    InMemory = 1,
+    Streaming = 2,
+    Gpu = 3
+}
+/// <summary>
 /// Specifies the aggregation function for pivot operations.
 /// </summary>
 public enum PivotAgg
@@ -17,6 +59,19 @@ public enum PivotAgg
     First,Sum,Min,Max, Mean,Median,Count,Len,Last
 }
 /// <summary>
+/// Rolling aggregations generated by <see cref="LazyFrame.WithRollingAggregates"/>.
+/// </summary>
+public enum RollingOp
+{
+    Mean = 0,
+    Sum = 1,
+    Min = 2,
+    Max = 3,
+    Std = 4,
+    Var = 5,
+    Median = 6
+}
+/// <summary>
 /// TimeUnit Enums
 /// </summary>
 public enum TimeUnit
@@ -32,11 +87,94 @@ public enum TimeUnit
     Year = 8
 }
 /// <summary>
+/// Text encoding of a CSV file.
+/// LossyUtf8 replaces invalid bytes with U+FFFD instead of failing.
+/// </summary>
+public enum CsvEncoding
+{
+    Utf8 = 0,
+    LossyUtf8 = 1
+}
+/// <summary>
+/// How a Parquet file is read in parallel.
+/// </summary>
+public enum ParallelStrategy
+{
+    /// <summary>Let Polars choose based on the file layout.</summary>
+    Auto = 0,
+    None = 1,
+    /// <summary>Decode columns in parallel.</summary>
+    Columns = 2,
+    /// <summary>Decode row groups in parallel.</summary>
+    RowGroups = 3,
+    /// <summary>Evaluate the predicate first, then decode only the matching rows.</summary>
+    Prefiltered = 4
+}
+/// <summary>
+/// Compression codec for Parquet output.
+/// </summary>
+public enum ParquetCompression
+{
+    Uncompressed = 0,
+    Snappy = 1,
+    Gzip = 2,
+    Brotli = 3,
+    Zstd = 4,
+    Lz4Raw = 5
+}
+/// <summary>
+/// Column statistics written to Parquet output.
+/// </summary>
+public enum ParquetStatistics
+{
+    /// <summary>min, max and null count.</summary>
+    Default = 0,
+    /// <summary>Default statistics plus distinct count.</summary>
+    Full = 1,
+    /// <summary>No statistics.</summary>
+    None = 2
+}
+/// <summary>
+/// Compression codec for Arrow IPC output.
+/// </summary>
+public enum IpcCompression
+{
+    None = 0,
+    LZ4 = 1,
+    Zstd = 2
+}
+/// <summary>
+/// Compression codec for Avro output.
+/// </summary>
+public enum AvroCompression
+{
+    None = 0,
+    Deflate = 1,
+    Snappy = 2
+}
+/// <summary>
+/// When fields are quoted in CSV output.
+/// </summary>
+public enum QuoteStyle
+{
+    /// <summary>Only fields that contain the separator, quote or a line break.</summary>
+    Necessary = 0,
+    Always = 1,
+    /// <summary>All fields that are not numbers.</summary>
+    NonNumeric = 2,
+    /// <summary>Never quote, even if the output becomes invalid CSV.</summary>
+    Never = 3
+}
+/// <summary>
 /// Concat Type Enum
 /// </summary>
 public enum ConcatType
 {
-    Vertical,Horizontal,Diagonal
+    Vertical,Horizontal,Diagonal,
+    /// <summary>
+    /// Diagonal, and columns with different types are cast to their common supertype. LazyFrame only.
+    /// </summary>
+    DiagonalRelaxed
 }
 
 /// <summary>
@@ -58,10 +196,22 @@ public enum DataTypeKind
     Null=18,
     Struct=19,List=20,Categorical=21,
     Decimal=22,
+    Array=23,
     Unknown = 0,
     SameAsInput=0
 }
 
+/// <summary>
+/// How a cast between two DataTypeKinds behaves.
+/// Lossless means casting there and back reproduces every value for every parameter of the target kind.
+/// </summary>
+public enum CastSupport
+{
+    Unsupported = 0,
+    Lossless = 1,
+    Lossy = 2
+}
+
 /// <summary>
 /// Defines which boundary of the window to use for the label.
 /// </summary>
@@ -125,6 +275,110 @@ internal static class EnumExtensions
         _ => CoreEnums.PlJoinType.Inner
     };
 
+    // 未知值原样透传，由 Rust 端统一报错
+    public static CoreEnums.PlJoinValidation ToNative(this JoinValidation validation) => validation switch
+    {
+        JoinValidation.ManyToMany => CoreEnums.PlJoinValidation.ManyToMany,
+        JoinValidation.OneToOne => CoreEnums.PlJoinValidation.OneToOne,
+        JoinValidation.OneToMany => CoreEnums.PlJoinValidation.OneToMany,
+        JoinValidation.ManyToOne => CoreEnums.PlJoinValidation.ManyToOne,
+        _ => (CoreEnums.PlJoinValidation)(int)validation
+    };
+
+    public static CoreEnums.PlJoinCoalesce ToNative(this JoinCoalesce coalesce) => coalesce switch
+    {
+        JoinCoalesce.JoinSpecific => CoreEnums.PlJoinCoalesce.JoinSpecific,
+        JoinCoalesce.CoalesceColumns => CoreEnums.PlJoinCoalesce.CoalesceColumns,
+        JoinCoalesce.KeepColumns => CoreEnums.PlJoinCoalesce.KeepColumns,
+        _ => (CoreEnums.PlJoinCoalesce)(int)coalesce
+    };
+
+    public static CoreEnums.PlCsvEncoding ToNative(this CsvEncoding encoding) => encoding switch
+    {
+        CsvEncoding.Utf8 => CoreEnums.PlCsvEncoding.Utf8,
+        CsvEncoding.LossyUtf8 => CoreEnums.PlCsvEncoding.LossyUtf8,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlCsvEncoding)(int)encoding
+    };
+
+    public static CoreEnums.PlParallelStrategy ToNative(this ParallelStrategy strategy) => strategy switch
+    {
+        ParallelStrategy.Auto => CoreEnums.PlParallelStrategy.Auto,
+        ParallelStrategy.None => CoreEnums.PlParallelStrategy.None,
+        ParallelStrategy.Columns => CoreEnums.PlParallelStrategy.Columns,
+        ParallelStrategy.RowGroups => CoreEnums.PlParallelStrategy.RowGroups,
+        ParallelStrategy.Prefiltered => CoreEnums.PlParallelStrategy.Prefiltered,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlParallelStrategy)(int)strategy
+    };
+
+    public static CoreEnums.PlParquetCompression ToNative(this ParquetCompression compression) => compression switch
+    {
+        ParquetCompression.Uncompressed => CoreEnums.PlParquetCompression.Uncompressed,
+        ParquetCompression.Snappy => CoreEnums.PlParquetCompression.Snappy,
+        ParquetCompression.Gzip => CoreEnums.PlParquetCompression.Gzip,
+        ParquetCompression.Brotli => CoreEnums.PlParquetCompression.Brotli,
+        ParquetCompression.Zstd => CoreEnums.PlParquetCompression.Zstd,
+        ParquetCompression.Lz4Raw => CoreEnums.PlParquetCompression.Lz4Raw,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlParquetCompression)(int)compression
+    };
+
+    public static CoreEnums.PlParquetStatistics ToNative(this ParquetStatistics statistics) => statistics switch
+    {
+        ParquetStatistics.Default => CoreEnums.PlParquetStatistics.Default,
+        ParquetStatistics.Full => CoreEnums.PlParquetStatistics.Full,
+        ParquetStatistics.None => CoreEnums.PlParquetStatistics.None,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlParquetStatistics)(int)statistics
+    };
+
+    public static CoreEnums.PlIpcCompression ToNative(this IpcCompression compression) => compression switch
+    {
+        IpcCompression.None => CoreEnums.PlIpcCompression.None,
+        IpcCompression.LZ4 => CoreEnums.PlIpcCompression.LZ4,
+        IpcCompression.Zstd => CoreEnums.PlIpcCompression.Zstd,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlIpcCompression)(int)compression
+    };
+
+    public static CoreEnums.PlAvroCompression ToNative(this AvroCompression compression) => compression switch
+    {
+        AvroCompression.None => CoreEnums.PlAvroCompression.None,
+        AvroCompression.Deflate => CoreEnums.PlAvroCompression.Deflate,
+        AvroCompression.Snappy => CoreEnums.PlAvroCompression.Snappy,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlAvroCompression)(int)compression
+    };
+
+    public static CoreEnums.PlQuoteStyle ToNative(this QuoteStyle style) => style switch
+    {
+        QuoteStyle.Necessary => CoreEnums.PlQuoteStyle.Necessary,
+        QuoteStyle.Always => CoreEnums.PlQuoteStyle.Always,
+        QuoteStyle.NonNumeric => CoreEnums.PlQuoteStyle.NonNumeric,
+        QuoteStyle.Never => CoreEnums.PlQuoteStyle.Never,
+        // 未知值原样透传，由 Rust 端统一报错
+        _ => (CoreEnums.PlQuoteStyle)(int)style
+    };
+
+    public static CoreEnums.PlUniqueKeep ToNative(this UniqueKeepStrategy keep) => keep switch
+    {
+        UniqueKeepStrategy.First => CoreEnums.PlUniqueKeep.First,
+        UniqueKeepStrategy.Last => CoreEnums.PlUniqueKeep.Last,
+        UniqueKeepStrategy.Any => CoreEnums.PlUniqueKeep.Any,
+        UniqueKeepStrategy.None => CoreEnums.PlUniqueKeep.None,
+        _ => (CoreEnums.PlUniqueKeep)(int)keep
+    };
+
+    public static CoreEnums.PlEngine ToNative(this Engine engine) => engine switch
+    {
+        Engine.Auto => CoreEnums.PlEngine.Auto,
+        Engine.InMemory => CoreEnums.PlEngine.InMemory,
+        Engine.Streaming => CoreEnums.PlEngine.Streaming,
+        Engine.Gpu => CoreEnums.PlEngine.Gpu,
+        _ => (CoreEnums.PlEngine)(int)engine
+    };
+
     //
     public static CoreEnums.PlPivotAgg ToNative(this PivotAgg agg) => agg switch
     {
@@ -140,11 +394,25 @@ internal static class EnumExtensions
         _ => CoreEnums.PlPivotAgg.First
     };
     
+    // 未知值原样透传，由 Rust 端统一报错
+    public static CoreEnums.PlRollingOp ToNative(this RollingOp op) => op switch
+    {
+        RollingOp.Mean => CoreEnums.PlRollingOp.Mean,
+        RollingOp.Sum => CoreEnums.PlRollingOp.Sum,
+        RollingOp.Min => CoreEnums.PlRollingOp.Min,
+        RollingOp.Max => CoreEnums.PlRollingOp.Max,
+        RollingOp.Std => CoreEnums.PlRollingOp.Std,
+        RollingOp.Var => CoreEnums.PlRollingOp.Var,
+        RollingOp.Median => CoreEnums.PlRollingOp.Median,
+        _ => (CoreEnums.PlRollingOp)(int)op
+    };
+
     public static CoreEnums.PlConcatType ToNative(this ConcatType type) => type switch
     {
         ConcatType.Vertical => CoreEnums.PlConcatType.Vertical,
         ConcatType.Horizontal => CoreEnums.PlConcatType.Horizontal,
         ConcatType.Diagonal => CoreEnums.PlConcatType.Diagonal,
+        ConcatType.DiagonalRelaxed => CoreEnums.PlConcatType.DiagonalRelaxed,
         _ => CoreEnums.PlConcatType.Vertical
     };
     internal static CoreEnums.PlLabel ToNative(this Label label) => label switch
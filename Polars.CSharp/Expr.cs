@@ -1,4 +1,5 @@
 #pragma warning disable CS1591 // 缺少对公共可见类型或成员的 XML 注释
+using System.Text;
 using Apache.Arrow;
 using Polars.NET.Core;
 
@@ -15,7 +16,7 @@ public class Expr : IDisposable
     {
         Handle = handle;
     }
-    private static Expr MakeLit(object val)
+    internal static Expr MakeLit(object val)
     {
         // 1. 如果传进来的已经是 Expr，直接返回（防止套娃）
         if (val is Expr e) return e;
@@ -36,9 +37,8 @@ public class Expr : IDisposable
             // --- 浮点 ---
             double d => new Expr(PolarsWrapper.Lit(d)),
             float f => new Expr(PolarsWrapper.Lit(f)),
-            // decimal 通常需要转 double，因为 Polars 内核没有 decimal128 的直接 Lit 入口(通常)
-            // 或者如果有 pl_lit_decimal 再改
-            // decimal dec => new Expr(PolarsWrapper.Lit((double)dec)), 
+            // decimal 走 pl_lit_decimal，保留 scale
+            decimal dec => Polars.Lit(dec),
 
             // --- 基础 ---
             string str => new Expr(PolarsWrapper.Lit(str)),
@@ -47,6 +47,14 @@ public class Expr : IDisposable
             // --- 时间 ---
             // 假设 Wrapper 里有对应实现
             DateTime dt => new Expr(PolarsWrapper.Lit(dt)),
+            DateTimeOffset dto => Polars.Lit(dto),
+            DateOnly date => Polars.Lit(date),
+            TimeOnly time => Polars.Lit(time),
+            TimeSpan span => Polars.Lit(span),
+
+            // --- 二进制 ---
+            byte[] bytes => Polars.Lit(bytes),
+            Series series => Polars.Lit(series),
             
             // --- Null ---
             null => new Expr(PolarsWrapper.LitNull()),
@@ -452,6 +460,36 @@ public class Expr : IDisposable
     /// </summary>
     public Expr Count() => new(PolarsWrapper.Count(CloneHandle()));
 
+    /// <summary>
+    /// Count the number of null values in this expression.
+    /// </summary>
+    public Expr NullCount() => new(PolarsWrapper.NullCount(CloneHandle()));
+
+    /// <summary>
+    /// Check whether this expression contains any null value.
+    /// </summary>
+    public Expr HasNulls() => new(PolarsWrapper.HasNulls(CloneHandle()));
+
+    /// <summary>
+    /// Compute the sample skewness.
+    /// </summary>
+    /// <param name="bias">If false, apply the sample bias correction.</param>
+    public Expr Skew(bool bias = true) => new(PolarsWrapper.Skew(CloneHandle(), bias));
+
+    /// <summary>
+    /// Compute the kurtosis.
+    /// </summary>
+    /// <param name="fisher">Subtract 3.0 so that a normal distribution gives 0.</param>
+    /// <param name="bias">If false, apply the sample bias correction.</param>
+    public Expr Kurtosis(bool fisher = true, bool bias = true) => new(PolarsWrapper.Kurtosis(CloneHandle(), fisher, bias));
+
+    /// <summary>
+    /// Compute the entropy -sum(pk * log(pk)).
+    /// </summary>
+    /// <param name="logBase">Base of the logarithm.</param>
+    /// <param name="normalize">Normalize the values into probabilities first.</param>
+    public Expr Entropy(double logBase = Math.E, bool normalize = true) => new(PolarsWrapper.Entropy(CloneHandle(), logBase, normalize));
+
     /// <summary>
     /// Get the standard deviation.
     /// </summary>
@@ -472,6 +510,77 @@ public class Expr : IDisposable
     /// </summary>
     public Expr Quantile(double quantile, string method = "nearest") 
         => new(PolarsWrapper.Quantile(CloneHandle(), quantile, method)); // CloneHandle 因为 Quantile 消耗 Expr
+
+    /// <summary>
+    /// Flag values outside [Q1 - k*IQR, Q3 + k*IQR] (Tukey's fences).
+    /// </summary>
+    /// <param name="k">Fence multiplier, 1.5 by default.</param>
+    public Expr IsOutlierIqr(double k = 1.5) => new(PolarsWrapper.IsOutlierIqr(CloneHandle(), k));
+
+    /// <summary>
+    /// Standardize values: (x - mean) / std, using the sample standard deviation.
+    /// </summary>
+    public Expr ZScore() => new(PolarsWrapper.ZScore(CloneHandle()));
+
+    /// <summary>
+    /// Weighted mean: sum(x * w) / sum(w). Rows where x is null do not count towards sum(w).
+    /// </summary>
+    public Expr WeightedMean(Expr weights) => new(PolarsWrapper.WeightedMean(CloneHandle(), weights.CloneHandle()));
+
+    /// <summary>
+    /// Weighted sum: sum(x * w).
+    /// </summary>
+    public Expr WeightedSum(Expr weights) => new(PolarsWrapper.WeightedSum(CloneHandle(), weights.CloneHandle()));
+
+    /// <summary>
+    /// Dot product with another expression: sum(a * b).
+    /// </summary>
+    public Expr Dot(Expr other) => new(PolarsWrapper.Dot(CloneHandle(), other.CloneHandle()));
+
+    /// <summary>
+    /// Estimate the number of distinct values with HyperLogLog (UInt64).
+    /// The standard error is about 1.04 / sqrt(2^precision).
+    /// </summary>
+    /// <param name="precision">Number of register bits, between 4 and 18.</param>
+    public Expr ApproxNUniqueHll(byte precision = 14)
+        => new(PolarsWrapper.ApproxNUniqueHll(CloneHandle(), precision));
+
+    // ==========================================
+    // Distinct
+    // ==========================================
+    /// <summary>
+    /// Count the number of distinct values (null counts as a value).
+    /// </summary>
+    public Expr NUnique() => new(PolarsWrapper.NUnique(CloneHandle()));
+    /// <summary>
+    /// Get the distinct values.
+    /// </summary>
+    /// <param name="maintainOrder">Keep the order of first appearance (slower).</param>
+    public Expr Unique(bool maintainOrder = false) => new(PolarsWrapper.Unique(CloneHandle(), maintainOrder));
+    /// <summary>
+    /// Get the index of the first occurrence of each distinct value.
+    /// </summary>
+    public Expr ArgUnique() => new(PolarsWrapper.ArgUnique(CloneHandle()));
+    /// <summary>
+    /// Count how often each distinct value occurs, in order of first appearance.
+    /// </summary>
+    public Expr UniqueCounts() => new(PolarsWrapper.UniqueCounts(CloneHandle()));
+    /// <summary>
+    /// Mark values that occur exactly once.
+    /// </summary>
+    public Expr IsUnique() => new(PolarsWrapper.IsUnique(CloneHandle()));
+    /// <summary>
+    /// Mark values that occur more than once.
+    /// </summary>
+    public Expr IsDuplicated() => new(PolarsWrapper.IsDuplicated(CloneHandle()));
+    /// <summary>
+    /// Mark the first occurrence of each distinct value.
+    /// </summary>
+    public Expr IsFirstDistinct() => new(PolarsWrapper.IsFirstDistinct(CloneHandle()));
+    /// <summary>
+    /// Mark the last occurrence of each distinct value.
+    /// </summary>
+    public Expr IsLastDistinct() => new(PolarsWrapper.IsLastDistinct(CloneHandle()));
     // ==========================================
     // Logic / Comparison
     // ==========================================
@@ -482,6 +591,27 @@ public class Expr : IDisposable
     public Expr IsBetween(Expr lower, Expr upper)  
         => new(PolarsWrapper.IsBetween(CloneHandle(), lower.CloneHandle(), upper.CloneHandle()));
 
+    /// <summary>
+    /// Check if the value is contained in the other expression, which should be a List
+    /// (e.g. an imploded column or literal).
+    /// </summary>
+    /// <param name="other">List expression to search in.</param>
+    /// <param name="nullsEqual">Treat null as a matchable value.</param>
+    public Expr IsIn(Expr other, bool nullsEqual = false)
+        => new(PolarsWrapper.IsIn(CloneHandle(), other.CloneHandle(), nullsEqual));
+
+    /// <summary>
+    /// Check if the value is contained in the given Series.
+    /// </summary>
+    /// <param name="values">Values to search in. The Series is not copied.</param>
+    /// <param name="nullsEqual">Treat null as a matchable value.</param>
+    public Expr IsIn(Series values, bool nullsEqual = false)
+    {
+        using var lit = Polars.Lit(values);
+        using var list = lit.Implode();
+        return IsIn(list, nullsEqual);
+    }
+
     // ==========================================
     // Casting
     // ==========================================
@@ -492,6 +622,15 @@ public class Expr : IDisposable
     public Expr Cast(DataType dtype, bool strict = false)
         => new(PolarsWrapper.ExprCast(CloneHandle(), dtype.Handle, strict));
 
+    /// <summary>
+    /// Cast the expression to a different data type, controlling numeric overflow.
+    /// </summary>
+    /// <param name="dtype">Target type.</param>
+    /// <param name="strict">Throw on failed conversions. If false, they become null.</param>
+    /// <param name="wrapNumerical">Wrap overflowing numbers (e.g. 300 to UInt8 gives 44). Takes precedence over strict.</param>
+    public Expr Cast(DataType dtype, bool strict, bool wrapNumerical)
+        => new(PolarsWrapper.ExprCastOpts(Handle, dtype.Handle, strict, wrapNumerical));
+
     // ==========================================
     // UDF / Map
     // ==========================================
@@ -515,6 +654,33 @@ public class Expr : IDisposable
     public Expr Map(Func<IArrowArray, IArrowArray> function, DataType outputType)
         => new(PolarsWrapper.Map(CloneHandle(), function, outputType.Handle));
 
+    /// <summary>
+    /// Apply a C# function to every element. Unlike <see cref="Map{TInput, TOutput}"/>, it stays
+    /// element-wise inside group_by().agg() instead of receiving the whole group.
+    /// </summary>
+    /// <param name="function">Element-wise function. Nulls stay null unless TInput is nullable.</param>
+    /// <param name="outputType">Output type. Use DataType.SameAsInput to keep the input type.</param>
+    public Expr MapElements<TInput, TOutput>(Func<TInput, TOutput> function, DataType outputType)
+        => new(PolarsWrapper.MapElements(CloneHandle(), UdfUtils.Wrap(function), outputType.Handle));
+
+    /// <summary>
+    /// Apply a raw Arrow-to-Arrow function chunk by chunk. The output must have the same length as the input chunk.
+    /// </summary>
+    public Expr MapElements(Func<IArrowArray, IArrowArray> function, DataType outputType)
+        => new(PolarsWrapper.MapElements(CloneHandle(), function, outputType.Handle));
+
+    /// <summary>
+    /// Apply an Arrow-to-Arrow function to the whole batch at once. The input is rechunked into one array.
+    /// </summary>
+    /// <param name="function">Batch function.</param>
+    /// <param name="outputType">Output type. Use DataType.SameAsInput to keep the input type.</param>
+    /// <param name="isElementwise">
+    /// Set to true if each output row only depends on its own input row. Otherwise the function
+    /// sees the whole batch (e.g. normalization, ranking) and is called once per group inside group_by().agg().
+    /// </param>
+    public Expr MapBatches(Func<IArrowArray, IArrowArray> function, DataType outputType, bool isElementwise = false)
+        => new(PolarsWrapper.MapBatches(CloneHandle(), function, outputType.Handle, isElementwise));
+
     #region Window & Offset Functions
 
     /// <summary>
@@ -542,6 +708,17 @@ public class Expr : IDisposable
         return Over(exprs);
     }
 
+    /// <summary>
+    /// Share of the total: value / sum(value) over the partition (Float64).
+    /// With no partition columns the sum is taken over the whole column.
+    /// Example: Col("Amt").FractionOfTotal(Col("Group"))
+    /// </summary>
+    public Expr FractionOfTotal(params Expr[] partitionBy)
+    {
+        var partitionHandles = System.Array.ConvertAll(partitionBy, e => e.CloneHandle());
+        return new Expr(PolarsWrapper.FractionOfTotal(CloneHandle(), partitionHandles));
+    }
+
     /// <summary>
     /// Shift values by the given number of indices.
     /// Positive values shift downstream, negative values shift upstream.
@@ -554,6 +731,24 @@ public class Expr : IDisposable
     /// </summary>
     public Expr Diff(long n = 1) => new(PolarsWrapper.Diff(CloneHandle(), n));
 
+    /// <summary>
+    /// For a boolean expression: position within the current run of consecutive true values (1, 2, 3...),
+    /// or 0 where the condition is false or null (UInt32).
+    /// </summary>
+    public Expr StreakLength() => new(PolarsWrapper.StreakLength(CloneHandle()));
+
+    /// <summary>
+    /// Number of consecutive increases compared with the previous row (UInt32).
+    /// Resets to 0 when the value stays flat, decreases or is null.
+    /// </summary>
+    public Expr IncreasingStreak() => new(PolarsWrapper.IncreasingStreak(CloneHandle()));
+
+    /// <summary>
+    /// Number of consecutive decreases compared with the previous row (UInt32).
+    /// Resets to 0 when the value stays flat, increases or is null.
+    /// </summary>
+    public Expr DecreasingStreak() => new(PolarsWrapper.DecreasingStreak(CloneHandle()));
+
     #endregion
 
     // ==========================================
@@ -772,6 +967,55 @@ public class Expr : IDisposable
     /// Aggregate values into a list.
     /// </summary>
     public Expr Implode() => new Expr(PolarsWrapper.Implode(CloneHandle()));
+    /// <summary>
+    /// Flatten a list column into multiple rows. Same as <see cref="Explode"/>.
+    /// </summary>
+    public Expr Flatten() => new(PolarsWrapper.Flatten(CloneHandle()));
+    /// <summary>
+    /// Remove null values. The result is shorter than the input.
+    /// </summary>
+    public Expr DropNulls() => new(PolarsWrapper.DropNulls(CloneHandle()));
+    /// <summary>
+    /// Keep only the values where the predicate is true. Mostly used for conditional
+    /// aggregation, e.g. Col("x").Filter(Col("y") > 0).Sum() inside group_by().agg().
+    /// </summary>
+    public Expr Filter(Expr predicate) => new(PolarsWrapper.Filter(CloneHandle(), predicate.CloneHandle()));
+    /// <summary>
+    /// Append n copies of a value (may be null) to the end, e.g. to pad a shorter column.
+    /// </summary>
+    public Expr ExtendConstant(Expr value, Expr n)
+        => new(PolarsWrapper.ExtendConstant(CloneHandle(), value.CloneHandle(), n.CloneHandle()));
+    /// <summary>
+    /// Append n copies of a value (may be null) to the end, e.g. to pad a shorter column.
+    /// </summary>
+    public Expr ExtendConstant(Expr value, long n) => ExtendConstant(value, Polars.Lit(n));
+
+    /// <summary>
+    /// Bin values into discrete categories by the given breakpoints. The result is Categorical.
+    /// </summary>
+    /// <param name="breaks">Bin edges, in ascending order.</param>
+    /// <param name="labels">Bin names. Must have breaks.Length + 1 entries. Null generates "(a, b]" style labels.</param>
+    /// <param name="leftClosed">Use [a, b) intervals instead of (a, b].</param>
+    /// <param name="includeBreaks">Return a struct of { breakpoint, category } instead.</param>
+    public Expr Cut(double[] breaks, string[]? labels = null, bool leftClosed = false, bool includeBreaks = false)
+        => new(PolarsWrapper.Cut(CloneHandle(), breaks, labels, leftClosed, includeBreaks));
+
+    /// <summary>
+    /// Bin values into discrete categories by quantiles, e.g. [0.25, 0.5, 0.75] for quartiles.
+    /// </summary>
+    /// <param name="quantiles">Quantile edges between 0 and 1.</param>
+    /// <param name="labels">Bin names. Must have quantiles.Length + 1 entries. Null generates labels.</param>
+    /// <param name="leftClosed">Use [a, b) intervals instead of (a, b].</param>
+    /// <param name="allowDuplicates">Merge bins with duplicate edges instead of throwing.</param>
+    /// <param name="includeBreaks">Return a struct of { breakpoint, category } instead.</param>
+    public Expr QCut(double[] quantiles, string[]? labels = null, bool leftClosed = false, bool allowDuplicates = false, bool includeBreaks = false)
+        => new(PolarsWrapper.QCut(CloneHandle(), quantiles, labels, leftClosed, allowDuplicates, includeBreaks));
+
+    /// <summary>
+    /// Bin values into <paramref name="bins"/> groups of (roughly) equal size.
+    /// </summary>
+    public Expr QCut(int bins, string[]? labels = null, bool leftClosed = false, bool allowDuplicates = false, bool includeBreaks = false)
+        => new(PolarsWrapper.QCutUniform(CloneHandle(), bins, labels, leftClosed, allowDuplicates, includeBreaks));
     // ==========================================
     // Namespaces
     // ==========================================
@@ -791,6 +1035,11 @@ public class Expr : IDisposable
     /// </summary>
     public ListOps List => new(this);
 
+    /// <summary>
+    /// Access fixed-width array operations.
+    /// </summary>
+    public ArrOps Arr => new(this);
+
     /// <summary>
     /// Access struct operations.
     /// </summary>
@@ -800,6 +1049,45 @@ public class Expr : IDisposable
     /// Access column renaming operations.
     /// </summary>
     public NameOps Name => new(this);
+
+    /// <summary>
+    /// Access binary operations.
+    /// </summary>
+    public BinOps Bin => new(this);
+
+    /// <summary>
+    /// Access categorical operations.
+    /// </summary>
+    public CatOps Cat => new(this);
+
+    /// <summary>
+    /// Access expression introspection.
+    /// </summary>
+    public MetaOps Meta => new(this);
+    // ---------------------------------------------------
+    // Serialization
+    // ---------------------------------------------------
+    /// <summary>
+    /// Serialize the expression into Polars' compact binary format.
+    /// Only guaranteed to be readable by the same Polars version. Expressions containing C# UDFs cannot be serialized.
+    /// </summary>
+    public byte[] Serialize() => PolarsWrapper.ExprSerialize(Handle, 0);
+
+    /// <summary>
+    /// Serialize the expression into a readable JSON string.
+    /// Expressions containing C# UDFs cannot be serialized.
+    /// </summary>
+    public string SerializeJson() => Encoding.UTF8.GetString(PolarsWrapper.ExprSerialize(Handle, 1));
+
+    /// <summary>
+    /// Restore an expression produced by <see cref="Serialize"/>.
+    /// </summary>
+    public static Expr Deserialize(byte[] bytes) => new(PolarsWrapper.ExprDeserialize(bytes, 0));
+
+    /// <summary>
+    /// Restore an expression produced by <see cref="SerializeJson"/>.
+    /// </summary>
+    public static Expr DeserializeJson(string json) => new(PolarsWrapper.ExprDeserialize(Encoding.UTF8.GetBytes(json), 1));
     // ---------------------------------------------------
     // Clean Up
     // ---------------------------------------------------
@@ -883,6 +1171,33 @@ public class DtOps
     /// <summary>Get the weekday from the underlying date/datetime.</summary>
     public Expr Weekday() => Wrap(PolarsWrapper.DtWeekday);
 
+    /// <summary>Get the ISO week number (1-53) from the underlying date/datetime.</summary>
+    public Expr Week() => Wrap(PolarsWrapper.DtWeek);
+
+    /// <summary>Get the quarter (1-4) from the underlying date/datetime.</summary>
+    public Expr Quarter() => Wrap(PolarsWrapper.DtQuarter);
+
+    /// <summary>Total number of days in the duration, truncated toward zero.</summary>
+    public Expr TotalDays() => Wrap(PolarsWrapper.DtTotalDays);
+
+    /// <summary>Total number of hours in the duration, truncated toward zero.</summary>
+    public Expr TotalHours() => Wrap(PolarsWrapper.DtTotalHours);
+
+    /// <summary>Total number of minutes in the duration, truncated toward zero.</summary>
+    public Expr TotalMinutes() => Wrap(PolarsWrapper.DtTotalMinutes);
+
+    /// <summary>Total number of seconds in the duration, truncated toward zero.</summary>
+    public Expr TotalSeconds() => Wrap(PolarsWrapper.DtTotalSeconds);
+
+    /// <summary>Total number of milliseconds in the duration, truncated toward zero.</summary>
+    public Expr TotalMilliseconds() => Wrap(PolarsWrapper.DtTotalMilliseconds);
+
+    /// <summary>Total number of microseconds in the duration, truncated toward zero.</summary>
+    public Expr TotalMicroseconds() => Wrap(PolarsWrapper.DtTotalMicroseconds);
+
+    /// <summary>Total number of nanoseconds in the duration, truncated toward zero.</summary>
+    public Expr TotalNanoseconds() => Wrap(PolarsWrapper.DtTotalNanoseconds);
+
     /// <summary>Get the hour from the underlying datetime.</summary>
     public Expr Hour() => Wrap(PolarsWrapper.DtHour);
 
@@ -910,6 +1225,16 @@ public class DtOps
         return new Expr(PolarsWrapper.DtToString(h, format));
     }
 
+    /// <summary>
+    /// Format the date/datetime as a string (same as Python's dt.strftime).
+    /// A null format or "iso" gives ISO 8601; "iso:strict" separates date and time with 'T'.
+    /// </summary>
+    public Expr Strftime(string? format = null)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.DtStrftime(h, format));
+    }
+
     /// <summary>
     /// Format the date/datetime as a string using the default format "%Y-%m-%dT%H:%M:%S%.f".
     /// </summary>
@@ -951,6 +1276,16 @@ public class DtOps
     public Expr Truncate(TimeSpan every)
         => Truncate(DurationFormatter.ToPolarsString(every));
     /// <summary>
+    /// Truncate the datetimes using a duration string expression.
+    /// The expression may be a column, so each row can use its own bucket size.
+    /// </summary>
+    public Expr Truncate(Expr every)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var e = PolarsWrapper.CloneExpr(every.Handle);
+        return new Expr(PolarsWrapper.DtTruncate(h, e));
+    }
+    /// <summary>
     /// Round the datetimes to the given interval.
     /// </summary>
     public Expr Round(string every)
@@ -965,6 +1300,16 @@ public class DtOps
     /// <returns></returns>
     public Expr Round(TimeSpan every)
         => Round(DurationFormatter.ToPolarsString(every));
+    /// <summary>
+    /// Round the datetimes using a duration string expression.
+    /// Unlike <see cref="Truncate(Expr)"/>, the interval must evaluate to a single value.
+    /// </summary>
+    public Expr Round(Expr every)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var e = PolarsWrapper.CloneExpr(every.Handle);
+        return new Expr(PolarsWrapper.DtRound(h, e));
+    }
     // ==========================================
     // Offset (时间平移)
     // ==========================================
@@ -978,11 +1323,14 @@ public class DtOps
     }
 
     /// <summary>
-    /// Offset the datetimes by a constant duration string (e.g., "1d", "-2h").
+    /// Offset the datetimes by a constant duration string (e.g., "1d", "-2h", "1mo", "3d12h").
+    /// Calendar offsets clamp to the month end (Jan 31 + "1mo" = Feb 28/29).
+    /// Invalid duration strings throw immediately instead of at collect time.
     /// </summary>
     public Expr OffsetBy(string duration)
     {
-        return OffsetBy(Polars.Lit(duration));
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.DtOffsetBy(h, duration));
     }
     /// <summary>
     /// Offset the datetimes by TimeSpan
@@ -1031,6 +1379,16 @@ public class DtOps
         var h = PolarsWrapper.CloneExpr(_expr.Handle);
         return new Expr(PolarsWrapper.DtReplaceTimeZone(h, timeZone, ambiguous, nonExistent));
     }
+
+    /// <summary>
+    /// Base UTC offset of the time zone (without daylight saving), as a Duration in milliseconds.
+    /// </summary>
+    public Expr BaseUtcOffset() => Wrap(PolarsWrapper.DtBaseUtcOffset);
+
+    /// <summary>
+    /// Additional daylight saving offset, as a Duration in milliseconds (zero outside DST).
+    /// </summary>
+    public Expr DstOffset() => Wrap(PolarsWrapper.DtDstOffset);
     
 }
 
@@ -1059,12 +1417,68 @@ public class StringOps
     /// Transfer String to LowerClass.
     /// </summary>
     public Expr ToLower() => Wrap(PolarsWrapper.StrToLower);
+    /// <summary>
+    /// Uppercase the first letter of every word and lowercase the rest.
+    /// </summary>
+    public Expr ToTitlecase() => Wrap(PolarsWrapper.StrToTitlecase);
+    /// <summary>
+    /// Pad the start of the string until it reaches the given length.
+    /// </summary>
+    public Expr PadStart(ulong length, char fillChar = ' ')
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrPadStart(h, length, fillChar));
+    }
+    /// <summary>
+    /// Pad the end of the string until it reaches the given length.
+    /// </summary>
+    public Expr PadEnd(ulong length, char fillChar = ' ')
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrPadEnd(h, length, fillChar));
+    }
+    /// <summary>
+    /// Pad the start of the string with zeros, keeping a leading +/- sign in front.
+    /// </summary>
+    public Expr Zfill(ulong length)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrZfill(h, length));
+    }
     
     /// <summary>
     /// Get length in bytes.
     /// </summary>
     public Expr Len() => Wrap(PolarsWrapper.StrLenBytes);
     /// <summary>
+    /// Get length in bytes.
+    /// </summary>
+    public Expr LenBytes() => Wrap(PolarsWrapper.StrLenBytes);
+    /// <summary>
+    /// Get length in characters (Unicode code points).
+    /// </summary>
+    public Expr LenChars() => Wrap(PolarsWrapper.StrLenChars);
+    /// <summary>
+    /// Reverse the characters of the string.
+    /// </summary>
+    public Expr Reverse() => Wrap(PolarsWrapper.StrReverse);
+    /// <summary>
+    /// Take the first n characters. A negative n drops the last |n| characters.
+    /// </summary>
+    public Expr Head(long n)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrHead(h, n));
+    }
+    /// <summary>
+    /// Take the last n characters. A negative n drops the first |n| characters.
+    /// </summary>
+    public Expr Tail(long n)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrTail(h, n));
+    }
+    /// <summary>
     /// Slice string by length.
     /// </summary>
     /// <param name="offset"></param>
@@ -1099,6 +1513,67 @@ public class StringOps
         return new Expr(PolarsWrapper.StrExtract(h, pattern, groupIndex));
     }
     /// <summary>
+    /// Parse a JSON string column into a typed column (usually a Struct).
+    /// </summary>
+    /// <param name="dtype">Target type. Null infers it from the data.</param>
+    /// <param name="inferSchemaLen">Number of rows used for inference. 0 scans all rows.</param>
+    public Expr JsonDecode(DataType? dtype = null, ulong inferSchemaLen = 100)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrJsonDecode(h, dtype?.Handle, inferSchemaLen));
+    }
+    /// <summary>
+    /// Extract the first match of a JSONPath expression as a string.
+    /// </summary>
+    /// <param name="jsonPath">e.g. "$.user.name"</param>
+    public Expr JsonPathMatch(string jsonPath)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrJsonPathMatch(h, jsonPath));
+    }
+    /// <summary>
+    /// Extract all non-overlapping regex matches as a list of strings.
+    /// </summary>
+    /// <param name="pattern"></param>
+    /// <returns></returns>
+    public Expr ExtractAll(string pattern)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrExtractAll(h, pattern));
+    }
+    /// <summary>
+    /// Extract all capture groups of the first match into a Struct.
+    /// Named groups use their name as field name.
+    /// </summary>
+    /// <param name="pattern"></param>
+    /// <returns></returns>
+    public Expr ExtractGroups(string pattern)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrExtractGroups(h, pattern));
+    }
+    /// <summary>
+    /// Count the number of matches of a pattern (UInt32).
+    /// </summary>
+    /// <param name="pattern"></param>
+    /// <param name="literal">Treat the pattern as a plain string instead of a regex.</param>
+    /// <returns></returns>
+    public Expr CountMatches(string pattern, bool literal = false)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrCountMatches(h, pattern, literal));
+    }
+    /// <summary>
+    /// Vertically concatenate all strings in the column (or each group) into a single string.
+    /// </summary>
+    /// <param name="separator">Separator placed between values.</param>
+    /// <param name="ignoreNulls">Skip nulls. If false, any null makes the result null.</param>
+    public Expr Join(string separator = "", bool ignoreNulls = true)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrJoin(h, separator, ignoreNulls));
+    }
+    /// <summary>
     /// Check if the string contains a substring that matches a pattern.
     /// </summary>
     /// <param name="pattern"></param>
@@ -1112,11 +1587,30 @@ public class StringOps
     /// Split the string by a substring.
     /// </summary>
     /// <param name="separator"></param>
+    /// <param name="inclusive">Keep the separator at the end of each part.</param>
     /// <returns></returns>
-    public Expr Split(string separator)
+    public Expr Split(string separator, bool inclusive = false)
     {
          var h = PolarsWrapper.CloneExpr(_expr.Handle);
-         return new Expr(PolarsWrapper.StrSplit(h, separator));
+         return new Expr(PolarsWrapper.StrSplit(h, separator, inclusive));
+    }
+    /// <summary>
+    /// Split the string by a substring exactly n times, into a Struct of n + 1 fields
+    /// (field_0, field_1, ...). Missing parts are null.
+    /// </summary>
+    public Expr SplitExact(string separator, ulong n)
+    {
+         var h = PolarsWrapper.CloneExpr(_expr.Handle);
+         return new Expr(PolarsWrapper.StrSplitExact(h, separator, n));
+    }
+    /// <summary>
+    /// Split the string by a substring into a Struct of at most n fields.
+    /// The remainder is kept in the last field.
+    /// </summary>
+    public Expr SplitN(string separator, ulong n)
+    {
+         var h = PolarsWrapper.CloneExpr(_expr.Handle);
+         return new Expr(PolarsWrapper.StrSplitN(h, separator, n));
     }
     // ==========================================
     // Strip / Clean (去除字符)
@@ -1208,11 +1702,40 @@ public class StringOps
 
     /// <summary>
     /// Convert string to Datetime using the specified format.
+    /// Unparsable values become null.
+    /// </summary>
+    /// <param name="format">strftime format. Null lets Polars infer it.</param>
+    /// <param name="unit">Nanoseconds, Microseconds or Milliseconds.</param>
+    /// <param name="timeZone">Time zone of the result. Null means naive.</param>
+    public Expr ToDatetime(string? format, TimeUnit unit = TimeUnit.Microseconds, string? timeZone = null)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrToDatetime(h, format, (int)unit.ToNative(), timeZone));
+    }
+
+    /// <summary>
+    /// Convert string to Time using the specified format.
+    /// Unparsable values become null.
     /// </summary>
-    public Expr ToDatetime(string format)
+    /// <param name="format">strftime format. Null lets Polars infer it.</param>
+    public Expr ToTime(string? format = null)
     {
         var h = PolarsWrapper.CloneExpr(_expr.Handle);
-        return new Expr(PolarsWrapper.StrToDatetime(h, format));
+        return new Expr(PolarsWrapper.StrToTime(h, format));
+    }
+
+    /// <summary>
+    /// Parse strings into a Date, Datetime or Time column.
+    /// </summary>
+    /// <param name="dtype">Target type: Date, Datetime or Time.</param>
+    /// <param name="format">strftime format. Null lets Polars infer it.</param>
+    /// <param name="strict">Throw when a value cannot be parsed; otherwise it becomes null.</param>
+    /// <param name="exact">Require the whole string to match; otherwise search for a matching part.</param>
+    /// <param name="cache">Cache the results of repeated values.</param>
+    public Expr Strptime(DataType dtype, string? format = null, bool strict = true, bool exact = true, bool cache = true)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StrStrptime(h, dtype.Handle, format, strict, exact, cache));
     }
 }
 
@@ -1293,7 +1816,26 @@ public class ListOps
     /// <returns></returns>
     public Expr Mean() => Wrap(PolarsWrapper.ListMean);
     /// <summary>
+    /// Calculate the standard deviation of the list elements.
+    /// </summary>
+    /// <param name="ddof">Delta degrees of freedom; 1 gives the sample standard deviation.</param>
+    public Expr Std(byte ddof = 1)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.ListStd(h, ddof));
+    }
+    /// <summary>
+    /// Get the index of the minimum element in each list.
+    /// </summary>
+    public Expr ArgMin() => Wrap(PolarsWrapper.ListArgMin);
+    /// <summary>
+    /// Get the index of the maximum element in each list.
+    /// </summary>
+    public Expr ArgMax() => Wrap(PolarsWrapper.ListArgMax);
+    /// <summary>
     /// Check if the list contains a specific item.
+    /// Evaluated row by row: row i checks whether its own list contains the item of row i,
+    /// so <paramref name="item"/> may be a literal or another column.
     /// </summary>
     /// <param name="item"></param>
     /// <returns></returns>
@@ -1316,6 +1858,160 @@ public class ListOps
     /// <param name="item"></param>
     /// <returns></returns>
     public Expr Contains(string item) => Contains(Polars.Lit(item));
+    /// <summary>
+    /// Remove duplicate elements from each list.
+    /// </summary>
+    /// <param name="maintainOrder">Keep the order of first occurrence (slower).</param>
+    public Expr Unique(bool maintainOrder = false)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.ListUnique(h, maintainOrder));
+    }
+    /// <summary>
+    /// Slice every list. Offset and length may be literals or other columns.
+    /// A negative offset counts from the end; a null length takes everything to the end.
+    /// </summary>
+    public Expr Slice(Expr offset, Expr? length = null)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var o = PolarsWrapper.CloneExpr(offset.Handle);
+        var l = length is null ? null : PolarsWrapper.CloneExpr(length.Handle);
+        return new Expr(PolarsWrapper.ListSlice(h, o, l));
+    }
+    /// <summary>
+    /// Slice every list with a fixed offset and optional length.
+    /// </summary>
+    public Expr Slice(long offset, long? length = null)
+        => Slice(Polars.Lit(offset), length.HasValue ? Polars.Lit(length.Value) : null);
+    /// <summary>
+    /// Take the first n elements of each list.
+    /// </summary>
+    public Expr Head(Expr n)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.ListHead(h, PolarsWrapper.CloneExpr(n.Handle)));
+    }
+    /// <summary>
+    /// Take the first n elements of each list.
+    /// </summary>
+    public Expr Head(long n = 5) => Head(Polars.Lit(n));
+    /// <summary>
+    /// Take the last n elements of each list.
+    /// </summary>
+    public Expr Tail(Expr n)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.ListTail(h, PolarsWrapper.CloneExpr(n.Handle)));
+    }
+    /// <summary>
+    /// Take the last n elements of each list.
+    /// </summary>
+    public Expr Tail(long n = 5) => Tail(Polars.Lit(n));
+    /// <summary>
+    /// Reverse the elements of each list.
+    /// </summary>
+    public Expr Reverse() => Wrap(PolarsWrapper.ListReverse);
+    /// <summary>
+    /// Remove null elements from each list.
+    /// </summary>
+    public Expr DropNulls() => Wrap(PolarsWrapper.ListDropNulls);
+
+    private Expr SetOperation(Expr other, PlListSetOp op)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var o = PolarsWrapper.CloneExpr(other.Handle);
+        return new Expr(PolarsWrapper.ListSetOperation(h, o, op));
+    }
+    /// <summary>
+    /// Row-wise union of two list columns.
+    /// </summary>
+    public Expr Union(Expr other) => SetOperation(other, PlListSetOp.Union);
+    /// <summary>
+    /// Row-wise intersection of two list columns.
+    /// </summary>
+    public Expr SetIntersection(Expr other) => SetOperation(other, PlListSetOp.Intersection);
+    /// <summary>
+    /// Row-wise difference: elements of this list that are not in <paramref name="other"/>.
+    /// </summary>
+    public Expr SetDifference(Expr other) => SetOperation(other, PlListSetOp.Difference);
+    /// <summary>
+    /// Row-wise symmetric difference: elements in exactly one of the two lists.
+    /// </summary>
+    public Expr SetSymmetricDifference(Expr other) => SetOperation(other, PlListSetOp.SymmetricDifference);
+}
+
+// ==========================================
+// ArrOps Helper Class
+// ==========================================
+/// <summary>
+/// Offers methods for fixed-width array operations.
+/// </summary>
+public class ArrOps
+{
+    private readonly Expr _expr;
+    internal ArrOps(Expr expr) { _expr = expr; }
+
+    private Expr Wrap(Func<ExprHandle, ExprHandle> op)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(op(h));
+    }
+    /// <summary>
+    /// Get the width of each array.
+    /// </summary>
+    public Expr Len() => Wrap(PolarsWrapper.ArrLen);
+    /// <summary>
+    /// Calculate the sum of the array elements.
+    /// </summary>
+    public Expr Sum() => Wrap(PolarsWrapper.ArrSum);
+    /// <summary>
+    /// Calculate the minimum of the array elements.
+    /// </summary>
+    public Expr Min() => Wrap(PolarsWrapper.ArrMin);
+    /// <summary>
+    /// Calculate the maximum of the array elements.
+    /// </summary>
+    public Expr Max() => Wrap(PolarsWrapper.ArrMax);
+    /// <summary>
+    /// Calculate the mean of the array elements.
+    /// </summary>
+    public Expr Mean() => Wrap(PolarsWrapper.ArrMean);
+    /// <summary>
+    /// Convert the fixed-width array into a variable-length list.
+    /// </summary>
+    public Expr ToList() => Wrap(PolarsWrapper.ArrToList);
+    /// <summary>
+    /// Get the element at the given index. A negative index counts from the end.
+    /// </summary>
+    /// <param name="index">Index literal or column.</param>
+    /// <param name="nullOnOob">Return null for an out-of-bounds index instead of throwing.</param>
+    public Expr Get(Expr index, bool nullOnOob = true)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var i = PolarsWrapper.CloneExpr(index.Handle);
+        return new Expr(PolarsWrapper.ArrGet(h, i, nullOnOob));
+    }
+    /// <summary>
+    /// Get the element at the given index. A negative index counts from the end.
+    /// </summary>
+    public Expr Get(long index, bool nullOnOob = true) => Get(Polars.Lit(index), nullOnOob);
+    /// <summary>
+    /// Check row by row whether the array contains the item.
+    /// </summary>
+    public Expr Contains(Expr item)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var i = PolarsWrapper.CloneExpr(item.Handle);
+        return new Expr(PolarsWrapper.ArrContains(h, i));
+    }
+    /// <summary>
+    /// Check whether the array contains a specific integer item.
+    /// </summary>
+    public Expr Contains(int item) => Contains(Polars.Lit(item));
+    /// <summary>
+    /// Check whether the array contains a specific string item.
+    /// </summary>
+    public Expr Contains(string item) => Contains(Polars.Lit(item));
 }
 
 // ==========================================
@@ -1346,6 +2042,26 @@ public class StructOps
         return new Expr(PolarsWrapper.StructFieldByIndex(h, index));
     }
     /// <summary>
+    /// Retrieve several fields at once, each becoming its own column.
+    /// Pass "*" to retrieve every field.
+    /// </summary>
+    public Expr Fields(params string[] names)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.StructFields(h, names));
+    }
+    /// <summary>
+    /// Add or replace fields. A field with an existing name is overwritten.
+    /// Use <see cref="Polars.Field(string)"/> to refer to the current fields of the struct.
+    /// Example: Col("s").Struct.WithFields((Field("a") * 2).Alias("a2"))
+    /// </summary>
+    public Expr WithFields(params Expr[] fields)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        var fieldHandles = fields.Select(f => PolarsWrapper.CloneExpr(f.Handle)).ToArray();
+        return new Expr(PolarsWrapper.StructWithFields(h, fieldHandles));
+    }
+    /// <summary>
     /// Rename the fields of the struct.
     /// </summary>
     public Expr RenameFields(params string[] names)
@@ -1392,4 +2108,201 @@ public class NameOps
         var h = PolarsWrapper.CloneExpr(_expr.Handle);
         return new(PolarsWrapper.Suffix(h, suffix)); // Wrapper 需确认签名 
     }
+
+    /// <summary>
+    /// Keep the original root column name, ignoring any previous alias.
+    /// </summary>
+    public Expr Keep() => new(PolarsWrapper.NameKeep(PolarsWrapper.CloneExpr(_expr.Handle)));
+
+    /// <summary>
+    /// Make the output column name lowercase.
+    /// </summary>
+    public Expr ToLowercase() => new(PolarsWrapper.NameToLowercase(PolarsWrapper.CloneExpr(_expr.Handle)));
+
+    /// <summary>
+    /// Make the output column name uppercase.
+    /// </summary>
+    public Expr ToUppercase() => new(PolarsWrapper.NameToUppercase(PolarsWrapper.CloneExpr(_expr.Handle)));
+
+    /// <summary>
+    /// Rename the output column with a custom function. Useful with selectors over many columns.
+    /// </summary>
+    /// <param name="function">Maps the original column name to the new name.</param>
+    public Expr Map(Func<string, string> function)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new(PolarsWrapper.NameMap(h, function));
+    }
+}
+
+// ==========================================
+// BinOps Helper Class
+// ==========================================
+/// <summary>
+/// Offers methods for binary columns.
+/// </summary>
+public class BinOps
+{
+    private readonly Expr _expr;
+    internal BinOps(Expr expr) { _expr = expr; }
+
+    /// <summary>
+    /// Decode WKB/EWKB Point geometry into a struct of { x: f64, y: f64 }.
+    /// Non-point or malformed values become null.
+    /// </summary>
+    public Expr WkbToXy()
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinWkbToXy(h));
+    }
+    /// <summary>
+    /// Get the size of each binary value in bytes.
+    /// </summary>
+    public Expr Size()
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinSize(h));
+    }
+    /// <summary>
+    /// Encode binary values into a string column.
+    /// </summary>
+    /// <param name="encoding">"hex" or "base64".</param>
+    public Expr Encode(string encoding)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinEncode(h, encoding));
+    }
+    /// <summary>
+    /// Decode hex/base64 encoded binary values back into raw bytes.
+    /// </summary>
+    /// <param name="encoding">"hex" or "base64".</param>
+    /// <param name="strict">Throw on invalid input; otherwise it becomes null.</param>
+    public Expr Decode(string encoding, bool strict = true)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinDecode(h, encoding, strict));
+    }
+    /// <summary>
+    /// Check if the binary values contain the given bytes.
+    /// </summary>
+    public Expr Contains(Expr pattern)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinContains(h, PolarsWrapper.CloneExpr(pattern.Handle)));
+    }
+    /// <summary>
+    /// Check if the binary values contain the UTF-8 bytes of the given string.
+    /// </summary>
+    public Expr Contains(string pattern) => Contains(Polars.Lit(pattern));
+    /// <summary>
+    /// Check if the binary values start with the given prefix.
+    /// </summary>
+    public Expr StartsWith(Expr prefix)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinStartsWith(h, PolarsWrapper.CloneExpr(prefix.Handle)));
+    }
+    /// <summary>
+    /// Check if the binary values start with the UTF-8 bytes of the given string.
+    /// </summary>
+    public Expr StartsWith(string prefix) => StartsWith(Polars.Lit(prefix));
+    /// <summary>
+    /// Check if the binary values end with the given suffix.
+    /// </summary>
+    public Expr EndsWith(Expr suffix)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.BinEndsWith(h, PolarsWrapper.CloneExpr(suffix.Handle)));
+    }
+    /// <summary>
+    /// Check if the binary values end with the UTF-8 bytes of the given string.
+    /// </summary>
+    public Expr EndsWith(string suffix) => EndsWith(Polars.Lit(suffix));
+}
+
+// ==========================================
+// CatOps Helper Class
+// ==========================================
+/// <summary>
+/// Offers methods for categorical columns. String operations run once on the category
+/// dictionary and are broadcast back to the rows, which is cheaper than casting to String.
+/// </summary>
+public class CatOps
+{
+    private readonly Expr _expr;
+    internal CatOps(Expr expr) { _expr = expr; }
+
+    private Expr Wrap(Func<ExprHandle, ExprHandle> op)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(op(h));
+    }
+    /// <summary>
+    /// Get the categories as a String column.
+    /// </summary>
+    public Expr GetCategories() => Wrap(PolarsWrapper.CatGetCategories);
+    /// <summary>
+    /// Get the length of each value in bytes.
+    /// </summary>
+    public Expr LenBytes() => Wrap(PolarsWrapper.CatLenBytes);
+    /// <summary>
+    /// Get the length of each value in characters.
+    /// </summary>
+    public Expr LenChars() => Wrap(PolarsWrapper.CatLenChars);
+    /// <summary>
+    /// Check if the values start with the given prefix.
+    /// </summary>
+    public Expr StartsWith(string prefix)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.CatStartsWith(h, prefix));
+    }
+    /// <summary>
+    /// Check if the values end with the given suffix.
+    /// </summary>
+    public Expr EndsWith(string suffix)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.CatEndsWith(h, suffix));
+    }
+    /// <summary>
+    /// Slice each value by characters. The result is a String column.
+    /// </summary>
+    /// <param name="offset">Start position. A negative offset counts from the end.</param>
+    /// <param name="length">Number of characters to take. Null takes everything to the end.</param>
+    public Expr Slice(long offset, long? length = null)
+    {
+        var h = PolarsWrapper.CloneExpr(_expr.Handle);
+        return new Expr(PolarsWrapper.CatSlice(h, offset, length));
+    }
+}
+
+// ==========================================
+// MetaOps Helper Class
+// ==========================================
+/// <summary>
+/// Offers methods for inspecting an expression. These calls do not consume the expression.
+/// </summary>
+public class MetaOps
+{
+    private readonly Expr _expr;
+    internal MetaOps(Expr expr) { _expr = expr; }
+
+    /// <summary>
+    /// Get the output column name. Throws if the name depends on the schema, e.g. for selectors.
+    /// </summary>
+    public string OutputName() => PolarsWrapper.ExprMetaOutputName(_expr.Handle);
+    /// <summary>
+    /// Get the names of all root columns the expression reads from.
+    /// </summary>
+    public string[] RootNames() => PolarsWrapper.ExprMetaRootNames(_expr.Handle);
+    /// <summary>
+    /// Format the expression as a tree.
+    /// </summary>
+    /// <param name="asDot">Return Graphviz dot instead of plain text.</param>
+    public string TreeFormat(bool asDot = false) => PolarsWrapper.ExprMetaTreeFormat(_expr.Handle, asDot);
+    /// <summary>
+    /// Check whether two expressions are structurally equal (same tree, same arguments).
+    /// </summary>
+    public bool Eq(Expr other) => PolarsWrapper.ExprMetaEq(_expr.Handle, other.Handle);
 }
\ No newline at end of file
@@ -0,0 +1,190 @@
+using Polars.NET.Core;
+
+namespace Polars.CSharp;
+
+/// <summary>
+/// Options for reading and scanning CSV files.
+/// </summary>
+public class CsvReadOptions
+{
+    /// <summary>The first row holds the column names.</summary>
+    public bool HasHeader { get; set; } = true;
+    /// <summary>Field separator.</summary>
+    public char Separator { get; set; } = ',';
+    /// <summary>Quote character. Null turns quote handling off.</summary>
+    public char? QuoteChar { get; set; } = '"';
+    /// <summary>Line terminator.</summary>
+    public char EolChar { get; set; } = '\n';
+    /// <summary>Rows to skip before the header.</summary>
+    public ulong SkipRows { get; set; }
+    /// <summary>Stop after this many rows. Null reads the whole file.</summary>
+    public ulong? NRows { get; set; }
+    /// <summary>Rows used to infer column types. Null scans the whole file.</summary>
+    public ulong? InferSchemaLength { get; set; } = 100;
+    /// <summary>Lines starting with this prefix are skipped.</summary>
+    public string? CommentPrefix { get; set; }
+    /// <summary>Strings read as null in every column.</summary>
+    public string[]? NullValues { get; set; }
+    /// <summary>Text encoding of the file.</summary>
+    public CsvEncoding Encoding { get; set; } = CsvEncoding.Utf8;
+    /// <summary>Try to parse date and datetime columns.</summary>
+    public bool TryParseDates { get; set; } = true;
+    /// <summary>Read values that fail to parse as null instead of failing.</summary>
+    public bool IgnoreErrors { get; set; }
+    /// <summary>Drop extra fields on rows that are longer than the header.</summary>
+    public bool TruncateRaggedLines { get; set; }
+    /// <summary>Lower memory use at the cost of speed.</summary>
+    public bool LowMemory { get; set; }
+    /// <summary>Parse "1,5" as 1.5. The separator must then not be a comma.</summary>
+    public bool DecimalComma { get; set; }
+    /// <summary>Read missing fields as null rather than empty strings.</summary>
+    public bool MissingIsNull { get; set; } = true;
+
+    internal PlCsvReadArgs ToNative() => new()
+    {
+        HasHeader = HasHeader,
+        Separator = (byte)Separator,
+        QuoteChar = QuoteChar is char q ? (byte)q : (byte)0,
+        EolChar = (byte)EolChar,
+        SkipRows = (UIntPtr)SkipRows,
+        NRows = NRows is ulong n ? (long)n : -1,
+        InferSchemaLength = InferSchemaLength is ulong l ? (long)l : -1,
+        Encoding = Encoding.ToNative(),
+        TryParseDates = TryParseDates,
+        IgnoreErrors = IgnoreErrors,
+        TruncateRaggedLines = TruncateRaggedLines,
+        LowMemory = LowMemory,
+        DecimalComma = DecimalComma,
+        MissingIsNull = MissingIsNull
+    };
+}
+
+/// <summary>
+/// Options for writing and sinking CSV files.
+/// </summary>
+public class CsvWriteOptions
+{
+    /// <summary>Field separator.</summary>
+    public char Separator { get; set; } = ',';
+    /// <summary>Quote character.</summary>
+    public char QuoteChar { get; set; } = '"';
+    /// <summary>When fields are quoted.</summary>
+    public QuoteStyle QuoteStyle { get; set; } = QuoteStyle.Necessary;
+    /// <summary>Line terminator. Null means "\n".</summary>
+    public string? LineTerminator { get; set; }
+    /// <summary>chrono format string for Date columns, e.g. "%Y/%m/%d".</summary>
+    public string? DateFormat { get; set; }
+    /// <summary>chrono format string for Time columns.</summary>
+    public string? TimeFormat { get; set; }
+    /// <summary>chrono format string for Datetime columns.</summary>
+    public string? DatetimeFormat { get; set; }
+    /// <summary>Fixed number of decimals for floats. Null keeps the shortest exact form.</summary>
+    public int? FloatPrecision { get; set; }
+    /// <summary>Text written for null values. Null means an empty field.</summary>
+    public string? NullValue { get; set; }
+    /// <summary>Write the header row.</summary>
+    public bool IncludeHeader { get; set; } = true;
+    /// <summary>Start the file with a UTF-8 byte order mark (for Excel).</summary>
+    public bool IncludeBom { get; set; }
+
+    internal PlCsvWriteArgs ToNative() => new()
+    {
+        Separator = (byte)Separator,
+        QuoteChar = (byte)QuoteChar,
+        QuoteStyle = QuoteStyle.ToNative(),
+        FloatPrecision = FloatPrecision ?? -1,
+        IncludeHeader = IncludeHeader,
+        IncludeBom = IncludeBom
+    };
+}
+
+/// <summary>
+/// Options for reading and scanning Parquet files.
+/// </summary>
+public class ParquetReadOptions
+{
+    /// <summary>Columns to read. Null or empty reads all columns.</summary>
+    public string[]? Columns { get; set; }
+    /// <summary>Stop after this many rows. Null reads the whole file.</summary>
+    public ulong? NRows { get; set; }
+    /// <summary>If set, add a row index column with this name as the first column.</summary>
+    public string? RowIndexName { get; set; }
+    /// <summary>Start value of the row index column.</summary>
+    public uint RowIndexOffset { get; set; }
+    /// <summary>How the file is read in parallel.</summary>
+    public ParallelStrategy Parallel { get; set; } = ParallelStrategy.Auto;
+    /// <summary>Skip row groups using their statistics. Scan only.</summary>
+    public bool UseStatistics { get; set; } = true;
+    /// <summary>Lower memory use at the cost of speed.</summary>
+    public bool LowMemory { get; set; }
+    /// <summary>Merge the result into a single chunk.</summary>
+    public bool Rechunk { get; set; }
+    /// <summary>Cache the scan result when the plan reads it more than once. Scan only.</summary>
+    public bool Cache { get; set; } = true;
+    /// <summary>Expand glob patterns such as "data/*.parquet" in the path. Scan only.</summary>
+    public bool Glob { get; set; } = true;
+
+    internal PlParquetReadArgs ToNative() => new()
+    {
+        NRows = NRows is ulong n ? (long)n : -1,
+        RowIndexOffset = RowIndexOffset,
+        Parallel = Parallel.ToNative(),
+        UseStatistics = UseStatistics,
+        LowMemory = LowMemory,
+        Rechunk = Rechunk,
+        Cache = Cache,
+        Glob = Glob
+    };
+}
+
+/// <summary>
+/// Options for writing and sinking Parquet files.
+/// </summary>
+public class ParquetWriteOptions
+{
+    /// <summary>Compression codec.</summary>
+    public ParquetCompression Compression { get; set; } = ParquetCompression.Zstd;
+    /// <summary>Codec level: Gzip 0-9, Brotli 0-11, Zstd 1-22. Null uses the codec default.</summary>
+    public int? CompressionLevel { get; set; }
+    /// <summary>Which column statistics are written.</summary>
+    public ParquetStatistics Statistics { get; set; } = ParquetStatistics.Default;
+    /// <summary>Rows per row group. Null uses the Polars default.</summary>
+    public long? RowGroupSize { get; set; }
+    /// <summary>Data page size in bytes. Null uses the Polars default (1 MB).</summary>
+    public long? DataPageSize { get; set; }
+
+    internal PlParquetWriteArgs ToNative() => new()
+    {
+        Compression = Compression.ToNative(),
+        CompressionLevel = CompressionLevel ?? -1,
+        Statistics = Statistics.ToNative(),
+        RowGroupSize = RowGroupSize ?? -1,
+        DataPageSize = DataPageSize ?? -1
+    };
+}
+
+/// <summary>
+/// Options for scanning NDJSON files.
+/// </summary>
+public class NdjsonReadOptions
+{
+    /// <summary>Rows used to infer the schema. Null scans the whole file.</summary>
+    public ulong? InferSchemaLength { get; set; } = 100;
+    /// <summary>Rows parsed per batch. Null uses the Polars default.</summary>
+    public ulong? BatchSize { get; set; }
+    /// <summary>Stop after this many rows. Null reads the whole file.</summary>
+    public ulong? NRows { get; set; }
+    /// <summary>Read values that fail to parse as null instead of failing.</summary>
+    public bool IgnoreErrors { get; set; }
+    /// <summary>Lower memory use at the cost of speed.</summary>
+    public bool LowMemory { get; set; }
+
+    internal PlNdjsonReadArgs ToNative() => new()
+    {
+        InferSchemaLength = InferSchemaLength is ulong l ? (long)l : 0,
+        BatchSize = BatchSize is ulong b ? (long)b : 0,
+        NRows = NRows is ulong n ? (long)n : -1,
+        IgnoreErrors = IgnoreErrors,
+        LowMemory = LowMemory
+    };
+}
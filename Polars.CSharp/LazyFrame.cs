@@ -1,5 +1,6 @@
 using System.Collections.Concurrent;
 using System.Data;
+using System.Text.Json;
 using Apache.Arrow;
 using Polars.NET.Core;
 using Polars.NET.Core.Arrow;
@@ -52,6 +53,18 @@ public class LazyFrame : IDisposable
         return new LazyFrame(handle);
     }
     /// <summary>
+    /// Scans a CSV file lazily with the full set of parser options.
+    /// </summary>
+    /// <param name="path">Path to the CSV file.</param>
+    /// <param name="options">Parser options.</param>
+    /// <param name="schema">Optional column types that override inference.</param>
+    public static LazyFrame ScanCsv(string path, CsvReadOptions options, Dictionary<string, DataType>? schema = null)
+    {
+        var schemaHandles = schema?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new LazyFrame(PolarsWrapper.ScanCsv(
+            path, schemaHandles, options.ToNative(), options.CommentPrefix, options.NullValues));
+    }
+    /// <summary>
     /// Read a Parquet file as a LazyFrame.
     /// </summary>
     /// <param name="path"></param>
@@ -62,6 +75,16 @@ public class LazyFrame : IDisposable
         return new LazyFrame(PolarsWrapper.ScanParquet(path));
     }
     /// <summary>
+    /// Scan a Parquet file (or glob pattern) with column selection, row limit and row index options.
+    /// </summary>
+    /// <param name="path">Path or glob pattern.</param>
+    /// <param name="options">Scan options.</param>
+    public static LazyFrame ScanParquet(string path, ParquetReadOptions options)
+    {
+        return new LazyFrame(PolarsWrapper.ScanParquet(
+            path, options.ToNative(), options.Columns, options.RowIndexName));
+    }
+    /// <summary>
     /// Read an IPC (Feather) file as a LazyFrame.
     /// </summary>
     /// <param name="path"></param>
@@ -82,6 +105,78 @@ public class LazyFrame : IDisposable
         return new LazyFrame(PolarsWrapper.ScanNdjson(path));
     }
     /// <summary>
+    /// Scan several Parquet files as one LazyFrame.
+    /// </summary>
+    /// <param name="paths">File paths; at least one is required.</param>
+    /// <param name="allowMissingColumns">Fill columns missing from later files with nulls instead of failing.</param>
+    /// <param name="includeFilePaths">If set, add a column with this name holding the source file of each row.</param>
+    public static LazyFrame ScanParquet(string[] paths, bool allowMissingColumns = false, string? includeFilePaths = null)
+    {
+        RequirePaths(paths);
+        return new LazyFrame(PolarsWrapper.ScanParquet(paths, allowMissingColumns, includeFilePaths));
+    }
+    /// <summary>
+    /// Scan several IPC (Feather) files as one LazyFrame.
+    /// </summary>
+    /// <param name="paths">File paths; at least one is required.</param>
+    /// <param name="includeFilePaths">If set, add a column with this name holding the source file of each row.</param>
+    public static LazyFrame ScanIpc(string[] paths, string? includeFilePaths = null)
+    {
+        RequirePaths(paths);
+        return new LazyFrame(PolarsWrapper.ScanIpc(paths, includeFilePaths));
+    }
+    /// <summary>
+    /// Scan several CSV files as one LazyFrame. All files are parsed with the same options.
+    /// </summary>
+    /// <param name="paths">File paths; at least one is required.</param>
+    /// <param name="options">Parser options (defaults when null).</param>
+    /// <param name="schema">Optional column types that override inference.</param>
+    /// <param name="includeFilePaths">If set, add a column with this name holding the source file of each row.</param>
+    public static LazyFrame ScanCsv(
+        string[] paths,
+        CsvReadOptions? options = null,
+        Dictionary<string, DataType>? schema = null,
+        string? includeFilePaths = null)
+    {
+        RequirePaths(paths);
+        options ??= new CsvReadOptions();
+        var schemaHandles = schema?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new LazyFrame(PolarsWrapper.ScanCsv(
+            paths, schemaHandles, options.ToNative(), options.CommentPrefix, options.NullValues, includeFilePaths));
+    }
+    /// <summary>
+    /// Scan a NDJSON file with schema, inference and error-handling options.
+    /// </summary>
+    /// <param name="path">Path to the NDJSON file.</param>
+    /// <param name="options">Scan options.</param>
+    /// <param name="schema">Full schema; skips inference when set.</param>
+    /// <param name="schemaOverwrite">Types for some columns; the rest are inferred.</param>
+    public static LazyFrame ScanNdjson(
+        string path,
+        NdjsonReadOptions options,
+        Dictionary<string, DataType>? schema = null,
+        Dictionary<string, DataType>? schemaOverwrite = null)
+    {
+        var schemaHandles = schema?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        var overwriteHandles = schemaOverwrite?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new LazyFrame(PolarsWrapper.ScanNdjson(path, schemaHandles, overwriteHandles, options.ToNative()));
+    }
+    /// <summary>
+    /// Scan several NDJSON files as one LazyFrame.
+    /// </summary>
+    /// <param name="paths">File paths; at least one is required.</param>
+    /// <param name="includeFilePaths">If set, add a column with this name holding the source file of each row.</param>
+    public static LazyFrame ScanNdjson(string[] paths, string? includeFilePaths = null)
+    {
+        RequirePaths(paths);
+        return new LazyFrame(PolarsWrapper.ScanNdjson(paths, includeFilePaths));
+    }
+    private static void RequirePaths(string[] paths)
+    {
+        if (paths == null || paths.Length == 0)
+            throw new ArgumentException("At least one path is required.", nameof(paths));
+    }
+    /// <summary>
     /// Scan Arrow Stream As LazyFrame
     /// </summary>
     /// <typeparam name="T"></typeparam>
@@ -165,6 +260,21 @@ public class LazyFrame : IDisposable
         return new LazyFrame(handle);
     }
     /// <summary>
+    /// Scan a RecordBatch source that can use projection and row-limit pushdown.
+    /// The factory receives the needed columns (null = all) and the row limit (null = no limit).
+    /// Both are hints: extra columns or rows returned by the factory are trimmed by Polars.
+    /// </summary>
+    /// <param name="factory">Creates a fresh stream each time the plan is executed.</param>
+    /// <param name="schema">Full schema of the source.</param>
+    public static LazyFrame ScanRecordBatches(Func<string[]?, long?, IEnumerable<RecordBatch>> factory, Schema schema)
+    {
+        var handle = ArrowStreamInterop.ScanStreamPushdown(
+            (columns, nRows) => factory(columns, nRows).GetEnumerator(),
+            schema
+        );
+        return new LazyFrame(handle);
+    }
+    /// <summary>
     /// 
     /// </summary>
     /// <param name="reader"></param>
@@ -291,6 +401,46 @@ public class LazyFrame : IDisposable
         return PolarsWrapper.Explain(Handle, optimized);
     }
     /// <summary>
+    /// Get the query plan drawn as a tree (same as Python's explain(format="tree")).
+    /// </summary>
+    public string ExplainTree(bool optimized = true)
+    {
+        return PolarsWrapper.ExplainTree(Handle, optimized);
+    }
+    /// <summary>
+    /// Get the query plan in Graphviz DOT format, ready to be rendered with dot.
+    /// </summary>
+    public string ToDot(bool optimized = true)
+    {
+        return PolarsWrapper.ToDot(Handle, optimized);
+    }
+    /// <summary>
+    /// Collect the query repeatedly and report timings.
+    /// The LazyFrame is not consumed.
+    /// </summary>
+    /// <param name="iterations">Number of times to collect; must be greater than 0.</param>
+    public BenchmarkResult Benchmark(int iterations = 5)
+    {
+        if (iterations <= 0)
+            throw new ArgumentOutOfRangeException(nameof(iterations), "iterations must be greater than 0");
+
+        var json = PolarsWrapper.BenchCollect(Handle, (ulong)iterations);
+        using var doc = JsonDocument.Parse(json);
+        var root = doc.RootElement;
+        var peak = root.GetProperty("peak_rss_bytes");
+
+        return new BenchmarkResult
+        {
+            Iterations = root.GetProperty("iterations").GetInt32(),
+            Rows = root.GetProperty("rows").GetInt64(),
+            WallMs = root.GetProperty("wall_ms").EnumerateArray().Select(e => e.GetDouble()).ToArray(),
+            MinMs = root.GetProperty("min_ms").GetDouble(),
+            MeanMs = root.GetProperty("mean_ms").GetDouble(),
+            MaxMs = root.GetProperty("max_ms").GetDouble(),
+            PeakRssBytes = peak.ValueKind == JsonValueKind.Null ? null : peak.GetInt64()
+        };
+    }
+    /// <summary>
     /// Clone the LazyFrame, creating a new independent copy.
     /// </summary>
     /// <returns></returns>
@@ -302,6 +452,14 @@ public class LazyFrame : IDisposable
     {
         return PolarsWrapper.LazyClone(Handle);
     }
+    /// <summary>
+    /// Create a read-only, reference-counted handle to this query plan that can be used from several threads.
+    /// This LazyFrame stays usable.
+    /// </summary>
+    public SharedLazyFrame Share()
+    {
+        return new SharedLazyFrame(PolarsWrapper.LazyShare(CloneHandle()));
+    }
     // ==========================================
     // Transformations
     // ==========================================
@@ -342,6 +500,156 @@ public class LazyFrame : IDisposable
         return new LazyFrame(PolarsWrapper.LazyWithColumns(lfClone, handles));
     }
     /// <summary>
+    /// Add fixed-window rolling aggregations for every column picked by the selector.
+    /// Output columns are named "{column}{suffix}_{op}", e.g. "price_rolling_mean".
+    /// </summary>
+    /// <param name="selector">Columns to aggregate.</param>
+    /// <param name="ops">Aggregations to compute for each column.</param>
+    /// <param name="window">Window size in rows, e.g. "3" or "3i".</param>
+    /// <param name="suffix">Name suffix; defaults to "_rolling".</param>
+    public LazyFrame WithRollingAggregates(Selector selector, RollingOp[] ops, string window, string? suffix = null)
+    {
+        var lfClone = CloneHandle();
+        var sel = selector.CloneHandle();
+        var codes = ops.Select(o => o.ToNative()).ToArray();
+        return new LazyFrame(PolarsWrapper.LazyWithRollingAggregates(lfClone, sel, codes, window, suffix));
+    }
+    /// <summary>
+    /// Cast several columns in one step.
+    /// </summary>
+    /// <param name="dtypes">Target type for each column name.</param>
+    /// <param name="strict">Throw on values that cannot be cast; otherwise they become null.</param>
+    public LazyFrame CastColumns(Dictionary<string, DataType> dtypes, bool strict = true)
+    {
+        var lfClone = CloneHandle();
+        var names = dtypes.Keys.ToArray();
+        var handles = dtypes.Values.Select(t => t.Handle).ToArray();
+        return new LazyFrame(PolarsWrapper.LazyCastColumns(lfClone, names, handles, strict));
+    }
+    /// <summary>
+    /// Run a C# function over the whole DataFrame as a step of the plan.
+    /// Predicates and projections are not pushed through the function.
+    /// </summary>
+    /// <param name="func">Transform applied to the materialized input.</param>
+    /// <param name="outputSchema">Schema of the result. Null means the same as the input.</param>
+    /// <param name="validateOutput">Check at run time that the result matches the declared schema.</param>
+    public LazyFrame MapBatches(
+        Func<DataFrame, DataFrame> func,
+        Dictionary<string, DataType>? outputSchema = null,
+        bool validateOutput = false)
+    {
+        RecordBatch Transform(RecordBatch batch)
+        {
+            using var input = DataFrame.FromArrow(batch);
+            using var output = func(input);
+            return output.ToArrow();
+        }
+
+        var schemaHandles = outputSchema?.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        var lfClone = CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyMapBatches(lfClone, Transform, schemaHandles, validateOutput));
+    }
+    /// <summary>
+    /// Make the columns of other LazyFrames (e.g. a one-row parameter table) available
+    /// to expressions on this one, without an explicit join.
+    /// </summary>
+    /// <param name="contexts">Auxiliary LazyFrames.</param>
+    public LazyFrame WithContext(params LazyFrame[] contexts)
+    {
+        if (contexts.Length == 0)
+            throw new ArgumentException("WithContext requires at least one LazyFrame.", nameof(contexts));
+
+        var ctxClones = contexts.Select(c => c.CloneHandle()).ToArray();
+        return new LazyFrame(PolarsWrapper.LazyWithContext(CloneHandle(), ctxClones));
+    }
+    /// <summary>
+    /// Fill null values in every column.
+    /// </summary>
+    /// <param name="value">The expression (or literal) to replace nulls with.</param>
+    public LazyFrame FillNull(Expr value)
+    {
+        return new LazyFrame(PolarsWrapper.LazyFillNull(CloneHandle(), value.CloneHandle()));
+    }
+    /// <summary>
+    /// Fill null values in every column with a literal value.
+    /// </summary>
+    public LazyFrame FillNull(object value) => FillNull(Expr.MakeLit(value));
+    /// <summary>
+    /// Fill NaN values in every floating point column. Nulls are left as they are.
+    /// </summary>
+    public LazyFrame FillNan(Expr value)
+    {
+        return new LazyFrame(PolarsWrapper.LazyFillNan(CloneHandle(), value.CloneHandle()));
+    }
+    /// <summary>
+    /// Fill NaN values in every floating point column with a literal value.
+    /// </summary>
+    public LazyFrame FillNan(object value) => FillNan(Expr.MakeLit(value));
+    /// <summary>
+    /// Rename columns. Only the schema changes, so projection pushdown still works.
+    /// </summary>
+    /// <param name="oldNames">Existing column names.</param>
+    /// <param name="newNames">New names, one per old name.</param>
+    /// <param name="strict">Throw on collect if an old name does not exist; otherwise it is ignored.</param>
+    public LazyFrame Rename(string[] oldNames, string[] newNames, bool strict = true)
+    {
+        return new LazyFrame(PolarsWrapper.LazyRename(CloneHandle(), oldNames, newNames, strict));
+    }
+    /// <summary>
+    /// Rename columns using an old name -> new name mapping.
+    /// </summary>
+    public LazyFrame Rename(Dictionary<string, string> mapping, bool strict = true)
+    {
+        return Rename(mapping.Keys.ToArray(), mapping.Values.ToArray(), strict);
+    }
+    /// <summary>
+    /// Drop columns by name. Throws on collect if a column does not exist.
+    /// </summary>
+    public LazyFrame Drop(params string[] columns)
+    {
+        return Drop(columns, strict: true);
+    }
+    /// <summary>
+    /// Drop columns by name.
+    /// </summary>
+    /// <param name="columns"></param>
+    /// <param name="strict">Throw on collect if a column does not exist; otherwise it is ignored.</param>
+    public LazyFrame Drop(string[] columns, bool strict)
+    {
+        return new LazyFrame(PolarsWrapper.LazyDrop(CloneHandle(), columns, strict));
+    }
+    /// <summary>
+    /// Drop every column picked by the selector, e.g. all numeric columns.
+    /// </summary>
+    public LazyFrame Drop(Selector selector)
+    {
+        return new LazyFrame(PolarsWrapper.LazyDrop(CloneHandle(), selector.CloneHandle()));
+    }
+    /// <summary>
+    /// Expand Struct columns into top-level columns, one per field.
+    /// </summary>
+    public LazyFrame Unnest(params string[] columns)
+    {
+        return new LazyFrame(PolarsWrapper.LazyUnnest(CloneHandle(), columns));
+    }
+    /// <summary>
+    /// Expand every Struct column picked by the selector into top-level columns.
+    /// </summary>
+    public LazyFrame Unnest(Selector selector)
+    {
+        return new LazyFrame(PolarsWrapper.LazyUnnest(CloneHandle(), selector.CloneHandle()));
+    }
+    /// <summary>
+    /// Drop duplicate rows.
+    /// </summary>
+    /// <param name="subset">Columns to compare. Null compares all columns.</param>
+    /// <param name="keep">Which row to keep among duplicates.</param>
+    /// <param name="maintainOrder">Keep the original row order (slower).</param>
+    public LazyFrame Unique(Selector? subset = null, UniqueKeepStrategy keep = UniqueKeepStrategy.First, bool maintainOrder = false)
+    {
+        return new LazyFrame(PolarsWrapper.LazyUnique(CloneHandle(), subset?.CloneHandle(), keep.ToNative(), maintainOrder));
+    }
+    /// <summary>
     /// Sort the LazyFrame by a single column.
     /// </summary>
     public LazyFrame Sort(string column, bool descending = false)
@@ -417,6 +725,26 @@ public class LazyFrame : IDisposable
         return new LazyFrame(h);
     }
     /// <summary>
+    /// Sort by multiple expressions, with a direction and null placement per key.
+    /// </summary>
+    /// <param name="exprs">Sort keys.</param>
+    /// <param name="descending">Per-key direction. Null means all ascending.</param>
+    /// <param name="nullsLast">Per-key null placement. Null means nulls first for every key.</param>
+    /// <param name="maintainOrder">Keep the original order of rows with equal keys (stable sort).</param>
+    /// <param name="multithreaded">Sort using multiple threads.</param>
+    public LazyFrame Sort(Expr[] exprs, bool[]? descending, bool[]? nullsLast, bool maintainOrder = false, bool multithreaded = true)
+    {
+        if (descending != null && descending.Length != exprs.Length)
+            throw new ArgumentException("Expressions and descending array must have the same length.");
+        if (nullsLast != null && nullsLast.Length != exprs.Length)
+            throw new ArgumentException("Expressions and nullsLast array must have the same length.");
+
+        var clonedHandles = exprs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        return new LazyFrame(PolarsWrapper.LazySortExprs(
+            CloneHandle(), clonedHandles, descending, nullsLast, maintainOrder, multithreaded
+        ));
+    }
+    /// <summary>
     /// Limit the number of rows in the LazyFrame.
     /// </summary>
     /// <param name="n"></param>
@@ -427,6 +755,40 @@ public class LazyFrame : IDisposable
         return new LazyFrame(PolarsWrapper.LazyLimit(lfClone, n));
     }
     /// <summary>
+    /// Take a window of rows (OFFSET / LIMIT paging). The slice is pushed down to the scan.
+    /// </summary>
+    /// <param name="offset">Start row. Negative values count from the end.</param>
+    /// <param name="length">Maximum number of rows.</param>
+    public LazyFrame Slice(long offset, uint length)
+    {
+        return new LazyFrame(PolarsWrapper.LazySlice(CloneHandle(), offset, length));
+    }
+    /// <summary>
+    /// Shift all columns by n rows. Positive n shifts down, negative n shifts up.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <param name="fillValue">Value for the vacated slots. Null leaves them null.</param>
+    public LazyFrame Shift(long n = 1, Expr? fillValue = null)
+    {
+        return new LazyFrame(PolarsWrapper.LazyShift(CloneHandle(), n, fillValue?.CloneHandle()));
+    }
+    /// <summary>
+    /// Reverse the row order.
+    /// </summary>
+    public LazyFrame Reverse()
+    {
+        return new LazyFrame(PolarsWrapper.LazyReverse(CloneHandle()));
+    }
+    /// <summary>
+    /// Take every nth row, starting at offset (downsampling).
+    /// </summary>
+    /// <param name="n">Step, must be greater than 0.</param>
+    /// <param name="offset">Index of the first row to take.</param>
+    public LazyFrame GatherEvery(ulong n, ulong offset = 0)
+    {
+        return new LazyFrame(PolarsWrapper.LazyGatherEvery(CloneHandle(), n, offset));
+    }
+    /// <summary>
     /// Explode list-like columns into multiple rows.
     /// </summary>
     /// <param name="exprs"></param>
@@ -472,16 +834,21 @@ public class LazyFrame : IDisposable
     /// <param name="lfs"></param>
     /// <param name="rechunk"></param>
     /// <param name="parallel"></param>
+    /// <param name="toSupertypes">Cast columns with different types to their common supertype instead of failing.</param>
+    /// <param name="maintainOrder">Keep rows in input order. Turning this off allows faster parallel execution.</param>
     /// <returns></returns>
     public static LazyFrame Concat(
         IEnumerable<LazyFrame> lfs, 
         ConcatType how = ConcatType.Vertical, 
         bool rechunk = false, 
-        bool parallel = true)
+        bool parallel = true,
+        bool toSupertypes = false,
+        bool maintainOrder = true)
     {
-        var lfClones = lfs.Select(l => l.CloneHandle()).ToArray();
-        var handles = lfClones.Select(l => l).ToArray();
-        return new LazyFrame(PolarsWrapper.LazyConcat(handles, how.ToNative(), rechunk, parallel));
+        var handles = lfs.Select(l => l.CloneHandle()).ToArray();
+        if (handles.Length == 0)
+            throw new ArgumentException("At least one LazyFrame is required.", nameof(lfs));
+        return new LazyFrame(PolarsWrapper.LazyConcat(handles, how.ToNative(), rechunk, parallel, toSupertypes, maintainOrder));
     }
 
     // ==========================================
@@ -522,6 +889,138 @@ public class LazyFrame : IDisposable
     {
         return Join(other,new Expr[] {leftOn}, new Expr[] {rightOn}, how);
     }
+    /// <summary>
+    /// Join with another LazyFrame, controlling whether both inputs are materialized in parallel.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="leftOn"></param>
+    /// <param name="rightOn"></param>
+    /// <param name="how"></param>
+    /// <param name="allowParallel">False materializes the two inputs one after another, which keeps memory usage lower on skewed data.</param>
+    /// <returns></returns>
+    public LazyFrame Join(LazyFrame other, Expr[] leftOn, Expr[] rightOn, JoinType how, bool allowParallel)
+    {
+        var lOn = leftOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var rOn = rightOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var lfClone = CloneHandle();
+        var otherClone = other.CloneHandle();
+        return new LazyFrame(PolarsWrapper.JoinAllowParallel(
+            lfClone, 
+            otherClone, 
+            lOn, 
+            rOn, 
+            how.ToNative(),
+            allowParallel
+        ));
+    }
+    /// <summary>
+    /// Join with another LazyFrame, with full control over suffix, key validation, null keys and key columns.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="leftOn"></param>
+    /// <param name="rightOn"></param>
+    /// <param name="how"></param>
+    /// <param name="suffix">Suffix for duplicate column names from the right side. Null means "_right".</param>
+    /// <param name="validate">Uniqueness check on the join keys, raised on collect.</param>
+    /// <param name="joinNulls">Treat null keys as equal.</param>
+    /// <param name="coalesce">Whether to merge the left and right key columns.</param>
+    /// <returns></returns>
+    public LazyFrame Join(
+        LazyFrame other,
+        Expr[] leftOn, Expr[] rightOn,
+        JoinType how,
+        string? suffix,
+        JoinValidation validate = JoinValidation.ManyToMany,
+        bool joinNulls = false,
+        JoinCoalesce coalesce = JoinCoalesce.JoinSpecific)
+    {
+        var lOn = leftOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var rOn = rightOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var lfClone = CloneHandle();
+        var otherClone = other.CloneHandle();
+        return new LazyFrame(PolarsWrapper.JoinWithArgs(
+            lfClone, 
+            otherClone, 
+            lOn, 
+            rOn, 
+            how.ToNative(),
+            suffix,
+            validate.ToNative(),
+            joinNulls,
+            coalesce.ToNative()
+        ));
+    }
+    /// <summary>
+    /// Join with another LazyFrame using the same key columns on both sides.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="on">Key expressions, applied to both sides.</param>
+    /// <param name="how"></param>
+    /// <param name="suffix">Suffix for duplicate column names from the right side. Null means "_right".</param>
+    /// <param name="validate">Uniqueness check on the join keys, raised on collect.</param>
+    /// <param name="joinNulls">Treat null keys as equal.</param>
+    /// <param name="coalesce">Whether to merge the left and right key columns.</param>
+    /// <returns></returns>
+    public LazyFrame Join(
+        LazyFrame other,
+        Expr[] on,
+        JoinType how = JoinType.Inner,
+        string? suffix = null,
+        JoinValidation validate = JoinValidation.ManyToMany,
+        bool joinNulls = false,
+        JoinCoalesce coalesce = JoinCoalesce.JoinSpecific)
+    {
+        if (on.Length == 0)
+            throw new ArgumentException("At least one join key is required.", nameof(on));
+
+        var onHandles = on.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        return new LazyFrame(PolarsWrapper.JoinOn(
+            CloneHandle(),
+            other.CloneHandle(),
+            onHandles,
+            how.ToNative(),
+            suffix,
+            validate.ToNative(),
+            joinNulls,
+            coalesce.ToNative()
+        ));
+    }
+    /// <summary>
+    /// Inequality join: keep the row pairs for which all predicates hold.
+    /// Right-side columns that clash with left-side names get a "_right" suffix,
+    /// and predicates can refer to them by that name.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="predicates">e.g. Col("time") &gt;= Col("start"), Col("time") &lt; Col("end")</param>
+    /// <returns></returns>
+    public LazyFrame JoinWhere(LazyFrame other, params Expr[] predicates)
+    {
+        if (predicates.Length == 0)
+            throw new ArgumentException("JoinWhere requires at least one predicate.", nameof(predicates));
+
+        var preds = predicates.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        return new LazyFrame(PolarsWrapper.JoinWhere(CloneHandle(), other.CloneHandle(), preds));
+    }
+    /// <summary>
+    /// Cartesian product with another LazyFrame. No join keys are needed.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="suffix">Suffix for duplicate column names from the right side. Null means "_right".</param>
+    /// <returns></returns>
+    public LazyFrame CrossJoin(LazyFrame other, string? suffix = null)
+    {
+        return new LazyFrame(PolarsWrapper.CrossJoin(CloneHandle(), other.CloneHandle(), suffix));
+    }
+    /// <summary>
+    /// Merge two LazyFrames that are both sorted ascending by <paramref name="key"/> into one sorted result.
+    /// Cheaper than concat followed by sort. The inputs are not checked: unsorted input gives an unsorted result.
+    /// </summary>
+    /// <param name="other">LazyFrame with the same schema.</param>
+    /// <param name="key">Column both sides are sorted by.</param>
+    public LazyFrame MergeSorted(LazyFrame other, string key)
+    {
+        return new LazyFrame(PolarsWrapper.MergeSorted(CloneHandle(), other.CloneHandle(), key));
+    }
 
     /// <summary>
     /// Perform an As-Of Join (time-series join).
@@ -585,6 +1084,15 @@ public class LazyFrame : IDisposable
         return new LazyGroupBy(lfClone, keys);
     }
     /// <summary>
+    /// Start a GroupBy operation on specified keys.
+    /// </summary>
+    /// <param name="keys"></param>
+    /// <param name="maintainOrder">Output groups in order of first appearance. False is faster but the order is not defined.</param>
+    public LazyGroupBy GroupBy(Expr[] keys, bool maintainOrder)
+    {
+        return new LazyGroupBy(CloneHandle(), keys, maintainOrder);
+    }
+    /// <summary>
     /// Group by dynamic windows based on a time index.
     /// </summary>
     public LazyDynamicGroupBy GroupByDynamic(
@@ -617,6 +1125,49 @@ public class LazyFrame : IDisposable
             startBy
         );
     }
+    /// <summary>
+    /// Create a window ending at each row's index value and aggregate over it.
+    /// Unlike GroupByDynamic, the windows follow the data points instead of a fixed grid.
+    /// </summary>
+    /// <param name="indexColumn">Time or integer column, must be sorted.</param>
+    /// <param name="period">Window length, e.g. "2d" or "3i" for an integer index.</param>
+    /// <param name="offset">Window start relative to the index value. Null means -period, i.e. (t - period, t].</param>
+    /// <param name="by">Additional group keys.</param>
+    /// <param name="closedWindow">Which window bounds are inclusive.</param>
+    public LazyRollingGroupBy Rolling(
+        string indexColumn,
+        string period,
+        string? offset = null,
+        Expr[]? by = null,
+        ClosedWindow closedWindow = ClosedWindow.Right)
+    {
+        return new LazyRollingGroupBy(
+            CloneHandle(),
+            indexColumn,
+            period,
+            offset,
+            by ?? new Expr[]{},
+            closedWindow
+        );
+    }
+    /// <summary>
+    /// Create a window ending at each row's time value and aggregate over it.
+    /// </summary>
+    public LazyRollingGroupBy Rolling(
+        string indexColumn,
+        TimeSpan period,
+        TimeSpan? offset = null,
+        Expr[]? by = null,
+        ClosedWindow closedWindow = ClosedWindow.Right)
+    {
+        return Rolling(
+            indexColumn,
+            DurationFormatter.ToPolarsString(period),
+            DurationFormatter.ToPolarsString(offset),
+            by,
+            closedWindow
+        );
+    }
     // ==========================================
     // Execution (Collect)
     // ==========================================
@@ -639,6 +1190,75 @@ public class LazyFrame : IDisposable
         return new DataFrame(PolarsWrapper.CollectStreaming(Handle));
     }
     /// <summary>
+    /// Count the rows the query produces without materializing its columns.
+    /// File scans can answer this from metadata. The LazyFrame is not consumed.
+    /// </summary>
+    public ulong Count()
+    {
+        return PolarsWrapper.LazyCount(CloneHandle());
+    }
+    /// <summary>
+    /// Execute the query plan and report how long each node took.
+    /// Timings has the columns "node", "start" and "end", in microseconds.
+    /// The LazyFrame is not consumed.
+    /// </summary>
+    public (DataFrame Result, DataFrame Timings) Profile()
+    {
+        var (result, timings) = PolarsWrapper.LazyProfile(CloneHandle());
+        return (new DataFrame(result), new DataFrame(timings));
+    }
+    /// <summary>
+    /// Execute the query plan with the given engine and return a DataFrame.
+    /// </summary>
+    public DataFrame Collect(Engine engine)
+    {
+        return new DataFrame(PolarsWrapper.CollectWithEngine(Handle, engine.ToNative()));
+    }
+    /// <summary>
+    /// Run the query on only the first nRows of every data source (for debugging).
+    /// Unlike Limit, sources read less data, but filters, joins and aggregations
+    /// see incomplete input, so the result may differ from Collect().
+    /// </summary>
+    public DataFrame Fetch(ulong nRows = 500)
+    {
+        return new DataFrame(PolarsWrapper.LazyFetch(CloneHandle(), nRows));
+    }
+    /// <summary>
+    /// Execute the query plan and spool the result into an uncompressed IPC file under
+    /// <paramref name="spoolDir"/>, returning a LazyFrame that scans that file.
+    /// The result stays out of memory until it is queried again.
+    /// The file is not deleted automatically; the caller owns <paramref name="spoolDir"/>.
+    /// </summary>
+    public LazyFrame CollectSpooled(string spoolDir)
+    {
+        return new LazyFrame(PolarsWrapper.CollectSpooled(Handle, spoolDir));
+    }
+    /// <summary>
+    /// Execute the query with the streaming engine and pull the result batch by batch,
+    /// so the full result never has to be materialized.
+    /// Stopping the enumeration early aborts the query. The LazyFrame is not consumed.
+    /// </summary>
+    /// <param name="batchSize">Rows per batch (the last one may be shorter). 0 returns the engine's morsels as they are.</param>
+    public IEnumerable<RecordBatch> CollectBatches(ulong batchSize = 0)
+    {
+        using var iter = PolarsWrapper.LazyCollectBatches(CloneHandle(), batchSize);
+        while (PolarsWrapper.BatchIterNext(iter) is { } batch)
+        {
+            yield return batch;
+        }
+    }
+    /// <summary>
+    /// Execute several LazyFrames together. The plans are optimized as a whole,
+    /// so a shared sub-plan (e.g. the same expensive scan) runs only once.
+    /// The LazyFrames are not consumed.
+    /// </summary>
+    /// <returns>One DataFrame per input, in input order.</returns>
+    public static DataFrame[] CollectAll(IEnumerable<LazyFrame> lfs)
+    {
+        var lfClones = lfs.Select(l => l.CloneHandle()).ToArray();
+        return PolarsWrapper.LazyCollectAll(lfClones).Select(h => new DataFrame(h)).ToArray();
+    }
+    /// <summary>
     /// Execute the query plan asynchronously and return a DataFrame.
     /// </summary>
     public async Task<DataFrame> CollectAsync()
@@ -659,6 +1279,15 @@ public class LazyFrame : IDisposable
         PolarsWrapper.SinkParquet(Handle, path);
     }
     /// <summary>
+    /// Sink the LazyFrame to a Parquet file with compression, statistics and page options.
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    /// <param name="options">Write options.</param>
+    public void SinkParquet(string path, ParquetWriteOptions options)
+    {
+        PolarsWrapper.SinkParquet(Handle, path, options.ToNative());
+    }
+    /// <summary>
     /// Sink the LazyFrame to a CSV file.
     /// </summary>
     /// <param name="path"></param>
@@ -668,6 +1297,15 @@ public class LazyFrame : IDisposable
         PolarsWrapper.SinkIpc(Handle, path);
     }
     /// <summary>
+    /// Sink the LazyFrame to an IPC file with body compression.
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    /// <param name="compression">Compression codec.</param>
+    public void SinkIpc(string path, IpcCompression compression)
+    {
+        PolarsWrapper.SinkIpc(Handle, path, compression.ToNative());
+    }
+    /// <summary>
     /// Sink the LazyFrame to JSON file.
     /// </summary>
     /// <param name="path"></param>
@@ -677,6 +1315,14 @@ public class LazyFrame : IDisposable
         PolarsWrapper.SinkJson(Handle, path);
     }
     /// <summary>
+    /// Sink the LazyFrame to an NDJSON file (one JSON object per line).
+    /// </summary>
+    /// <param name="path">Output path.</param>
+    public void SinkNdjson(string path)
+    {
+        PolarsWrapper.SinkNdjson(Handle, path);
+    }
+    /// <summary>
     /// Sink the LazyFrame to CSV file.
     /// </summary>
     /// <param name="path"></param>
@@ -686,6 +1332,17 @@ public class LazyFrame : IDisposable
         PolarsWrapper.SinkCsv(Handle, path);
     }
     /// <summary>
+    /// Sink the LazyFrame to CSV file with writer options.
+    /// </summary>
+    /// <param name="path"></param>
+    /// <param name="options"></param>
+    public void SinkCsv(string path, CsvWriteOptions options)
+    {
+        PolarsWrapper.SinkCsv(
+            Handle, path, options.ToNative(),
+            options.LineTerminator, options.DateFormat, options.TimeFormat, options.DatetimeFormat, options.NullValue);
+    }
+    /// <summary>
     /// 通用流式 Sink：每计算出一批数据，就触发一次回调。
     /// 这是实现自定义 Sink（如数据库、网络流、消息队列）的基础。
     /// </summary>
@@ -700,6 +1357,18 @@ public class LazyFrame : IDisposable
         using var _ = lfRes.CollectStreaming(); 
     }
     /// <summary>
+    /// Stream the result to callbacks on the calling thread: the output schema first,
+    /// then every batch in row order, then the total row count.
+    /// An exception thrown by a callback aborts the query and is rethrown here.
+    /// </summary>
+    /// <param name="onSchema">Receives the output schema before any data.</param>
+    /// <param name="onBatch">Receives each batch in row order.</param>
+    /// <param name="onFinish">Receives the total number of rows.</param>
+    public void Sink(Action<Schema> onSchema, Action<RecordBatch> onBatch, Action<ulong>? onFinish = null)
+    {
+        PolarsWrapper.SinkCallback(CloneHandle(), onSchema, onBatch, onFinish ?? (_ => { }));
+    }
+    /// <summary>
     /// 通用流式 Sink 接口：将 LazyFrame 计算结果流式转换为 IDataReader 并交给 writerAction 处理。
     /// 全程内存占用极低 (O(1))。
     /// 用户可以在 writerAction 里使用 SqlBulkCopy, NpgsqlBinaryImporter 等工具。
@@ -747,4 +1416,24 @@ public class LazyFrame : IDisposable
     {
         Handle?.Dispose();
     }
-}
\ No newline at end of file
+}
+/// <summary>
+/// Timings reported by <see cref="LazyFrame.Benchmark"/>.
+/// </summary>
+public sealed class BenchmarkResult
+{
+    /// <summary>Number of collects that were run.</summary>
+    public int Iterations { get; init; }
+    /// <summary>Row count of the collected result.</summary>
+    public long Rows { get; init; }
+    /// <summary>Wall time of each collect in milliseconds.</summary>
+    public double[] WallMs { get; init; } = System.Array.Empty<double>();
+    /// <summary>Fastest collect in milliseconds.</summary>
+    public double MinMs { get; init; }
+    /// <summary>Average collect time in milliseconds.</summary>
+    public double MeanMs { get; init; }
+    /// <summary>Slowest collect in milliseconds.</summary>
+    public double MaxMs { get; init; }
+    /// <summary>Peak resident memory of the process in bytes; only available on Linux.</summary>
+    public long? PeakRssBytes { get; init; }
+}
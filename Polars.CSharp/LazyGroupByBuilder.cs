@@ -9,11 +9,13 @@ public class LazyGroupBy
 {
     private readonly LazyFrameHandle _lfHandle; // 这是克隆来的 Handle
     private readonly Expr[] _keys;
+    private readonly bool _maintainOrder;
 
-    internal LazyGroupBy(LazyFrameHandle lfHandle, Expr[] keys)
+    internal LazyGroupBy(LazyFrameHandle lfHandle, Expr[] keys, bool maintainOrder = true)
     {
         _lfHandle = lfHandle;
         _keys = keys;
+        _maintainOrder = maintainOrder;
     }
 
     /// <summary>
@@ -32,7 +34,7 @@ public class LazyGroupBy
         // 注意：这里传入的是 _lfHandle。
         // NativeBindings.pl_lazy_groupby_agg 会消耗这个 handle。
         // 因为我们在创建 LazyGroupBy 时已经 Clone 过了，所以这里消耗的是副本，安全！
-        var resHandle = PolarsWrapper.LazyGroupByAgg(_lfHandle, keyHandles, aggHandles);
+        var resHandle = PolarsWrapper.LazyGroupByAgg(_lfHandle, keyHandles, aggHandles, _maintainOrder);
         
         return new LazyFrame(resHandle);
     }
@@ -105,4 +107,52 @@ public class LazyDynamicGroupBy
             // 3. 返回新对象
             return new LazyFrame(newHandle);
     }
-}
\ No newline at end of file
+}
+
+/// <summary>
+/// Intermediate builder for LazyFrame.Rolling operations.
+/// </summary>
+public class LazyRollingGroupBy
+{
+    private readonly LazyFrameHandle _lfHandle;
+    private readonly Expr[] _keys;
+    private readonly string _indexColumn;
+    private readonly string _period;
+    private readonly string? _offset;
+    private readonly ClosedWindow _closedWindow;
+
+    internal LazyRollingGroupBy(
+        LazyFrameHandle lfHandle,
+        string indexColumn,
+        string period,
+        string? offset,
+        Expr[] keys,
+        ClosedWindow closedWindow)
+    {
+        _lfHandle = lfHandle;
+        _indexColumn = indexColumn;
+        _period = period;
+        _offset = offset;
+        _keys = keys;
+        _closedWindow = closedWindow;
+    }
+    /// <summary>
+    /// Apply aggregations to each window.
+    /// This consumes the internal LazyFrame handle.
+    /// </summary>
+    public LazyFrame Agg(params Expr[] aggs)
+    {
+        var keyHandles = _keys.Select(k => PolarsWrapper.CloneExpr(k.Handle)).ToArray();
+        var aggHandles = aggs.Select(a => PolarsWrapper.CloneExpr(a.Handle)).ToArray();
+        var newHandle = PolarsWrapper.LazyRolling(
+            _lfHandle,
+            _indexColumn,
+            _period,
+            _offset,
+            _closedWindow.ToNative(),
+            keyHandles,
+            aggHandles
+        );
+        return new LazyFrame(newHandle);
+    }
+}
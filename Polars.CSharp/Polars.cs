@@ -39,6 +39,42 @@ public static class Polars
     public static Expr Lit(bool value) => new(PolarsWrapper.Lit(value));
     public static Expr Lit(long value) => new(PolarsWrapper.Lit(value));
     public static Expr Lit(float value) => new(PolarsWrapper.Lit(value));
+    public static Expr Lit(DateOnly value) => new(PolarsWrapper.LitDate(value.DayNumber - DateOnly.FromDateTime(DateTime.UnixEpoch).DayNumber));
+    // TimeOnly.Ticks 是距午夜的 100ns 单位
+    public static Expr Lit(TimeOnly value) => new(PolarsWrapper.LitTime(value.Ticks * 100));
+    public static Expr Lit(TimeSpan value) => new(PolarsWrapper.LitDuration(value.Ticks / 10, (int)TimeUnit.Microseconds));
+    public static Expr Lit(DateTimeOffset value) => LitDatetime(value.UtcDateTime, TimeUnit.Microseconds, "UTC");
+    public static Expr Lit(decimal value) => new(PolarsWrapper.LitDecimal(value));
+    public static Expr Lit(byte[] value) => new(PolarsWrapper.LitBinary(value));
+
+    /// <summary>
+    /// Create a datetime literal with an explicit time unit and time zone.
+    /// </summary>
+    /// <param name="value">The datetime. Its wall-clock value is stored as-is.</param>
+    /// <param name="unit">Nanoseconds, Microseconds or Milliseconds.</param>
+    /// <param name="timeZone">Time zone of the literal. Null means naive.</param>
+    public static Expr LitDatetime(DateTime value, TimeUnit unit = TimeUnit.Microseconds, string? timeZone = null)
+    {
+        long ticks = value.Ticks - DateTime.UnixEpoch.Ticks;
+        long v = unit switch
+        {
+            TimeUnit.Nanoseconds => ticks * 100,
+            TimeUnit.Microseconds => ticks / 10,
+            TimeUnit.Milliseconds => ticks / TimeSpan.TicksPerMillisecond,
+            _ => throw new ArgumentOutOfRangeException(nameof(unit), "Datetime literals support Nanoseconds, Microseconds or Milliseconds.")
+        };
+        return new(PolarsWrapper.LitDatetime(v, (int)unit, timeZone));
+    }
+
+    /// <summary>
+    /// Create a null literal of the given type, so it does not get inferred as the Null type.
+    /// </summary>
+    public static Expr LitNull(DataType dtype) => new(PolarsWrapper.LitNull(dtype.Handle));
+
+    /// <summary>
+    /// Embed a Series into an expression as a literal column. The data is not copied.
+    /// </summary>
+    public static Expr Lit(Series series) => new(PolarsWrapper.LitSeries(series.Handle));
     
     // ---------------------------------------------------------
     // Selectors Entry Points
@@ -144,6 +180,207 @@ public static class Polars
         var handles = exprs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
         return new Expr(PolarsWrapper.AsStruct(handles));
     }
+    /// <summary>
+    /// Encode several columns as one JSON object string per row: {"a": ..., "b": ...}.
+    /// Same as AsStruct(exprs).Struct.JsonEncode(). The output keeps the name of the first expression.
+    /// </summary>
+    public static Expr RowJsonEncode(params Expr[] exprs)
+    {
+        var handles = exprs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        return new Expr(PolarsWrapper.RowJsonEncode(handles));
+    }
+    /// <summary>
+    /// Refer to a field of the struct being modified in <see cref="StructOps.WithFields"/>.
+    /// </summary>
+    public static Expr Field(string name) => new(PolarsWrapper.Field(name));
+    /// <summary>
+    /// Encode x/y coordinates as a little-endian 2D WKB Point.
+    /// The result is null when either coordinate is null.
+    /// </summary>
+    public static Expr XyToWkb(Expr x, Expr y)
+    {
+        var xh = PolarsWrapper.CloneExpr(x.Handle);
+        var yh = PolarsWrapper.CloneExpr(y.Handle);
+        return new Expr(PolarsWrapper.XyToWkb(xh, yh));
+    }
+    // ==========================================
+    // Temporal Ranges
+    // ==========================================
+
+    /// <summary>
+    /// Generate a Date column from start to end.
+    /// </summary>
+    /// <param name="start">Scalar start date (Date or Datetime).</param>
+    /// <param name="end">Scalar end date.</param>
+    /// <param name="interval">Duration string, e.g. "1d", "1w", "1mo".</param>
+    /// <param name="closed">"both", "left", "right" or "none".</param>
+    public static Expr DateRange(Expr start, Expr end, string interval = "1d", string closed = "both")
+        => new(PolarsWrapper.DateRange(PolarsWrapper.CloneExpr(start.Handle), PolarsWrapper.CloneExpr(end.Handle), interval, closed));
+
+    /// <summary>
+    /// Generate one Date list per row from the start/end columns.
+    /// </summary>
+    public static Expr DateRanges(Expr start, Expr end, string interval = "1d", string closed = "both")
+        => new(PolarsWrapper.DateRanges(PolarsWrapper.CloneExpr(start.Handle), PolarsWrapper.CloneExpr(end.Handle), interval, closed));
+
+    /// <summary>
+    /// Generate a Time column from start to end.
+    /// </summary>
+    public static Expr TimeRange(Expr start, Expr end, string interval = "1h", string closed = "both")
+        => new(PolarsWrapper.TimeRange(PolarsWrapper.CloneExpr(start.Handle), PolarsWrapper.CloneExpr(end.Handle), interval, closed));
+
+    /// <summary>
+    /// Generate one Time list per row from the start/end columns.
+    /// </summary>
+    public static Expr TimeRanges(Expr start, Expr end, string interval = "1h", string closed = "both")
+        => new(PolarsWrapper.TimeRanges(PolarsWrapper.CloneExpr(start.Handle), PolarsWrapper.CloneExpr(end.Handle), interval, closed));
+
+    /// <summary>
+    /// Generate a Datetime column from start to end.
+    /// </summary>
+    /// <param name="start">Scalar start.</param>
+    /// <param name="end">Scalar end.</param>
+    /// <param name="interval">Duration string, e.g. "15m", "1h", "1mo".</param>
+    /// <param name="closed">"both", "left", "right" or "none".</param>
+    /// <param name="unit">Nanoseconds, Microseconds or Milliseconds. Inferred from the inputs when null.</param>
+    /// <param name="timeZone">Time zone of the result. Inferred from the inputs when null.</param>
+    public static Expr DatetimeRange(Expr start, Expr end, string interval = "1d", string closed = "both", TimeUnit? unit = null, string? timeZone = null)
+        => new(PolarsWrapper.DatetimeRange(PolarsWrapper.CloneExpr(start.Handle), PolarsWrapper.CloneExpr(end.Handle), interval, closed, unit.HasValue ? (int)unit.Value.ToNative() : -1, timeZone));
+
+    /// <summary>
+    /// Generate one Datetime list per row from the start/end columns.
+    /// </summary>
+    public static Expr DatetimeRanges(Expr start, Expr end, string interval = "1d", string closed = "both", TimeUnit? unit = null, string? timeZone = null)
+        => new(PolarsWrapper.DatetimeRanges(PolarsWrapper.CloneExpr(start.Handle), PolarsWrapper.CloneExpr(end.Handle), interval, closed, unit.HasValue ? (int)unit.Value.ToNative() : -1, timeZone));
+    // ==========================================
+    // String Concatenation
+    // ==========================================
+
+    /// <summary>
+    /// Horizontally concatenate several columns into one string column.
+    /// </summary>
+    /// <param name="exprs">Columns to concatenate.</param>
+    /// <param name="separator">Separator placed between values.</param>
+    /// <param name="ignoreNulls">Skip nulls. If false, any null input makes the row null.</param>
+    public static Expr ConcatStr(Expr[] exprs, string separator = "", bool ignoreNulls = false)
+    {
+        var handles = exprs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        return new Expr(PolarsWrapper.ConcatStr(handles, separator, ignoreNulls));
+    }
+    // ==========================================
+    // Horizontal (row-wise across columns)
+    // ==========================================
+    private static ExprHandle[] CloneAll(Expr[] exprs)
+        => exprs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+
+    /// <summary>
+    /// Row-wise minimum across the given columns.
+    /// </summary>
+    public static Expr MinHorizontal(params Expr[] exprs) => new(PolarsWrapper.MinHorizontal(CloneAll(exprs)));
+    /// <summary>
+    /// Row-wise maximum across the given columns.
+    /// </summary>
+    public static Expr MaxHorizontal(params Expr[] exprs) => new(PolarsWrapper.MaxHorizontal(CloneAll(exprs)));
+    /// <summary>
+    /// Row-wise logical OR across the given boolean columns.
+    /// </summary>
+    public static Expr AnyHorizontal(params Expr[] exprs) => new(PolarsWrapper.AnyHorizontal(CloneAll(exprs)));
+    /// <summary>
+    /// Row-wise logical AND across the given boolean columns.
+    /// </summary>
+    public static Expr AllHorizontal(params Expr[] exprs) => new(PolarsWrapper.AllHorizontal(CloneAll(exprs)));
+    /// <summary>
+    /// Row-wise sum across the given columns. Nulls are skipped.
+    /// </summary>
+    public static Expr SumHorizontal(params Expr[] exprs) => SumHorizontal(exprs, true);
+    /// <summary>
+    /// Row-wise sum across the given columns.
+    /// </summary>
+    /// <param name="exprs">Columns to sum.</param>
+    /// <param name="ignoreNulls">Skip nulls. If false, any null input makes the row null.</param>
+    public static Expr SumHorizontal(Expr[] exprs, bool ignoreNulls)
+        => new(PolarsWrapper.SumHorizontal(CloneAll(exprs), ignoreNulls));
+    /// <summary>
+    /// Row-wise mean across the given columns. Nulls are skipped.
+    /// </summary>
+    public static Expr MeanHorizontal(params Expr[] exprs) => MeanHorizontal(exprs, true);
+    /// <summary>
+    /// Row-wise mean across the given columns.
+    /// </summary>
+    /// <param name="exprs">Columns to average.</param>
+    /// <param name="ignoreNulls">Skip nulls. If false, any null input makes the row null.</param>
+    public static Expr MeanHorizontal(Expr[] exprs, bool ignoreNulls)
+        => new(PolarsWrapper.MeanHorizontal(CloneAll(exprs), ignoreNulls));
+    /// <summary>
+    /// Take the first non-null value of each row across the given columns.
+    /// </summary>
+    public static Expr Coalesce(params Expr[] exprs) => new(PolarsWrapper.Coalesce(CloneAll(exprs)));
+    /// <summary>
+    /// Repeat a value n times. The output column is named "repeat".
+    /// </summary>
+    /// <param name="value">Value to repeat.</param>
+    /// <param name="n">Number of repetitions.</param>
+    /// <param name="dtype">Cast the value to this type first. Null keeps the value's type.</param>
+    public static Expr Repeat(Expr value, Expr n, DataType? dtype = null)
+        => new(PolarsWrapper.Repeat(PolarsWrapper.CloneExpr(value.Handle), PolarsWrapper.CloneExpr(n.Handle), dtype?.Handle));
+    /// <summary>
+    /// Repeat a value n times. The output column is named "repeat".
+    /// </summary>
+    public static Expr Repeat(Expr value, long n, DataType? dtype = null) => Repeat(value, Lit(n), dtype);
+    // ==========================================
+    // Correlation / Covariance
+    // ==========================================
+    /// <summary>
+    /// Pearson correlation between two columns.
+    /// </summary>
+    public static Expr Corr(Expr a, Expr b)
+        => new(PolarsWrapper.PearsonCorr(PolarsWrapper.CloneExpr(a.Handle), PolarsWrapper.CloneExpr(b.Handle)));
+    /// <summary>
+    /// Spearman rank correlation between two columns.
+    /// </summary>
+    /// <param name="a">First column.</param>
+    /// <param name="b">Second column.</param>
+    /// <param name="propagateNans">Return NaN if either column contains NaN. Otherwise NaNs are ignored when ranking.</param>
+    public static Expr SpearmanRankCorr(Expr a, Expr b, bool propagateNans = false)
+        => new(PolarsWrapper.SpearmanRankCorr(PolarsWrapper.CloneExpr(a.Handle), PolarsWrapper.CloneExpr(b.Handle), propagateNans));
+    /// <summary>
+    /// Covariance between two columns.
+    /// </summary>
+    public static Expr Cov(Expr a, Expr b, byte ddof = 1)
+        => new(PolarsWrapper.Cov(PolarsWrapper.CloneExpr(a.Handle), PolarsWrapper.CloneExpr(b.Handle), ddof));
+    /// <summary>
+    /// Rolling Pearson correlation between two columns.
+    /// </summary>
+    /// <param name="x">First column.</param>
+    /// <param name="y">Second column.</param>
+    /// <param name="windowSize">Number of rows in each window.</param>
+    /// <param name="minPeriods">Minimum number of rows required for a result. Defaults to windowSize.</param>
+    /// <param name="ddof">Delta degrees of freedom.</param>
+    public static Expr RollingCorr(Expr x, Expr y, int windowSize, int? minPeriods = null, byte ddof = 1)
+        => new(PolarsWrapper.RollingCorr(PolarsWrapper.CloneExpr(x.Handle), PolarsWrapper.CloneExpr(y.Handle),
+            (uint)windowSize, (uint)(minPeriods ?? windowSize), ddof));
+    /// <summary>
+    /// Rolling covariance between two columns.
+    /// </summary>
+    /// <param name="x">First column.</param>
+    /// <param name="y">Second column.</param>
+    /// <param name="windowSize">Number of rows in each window.</param>
+    /// <param name="minPeriods">Minimum number of rows required for a result. Defaults to windowSize.</param>
+    /// <param name="ddof">Delta degrees of freedom.</param>
+    public static Expr RollingCov(Expr x, Expr y, int windowSize, int? minPeriods = null, byte ddof = 1)
+        => new(PolarsWrapper.RollingCov(PolarsWrapper.CloneExpr(x.Handle), PolarsWrapper.CloneExpr(y.Handle),
+            (uint)windowSize, (uint)(minPeriods ?? windowSize), ddof));
+    // ==========================================
+    // Global Config
+    // ==========================================
+
+    /// <summary>
+    /// Release process-wide native state before unloading the host (e.g. an AssemblyLoadContext).
+    /// Clears <see cref="DataFrameRegistry"/> and waits until the Polars thread pool is idle.
+    /// The thread pool itself lives for the whole process and is not destroyed.
+    /// </summary>
+    /// <returns>Number of registry entries that were still registered (i.e. leaked).</returns>
+    public static int Shutdown() => (int)PolarsWrapper.Shutdown();
     // ==========================================
     // SQL Context
     // ==========================================
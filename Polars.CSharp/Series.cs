@@ -586,6 +586,15 @@ public class Series : IDisposable
         set => PolarsWrapper.SeriesRename(Handle, value);
     }
     /// <summary>
+    /// Arrow extension type name (e.g. "arrow.uuid") carried over from an Arrow import,
+    /// or null if the Series was not created from an extension-typed array.
+    /// </summary>
+    public string? ExtensionName => PolarsWrapper.SeriesExtensionName(Handle);
+    /// <summary>
+    /// Raw metadata string of the Arrow extension type, or null if there is none.
+    /// </summary>
+    public string? ExtensionMetadata => PolarsWrapper.SeriesExtensionMetadata(Handle);
+    /// <summary>
     /// Get the number of null values in the Series.
     /// </summary>
     public long NullCount => PolarsWrapper.SeriesNullCount(Handle);
@@ -682,6 +691,13 @@ public class Series : IDisposable
     /// <returns></returns>
     public Series IsInfinite() => new(PolarsWrapper.SeriesIsInfinite(Handle));
     // ==========================================
+    // Categorical
+    // ==========================================
+    /// <summary>
+    /// Get the categories of a Categorical or Enum series as a String series.
+    /// </summary>
+    public Series GetCategories() => new(PolarsWrapper.SeriesCatGetCategories(Handle));
+    // ==========================================
     // Conversions (Arrow / DataFrame)
     // ==========================================
 
@@ -700,6 +716,15 @@ public class Series : IDisposable
         var handle = ArrowFfiBridge.ImportSeries(name, arrowArray);
         return new Series(handle);
     }
+    /// <summary>
+    /// Create Series from an Arrow Array described by a Field.
+    /// Field metadata such as "ARROW:extension:name" is kept, see <see cref="ExtensionName"/>.
+    /// </summary>
+    public static Series FromArrow(Field field, IArrowArray arrowArray)
+    {
+        var handle = ArrowFfiBridge.ImportSeries(field, arrowArray);
+        return new Series(handle);
+    }
 
     // ==========================================
     // High-Level Factories
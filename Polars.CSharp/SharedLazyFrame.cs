@@ -0,0 +1,49 @@
+using Polars.NET.Core;
+
+namespace Polars.CSharp;
+
+/// <summary>
+/// A read-only, reference-counted query plan that can be shared across threads.
+/// Each thread should take its own handle with <see cref="Retain"/> and build on a private copy
+/// obtained from <see cref="ClonePlan"/>. The plan is released when the last handle is disposed.
+/// </summary>
+public class SharedLazyFrame : IDisposable
+{
+    internal SharedLazyFrameHandle Handle { get; }
+
+    internal SharedLazyFrame(SharedLazyFrameHandle handle)
+    {
+        Handle = handle;
+    }
+
+    /// <summary>
+    /// Take another handle to the same plan (reference count + 1).
+    /// The returned handle must be disposed separately.
+    /// </summary>
+    public SharedLazyFrame Retain()
+    {
+        return new SharedLazyFrame(PolarsWrapper.LazyShareRetain(Handle));
+    }
+
+    /// <summary>
+    /// Copy the plan into an independent LazyFrame that can be chained further.
+    /// Only the plan is copied, not the data.
+    /// </summary>
+    public LazyFrame ClonePlan()
+    {
+        return new LazyFrame(PolarsWrapper.LazyShareClonePlan(Handle));
+    }
+
+    /// <summary>
+    /// Number of live handles to this plan (for debugging).
+    /// </summary>
+    public long RefCount => (long)PolarsWrapper.LazyShareRefCount(Handle);
+
+    /// <summary>
+    /// Release this handle (reference count - 1).
+    /// </summary>
+    public void Dispose()
+    {
+        Handle?.Dispose();
+    }
+}
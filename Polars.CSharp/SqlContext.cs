@@ -16,6 +16,11 @@ public class SqlContext : IDisposable
         Handle = PolarsWrapper.SqlContextNew();
     }
 
+    private SqlContext(SqlContextHandle handle)
+    {
+        Handle = handle;
+    }
+
     /// <summary>
     /// Register a LazyFrame as a table in the SQL context.
     /// </summary>
@@ -52,6 +57,25 @@ public class SqlContext : IDisposable
         return new LazyFrame(lfHandle);
     }
     /// <summary>
+    /// Materialize every registered table to disk as IPC files, together with a
+    /// catalog.json manifest, so the catalog can be reloaded with <see cref="Restore"/>.
+    /// </summary>
+    /// <param name="directory">Target directory. Created if it does not exist.</param>
+    public void Snapshot(string directory)
+    {
+        PolarsWrapper.SqlSnapshot(Handle, directory);
+    }
+
+    /// <summary>
+    /// Load a catalog written by <see cref="Snapshot"/>.
+    /// Tables are registered as memory-mapped IPC scans.
+    /// </summary>
+    /// <param name="directory">Directory containing catalog.json.</param>
+    public static SqlContext Restore(string directory)
+    {
+        return new SqlContext(PolarsWrapper.SqlRestore(directory));
+    }
+    /// <summary>
     /// Dispose the SQL Context and release resources.
     /// </summary>
     public void Dispose()
@@ -454,7 +454,7 @@ and ListOps(handle: ExprHandle) =
     member _.Max() = new Expr(PolarsWrapper.ListMax handle)
     member _.Mean() = new Expr(PolarsWrapper.ListMean handle)
     member _.Sort(descending: bool) = new Expr(PolarsWrapper.ListSort(handle, descending))
-    // Contains
+    // Contains: 逐行判断，第 i 行的 list 是否包含第 i 行的 item (item 可以是字面量或另一列)
     member _.Contains(item: Expr) : Expr = 
         new Expr(PolarsWrapper.ListContains(handle, item.CloneHandle()))
     member _.Contains(item: int) = 
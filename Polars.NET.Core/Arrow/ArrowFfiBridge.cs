@@ -29,6 +29,22 @@ namespace Polars.NET.Core.Arrow
                 return PolarsWrapper.SeriesFromArrow(name, &cArray, &cSchema);
             }
         }
+        /// <summary>
+        /// 带 Field 导入：Field 的 metadata (如 ARROW:extension:name) 会一并传给 Rust
+        /// </summary>
+        public static SeriesHandle ImportSeries(Field field, IArrowArray arrowArray)
+        {
+            unsafe
+            {
+                var cArray = new CArrowArray();
+                var cSchema = new CArrowSchema();
+
+                CArrowSchemaExporter.ExportField(field, &cSchema);
+                CArrowArrayExporter.ExportArray(arrowArray, &cArray);
+
+                return PolarsWrapper.SeriesFromArrow(field.Name, &cArray, &cSchema);
+            }
+        }
         // ==========================================
         // DataFrame Import (FromArrow)
         // ==========================================
@@ -18,6 +18,13 @@ namespace Polars.NET.Core.Arrow
             public Apache.Arrow.Schema Schema = default!;
         }
 
+        // 带下推信息的上下文：工厂会收到需要的列 (null = 全部) 和行数上限 (null = 不限)
+        private class PushdownScanContext
+        {
+            public Func<string[]?, long?, IEnumerator<Apache.Arrow.RecordBatch>> Factory = default!;
+            public Apache.Arrow.Schema Schema = default!;
+        }
+
         /// <summary>
         /// 准备 Lazy Scan 上下文，并返回指向 Context 的 GCHandle 指针。
         /// </summary>
@@ -50,6 +57,12 @@ namespace Polars.NET.Core.Arrow
             return &DestroyScanContextStatic;
         }
 
+        // 3. 获取带下推信息的创建流回调
+        public static delegate* unmanaged[Cdecl]<void*, byte**, UIntPtr, long, CArrowArrayStream*> GetPushdownFactoryCallback()
+        {
+            return &PushdownStreamFactoryCallbackStatic;
+        }
+
         // --- 实现细节 ---
 
         [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
@@ -83,6 +96,61 @@ namespace Polars.NET.Core.Arrow
             }
         }
 
+        [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+        private static CArrowArrayStream* PushdownStreamFactoryCallbackStatic(void* userData, byte** columns, UIntPtr len, long nRows)
+        {
+            try
+            {
+                var handle = GCHandle.FromIntPtr((IntPtr)userData);
+                var context = (PushdownScanContext)handle.Target!;
+
+                // 1. 解码列名 (columns 只在回调期间有效，必须立即拷贝)
+                string[]? names = null;
+                if (columns != null)
+                {
+                    names = new string[(int)len];
+                    for (int i = 0; i < names.Length; i++)
+                    {
+                        names[i] = Marshal.PtrToStringUTF8((IntPtr)columns[i])!;
+                    }
+                }
+                long? limit = nRows < 0 ? null : nRows;
+
+                // 2. 下推只是提示：工厂可能仍返回全部列，这里统一按需要的列裁剪，保证和导出的 Schema 一致
+                var schema = context.Schema;
+                var enumerator = context.Factory(names, limit);
+                if (names != null)
+                {
+                    schema = new Apache.Arrow.Schema(names.Select(n => context.Schema.GetFieldByName(n)), context.Schema.Metadata);
+                    enumerator = ProjectBatches(enumerator, schema);
+                }
+
+                var ptr = (CArrowArrayStream*)Marshal.AllocHGlobal(sizeof(CArrowArrayStream));
+                var exporter = new ArrowStreamExporter(enumerator, schema);
+                exporter.Export(ptr);
+
+                return ptr;
+            }
+            catch (Exception ex)
+            {
+                Console.WriteLine($"[Polars.NET Critical] Error in Pushdown Stream Factory Callback: {ex}");
+                return null;
+            }
+        }
+
+        private static IEnumerator<RecordBatch> ProjectBatches(IEnumerator<RecordBatch> source, Apache.Arrow.Schema schema)
+        {
+            using (source)
+            {
+                while (source.MoveNext())
+                {
+                    var batch = source.Current;
+                    var arrays = schema.FieldsList.Select(f => batch.Column(f.Name)).ToList();
+                    yield return new RecordBatch(schema, arrays, batch.Length);
+                }
+            }
+        }
+
         [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
         private static void DestroyScanContextStatic(void* userData)
         {
@@ -200,6 +268,38 @@ namespace Polars.NET.Core.Arrow
                 CArrowSchema.Free(cSchema);
             }
         }
+
+        /// <summary>
+        /// 同 ScanStream，但工厂会收到 Polars 下推的列裁剪和行数限制。
+        /// </summary>
+        public static LazyFrameHandle ScanStreamPushdown(
+            Func<string[]?, long?, IEnumerator<RecordBatch>> streamFactory,
+            Schema schema)
+        {
+            var context = new PushdownScanContext
+            {
+                Factory = streamFactory,
+                Schema = schema
+            };
+            var userData = (void*)GCHandle.ToIntPtr(GCHandle.Alloc(context));
+
+            var cSchema = CArrowSchema.Create();
+            CArrowSchemaExporter.ExportSchema(schema, cSchema);
+
+            try
+            {
+                return PolarsWrapper.LazyFrameScanStreamPushdown(
+                    cSchema,
+                    GetPushdownFactoryCallback(),
+                    GetDestroyCallback(),
+                    userData
+                );
+            }
+            finally
+            {
+                CArrowSchema.Free(cSchema);
+            }
+        }
         // ------------------------------------------------------------
         // Sink to DataBase
         // ------------------------------------------------------------
@@ -215,6 +315,17 @@ namespace Polars.NET.Core.Arrow
             byte* errorMsg // 接收错误信息的 buffer (1KB)
         );
 
+        // 对应 Rust pl_lazy_sink_callback 的三个回调: (user_data, ..., error) -> i32 (0 = Ok)
+        // schema / array 结构体归 Rust，只在回调期间有效，C# 不要释放
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        public delegate int SinkSchemaCallback(IntPtr userData, CArrowSchema* schema, byte* errorMsg);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        public delegate int SinkBatchCallback(IntPtr userData, CArrowArray* array, byte* errorMsg);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        public delegate int SinkFinishCallback(IntPtr userData, ulong rowCount, byte* errorMsg);
+
         // [新增] 对应 Rust: fn(*mut c_void)
         [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
         public delegate void CleanupCallback(void* userData);
@@ -25,7 +25,8 @@ public enum PlDataType : int
     Struct = 19,
     List = 20,
     Categorical=21,
-    Decimal=22
+    Decimal=22,
+    Array=23
 }
 
 public enum PlJoinType
@@ -38,6 +39,62 @@ public enum PlJoinType
     Anti = 5
 }
 
+// Join 键唯一性检查 (对应 pl_lazy_join_with_args 的 validation_code)
+public enum PlJoinValidation
+{
+    ManyToMany = 0,
+    OneToOne = 1,
+    OneToMany = 2,
+    ManyToOne = 3
+}
+
+// Join 键列是否合并 (对应 pl_lazy_join_with_args 的 coalesce_code)
+public enum PlJoinCoalesce
+{
+    JoinSpecific = 0,
+    CoalesceColumns = 1,
+    KeepColumns = 2
+}
+
+// 去重时保留哪一行 (对应 pl_lazy_unique 的 keep_code)
+public enum PlUniqueKeep
+{
+    First = 0,
+    Last = 1,
+    Any = 2,
+    None = 3
+}
+
+// 执行引擎 (对应 pl_lazy_collect_engine 的 engine_code)
+public enum PlEngine
+{
+    Auto = 0,
+    InMemory = 1,
+    Streaming = 2,
+    Gpu = 3
+}
+
+// 批量 rolling 聚合 (对应 pl_lazy_with_rolling_aggregates 的 ops_codes)
+public enum PlRollingOp
+{
+    Mean = 0,
+    Sum = 1,
+    Min = 2,
+    Max = 3,
+    Std = 4,
+    Var = 5,
+    Median = 6
+}
+
+// list 集合运算 (对应 pl_expr_list_set_operation 的 op)
+public enum PlListSetOp
+{
+    Union = 0,
+    Intersection = 1,
+    Difference = 2,
+    SymmetricDifference = 3
+}
+
 // 对应 Pivot 的聚合方式
 public enum PlPivotAgg
 {
@@ -65,6 +122,60 @@ public enum PlTimeUnit
     Month =7,
     Year =8
 }
+// CSV 编码: LossyUtf8 把非法字节替换为 U+FFFD
+public enum PlCsvEncoding : int
+{
+    Utf8 = 0,
+    LossyUtf8 = 1
+}
+// Parquet 读取的并行策略
+public enum PlParallelStrategy : int
+{
+    Auto = 0,
+    None = 1,
+    Columns = 2,
+    RowGroups = 3,
+    Prefiltered = 4
+}
+// Parquet 压缩算法
+public enum PlParquetCompression : int
+{
+    Uncompressed = 0,
+    Snappy = 1,
+    Gzip = 2,
+    Brotli = 3,
+    Zstd = 4,
+    Lz4Raw = 5
+}
+// Parquet 列统计信息: Default = min/max/null_count, Full 额外写 distinct_count
+public enum PlParquetStatistics : int
+{
+    Default = 0,
+    Full = 1,
+    None = 2
+}
+// IPC 压缩算法
+public enum PlIpcCompression : int
+{
+    None = 0,
+    LZ4 = 1,
+    Zstd = 2
+}
+// Avro 压缩算法
+public enum PlAvroCompression : int
+{
+    None = 0,
+    Deflate = 1,
+    Snappy = 2
+}
+// CSV 写出时的引号策略
+public enum PlQuoteStyle : int
+{
+    Necessary = 0,
+    Always = 1,
+    NonNumeric = 2,
+    Never = 3
+}
 /// <summary>
 /// Concat Type Enum
 /// </summary>
@@ -72,7 +183,9 @@ public enum PlConcatType
 {
     Vertical = 0,
     Horizontal = 1,
-    Diagonal = 2
+    Diagonal = 2,
+    // Diagonal + 自动提升为公共超类型 (仅 Lazy)
+    DiagonalRelaxed = 3
 }
 
 public enum PlLabel
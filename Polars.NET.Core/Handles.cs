@@ -33,6 +33,9 @@ public class ExprHandle : PolarsHandle
 // 3. DataFrame Handle
 public class DataFrameHandle : PolarsHandle
 {
+    public DataFrameHandle() : base() { }
+    // 接管 Rust 通过输出数组 / 回调交出来的裸指针
+    internal DataFrameHandle(IntPtr ptr) : base() { SetHandle(ptr); }
     protected override bool ReleaseHandle()
     {
         NativeBindings.pl_dataframe_free(handle);
@@ -50,6 +53,36 @@ public class LazyFrameHandle : PolarsHandle
     }
 }
 
+// 共享 LazyFrame Handle (Rust 端 Arc 引用计数，每个句柄单独释放)
+public class SharedLazyFrameHandle : PolarsHandle
+{
+    protected override bool ReleaseHandle()
+    {
+        NativeBindings.pl_lazy_share_free(handle);
+        return true;
+    }
+}
+
+// 异步查询句柄：释放只影响轮询，后台查询照常执行完并触发回调
+public class QueryHandle : PolarsHandle
+{
+    protected override bool ReleaseHandle()
+    {
+        NativeBindings.pl_query_free(handle);
+        return true;
+    }
+}
+
+// 分批拉取句柄：提前释放时后台查询会自行中止
+public class BatchIterHandle : PolarsHandle
+{
+    protected override bool ReleaseHandle()
+    {
+        NativeBindings.pl_batch_iter_free(handle);
+        return true;
+    }
+}
+
 // 5. Selector Handle
 public class SelectorHandle : PolarsHandle
 {
@@ -0,0 +1,85 @@
+using System.Runtime.InteropServices;
+
+namespace Polars.NET.Core;
+
+// 与 Rust 端 #[repr(C)] 参数结构体一一对应，字段顺序不能改
+// 字符串字段为 UTF-8 指针，由 Wrapper 在调用期间分配和释放
+
+// 对应 io.rs: CsvReadArgs
+[StructLayout(LayoutKind.Sequential)]
+public struct PlCsvReadArgs
+{
+    public bool HasHeader;
+    public byte Separator;
+    public byte QuoteChar;          // 0 = 不处理引号
+    public byte EolChar;
+    public UIntPtr SkipRows;
+    public long NRows;              // < 0 = 读取全部
+    public long InferSchemaLength;  // < 0 = 扫描全部行推断类型
+    public IntPtr CommentPrefix;    // NULL = 无注释行
+    public IntPtr NullValues;       // const char**
+    public UIntPtr NullValuesLen;
+    public PlCsvEncoding Encoding;
+    public bool TryParseDates;
+    public bool IgnoreErrors;
+    public bool TruncateRaggedLines;
+    public bool LowMemory;
+    public bool DecimalComma;
+    public bool MissingIsNull;
+}
+
+// 对应 io.rs: CsvWriteArgs
+[StructLayout(LayoutKind.Sequential)]
+public struct PlCsvWriteArgs
+{
+    public byte Separator;
+    public byte QuoteChar;
+    public PlQuoteStyle QuoteStyle;
+    public IntPtr LineTerminator;   // NULL = "\n"
+    public IntPtr DateFormat;       // NULL = 默认格式
+    public IntPtr TimeFormat;
+    public IntPtr DatetimeFormat;
+    public int FloatPrecision;      // < 0 = 不固定小数位
+    public IntPtr NullValue;        // NULL = 空字符串
+    public bool IncludeHeader;
+    public bool IncludeBom;
+}
+
+// 对应 io.rs: ParquetReadArgs
+[StructLayout(LayoutKind.Sequential)]
+public struct PlParquetReadArgs
+{
+    public IntPtr Columns;          // const char**, NULL = 全部列
+    public UIntPtr ColumnsLen;
+    public long NRows;              // < 0 = 读取全部
+    public IntPtr RowIndexName;     // NULL = 不加行号列
+    public uint RowIndexOffset;
+    public PlParallelStrategy Parallel;
+    public bool UseStatistics;      // 仅 scan
+    public bool LowMemory;
+    public bool Rechunk;
+    public bool Cache;              // 仅 scan
+    public bool Glob;               // 仅 scan
+}
+
+// 对应 io.rs: ParquetWriteArgs，未设置的数值传 -1
+[StructLayout(LayoutKind.Sequential)]
+public struct PlParquetWriteArgs
+{
+    public PlParquetCompression Compression;
+    public int CompressionLevel;
+    public PlParquetStatistics Statistics;
+    public long RowGroupSize;
+    public long DataPageSize;
+}
+
+// 对应 io.rs: NdJsonReadArgs
+[StructLayout(LayoutKind.Sequential)]
+public struct PlNdjsonReadArgs
+{
+    public long InferSchemaLength;  // <= 0 = 扫描全部行推断 schema
+    public long BatchSize;          // <= 0 = 默认
+    public long NRows;              // < 0 = 读全部行
+    public bool IgnoreErrors;
+    public bool LowMemory;
+}
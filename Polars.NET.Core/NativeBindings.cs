@@ -22,6 +22,25 @@ public unsafe delegate int UdfCallback(
     byte* msgBuf
 );
 
+// LazyFrame.map_batches 回调: 读入整个 DataFrame (StructArray + Schema)，把结果流导出到 outStream，
+// 返回 0=Ok / 1=Error (失败时向 errorMsg 写入错误信息)。三个结构体都归 Rust，只在回调期间有效
+[UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+public unsafe delegate int TransformCallback(
+    CArrowArray* array,
+    CArrowSchema* schema,
+    Arrow.CArrowArrayStream* outStream,
+    byte* errorMsg
+);
+
+// name.map 回调: 读入原列名，向 outBuf 写入 \0 结尾的新列名 (失败时写错误信息)，返回 0=Ok / 1=Error
+[UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+public unsafe delegate int NameMapCallback(byte* name, byte* outBuf, UIntPtr bufLen);
+
+// 异步 collect 完成回调 (在 Rust 后台线程上触发)
+// 成功: df 非空，所有权交给 C#；失败: df 为空，error 只在回调期间有效
+[UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+public delegate void QueryCompleteCallback(IntPtr userData, IntPtr df, IntPtr error);
+
 
 unsafe internal partial class NativeBindings
 {
@@ -29,6 +48,9 @@ unsafe internal partial class NativeBindings
     
     [LibraryImport(LibName)] public static partial void pl_expr_free(IntPtr ptr);
     [LibraryImport(LibName)] public static partial void pl_lazy_frame_free(IntPtr ptr);
+    [LibraryImport(LibName)] public static partial void pl_lazy_share_free(IntPtr ptr);
+    [LibraryImport(LibName)] public static partial void pl_query_free(IntPtr ptr);
+    [LibraryImport(LibName)] public static partial void pl_batch_iter_free(IntPtr ptr);
     [LibraryImport(LibName)] public static partial void pl_selector_free(IntPtr ptr);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial DataFrameHandle pl_read_csv(
@@ -39,10 +61,16 @@ unsafe internal partial class NativeBindings
         UIntPtr skipRows,
         [MarshalAs(UnmanagedType.I1)] bool tryParseDates // [新增]
     );
+    // schema 为无效句柄时自动推断；args 只在调用期间借用
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_csv_with_options(string path, SchemaHandle schema, in PlCsvReadArgs args);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_csv_with_options(string path, SchemaHandle schema, in PlCsvReadArgs args);
     [LibraryImport(LibName)]
     public static partial void pl_dataframe_free(IntPtr ptr);
     // String Free
     [LibraryImport(LibName)] public static partial void pl_free_string(IntPtr ptr);
+    [LibraryImport(LibName)] public static partial void pl_free_string_array(IntPtr ptr, UIntPtr len);
     [LibraryImport(LibName)] public static partial void pl_to_arrow(DataFrameHandle handle, CArrowArray* arr, CArrowSchema* schema);
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_dataframe_from_arrow_record_batch(
@@ -54,6 +82,12 @@ unsafe internal partial class NativeBindings
         IntPtr[] columns, 
         UIntPtr len
     );
+    // 按 Schema 创建 0 行 DataFrame，schema 只借用
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_empty_from_schema(SchemaHandle schema);
+    // 按 Schema 生成随机测试数据，相同 seed 结果相同；schema 只借用
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_generate(SchemaHandle schema, UIntPtr nRows, ulong seed, double nullRatio);
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_dataframe_new_from_stream(
         Arrow.CArrowArrayStream* stream
@@ -67,6 +101,14 @@ unsafe internal partial class NativeBindings
         void* userData
     );
 
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_frame_scan_stream_pushdown(
+        CArrowSchema* schema,
+        delegate* unmanaged[Cdecl]<void*, byte**, UIntPtr, long, Arrow.CArrowArrayStream*> callback,
+        delegate* unmanaged[Cdecl]<void*, void> destroyCallback,
+        void* userData
+    );
+
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial ExprHandle pl_expr_col(string name);
     [LibraryImport(LibName)] 
@@ -113,6 +155,16 @@ unsafe internal partial class NativeBindings
     );
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_dataframe_clone(DataFrameHandle df);
+    // 进程级注册表: put 借用 df，get 返回新句柄
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_registry_put(string name, DataFrameHandle df);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_registry_get(string name);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_registry_remove(string name, [MarshalAs(UnmanagedType.U1)] out bool removed);
+    // 进程级清理：清空注册表并等待线程池空闲，返回清理时仍存活的注册表条目数
+    [LibraryImport(LibName)]
+    public static partial UIntPtr pl_shutdown();
     [LibraryImport(LibName)]
     public static partial LazyFrameHandle pl_dataframe_lazy(DataFrameHandle df);
 
@@ -126,6 +178,10 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_filter(DataFrameHandle df, ExprHandle expr);
 
+    // mask 为借用，长度必须与 df 行数一致，Null 视为 false
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_filter_mask(DataFrameHandle df, SeriesHandle mask);
+
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_with_columns(DataFrameHandle df, IntPtr[] exprs, UIntPtr len);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
@@ -201,6 +257,19 @@ unsafe internal partial class NativeBindings
 
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_lit_datetime(long micros);
+    // Typed Literals (时间单位: 0=ns, 1=us, 2=ms)
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_lit_datetime(long value, int unitCode, string? timeZone);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_date(int days);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_time(long nanos);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_duration(long value, int unitCode);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_decimal(in Int128 value, UIntPtr scale);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_binary(byte[] ptr, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_null_typed(DataTypeHandle dtype);
+    // 把 Series 嵌进表达式 (只借用 series)
+    [LibraryImport(LibName)] public static partial ExprHandle pl_lit_series(SeriesHandle series);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_is_in(ExprHandle expr, ExprHandle other, [MarshalAs(UnmanagedType.U1)] bool nullsEqual);
 
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_alias(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string name);
@@ -214,6 +283,16 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_day(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_ordinal_day(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_weekday(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_week(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_quarter(ExprHandle expr);
+    // Duration -> 总量 (Int64，向零截断)
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_days(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_hours(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_minutes(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_seconds(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_milliseconds(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_microseconds(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_total_nanoseconds(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_hour(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_minute(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_second(ExprHandle expr);
@@ -222,19 +301,26 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_nanosecond(ExprHandle expr);
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_dt_to_string(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string format);
+    // format 为 null 或 "iso" 时输出 ISO 8601，"iso:strict" 用 'T' 分隔日期和时间
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_dt_strftime(ExprHandle expr, string? format);
     
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_date(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_time(ExprHandle expr);
 
-    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
-    public static partial ExprHandle pl_expr_dt_truncate(ExprHandle e, string every);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_dt_truncate(ExprHandle e, ExprHandle every);
 
-    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
-    public static partial ExprHandle pl_expr_dt_round(ExprHandle e, string every);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_dt_round(ExprHandle e, ExprHandle every);
 
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_dt_offset_by(ExprHandle e, ExprHandle by);
 
+    // 构建表达式时即校验 Duration 语法 ("1mo" / "3d12h" / "-2w")
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_dt_offset_by_str(ExprHandle e, string offset);
+
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_dt_timestamp(ExprHandle e, int unitCode);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
@@ -246,8 +332,37 @@ unsafe internal partial class NativeBindings
         string? ambiguous, 
         string? nonExistent
     );
+    // 返回 Duration(ms)：标准偏移 / 夏令时额外偏移
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_base_utc_offset(ExprHandle e);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_dt_dst_offset(ExprHandle e);
+    // Temporal Ranges: interval 为 Duration 字符串，closed 为 null 时等价于 "both"
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_date_range(ExprHandle start, ExprHandle end, string interval, string? closed);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_date_ranges(ExprHandle start, ExprHandle end, string interval, string? closed);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_time_range(ExprHandle start, ExprHandle end, string interval, string? closed);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_time_ranges(ExprHandle start, ExprHandle end, string interval, string? closed);
+    // unitCode: 0=ns, 1=us, 2=ms, 其他 = 由输入推断；timeZone 为 null 时由输入推断
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_datetime_range(ExprHandle start, ExprHandle end, string interval, string? closed, int unitCode, string? timeZone);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_datetime_ranges(ExprHandle start, ExprHandle end, string interval, string? closed, int unitCode, string? timeZone);
+
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_clone(ExprHandle expr);
+    // Expr Meta (只借用 expr)
+    [LibraryImport(LibName)] public static partial IntPtr pl_expr_meta_output_name(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial IntPtr pl_expr_meta_root_names(ExprHandle expr, out UIntPtr outLen);
+    [LibraryImport(LibName)] public static partial IntPtr pl_expr_meta_tree_format(ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool asDot);
+    [LibraryImport(LibName)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_expr_meta_eq(ExprHandle a, ExprHandle b);
+    // Expr 序列化 (format: 0 = 二进制, 1 = JSON)；返回的字节用 pl_free_bytes 释放
+    [LibraryImport(LibName)] public static partial IntPtr pl_expr_serialize(ExprHandle expr, int format, out UIntPtr outLen);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_deserialize(byte[] bytes, UIntPtr len, int format);
+    [LibraryImport(LibName)] public static partial void pl_free_bytes(IntPtr ptr, UIntPtr len);
 
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_map(
@@ -257,9 +372,42 @@ unsafe internal partial class NativeBindings
         CleanupCallback cleanup,
         IntPtr userData          
     );
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_map_batches(
+        ExprHandle expr,
+        UdfCallback callback,
+        DataTypeHandle outputType,
+        [MarshalAs(UnmanagedType.U1)] bool isElementwise,
+        CleanupCallback cleanup,
+        IntPtr userData
+    );
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_map_elements(
+        ExprHandle expr,
+        UdfCallback callback,
+        CleanupCallback cleanup,
+        IntPtr userData,
+        DataTypeHandle outputType
+    );
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_map_batches_transform(
+        LazyFrameHandle lf,
+        TransformCallback callback,
+        CleanupCallback cleanup,
+        IntPtr userData,
+        SchemaHandle outputSchema,
+        [MarshalAs(UnmanagedType.U1)] bool validateOutput
+    );
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_cast(ExprHandle expr, DataTypeHandle dtype, [MarshalAs(UnmanagedType.U1)] bool strict);
     [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_cast_opts(
+        ExprHandle expr,
+        DataTypeHandle dtype,
+        [MarshalAs(UnmanagedType.U1)] bool strict,
+        [MarshalAs(UnmanagedType.U1)] bool wrapNumerical
+    );
+    [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_groupby_agg(
         DataFrameHandle df, 
         IntPtr[] byExprs, UIntPtr byLen,
@@ -279,6 +427,17 @@ unsafe internal partial class NativeBindings
         IntPtr[] keys, UIntPtr keysLen,
         IntPtr[] aggs, UIntPtr aggsLen
     );
+    // 以每行 index 值为窗口终点聚合；offset 为 null 时使用 -period
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_rolling(
+        LazyFrameHandle lf,
+        string indexCol,
+        string period,
+        string? offset,
+        int closedWindow,
+        IntPtr[] keys, UIntPtr keysLen,
+        IntPtr[] aggs, UIntPtr aggsLen
+    );
     // Join 签名
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_join(
@@ -312,12 +471,50 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] 
     public static partial void pl_write_parquet(DataFrameHandle df, [MarshalAs(UnmanagedType.LPUTF8Str)] string path);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_parquet_with_options(DataFrameHandle df, string path, in PlParquetWriteArgs args);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_lazy_sink_parquet_with_options(LazyFrameHandle lf, string path, in PlParquetWriteArgs args);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial void pl_dataframe_write_ipc(DataFrameHandle df, string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_dataframe_write_ipc_with_compression(DataFrameHandle df, string path, PlIpcCompression compression);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_lazy_sink_ipc_with_compression(LazyFrameHandle lf, string path, PlIpcCompression compression);
+    // Avro
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_avro(DataFrameHandle df, string path, PlAvroCompression compression);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_avro(string path);
+    // Arrow IPC Stream 格式 (无 footer，可边写边读)
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_ipc_stream(DataFrameHandle df, string path, PlIpcCompression compression);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_ipc_stream(string path);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial void pl_dataframe_write_json(DataFrameHandle df, string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_dataframe_write_ndjson(DataFrameHandle df, string path);
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_read_parquet([MarshalAs(UnmanagedType.LPUTF8Str)] string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_parquet_with_options(string path, in PlParquetReadArgs args);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_parquet_with_options(string path, in PlParquetReadArgs args);
+    // Parquet 描述 / key_value_metadata (返回 JSON 字符串，需要 pl_free_string)
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_parquet_with_descriptions(
+        DataFrameHandle df,
+        string path,
+        string? tableDesc,        // null = 不写表描述
+        IntPtr[] colNames,
+        IntPtr[] colDescs,
+        UIntPtr len
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial IntPtr pl_read_parquet_key_value_metadata(string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial IntPtr pl_read_parquet_descriptions(string path);
     [LibraryImport(LibName)]
     [UnmanagedCallConv(CallConvs = new[] { typeof(System.Runtime.CompilerServices.CallConvCdecl) })]
     public static partial void pl_dataframe_export_batches(
@@ -331,10 +528,23 @@ unsafe internal partial class NativeBindings
     // Read JSON
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial DataFrameHandle pl_read_json(string path);
+    // 从内存缓冲区读取，数组只在调用期间被固定，返回的 DataFrame 不引用它
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_read_csv_bytes(byte[] data, UIntPtr len, SchemaHandle schema, in PlCsvReadArgs args);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_read_parquet_bytes(byte[] data, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_read_ipc_bytes(byte[] data, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_read_json_bytes(byte[] data, UIntPtr len);
 
     // Scan NDJSON
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial LazyFrameHandle pl_scan_ndjson(string path);
+    // schema: 完整 schema，跳过推断；schemaOverwrite: 只覆盖部分列类型。均可传无效 Handle
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_ndjson_with_options(
+        string path, SchemaHandle schema, SchemaHandle schemaOverwrite, in PlNdjsonReadArgs args);
     // Lazy
     // [IO: CSV Scan (Lazy)]
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
@@ -352,17 +562,39 @@ unsafe internal partial class NativeBindings
     // IPC
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial DataFrameHandle pl_read_ipc(string path);
+    // memoryMap: 仅对未压缩文件生效，压缩文件自动退回普通读取
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_ipc_with_args(string path, [MarshalAs(UnmanagedType.U1)] bool memoryMap);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial LazyFrameHandle pl_scan_ipc(string path);
+    // 多文件扫描：includeFilePaths 为 null 时不加来源文件列
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_parquet_multi(
+        IntPtr[] paths, UIntPtr len,
+        [MarshalAs(UnmanagedType.U1)] bool allowMissingColumns,
+        string? includeFilePaths);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_ipc_multi(IntPtr[] paths, UIntPtr len, string? includeFilePaths);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_csv_multi(
+        IntPtr[] paths, UIntPtr len, SchemaHandle schema, in PlCsvReadArgs args, string? includeFilePaths);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_ndjson_multi(IntPtr[] paths, UIntPtr len, string? includeFilePaths);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial void pl_lazy_sink_ipc(LazyFrameHandle lf, string path);
     
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial void pl_lazy_sink_csv(LazyFrameHandle lf, string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_csv_with_options(DataFrameHandle df, string path, in PlCsvWriteArgs args);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_lazy_sink_csv_with_options(LazyFrameHandle lf, string path, in PlCsvWriteArgs args);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial void pl_lazy_sink_json(LazyFrameHandle lf, string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_lazy_sink_ndjson(LazyFrameHandle lf, string path);
     // Schema
     [LibraryImport(LibName)]
     public static partial void pl_schema_free(IntPtr ptr);
@@ -389,8 +621,17 @@ unsafe internal partial class NativeBindings
     );
     [LibraryImport(LibName)] public static partial IntPtr pl_lazy_schema(LazyFrameHandle lf);
     [LibraryImport(LibName)] public static partial IntPtr pl_lazy_explain(LazyFrameHandle lf,[MarshalAs(UnmanagedType.U1)] bool optimized);
+    [LibraryImport(LibName)] public static partial IntPtr pl_lazy_explain_tree(LazyFrameHandle lf,[MarshalAs(UnmanagedType.U1)] bool optimized);
+    [LibraryImport(LibName)] public static partial IntPtr pl_lazy_to_dot(LazyFrameHandle lf,[MarshalAs(UnmanagedType.U1)] bool optimized);
+    // 重复 collect 计时，返回 JSON；lf 只借用
+    [LibraryImport(LibName)] public static partial IntPtr pl_bench_collect(LazyFrameHandle lf, UIntPtr iterations);
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_filter(LazyFrameHandle lf, ExprHandle expr);
+    // 作用于所有列；fill_nan 只影响浮点列
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_fill_null(LazyFrameHandle lf, ExprHandle value);
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_fill_nan(LazyFrameHandle lf, ExprHandle value);
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_select(LazyFrameHandle lf, IntPtr[] exprs, UIntPtr len);
     [LibraryImport(LibName)] 
@@ -403,12 +644,30 @@ unsafe internal partial class NativeBindings
         bool* descending, // bool 数组指针 (Rust 端是 *const bool)
         UIntPtr descending_len   // bool 数量
     );
+    // 多键排序：descending / nullsLast 长度均为 len，传 null 表示全部为 false
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_sort_exprs(
+        LazyFrameHandle lf,
+        IntPtr[] exprs, UIntPtr len,
+        bool* descending,
+        bool* nullsLast,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder,
+        [MarshalAs(UnmanagedType.U1)] bool multithreaded
+    );
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_groupby_agg(
         LazyFrameHandle lf, 
         IntPtr[] keys, UIntPtr keysLen, 
         IntPtr[] aggs, UIntPtr aggsLen
     );
+    // maintainOrder = false 时组的输出顺序不定，但更快
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_groupby_agg_with_args(
+        LazyFrameHandle lf,
+        IntPtr[] keys, UIntPtr keysLen,
+        IntPtr[] aggs, UIntPtr aggsLen,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
     [LibraryImport(LibName)]
     public static partial LazyFrameHandle pl_lazy_join(
         LazyFrameHandle left, 
@@ -417,6 +676,53 @@ unsafe internal partial class NativeBindings
         IntPtr[] rightOn, UIntPtr rightLen,
         PlJoinType how
     );
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_join_allow_parallel(
+        LazyFrameHandle left, 
+        LazyFrameHandle right,
+        IntPtr[] leftOn, UIntPtr leftLen,
+        IntPtr[] rightOn, UIntPtr rightLen,
+        PlJoinType how,
+        [MarshalAs(UnmanagedType.U1)] bool allowParallel
+    );
+    // suffix 为 null 时使用默认的 "_right"
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_join_with_args(
+        LazyFrameHandle left, 
+        LazyFrameHandle right,
+        IntPtr[] leftOn, UIntPtr leftLen,
+        IntPtr[] rightOn, UIntPtr rightLen,
+        PlJoinType how,
+        string? suffix,
+        PlJoinValidation validation,
+        [MarshalAs(UnmanagedType.U1)] bool joinNulls,
+        PlJoinCoalesce coalesce
+    );
+    // 同一组键同时用于左右两侧，其余参数同 pl_lazy_join_with_args
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_join_on(
+        LazyFrameHandle left,
+        LazyFrameHandle right,
+        IntPtr[] on, UIntPtr onLen,
+        PlJoinType how,
+        string? suffix,
+        PlJoinValidation validation,
+        [MarshalAs(UnmanagedType.U1)] bool joinNulls,
+        PlJoinCoalesce coalesce
+    );
+    // 非等值 Join: predicates 之间是 AND 关系，右表重名列加 "_right" 后缀
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_join_where(
+        LazyFrameHandle left,
+        LazyFrameHandle right,
+        IntPtr[] predicates, UIntPtr len
+    );
+    // 笛卡尔积；suffix 为 null 时使用默认的 "_right"
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_cross_join(LazyFrameHandle left, LazyFrameHandle right, string? suffix);
+    // 两边必须已按 key 升序排好，Rust 端不做检查
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_merge_sorted(LazyFrameHandle left, LazyFrameHandle right, string key);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial LazyFrameHandle pl_lazy_join_asof(
         LazyFrameHandle left, LazyFrameHandle right,
@@ -427,13 +733,105 @@ unsafe internal partial class NativeBindings
     );
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_lazy_collect(LazyFrameHandle lf);
+    // 后台线程执行，完成后触发回调；lf 被消费
+    [LibraryImport(LibName)]
+    public static partial QueryHandle pl_lazy_collect_async(LazyFrameHandle lf, QueryCompleteCallback onComplete, IntPtr userData);
+    // 0 = 运行中, 1 = 成功, 2 = 失败
+    [LibraryImport(LibName)]
+    public static partial int pl_query_poll(QueryHandle query);
+    // 流式分批拉取结果；batchSize 为 0 时按 morsel 原样返回
+    [LibraryImport(LibName)]
+    public static partial BatchIterHandle pl_lazy_collect_batches(LazyFrameHandle lf, UIntPtr batchSize);
+    // true = 拿到一批; false = 已结束或出错 (出错时可取 last error)
+    [LibraryImport(LibName)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_batch_iter_next(BatchIterHandle iter, CArrowArray* outChunk, CArrowSchema* outSchema);
+    // 多个计划合并优化，公共子计划只执行一次；结果按输入顺序写入 outDfs (长度为 len)
+    [LibraryImport(LibName)]
+    public static partial void pl_lazy_collect_all(IntPtr[] lfs, UIntPtr len, [Out] IntPtr[] outDfs);
     [LibraryImport(LibName)]
     public static partial LazyFrameHandle pl_lazy_clone(LazyFrameHandle lf);
+    // 执行并返回各节点耗时 (列 node / start / end，单位微秒)；出错时两个输出都不写
+    [LibraryImport(LibName)]
+    public static partial void pl_lazy_profile(LazyFrameHandle lf, out DataFrameHandle result, out DataFrameHandle timings);
+    // 只统计行数 (select(len()))，扫描文件时可走元数据
+    [LibraryImport(LibName)]
+    public static partial void pl_lazy_count(LazyFrameHandle lf, out ulong count);
+    // 调试用：每个数据源只读前 nRows 行再执行
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_lazy_fetch(LazyFrameHandle lf, ulong nRows);
+    // Shared LazyFrame: 引用计数句柄，只读，可跨线程
+    [LibraryImport(LibName)]
+    public static partial SharedLazyFrameHandle pl_lazy_share(LazyFrameHandle lf);
+    [LibraryImport(LibName)]
+    public static partial SharedLazyFrameHandle pl_lazy_share_retain(SharedLazyFrameHandle shared);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_share_clone_plan(SharedLazyFrameHandle shared);
+    [LibraryImport(LibName)]
+    public static partial UIntPtr pl_lazy_share_ref_count(SharedLazyFrameHandle shared);
 
     [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_limit(LazyFrameHandle lf, uint n);
+    // 作用于所有列；fill 为无效句柄时空出的位置填 Null
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_shift(LazyFrameHandle lf, long n, ExprHandle fill);
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_reverse(LazyFrameHandle lf);
+    // offset 为负数时从末尾倒数
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_slice(LazyFrameHandle lf, long offset, uint len);
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_gather_every(LazyFrameHandle lf, UIntPtr n, UIntPtr offset);
     [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_with_columns(LazyFrameHandle lf, IntPtr[] exprs, UIntPtr len);
+    // 输出列名: {列名}{suffix}_{op}，suffix 为 null 时使用 "_rolling"
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_with_rolling_aggregates(
+        LazyFrameHandle lf,
+        SelectorHandle selector,
+        int[] opsCodes,
+        UIntPtr opsLen,
+        string window,
+        string? suffix
+    );
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_explode(LazyFrameHandle lf, IntPtr[] exprs, UIntPtr len);
+    // 批量 Cast: dtypes 只借用
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_cast_columns(
+        LazyFrameHandle lf,
+        IntPtr[] names,
+        IntPtr[] dtypes,
+        UIntPtr len,
+        [MarshalAs(UnmanagedType.U1)] bool strict
+    );
+    // Rename / Drop: 只改 schema，不阻断投影下推；strict 为 false 时忽略不存在的列
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_rename(
+        LazyFrameHandle lf,
+        IntPtr[] oldNames,
+        IntPtr[] newNames,
+        UIntPtr len,
+        [MarshalAs(UnmanagedType.U1)] bool strict
+    );
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_drop(
+        LazyFrameHandle lf,
+        IntPtr[] names,
+        UIntPtr len,
+        [MarshalAs(UnmanagedType.U1)] bool strict
+    );
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_drop_selector(LazyFrameHandle lf, SelectorHandle selector);
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_unnest(LazyFrameHandle lf, IntPtr[] names, UIntPtr len);
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_unnest_selector(LazyFrameHandle lf, SelectorHandle selector);
+    // 去重：subset 为无效句柄时比较全部列，否则 selector 被消费
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_unique(
+        LazyFrameHandle lf,
+        SelectorHandle subset,
+        PlUniqueKeep keep,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+    // 让表达式可以引用辅助 LazyFrame 中的列；lf 与 contexts 全部被消费
+    [LibraryImport(LibName)] 
+    public static partial LazyFrameHandle pl_lazy_with_context(LazyFrameHandle lf, IntPtr[] contexts, UIntPtr len);
     // --- Reshaping (Lazy) ---
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_unpivot(
@@ -451,9 +849,24 @@ unsafe internal partial class NativeBindings
         [MarshalAs(UnmanagedType.U1)] bool rechunk,
         [MarshalAs(UnmanagedType.U1)] bool parallel
     );
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_concat_with_args(
+        IntPtr[] lfs,
+        UIntPtr len,
+        int how,
+        [MarshalAs(UnmanagedType.U1)] bool rechunk,
+        [MarshalAs(UnmanagedType.U1)] bool parallel,
+        [MarshalAs(UnmanagedType.U1)] bool toSupertypes,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
     // --- Streaming & Sink ---
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_lazy_collect_streaming(LazyFrameHandle lf);
+    [LibraryImport(LibName)] 
+    public static partial DataFrameHandle pl_lazy_collect_engine(LazyFrameHandle lf, PlEngine engine);
+    // 结果写入 spoolDir 下的临时 IPC 文件，返回对该文件的 scan (文件由调用方清理)
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_collect_spooled(LazyFrameHandle lf, string spoolDir);
 
     [LibraryImport(LibName)] 
     public static partial void pl_lazy_sink_parquet(
@@ -469,12 +882,31 @@ unsafe internal partial class NativeBindings
         ArrowStreamInterop.CleanupCallback cleanup,
         IntPtr userData // 这里用 IntPtr 接应
     );
+    // 回调全部在调用线程上串行触发: schema -> batches (按行序) -> finish
+    [LibraryImport(LibName)]
+    public static partial void pl_lazy_sink_callback(
+        LazyFrameHandle lf,
+        ArrowStreamInterop.SinkSchemaCallback schemaCallback,
+        ArrowStreamInterop.SinkBatchCallback batchCallback,
+        ArrowStreamInterop.SinkFinishCallback finishCallback,
+        IntPtr userData
+    );
     // String Ops
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_contains(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string pat);
 
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_to_uppercase(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_to_lowercase(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_len_bytes(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_to_titlecase(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_len_chars(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_reverse(ExprHandle expr);
+    // head / tail 按字符截取，n 为负数时去掉末尾/开头的 |n| 个字符
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_head(ExprHandle expr, long n);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_tail(ExprHandle expr, long n);
+    // Padding: fillChar 为 Unicode code point
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_pad_start(ExprHandle expr, UIntPtr length, uint fillChar);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_pad_end(ExprHandle expr, UIntPtr length, uint fillChar);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_zfill(ExprHandle expr, UIntPtr length);
     // String Cleaning
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial ExprHandle pl_expr_str_strip_chars(ExprHandle e, string? matches);
@@ -502,12 +934,30 @@ unsafe internal partial class NativeBindings
     public static partial ExprHandle pl_expr_str_to_date(ExprHandle e, string format);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
-    public static partial ExprHandle pl_expr_str_to_datetime(ExprHandle e, string format);
+    public static partial ExprHandle pl_expr_str_to_datetime(ExprHandle e, string? format, int timeUnit, string? timeZone);
+
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_str_to_time(ExprHandle e, string? format);
+
+    // dtype 只借用，不消耗
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_str_strptime(
+        ExprHandle e,
+        DataTypeHandle dtype,
+        string? format,
+        [MarshalAs(UnmanagedType.U1)] bool strict,
+        [MarshalAs(UnmanagedType.U1)] bool exact,
+        [MarshalAs(UnmanagedType.U1)] bool cache
+    );
     
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_str_slice(ExprHandle expr, long offset, ulong length);
     [LibraryImport(LibName)] 
-    public static partial ExprHandle pl_expr_str_split(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string pat);
+    public static partial ExprHandle pl_expr_str_split(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string pat, [MarshalAs(UnmanagedType.U1)] bool inclusive);
+    [LibraryImport(LibName)] 
+    public static partial ExprHandle pl_expr_str_split_exact(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string pat, UIntPtr n);
+    [LibraryImport(LibName)] 
+    public static partial ExprHandle pl_expr_str_splitn(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string pat, UIntPtr n);
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_str_replace_all(
         ExprHandle expr, 
@@ -521,12 +971,58 @@ unsafe internal partial class NativeBindings
         string pat, 
         UIntPtr groupIndex
     );
+    // JSON: dtype 为无效 Handle 时自动推断；inferSchemaLen = 0 表示扫描全部行
+    [LibraryImport(LibName)] 
+    public static partial ExprHandle pl_expr_str_json_decode(ExprHandle expr, DataTypeHandle dtype, UIntPtr inferSchemaLen);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
+    public static partial ExprHandle pl_expr_str_json_path_match(ExprHandle expr, string path);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
+    public static partial ExprHandle pl_expr_str_extract_all(ExprHandle expr, string pat);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
+    public static partial ExprHandle pl_expr_str_extract_groups(ExprHandle expr, string pat);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
+    public static partial ExprHandle pl_expr_str_count_matches(ExprHandle expr, string pat, [MarshalAs(UnmanagedType.U1)] bool literal);
+    // separator 为 null 时等价于空字符串
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
+    public static partial ExprHandle pl_concat_str(IntPtr[] exprs, UIntPtr len, string? separator, [MarshalAs(UnmanagedType.U1)] bool ignoreNulls);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
+    public static partial ExprHandle pl_expr_str_join(ExprHandle expr, string? separator, [MarshalAs(UnmanagedType.U1)] bool ignoreNulls);
 
     // List Ops
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_first(ExprHandle expr);
-    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_get(ExprHandle expr, long index);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_get(ExprHandle expr, ExprHandle index, [MarshalAs(UnmanagedType.U1)] bool nullOnOob);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_explode(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_implode(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_flatten(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_drop_nulls(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_filter(ExprHandle expr, ExprHandle predicate);
+    // Binning (labels 为 null 时自动生成标签)
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_cut(
+        ExprHandle expr,
+        double[] breaks, UIntPtr breaksLen,
+        IntPtr[]? labels, UIntPtr labelsLen,
+        [MarshalAs(UnmanagedType.U1)] bool leftClosed,
+        [MarshalAs(UnmanagedType.U1)] bool includeBreaks
+    );
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_qcut(
+        ExprHandle expr,
+        double[] quantiles, UIntPtr quantilesLen,
+        IntPtr[]? labels, UIntPtr labelsLen,
+        [MarshalAs(UnmanagedType.U1)] bool leftClosed,
+        [MarshalAs(UnmanagedType.U1)] bool allowDuplicates,
+        [MarshalAs(UnmanagedType.U1)] bool includeBreaks
+    );
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_qcut_uniform(
+        ExprHandle expr,
+        UIntPtr nBins,
+        IntPtr[]? labels, UIntPtr labelsLen,
+        [MarshalAs(UnmanagedType.U1)] bool leftClosed,
+        [MarshalAs(UnmanagedType.U1)] bool allowDuplicates,
+        [MarshalAs(UnmanagedType.U1)] bool includeBreaks
+    );
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_join(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string sep);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_len(ExprHandle expr);
     // List Aggs
@@ -534,13 +1030,46 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_min(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_max(ExprHandle expr);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_mean(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_std(ExprHandle expr, byte ddof);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_arg_min(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_arg_max(ExprHandle expr);
     
     // List Other
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_sort(ExprHandle expr,[MarshalAs(UnmanagedType.U1)] bool descending);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_contains(ExprHandle expr, ExprHandle item);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_unique(ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool maintainOrder);
+    // length 传无效 Handle 表示取到末尾
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_slice(ExprHandle expr, ExprHandle offset, ExprHandle length);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_head(ExprHandle expr, ExprHandle n);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_tail(ExprHandle expr, ExprHandle n);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_reverse(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_drop_nulls(ExprHandle expr);
+    // Array Ops (定长数组)
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_len(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_sum(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_min(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_max(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_mean(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_to_list(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_get(ExprHandle expr, ExprHandle index, [MarshalAs(UnmanagedType.U1)] bool nullOnOob);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arr_contains(ExprHandle expr, ExprHandle item);
+    // op: 0=union, 1=intersection, 2=difference, 3=symmetric_difference
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_list_set_operation(ExprHandle left, ExprHandle right, int op);
     // Naming
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_prefix(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string prefix);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_suffix(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string suffix);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_name_keep(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_name_to_lowercase(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_name_to_uppercase(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_name_prefix(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string prefix);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_name_suffix(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string suffix);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_name_map(
+        ExprHandle expr,
+        NameMapCallback callback,
+        CleanupCallback cleanup,
+        IntPtr userData
+    );
     // --- Reshaping (Eager) ---
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_pivot(
@@ -563,6 +1092,23 @@ unsafe internal partial class NativeBindings
     // Expr Len
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_len();
+    // pl_expr_len 的别名 (对应 pl.len())
+    [LibraryImport(LibName)] public static partial ExprHandle pl_len();
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_null_count(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_has_nulls(ExprHandle expr);
+    // Distinct
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_n_unique(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_arg_unique(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_unique_counts(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_is_unique(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_is_duplicated(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_is_first_distinct(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_is_last_distinct(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_unique(ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool maintainOrder);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_skew(ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool bias);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_kurtosis(ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool fisher, [MarshalAs(UnmanagedType.U1)] bool bias);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_entropy(ExprHandle expr, double logBase, [MarshalAs(UnmanagedType.U1)] bool normalize);
     [LibraryImport(LibName)] public static partial IntPtr pl_get_last_error();
     [LibraryImport(LibName)] public static partial void pl_free_error_msg(IntPtr ptr);
     // =================================================================
@@ -614,6 +1160,31 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] public static partial ExprHandle pl_selector_into_expr(SelectorHandle sel);
     // Struct
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_as_struct(IntPtr[] exprs, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_as_struct(IntPtr[] exprs, UIntPtr len);
+    // 多列打包成一行 JSON 字符串
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_row_json_encode(IntPtr[] exprs, UIntPtr len);
+    // Horizontal (逐行跨列聚合)
+    [LibraryImport(LibName)] public static partial ExprHandle pl_min_horizontal(IntPtr[] exprs, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_max_horizontal(IntPtr[] exprs, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_any_horizontal(IntPtr[] exprs, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_all_horizontal(IntPtr[] exprs, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_sum_horizontal(IntPtr[] exprs, UIntPtr len, [MarshalAs(UnmanagedType.U1)] bool ignoreNulls);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_mean_horizontal(IntPtr[] exprs, UIntPtr len, [MarshalAs(UnmanagedType.U1)] bool ignoreNulls);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_coalesce(IntPtr[] exprs, UIntPtr len);
+    // 常量列 / 填充 (dtype 可为无效句柄，只借用)
+    [LibraryImport(LibName)] public static partial ExprHandle pl_repeat(ExprHandle value, ExprHandle n, DataTypeHandle dtype);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_extend_constant(ExprHandle expr, ExprHandle value, ExprHandle n);
+    // Correlation
+    [LibraryImport(LibName)] public static partial ExprHandle pl_pearson_corr(ExprHandle a, ExprHandle b);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_spearman_rank_corr(ExprHandle a, ExprHandle b, [MarshalAs(UnmanagedType.U1)] bool propagateNans);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_cov(ExprHandle a, ExprHandle b, byte ddof);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_rolling_corr(ExprHandle x, ExprHandle y, uint windowSize, uint minPeriods, byte ddof);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_rolling_cov(ExprHandle x, ExprHandle y, uint windowSize, uint minPeriods, byte ddof);
+    // 一次取出多个字段，"*" 取全部
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_struct_field(ExprHandle expr, IntPtr[] names, UIntPtr len);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_struct_with_fields(ExprHandle expr, IntPtr[] fields, UIntPtr len);
+    // pl.field(name)：只在 with_fields 内部有意义
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] public static partial ExprHandle pl_expr_field(string name);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_struct_field_by_name(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string name);
     [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_struct_field_by_index(ExprHandle e, long index);
@@ -626,6 +1197,28 @@ unsafe internal partial class NativeBindings
         UIntPtr len
     );
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_struct_json_encode(ExprHandle e);
+    // WKB Point <-> Struct { x, y }
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_bin_wkb_to_xy(ExprHandle e);
+    // Binary Ops (encoding: "hex" / "base64")
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_bin_size(ExprHandle e);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_bin_encode(ExprHandle e, string encoding);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_bin_decode(ExprHandle e, string encoding, [MarshalAs(UnmanagedType.U1)] bool strict);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_bin_contains(ExprHandle e, ExprHandle pat);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_bin_starts_with(ExprHandle e, ExprHandle prefix);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_bin_ends_with(ExprHandle e, ExprHandle suffix);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_xy_to_wkb(ExprHandle x, ExprHandle y);
+    // Categorical Ops
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_cat_get_categories(ExprHandle e);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_cat_len_bytes(ExprHandle e);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_cat_len_chars(ExprHandle e);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_cat_starts_with(ExprHandle e, string prefix);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial ExprHandle pl_expr_cat_ends_with(ExprHandle e, string suffix);
+    // length < 0 表示取到末尾
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_cat_slice(ExprHandle e, long offset, long length);
     // Window
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_over(
@@ -633,6 +1226,13 @@ unsafe internal partial class NativeBindings
         IntPtr[] partitionBy, 
         UIntPtr len
     );
+    // 占比: x / sum(x) over(partition)，partition 为空时按全表
+    [LibraryImport(LibName)] 
+    public static partial ExprHandle pl_expr_fraction_of_total(
+        ExprHandle expr, 
+        IntPtr[] partitionBy, 
+        UIntPtr len
+    );
 
     // SQL Context
     [LibraryImport(LibName)] 
@@ -647,9 +1247,19 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_sql_context_execute(SqlContextHandle ctx, IntPtr query);
 
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_sql_context_snapshot(SqlContextHandle ctx, string dir);
+
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial SqlContextHandle pl_sql_context_restore(string dir);
+
     // Shift / Diff
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_shift(ExprHandle expr, long n);
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_diff(ExprHandle expr, long n);
+    // Streaks: 连续段计数
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_streak_length(ExprHandle cond);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_is_increasing_streak(ExprHandle expr);
+    [LibraryImport(LibName)] public static partial ExprHandle pl_expr_is_decreasing_streak(ExprHandle expr);
 
     // Fill
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_forward_fill(ExprHandle expr, uint limit);
@@ -679,6 +1289,27 @@ unsafe internal partial class NativeBindings
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial ExprHandle pl_expr_quantile(ExprHandle e, double quantile, string interpol);
+    // IQR 规则离群点: x < Q1 - k*IQR 或 x > Q3 + k*IQR
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_is_outlier_iqr(ExprHandle e, double k);
+    // (x - mean) / std (ddof=1)
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_zscore(ExprHandle e);
+    // 加权平均 / 加权求和 / 点积
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_weighted_mean(ExprHandle e, ExprHandle weights);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_weighted_sum(ExprHandle e, ExprHandle weights);
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_dot(ExprHandle e, ExprHandle other);
+    // HyperLogLog 近似去重计数，precision 取值 4..=18
+    [LibraryImport(LibName)]
+    public static partial ExprHandle pl_expr_approx_n_unique_hll(ExprHandle e, byte precision);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_cardinality_report(DataFrameHandle df, byte precision);
+    // 返回 [by..., "count"]
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_group_sizes(DataFrameHandle df, IntPtr[] by, UIntPtr len, [MarshalAs(UnmanagedType.U1)] bool sort);
     // --- Series Lifecycle ---
     [LibraryImport(LibName)]
     public static partial void pl_series_free(IntPtr ptr);
@@ -779,6 +1410,13 @@ unsafe internal partial class NativeBindings
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial void pl_series_rename(SeriesHandle h, string name);
+
+    // Arrow 扩展类型 (如 arrow.uuid)，没有时返回 NULL
+    [LibraryImport(LibName)]
+    public static partial IntPtr pl_series_extension_name(SeriesHandle h);
+
+    [LibraryImport(LibName)]
+    public static partial IntPtr pl_series_extension_metadata(SeriesHandle h);
     [LibraryImport(LibName)]
     public static partial SeriesHandle pl_series_is_null(SeriesHandle s);
 
@@ -793,6 +1431,7 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_is_not_nan(SeriesHandle s);
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_is_finite(SeriesHandle s);
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_is_infinite(SeriesHandle s);
+    [LibraryImport(LibName)] public static partial SeriesHandle pl_series_cat_get_categories(SeriesHandle s);
     // --- Series Cast ---
     [LibraryImport(LibName)]
     public static partial SeriesHandle pl_series_cast(SeriesHandle s, DataTypeHandle dtype);
@@ -827,6 +1466,8 @@ unsafe internal partial class NativeBindings
     public static partial DataTypeHandle pl_datatype_new_categorical();
     [LibraryImport(LibName)]
     public static partial DataTypeHandle pl_datatype_new_list(DataTypeHandle inner);
+    [LibraryImport(LibName)]
+    public static partial DataTypeHandle pl_datatype_new_array(DataTypeHandle inner, UIntPtr width);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial DataTypeHandle pl_datatype_new_datetime(int unit, string? timezone);
     [LibraryImport(LibName)]
@@ -870,6 +1511,9 @@ unsafe internal partial class NativeBindings
         out IntPtr namePtr,       // 输出字符串指针
         out DataTypeHandle typeHandle // 输出类型句柄
     );
+    // 5. 类型转换矩阵 - 0 不支持 / 1 无损 / 2 有损 / -1 非法 code
+    [LibraryImport(LibName)]
+    public static partial int pl_supported_conversion(int fromKind, int toKind);
 
     // Arithmetic
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_add(SeriesHandle s1, SeriesHandle s2);
@@ -32,6 +32,43 @@ public static partial class PolarsWrapper
         return ErrorHelper.Check(NativeBindings.pl_dataframe_clone(df));
     }
     // ==========================================
+    // Registry (进程级，按名字共享)
+    // ==========================================
+    public static void RegistryPut(string name, DataFrameHandle df)
+    {
+        NativeBindings.pl_registry_put(name, df);
+        ErrorHelper.CheckVoid();
+    }
+    public static DataFrameHandle RegistryGet(string name)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_registry_get(name));
+    }
+    public static bool RegistryRemove(string name)
+    {
+        NativeBindings.pl_registry_remove(name, out bool removed);
+        ErrorHelper.CheckVoid();
+        return removed;
+    }
+    public static ulong Shutdown()
+    {
+        var leaked = (ulong)NativeBindings.pl_shutdown();
+        // Debug 构建下有泄漏时 Rust 会额外设置错误信息，泄漏数已经通过返回值告知，
+        // 这里读出并释放，避免残留到下一次 CheckVoid 被误报
+        IntPtr msgPtr = NativeBindings.pl_get_last_error();
+        if (msgPtr != IntPtr.Zero) NativeBindings.pl_free_error_msg(msgPtr);
+        return leaked;
+    }
+    // 每列一行: column, dtype, approx_n_unique, null_count
+    public static DataFrameHandle CardinalityReport(DataFrameHandle df, byte precision)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_cardinality_report(df, precision));
+    }
+    public static DataFrameHandle GroupSizes(DataFrameHandle df, string[] by, bool sort)
+    {
+        return UseUtf8StringArray(by, ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_group_sizes(df, ptrs, (UIntPtr)ptrs.Length, sort)));
+    }
+    // ==========================================
     // Scalar Access (标量获取 - O(1))
     // ==========================================
 
@@ -111,6 +148,8 @@ public static partial class PolarsWrapper
         expr.TransferOwnership(); // Expr 被消耗了
         return ErrorHelper.Check(h);
     }
+    public static DataFrameHandle FilterMask(DataFrameHandle df, SeriesHandle mask)
+        => ErrorHelper.Check(NativeBindings.pl_dataframe_filter_mask(df, mask));
     public static DataFrameHandle WithColumns(DataFrameHandle df, ExprHandle[] exprs)
     {
         var raw = HandlesToPtrs(exprs);
@@ -267,6 +306,23 @@ public static partial class PolarsWrapper
         // 直接使用 locker.Pointers 传给 Rust
         return ErrorHelper.Check(NativeBindings.pl_dataframe_new(locker.Pointers, (UIntPtr)series.Length));
     }
+    public static DataFrameHandle DataFrameEmptyFromSchema(Dictionary<string, DataTypeHandle> schema)
+    {
+        // 空 Schema 时 WithSchemaHandle 会传无效 Handle，这里直接建空表
+        if (schema.Count == 0)
+            return DataFrameNew(Array.Empty<SeriesHandle>());
+
+        return WithSchemaHandle(schema, schemaHandle =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_empty_from_schema(schemaHandle)));
+    }
+    public static DataFrameHandle DataFrameGenerate(Dictionary<string, DataTypeHandle> schema, ulong nRows, ulong seed, double nullRatio)
+    {
+        if (schema.Count == 0)
+            return DataFrameNew(Array.Empty<SeriesHandle>());
+
+        return WithSchemaHandle(schema, schemaHandle =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_generate(schemaHandle, (UIntPtr)nRows, seed, nullRatio)));
+    }
     /// <summary>
     /// Create a DataFrame from an Arrow C Stream.
     /// </summary>
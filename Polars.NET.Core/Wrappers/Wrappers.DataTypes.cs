@@ -11,6 +11,8 @@ public static partial class PolarsWrapper
     public static DataTypeHandle NewCategoricalType() => ErrorHelper.Check(NativeBindings.pl_datatype_new_categorical());
     public static DataTypeHandle NewListType(DataTypeHandle innerType)
        => ErrorHelper.Check(NativeBindings.pl_datatype_new_list(innerType));
+    public static DataTypeHandle NewArrayType(DataTypeHandle innerType, ulong width)
+       => ErrorHelper.Check(NativeBindings.pl_datatype_new_array(innerType, (UIntPtr)width));
     public static DataTypeHandle NewDateTimeType(int unit, string? timezone)
         => ErrorHelper.Check(NativeBindings.pl_datatype_new_datetime(unit,timezone));
     public static DataTypeHandle NewDurationType(int unit) 
@@ -129,4 +131,15 @@ public static partial class PolarsWrapper
         // 处理字符串 (Copy & Free)
         name = ErrorHelper.CheckString(namePtr);
     }
-}
\ No newline at end of file
+
+    /// <summary>
+    /// 查询两种 DataTypeKind 之间的转换级别 (0 不支持 / 1 无损 / 2 有损)。
+    /// </summary>
+    public static int SupportedConversion(int fromKind, int toKind)
+    {
+        int level = NativeBindings.pl_supported_conversion(fromKind, toKind);
+        // -1 表示 code 非法，Rust 端已经写入错误信息
+        if (level < 0) ErrorHelper.CheckVoid();
+        return level;
+    }
+}
@@ -1,3 +1,5 @@
+using System.Runtime.InteropServices;
+
 namespace Polars.NET.Core;
 
 public static partial class PolarsWrapper
@@ -77,6 +79,26 @@ public static partial class PolarsWrapper
         
         return ErrorHelper.Check(NativeBindings.pl_expr_lit_datetime(micros));
     }
+    // Typed Literals
+    public static ExprHandle LitDatetime(long value, int unitCode, string? timeZone)
+        => ErrorHelper.Check(NativeBindings.pl_lit_datetime(value, unitCode, timeZone));
+    public static ExprHandle LitDate(int days) => ErrorHelper.Check(NativeBindings.pl_lit_date(days));
+    public static ExprHandle LitTime(long nanos) => ErrorHelper.Check(NativeBindings.pl_lit_time(nanos));
+    public static ExprHandle LitDuration(long value, int unitCode)
+        => ErrorHelper.Check(NativeBindings.pl_lit_duration(value, unitCode));
+    public static ExprHandle LitDecimal(decimal value)
+    {
+        // decimal 自带 scale，放大成 Int128 (与 SeriesNewDecimal 的约定一致)
+        int scale = value.Scale;
+        Int128 unscaled = (Int128)(value * PowersOf10[scale]);
+        return ErrorHelper.Check(NativeBindings.pl_lit_decimal(in unscaled, (UIntPtr)scale));
+    }
+    public static ExprHandle LitBinary(byte[] bytes)
+        => ErrorHelper.Check(NativeBindings.pl_lit_binary(bytes, (UIntPtr)bytes.Length));
+    public static ExprHandle LitNull(DataTypeHandle dtype)
+        => ErrorHelper.Check(NativeBindings.pl_lit_null_typed(dtype));
+    public static ExprHandle LitSeries(SeriesHandle series)
+        => ErrorHelper.Check(NativeBindings.pl_lit_series(series));
     // Alias
 
     public static ExprHandle Alias(ExprHandle expr, string name) 
@@ -97,6 +119,15 @@ public static partial class PolarsWrapper
     public static ExprHandle DtDay(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_day, e);
     public static ExprHandle DtOrdinalDay(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_ordinal_day, e);
     public static ExprHandle DtWeekday(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_weekday, e);
+    public static ExprHandle DtWeek(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_week, e);
+    public static ExprHandle DtQuarter(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_quarter, e);
+    public static ExprHandle DtTotalDays(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_days, e);
+    public static ExprHandle DtTotalHours(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_hours, e);
+    public static ExprHandle DtTotalMinutes(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_minutes, e);
+    public static ExprHandle DtTotalSeconds(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_seconds, e);
+    public static ExprHandle DtTotalMilliseconds(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_milliseconds, e);
+    public static ExprHandle DtTotalMicroseconds(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_microseconds, e);
+    public static ExprHandle DtTotalNanoseconds(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_total_nanoseconds, e);
     public static ExprHandle DtHour(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_hour, e);
     public static ExprHandle DtMinute(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_minute, e);
     public static ExprHandle DtSecond(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_second, e);
@@ -110,20 +141,31 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle DtStrftime(ExprHandle e, string? format)
+        => UnaryStrOpNullable(NativeBindings.pl_expr_dt_strftime, e, format);
 
     public static ExprHandle DtDate(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_date, e);
     public static ExprHandle DtTime(ExprHandle e) => UnaryDtOp(NativeBindings.pl_expr_dt_time, e);
-    // Truncate / Round (Expr + String)
+    // Truncate / Round (Expr + Expr)，字符串版本包成 Lit
+    public static ExprHandle DtTruncate(ExprHandle e, ExprHandle every) 
+        => BinaryOp(NativeBindings.pl_expr_dt_truncate, e, every);
+
     public static ExprHandle DtTruncate(ExprHandle e, string every) 
-        => UnaryStrOp(NativeBindings.pl_expr_dt_truncate, e, every); // 注意这里用 UnaryStrOp
+        => DtTruncate(e, Lit(every));
+
+    public static ExprHandle DtRound(ExprHandle e, ExprHandle every)
+        => BinaryOp(NativeBindings.pl_expr_dt_round, e, every);
 
     public static ExprHandle DtRound(ExprHandle e, string every)
-        => UnaryStrOp(NativeBindings.pl_expr_dt_round, e, every);
+        => DtRound(e, Lit(every));
 
     // OffsetBy (Expr + Expr)
     public static ExprHandle DtOffsetBy(ExprHandle e, ExprHandle by)
         => BinaryOp(NativeBindings.pl_expr_dt_offset_by, e, by);
 
+    public static ExprHandle DtOffsetBy(ExprHandle e, string offset)
+        => UnaryStrOp(NativeBindings.pl_expr_dt_offset_by_str, e, offset);
+
     // Timestamp (Expr + Int)
     public static ExprHandle DtTimestamp(ExprHandle e, int unitCode)
     {
@@ -132,6 +174,8 @@ public static partial class PolarsWrapper
         return ErrorHelper.Check(h);
     }
     // TimeZone
+    public static ExprHandle DtBaseUtcOffset(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_dt_base_utc_offset, e);
+    public static ExprHandle DtDstOffset(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_dt_dst_offset, e);
     public static ExprHandle DtConvertTimeZone(ExprHandle e, string timeZone)
     {
         // 这是一个 UnaryStrOp，但我们可以直接手写以复用 TransferOwnership 逻辑
@@ -140,6 +184,38 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    // --- Temporal Ranges ---
+    private static ExprHandle TemporalRangeOp(
+        Func<ExprHandle, ExprHandle, string, string?, ExprHandle> op,
+        ExprHandle start, ExprHandle end, string interval, string? closed)
+    {
+        var h = op(start, end, interval, closed);
+        start.TransferOwnership();
+        end.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle DateRange(ExprHandle start, ExprHandle end, string interval, string? closed)
+        => TemporalRangeOp(NativeBindings.pl_date_range, start, end, interval, closed);
+    public static ExprHandle DateRanges(ExprHandle start, ExprHandle end, string interval, string? closed)
+        => TemporalRangeOp(NativeBindings.pl_date_ranges, start, end, interval, closed);
+    public static ExprHandle TimeRange(ExprHandle start, ExprHandle end, string interval, string? closed)
+        => TemporalRangeOp(NativeBindings.pl_time_range, start, end, interval, closed);
+    public static ExprHandle TimeRanges(ExprHandle start, ExprHandle end, string interval, string? closed)
+        => TemporalRangeOp(NativeBindings.pl_time_ranges, start, end, interval, closed);
+    public static ExprHandle DatetimeRange(ExprHandle start, ExprHandle end, string interval, string? closed, int unitCode, string? timeZone)
+    {
+        var h = NativeBindings.pl_datetime_range(start, end, interval, closed, unitCode, timeZone);
+        start.TransferOwnership();
+        end.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle DatetimeRanges(ExprHandle start, ExprHandle end, string interval, string? closed, int unitCode, string? timeZone)
+    {
+        var h = NativeBindings.pl_datetime_ranges(start, end, interval, closed, unitCode, timeZone);
+        start.TransferOwnership();
+        end.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static ExprHandle DtReplaceTimeZone(
         ExprHandle e, 
         string? timeZone, 
@@ -161,6 +237,39 @@ public static partial class PolarsWrapper
     public static ExprHandle StrToUpper(ExprHandle e) => UnaryStrOp(NativeBindings.pl_expr_str_to_uppercase, e);
     public static ExprHandle StrToLower(ExprHandle e) => UnaryStrOp(NativeBindings.pl_expr_str_to_lowercase, e);
     public static ExprHandle StrLenBytes(ExprHandle e) => UnaryStrOp(NativeBindings.pl_expr_str_len_bytes, e);
+    public static ExprHandle StrToTitlecase(ExprHandle e) => UnaryStrOp(NativeBindings.pl_expr_str_to_titlecase, e);
+    public static ExprHandle StrLenChars(ExprHandle e) => UnaryStrOp(NativeBindings.pl_expr_str_len_chars, e);
+    public static ExprHandle StrReverse(ExprHandle e) => UnaryStrOp(NativeBindings.pl_expr_str_reverse, e);
+    public static ExprHandle StrHead(ExprHandle e, long n)
+    {
+        var h = NativeBindings.pl_expr_str_head(e, n);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrTail(ExprHandle e, long n)
+    {
+        var h = NativeBindings.pl_expr_str_tail(e, n);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrPadStart(ExprHandle e, ulong length, char fillChar)
+    {
+        var h = NativeBindings.pl_expr_str_pad_start(e, (UIntPtr)length, fillChar);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrPadEnd(ExprHandle e, ulong length, char fillChar)
+    {
+        var h = NativeBindings.pl_expr_str_pad_end(e, (UIntPtr)length, fillChar);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrZfill(ExprHandle e, ulong length)
+    {
+        var h = NativeBindings.pl_expr_str_zfill(e, (UIntPtr)length);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     
     public static ExprHandle StrSlice(ExprHandle e, long offset, ulong length)
     {
@@ -175,9 +284,21 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
-    public static ExprHandle StrSplit(ExprHandle e, string pat) 
+    public static ExprHandle StrSplit(ExprHandle e, string pat, bool inclusive = false) 
+    {
+        var h = NativeBindings.pl_expr_str_split(e, pat, inclusive);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrSplitExact(ExprHandle e, string pat, ulong n)
+    {
+        var h = NativeBindings.pl_expr_str_split_exact(e, pat, (UIntPtr)n);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrSplitN(ExprHandle e, string pat, ulong n)
     {
-        var h = NativeBindings.pl_expr_str_split(e, pat);
+        var h = NativeBindings.pl_expr_str_splitn(e, pat, (UIntPtr)n);
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
@@ -187,6 +308,36 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle StrJsonDecode(ExprHandle e, DataTypeHandle? dtype, ulong inferSchemaLen)
+    {
+        // 无 dtype 时传无效 Handle (IntPtr.Zero)，由 Rust 推断
+        var h = NativeBindings.pl_expr_str_json_decode(e, dtype ?? new DataTypeHandle(), (UIntPtr)inferSchemaLen);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrJsonPathMatch(ExprHandle e, string path)
+        => UnaryStrOp(NativeBindings.pl_expr_str_json_path_match, e, path);
+    public static ExprHandle StrExtractAll(ExprHandle e, string pat)
+        => UnaryStrOp(NativeBindings.pl_expr_str_extract_all, e, pat);
+    public static ExprHandle StrExtractGroups(ExprHandle e, string pat)
+        => UnaryStrOp(NativeBindings.pl_expr_str_extract_groups, e, pat);
+    public static ExprHandle StrCountMatches(ExprHandle e, string pat, bool literal = false)
+    {
+        var h = NativeBindings.pl_expr_str_count_matches(e, pat, literal);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ConcatStr(ExprHandle[] exprs, string? separator, bool ignoreNulls)
+    {
+        var raw = HandlesToPtrs(exprs);
+        return ErrorHelper.Check(NativeBindings.pl_concat_str(raw, (UIntPtr)raw.Length, separator, ignoreNulls));
+    }
+    public static ExprHandle StrJoin(ExprHandle e, string? separator, bool ignoreNulls)
+    {
+        var h = NativeBindings.pl_expr_str_join(e, separator, ignoreNulls);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static ExprHandle StrStripChars(ExprHandle e, string? matches = null)
         => UnaryStrOpNullable(NativeBindings.pl_expr_str_strip_chars, e, matches);
 
@@ -209,8 +360,20 @@ public static partial class PolarsWrapper
     public static ExprHandle StrToDate(ExprHandle e, string format)
         => UnaryStrOp(NativeBindings.pl_expr_str_to_date, e, format);
 
-    public static ExprHandle StrToDatetime(ExprHandle e, string format)
-        => UnaryStrOp(NativeBindings.pl_expr_str_to_datetime, e, format);
+    public static ExprHandle StrToDatetime(ExprHandle e, string? format, int timeUnit = 1, string? timeZone = null)
+    {
+        var h = NativeBindings.pl_expr_str_to_datetime(e, format, timeUnit, timeZone);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle StrToTime(ExprHandle e, string? format)
+        => UnaryStrOpNullable(NativeBindings.pl_expr_str_to_time, e, format);
+    public static ExprHandle StrStrptime(ExprHandle e, DataTypeHandle dtype, string? format, bool strict, bool exact, bool cache)
+    {
+        var h = NativeBindings.pl_expr_str_strptime(e, dtype, format, strict, exact, cache);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     // Compare
     public static ExprHandle Eq(ExprHandle l, ExprHandle r) => BinaryOp(NativeBindings.pl_expr_eq, l, r);
     public static ExprHandle Neq(ExprHandle l, ExprHandle r) => BinaryOp(NativeBindings.pl_expr_neq, l, r);
@@ -259,6 +422,23 @@ public static partial class PolarsWrapper
     }
     // Statistics
     public static ExprHandle Count(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_count, e);
+    public static ExprHandle NullCount(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_null_count, e);
+    public static ExprHandle HasNulls(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_has_nulls, e);
+    // Distinct
+    public static ExprHandle NUnique(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_n_unique, e);
+    public static ExprHandle ArgUnique(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arg_unique, e);
+    public static ExprHandle UniqueCounts(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_unique_counts, e);
+    public static ExprHandle IsUnique(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_is_unique, e);
+    public static ExprHandle IsDuplicated(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_is_duplicated, e);
+    public static ExprHandle IsFirstDistinct(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_is_first_distinct, e);
+    public static ExprHandle IsLastDistinct(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_is_last_distinct, e);
+    public static ExprHandle Unique(ExprHandle e, bool maintainOrder)
+        => UnaryOp(h => NativeBindings.pl_expr_unique(h, maintainOrder), e);
+    public static ExprHandle Skew(ExprHandle e, bool bias) => UnaryOp(h => NativeBindings.pl_expr_skew(h, bias), e);
+    public static ExprHandle Kurtosis(ExprHandle e, bool fisher, bool bias)
+        => UnaryOp(h => NativeBindings.pl_expr_kurtosis(h, fisher, bias), e);
+    public static ExprHandle Entropy(ExprHandle e, double logBase, bool normalize)
+        => UnaryOp(h => NativeBindings.pl_expr_entropy(h, logBase, normalize), e);
     public static ExprHandle Std(ExprHandle e, int ddof) 
     {
         var h = NativeBindings.pl_expr_std(e, (byte)ddof);
@@ -279,6 +459,22 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle IsOutlierIqr(ExprHandle e, double k)
+    {
+        var h = NativeBindings.pl_expr_is_outlier_iqr(e, k);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ZScore(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_zscore, e);
+    public static ExprHandle WeightedMean(ExprHandle e, ExprHandle weights) => BinaryOp(NativeBindings.pl_expr_weighted_mean, e, weights);
+    public static ExprHandle WeightedSum(ExprHandle e, ExprHandle weights) => BinaryOp(NativeBindings.pl_expr_weighted_sum, e, weights);
+    public static ExprHandle Dot(ExprHandle e, ExprHandle other) => BinaryOp(NativeBindings.pl_expr_dot, e, other);
+    public static ExprHandle ApproxNUniqueHll(ExprHandle e, byte precision)
+    {
+        var h = NativeBindings.pl_expr_approx_n_unique_hll(e, precision);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     // IsBetween
     public static ExprHandle IsBetween(ExprHandle expr, ExprHandle lower, ExprHandle upper)
     {
@@ -289,19 +485,51 @@ public static partial class PolarsWrapper
         upper.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle IsIn(ExprHandle expr, ExprHandle other, bool nullsEqual)
+    {
+        var h = NativeBindings.pl_expr_is_in(expr, other, nullsEqual);
+        expr.TransferOwnership();
+        other.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
 
     // List
     public static ExprHandle ListFirst(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_first, e);
     
-    public static ExprHandle ListGet(ExprHandle e, long index)
+    public static ExprHandle ListGet(ExprHandle e, ExprHandle index, bool nullOnOob = true)
     {
-        var h = NativeBindings.pl_expr_list_get(e, index);
+        var h = NativeBindings.pl_expr_list_get(e, index, nullOnOob);
         e.TransferOwnership();
+        index.TransferOwnership();
         return ErrorHelper.Check(h);
     }
 
+    public static ExprHandle ListGet(ExprHandle e, long index)
+        => ListGet(e, Lit(index));
+
     public static ExprHandle Explode(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_explode, e);
     public static ExprHandle Implode(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_implode, e);
+    public static ExprHandle Flatten(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_flatten, e);
+    public static ExprHandle DropNulls(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_drop_nulls, e);
+    public static ExprHandle Filter(ExprHandle e, ExprHandle predicate) => BinaryOp(NativeBindings.pl_expr_filter, e, predicate);
+    // Binning
+    private static ExprHandle WithBinLabels(ExprHandle e, string[]? labels, Func<IntPtr[]?, UIntPtr, ExprHandle> op)
+    {
+        ExprHandle h = labels == null
+            ? op(null, UIntPtr.Zero)
+            : UseUtf8StringArray(labels, ptrs => op(ptrs, (UIntPtr)ptrs.Length));
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle Cut(ExprHandle e, double[] breaks, string[]? labels, bool leftClosed, bool includeBreaks)
+        => WithBinLabels(e, labels, (l, n) =>
+            NativeBindings.pl_expr_cut(e, breaks, (UIntPtr)breaks.Length, l, n, leftClosed, includeBreaks));
+    public static ExprHandle QCut(ExprHandle e, double[] quantiles, string[]? labels, bool leftClosed, bool allowDuplicates, bool includeBreaks)
+        => WithBinLabels(e, labels, (l, n) =>
+            NativeBindings.pl_expr_qcut(e, quantiles, (UIntPtr)quantiles.Length, l, n, leftClosed, allowDuplicates, includeBreaks));
+    public static ExprHandle QCutUniform(ExprHandle e, int nBins, string[]? labels, bool leftClosed, bool allowDuplicates, bool includeBreaks)
+        => WithBinLabels(e, labels, (l, n) =>
+            NativeBindings.pl_expr_qcut_uniform(e, (UIntPtr)nBins, l, n, leftClosed, allowDuplicates, includeBreaks));
     
     public static ExprHandle ListJoin(ExprHandle e, string sep)
     {
@@ -316,6 +544,14 @@ public static partial class PolarsWrapper
     public static ExprHandle ListMin(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_min, e);
     public static ExprHandle ListMax(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_max, e);
     public static ExprHandle ListMean(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_mean, e);
+    public static ExprHandle ListStd(ExprHandle e, byte ddof)
+    {
+        var h = NativeBindings.pl_expr_list_std(e, ddof);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ListArgMin(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_arg_min, e);
+    public static ExprHandle ListArgMax(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_arg_max, e);
 
     // --- List Other ---
     public static ExprHandle ListSort(ExprHandle e, bool descending)
@@ -332,12 +568,115 @@ public static partial class PolarsWrapper
         itemExpr.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle ListUnique(ExprHandle e, bool maintainOrder)
+    {
+        var h = NativeBindings.pl_expr_list_unique(e, maintainOrder);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ListSlice(ExprHandle e, ExprHandle offset, ExprHandle? length)
+    {
+        // 无 length 时传无效 Handle (IntPtr.Zero)，一直取到末尾
+        var h = NativeBindings.pl_expr_list_slice(e, offset, length ?? new ExprHandle());
+        e.TransferOwnership();
+        offset.TransferOwnership();
+        length?.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ListHead(ExprHandle e, ExprHandle n) => BinaryOp(NativeBindings.pl_expr_list_head, e, n);
+    public static ExprHandle ListTail(ExprHandle e, ExprHandle n) => BinaryOp(NativeBindings.pl_expr_list_tail, e, n);
+    public static ExprHandle ListReverse(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_reverse, e);
+    public static ExprHandle ListDropNulls(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_list_drop_nulls, e);
+    // --- Array ---
+    public static ExprHandle ArrLen(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arr_len, e);
+    public static ExprHandle ArrSum(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arr_sum, e);
+    public static ExprHandle ArrMin(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arr_min, e);
+    public static ExprHandle ArrMax(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arr_max, e);
+    public static ExprHandle ArrMean(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arr_mean, e);
+    public static ExprHandle ArrToList(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_arr_to_list, e);
+    public static ExprHandle ArrGet(ExprHandle e, ExprHandle index, bool nullOnOob = true)
+    {
+        var h = NativeBindings.pl_expr_arr_get(e, index, nullOnOob);
+        e.TransferOwnership();
+        index.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ArrContains(ExprHandle e, ExprHandle item) => BinaryOp(NativeBindings.pl_expr_arr_contains, e, item);
+    public static ExprHandle ListSetOperation(ExprHandle left, ExprHandle right, PlListSetOp op)
+    {
+        var h = NativeBindings.pl_expr_list_set_operation(left, right, (int)op);
+        left.TransferOwnership();
+        right.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     // --- Struct ---
     public static ExprHandle AsStruct(ExprHandle[] exprs)
     {
         var raw = HandlesToPtrs(exprs);
-        return ErrorHelper.Check(NativeBindings.pl_expr_as_struct(raw, (UIntPtr)raw.Length));
+        return ErrorHelper.Check(NativeBindings.pl_as_struct(raw, (UIntPtr)raw.Length));
+    }
+    // Horizontal
+    private static ExprHandle HorizontalOp(Func<IntPtr[], UIntPtr, ExprHandle> op, ExprHandle[] exprs)
+    {
+        var raw = HandlesToPtrs(exprs);
+        return ErrorHelper.Check(op(raw, (UIntPtr)raw.Length));
+    }
+    public static ExprHandle MinHorizontal(ExprHandle[] exprs) => HorizontalOp(NativeBindings.pl_min_horizontal, exprs);
+    public static ExprHandle MaxHorizontal(ExprHandle[] exprs) => HorizontalOp(NativeBindings.pl_max_horizontal, exprs);
+    public static ExprHandle AnyHorizontal(ExprHandle[] exprs) => HorizontalOp(NativeBindings.pl_any_horizontal, exprs);
+    public static ExprHandle AllHorizontal(ExprHandle[] exprs) => HorizontalOp(NativeBindings.pl_all_horizontal, exprs);
+    public static ExprHandle SumHorizontal(ExprHandle[] exprs, bool ignoreNulls)
+        => HorizontalOp((p, n) => NativeBindings.pl_sum_horizontal(p, n, ignoreNulls), exprs);
+    public static ExprHandle MeanHorizontal(ExprHandle[] exprs, bool ignoreNulls)
+        => HorizontalOp((p, n) => NativeBindings.pl_mean_horizontal(p, n, ignoreNulls), exprs);
+    public static ExprHandle Coalesce(ExprHandle[] exprs) => HorizontalOp(NativeBindings.pl_coalesce, exprs);
+    public static ExprHandle Repeat(ExprHandle value, ExprHandle n, DataTypeHandle? dtype)
+    {
+        var h = NativeBindings.pl_repeat(value, n, dtype ?? new DataTypeHandle());
+        value.TransferOwnership();
+        n.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle ExtendConstant(ExprHandle e, ExprHandle value, ExprHandle n)
+    {
+        var h = NativeBindings.pl_expr_extend_constant(e, value, n);
+        e.TransferOwnership();
+        value.TransferOwnership();
+        n.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    // Correlation
+    public static ExprHandle PearsonCorr(ExprHandle a, ExprHandle b) => BinaryOp(NativeBindings.pl_pearson_corr, a, b);
+    public static ExprHandle SpearmanRankCorr(ExprHandle a, ExprHandle b, bool propagateNans)
+        => BinaryOp((x, y) => NativeBindings.pl_spearman_rank_corr(x, y, propagateNans), a, b);
+    public static ExprHandle Cov(ExprHandle a, ExprHandle b, byte ddof)
+        => BinaryOp((x, y) => NativeBindings.pl_cov(x, y, ddof), a, b);
+    public static ExprHandle RollingCorr(ExprHandle x, ExprHandle y, uint windowSize, uint minPeriods, byte ddof)
+        => BinaryOp((l, r) => NativeBindings.pl_rolling_corr(l, r, windowSize, minPeriods, ddof), x, y);
+    public static ExprHandle RollingCov(ExprHandle x, ExprHandle y, uint windowSize, uint minPeriods, byte ddof)
+        => BinaryOp((l, r) => NativeBindings.pl_rolling_cov(l, r, windowSize, minPeriods, ddof), x, y);
+    public static ExprHandle RowJsonEncode(ExprHandle[] exprs)
+    {
+        var raw = HandlesToPtrs(exprs);
+        return ErrorHelper.Check(NativeBindings.pl_expr_row_json_encode(raw, (UIntPtr)raw.Length));
+    }
+    public static ExprHandle StructFields(ExprHandle e, string[] names)
+    {
+        return UseUtf8StringArray(names, ptrs =>
+        {
+            var h = NativeBindings.pl_expr_struct_field(e, ptrs, (UIntPtr)ptrs.Length);
+            e.TransferOwnership();
+            return ErrorHelper.Check(h);
+        });
     }
+    public static ExprHandle StructWithFields(ExprHandle e, ExprHandle[] fields)
+    {
+        var raw = HandlesToPtrs(fields);
+        var h = NativeBindings.pl_expr_struct_with_fields(e, raw, (UIntPtr)raw.Length);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle Field(string name) => ErrorHelper.Check(NativeBindings.pl_expr_field(name));
 
     public static ExprHandle StructFieldByName(ExprHandle e, string name)
     {
@@ -364,20 +703,57 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle BinWkbToXy(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_bin_wkb_to_xy, e);
+    public static ExprHandle BinSize(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_bin_size, e);
+    public static ExprHandle BinEncode(ExprHandle e, string encoding)
+        => UnaryStrOp(NativeBindings.pl_expr_bin_encode, e, encoding);
+    public static ExprHandle BinDecode(ExprHandle e, string encoding, bool strict)
+    {
+        var h = NativeBindings.pl_expr_bin_decode(e, encoding, strict);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle BinContains(ExprHandle e, ExprHandle pat) => BinaryOp(NativeBindings.pl_expr_bin_contains, e, pat);
+    public static ExprHandle BinStartsWith(ExprHandle e, ExprHandle prefix) => BinaryOp(NativeBindings.pl_expr_bin_starts_with, e, prefix);
+    public static ExprHandle BinEndsWith(ExprHandle e, ExprHandle suffix) => BinaryOp(NativeBindings.pl_expr_bin_ends_with, e, suffix);
+    // Categorical
+    public static ExprHandle CatGetCategories(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_cat_get_categories, e);
+    public static ExprHandle CatLenBytes(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_cat_len_bytes, e);
+    public static ExprHandle CatLenChars(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_cat_len_chars, e);
+    public static ExprHandle CatStartsWith(ExprHandle e, string prefix)
+        => UnaryStrOp(NativeBindings.pl_expr_cat_starts_with, e, prefix);
+    public static ExprHandle CatEndsWith(ExprHandle e, string suffix)
+        => UnaryStrOp(NativeBindings.pl_expr_cat_ends_with, e, suffix);
+    public static ExprHandle CatSlice(ExprHandle e, long offset, long? length)
+    {
+        var h = NativeBindings.pl_expr_cat_slice(e, offset, length ?? -1);
+        e.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static ExprHandle XyToWkb(ExprHandle x, ExprHandle y)
+    {
+        var h = NativeBindings.pl_expr_xy_to_wkb(x, y);
+        x.TransferOwnership();
+        y.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     // Naming
     public static ExprHandle Prefix(ExprHandle e, string p)
     {
-        var h = NativeBindings.pl_expr_prefix(e, p);
+        var h = NativeBindings.pl_expr_name_prefix(e, p);
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
     
     public static ExprHandle Suffix(ExprHandle e, string s)
     {
-        var h = NativeBindings.pl_expr_suffix(e, s);
+        var h = NativeBindings.pl_expr_name_suffix(e, s);
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle NameKeep(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_name_keep, e);
+    public static ExprHandle NameToLowercase(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_name_to_lowercase, e);
+    public static ExprHandle NameToUppercase(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_name_to_uppercase, e);
     // Window
     public static ExprHandle Over(ExprHandle expr, ExprHandle[] partitionBy)
     {
@@ -392,17 +768,79 @@ public static partial class PolarsWrapper
         
         return ErrorHelper.Check(h);
     }
+    public static ExprHandle FractionOfTotal(ExprHandle expr, ExprHandle[] partitionBy)
+    {
+        var rawPartition = HandlesToPtrs(partitionBy);
+        var h = NativeBindings.pl_expr_fraction_of_total(expr, rawPartition, (UIntPtr)rawPartition.Length);
+        expr.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     // Expr Length
-    public static ExprHandle Len() => ErrorHelper.Check(NativeBindings.pl_expr_len());
+    public static ExprHandle Len() => ErrorHelper.Check(NativeBindings.pl_len());
     // expr clone
     public static ExprHandle CloneExpr(ExprHandle expr)
     {
         return ErrorHelper.Check(NativeBindings.pl_expr_clone(expr));
     }
+    // Expr Meta (借用，不转移所有权)
+    public static string ExprMetaOutputName(ExprHandle expr)
+        => ErrorHelper.CheckString(NativeBindings.pl_expr_meta_output_name(expr));
+    public static string[] ExprMetaRootNames(ExprHandle expr)
+    {
+        IntPtr arr = NativeBindings.pl_expr_meta_root_names(expr, out UIntPtr len);
+        if (arr == IntPtr.Zero)
+        {
+            ErrorHelper.CheckVoid();
+            return Array.Empty<string>();
+        }
+        try
+        {
+            var names = new string[(int)len];
+            for (int i = 0; i < names.Length; i++)
+            {
+                IntPtr p = Marshal.ReadIntPtr(arr, i * IntPtr.Size);
+                names[i] = Marshal.PtrToStringUTF8(p) ?? "";
+            }
+            return names;
+        }
+        finally
+        {
+            NativeBindings.pl_free_string_array(arr, len);
+        }
+    }
+    public static string ExprMetaTreeFormat(ExprHandle expr, bool asDot)
+        => ErrorHelper.CheckString(NativeBindings.pl_expr_meta_tree_format(expr, asDot));
+    public static bool ExprMetaEq(ExprHandle a, ExprHandle b) => NativeBindings.pl_expr_meta_eq(a, b);
+    // Expr 序列化 (借用 expr)
+    public static byte[] ExprSerialize(ExprHandle expr, int format)
+    {
+        IntPtr ptr = NativeBindings.pl_expr_serialize(expr, format, out UIntPtr len);
+        if (ptr == IntPtr.Zero)
+        {
+            ErrorHelper.CheckVoid();
+            return Array.Empty<byte>();
+        }
+        try
+        {
+            var bytes = new byte[(int)len];
+            Marshal.Copy(ptr, bytes, 0, bytes.Length);
+            return bytes;
+        }
+        finally
+        {
+            NativeBindings.pl_free_bytes(ptr, len);
+        }
+    }
+    public static ExprHandle ExprDeserialize(byte[] bytes, int format)
+        => ErrorHelper.Check(NativeBindings.pl_expr_deserialize(bytes, (UIntPtr)bytes.Length, format));
     public static ExprHandle ExprCast(ExprHandle expr, DataTypeHandle dtype, bool strict)
     {
         return ErrorHelper.Check(NativeBindings.pl_expr_cast(expr, dtype, strict));
     }
+    public static ExprHandle ExprCastOpts(ExprHandle expr, DataTypeHandle dtype, bool strict, bool wrapNumerical)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_expr_cast_opts(expr, dtype, strict, wrapNumerical));
+    }
 
     // Shift
     public static ExprHandle Shift(ExprHandle e, long n)
@@ -419,6 +857,10 @@ public static partial class PolarsWrapper
         e.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    // Streaks
+    public static ExprHandle StreakLength(ExprHandle cond) => UnaryOp(NativeBindings.pl_expr_streak_length, cond);
+    public static ExprHandle IncreasingStreak(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_is_increasing_streak, e);
+    public static ExprHandle DecreasingStreak(ExprHandle e) => UnaryOp(NativeBindings.pl_expr_is_decreasing_streak, e);
 
     // Fill
     public static ExprHandle ForwardFill(ExprHandle e, uint limit)
@@ -1,3 +1,4 @@
+using System.Runtime.InteropServices;
 using Apache.Arrow;
 using Apache.Arrow.C;
 using Polars.NET.Core.Arrow;
@@ -28,6 +29,79 @@ public static partial class PolarsWrapper
             ));
         });
     }
+    public static DataFrameHandle ReadCsv(
+        string path,
+        Dictionary<string, DataTypeHandle>? schema,
+        PlCsvReadArgs args,
+        string? commentPrefix,
+        string[]? nullValues)
+    {
+        return WithSchemaHandle(schema, schemaHandle =>
+            WithCsvReadArgs(args, commentPrefix, nullValues, nativeArgs =>
+                ErrorHelper.Check(NativeBindings.pl_read_csv_with_options(path, schemaHandle, nativeArgs))));
+    }
+    public static LazyFrameHandle ScanCsv(
+        string path,
+        Dictionary<string, DataTypeHandle>? schema,
+        PlCsvReadArgs args,
+        string? commentPrefix,
+        string[]? nullValues)
+    {
+        return WithSchemaHandle(schema, schemaHandle =>
+            WithCsvReadArgs(args, commentPrefix, nullValues, nativeArgs =>
+                ErrorHelper.Check(NativeBindings.pl_scan_csv_with_options(path, schemaHandle, nativeArgs))));
+    }
+    public static LazyFrameHandle ScanCsv(
+        string[] paths,
+        Dictionary<string, DataTypeHandle>? schema,
+        PlCsvReadArgs args,
+        string? commentPrefix,
+        string[]? nullValues,
+        string? includeFilePaths)
+    {
+        return UseUtf8StringArray(paths, pathPtrs =>
+            WithSchemaHandle(schema, schemaHandle =>
+                WithCsvReadArgs(args, commentPrefix, nullValues, nativeArgs =>
+                    ErrorHelper.Check(NativeBindings.pl_scan_csv_multi(
+                        pathPtrs, (UIntPtr)pathPtrs.Length, schemaHandle, nativeArgs, includeFilePaths)))));
+    }
+    public static DataFrameHandle ReadCsv(
+        byte[] data,
+        Dictionary<string, DataTypeHandle>? schema,
+        PlCsvReadArgs args,
+        string? commentPrefix,
+        string[]? nullValues)
+    {
+        return WithSchemaHandle(schema, schemaHandle =>
+            WithCsvReadArgs(args, commentPrefix, nullValues, nativeArgs =>
+                ErrorHelper.Check(NativeBindings.pl_read_csv_bytes(data, (UIntPtr)data.Length, schemaHandle, nativeArgs))));
+    }
+    // 填好 args 里的字符串指针，调用结束后释放
+    private static unsafe T WithCsvReadArgs<T>(
+        PlCsvReadArgs args,
+        string? commentPrefix,
+        string[]? nullValues,
+        Func<PlCsvReadArgs, T> action)
+    {
+        var prefixPtr = commentPrefix == null ? IntPtr.Zero : Marshal.StringToCoTaskMemUTF8(commentPrefix);
+        try
+        {
+            args.CommentPrefix = prefixPtr;
+            return UseUtf8StringArray(nullValues ?? Array.Empty<string>(), ptrs =>
+            {
+                fixed (IntPtr* p = ptrs)
+                {
+                    args.NullValues = ptrs.Length == 0 ? IntPtr.Zero : (IntPtr)p;
+                    args.NullValuesLen = (UIntPtr)ptrs.Length;
+                    return action(args);
+                }
+            });
+        }
+        finally
+        {
+            if (prefixPtr != IntPtr.Zero) Marshal.FreeCoTaskMem(prefixPtr);
+        }
+    }
     public static Task<DataFrameHandle> ReadCsvAsync(
             string path,
             Dictionary<string, DataTypeHandle>? schema = null,
@@ -66,6 +140,18 @@ public static partial class PolarsWrapper
          if (!File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
          return ErrorHelper.Check(NativeBindings.pl_read_parquet(path));
     }
+    public static DataFrameHandle ReadParquet(byte[] data)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_read_parquet_bytes(data, (UIntPtr)data.Length));
+    }
+    public static DataFrameHandle ReadIpc(byte[] data)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_read_ipc_bytes(data, (UIntPtr)data.Length));
+    }
+    public static DataFrameHandle ReadJson(byte[] data)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_read_json_bytes(data, (UIntPtr)data.Length));
+    }
     public static Task<DataFrameHandle> ReadParquetAsync(string path)
     {
         return Task.Run(() => ReadParquet(path));
@@ -74,6 +160,45 @@ public static partial class PolarsWrapper
         if (!File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
         return ErrorHelper.Check(NativeBindings.pl_scan_parquet(path));
     } 
+    public static DataFrameHandle ReadParquet(
+        string path, PlParquetReadArgs args, string[]? columns, string? rowIndexName)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
+        return WithParquetReadArgs(args, columns, rowIndexName, nativeArgs =>
+            ErrorHelper.Check(NativeBindings.pl_read_parquet_with_options(path, nativeArgs)));
+    }
+    // glob 模式下 path 不一定是真实文件，这里不做存在性检查
+    public static LazyFrameHandle ScanParquet(
+        string path, PlParquetReadArgs args, string[]? columns, string? rowIndexName)
+    {
+        return WithParquetReadArgs(args, columns, rowIndexName, nativeArgs =>
+            ErrorHelper.Check(NativeBindings.pl_scan_parquet_with_options(path, nativeArgs)));
+    }
+    // columns 为 null 或空时传 NULL (读全部列)
+    private static unsafe T WithParquetReadArgs<T>(
+        PlParquetReadArgs args,
+        string[]? columns,
+        string? rowIndexName,
+        Func<PlParquetReadArgs, T> action)
+    {
+        return UseNullableUtf8Strings([rowIndexName], namePtr =>
+            UseUtf8StringArray(columns ?? Array.Empty<string>(), ptrs =>
+            {
+                fixed (IntPtr* p = ptrs)
+                {
+                    args.RowIndexName = namePtr[0];
+                    args.Columns = ptrs.Length == 0 ? IntPtr.Zero : (IntPtr)p;
+                    args.ColumnsLen = (UIntPtr)ptrs.Length;
+                    return action(args);
+                }
+            }));
+    }
+    public static LazyFrameHandle ScanParquet(string[] paths, bool allowMissingColumns, string? includeFilePaths)
+    {
+        return UseUtf8StringArray(paths, ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_scan_parquet_multi(
+                ptrs, (UIntPtr)ptrs.Length, allowMissingColumns, includeFilePaths)));
+    }
 
     public static void WriteCsv(DataFrameHandle df, string path)
     {
@@ -86,22 +211,141 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         ErrorHelper.CheckVoid();
     }
+    public static void WriteCsv(
+        DataFrameHandle df, string path, PlCsvWriteArgs args,
+        string? lineTerminator, string? dateFormat, string? timeFormat, string? datetimeFormat, string? nullValue)
+    {
+        UseNullableUtf8Strings([lineTerminator, dateFormat, timeFormat, datetimeFormat, nullValue], ptrs =>
+        {
+            args.LineTerminator = ptrs[0];
+            args.DateFormat = ptrs[1];
+            args.TimeFormat = ptrs[2];
+            args.DatetimeFormat = ptrs[3];
+            args.NullValue = ptrs[4];
+            NativeBindings.pl_write_csv_with_options(df, path, args);
+            return 0;
+        });
+        ErrorHelper.CheckVoid();
+    }
+    public static void SinkCsv(
+        LazyFrameHandle lf, string path, PlCsvWriteArgs args,
+        string? lineTerminator, string? dateFormat, string? timeFormat, string? datetimeFormat, string? nullValue)
+    {
+        UseNullableUtf8Strings([lineTerminator, dateFormat, timeFormat, datetimeFormat, nullValue], ptrs =>
+        {
+            args.LineTerminator = ptrs[0];
+            args.DateFormat = ptrs[1];
+            args.TimeFormat = ptrs[2];
+            args.DatetimeFormat = ptrs[3];
+            args.NullValue = ptrs[4];
+            NativeBindings.pl_lazy_sink_csv_with_options(lf, path, args);
+            return 0;
+        });
+        lf.TransferOwnership();
+        ErrorHelper.CheckVoid();
+    }
+    // 同 UseUtf8StringArray，但允许 null (对应 NULL 指针)
+    private static R UseNullableUtf8Strings<R>(string?[] strings, Func<IntPtr[], R> action)
+    {
+        var ptrs = new IntPtr[strings.Length];
+        try
+        {
+            for (int i = 0; i < strings.Length; i++)
+            {
+                ptrs[i] = strings[i] == null ? IntPtr.Zero : Marshal.StringToCoTaskMemUTF8(strings[i]);
+            }
+            return action(ptrs);
+        }
+        finally
+        {
+            foreach (var p in ptrs)
+            {
+                if (p != IntPtr.Zero) Marshal.FreeCoTaskMem(p);
+            }
+        }
+    }
     public static void WriteParquet(DataFrameHandle df, string path)
     {
         NativeBindings.pl_write_parquet(df, path);
         ErrorHelper.CheckVoid();
     }
+    public static void WriteParquet(DataFrameHandle df, string path, PlParquetWriteArgs args)
+    {
+        NativeBindings.pl_write_parquet_with_options(df, path, args);
+        ErrorHelper.CheckVoid();
+    }
+    public static void WriteParquetWithDescriptions(
+        DataFrameHandle df,
+        string path,
+        string? tableDescription,
+        Dictionary<string, string>? columnDescriptions)
+    {
+        var names = columnDescriptions?.Keys.ToArray() ?? Array.Empty<string>();
+        var descs = columnDescriptions?.Values.ToArray() ?? Array.Empty<string>();
+
+        UseUtf8StringArray(names, namePtrs =>
+            UseUtf8StringArray(descs, descPtrs =>
+            {
+                NativeBindings.pl_write_parquet_with_descriptions(
+                    df, path, tableDescription, namePtrs, descPtrs, (UIntPtr)namePtrs.Length);
+                return 0;
+            }));
+        ErrorHelper.CheckVoid();
+    }
+    // 返回 JSON: {"key": "value" | null, ...}
+    public static string ReadParquetKeyValueMetadata(string path)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
+        return ErrorHelper.CheckString(NativeBindings.pl_read_parquet_key_value_metadata(path));
+    }
+    // 返回 JSON: {"table": "..." | null, "columns": {"列名": "描述"}}
+    public static string ReadParquetDescriptions(string path)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
+        return ErrorHelper.CheckString(NativeBindings.pl_read_parquet_descriptions(path));
+    }
     public static void WriteIpc(DataFrameHandle df, string path)
     {
         NativeBindings.pl_dataframe_write_ipc(df, path);
         ErrorHelper.CheckVoid(); 
     }
+    public static void WriteIpc(DataFrameHandle df, string path, PlIpcCompression compression)
+    {
+        NativeBindings.pl_dataframe_write_ipc_with_compression(df, path, compression);
+        ErrorHelper.CheckVoid();
+    }
+    public static void WriteIpcStream(DataFrameHandle df, string path, PlIpcCompression compression)
+    {
+        NativeBindings.pl_write_ipc_stream(df, path, compression);
+        ErrorHelper.CheckVoid();
+    }
+    public static DataFrameHandle ReadIpcStream(string path)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"IPC stream file not found: {path}");
+        return ErrorHelper.Check(NativeBindings.pl_read_ipc_stream(path));
+    }
+
+    public static void WriteAvro(DataFrameHandle df, string path, PlAvroCompression compression)
+    {
+        NativeBindings.pl_write_avro(df, path, compression);
+        ErrorHelper.CheckVoid();
+    }
+    public static DataFrameHandle ReadAvro(string path)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"Avro file not found: {path}");
+        return ErrorHelper.Check(NativeBindings.pl_read_avro(path));
+    }
 
     public static void WriteJson(DataFrameHandle df, string path)
     {
         NativeBindings.pl_dataframe_write_json(df, path);
         ErrorHelper.CheckVoid();
     }
+    public static void WriteNdjson(DataFrameHandle df, string path)
+    {
+        NativeBindings.pl_dataframe_write_ndjson(df, path);
+        ErrorHelper.CheckVoid();
+    }
     // Sink Parquet
     public static void SinkParquet(LazyFrameHandle lf, string path)
     {
@@ -109,6 +353,12 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         ErrorHelper.CheckVoid();
     }
+    public static void SinkParquet(LazyFrameHandle lf, string path, PlParquetWriteArgs args)
+    {
+        NativeBindings.pl_lazy_sink_parquet_with_options(lf, path, args);
+        lf.TransferOwnership();
+        ErrorHelper.CheckVoid();
+    }
     // JSON Eager
     public static DataFrameHandle ReadJson(string path)
     {
@@ -122,23 +372,54 @@ public static partial class PolarsWrapper
         if (!File.Exists(path)) throw new FileNotFoundException($"NDJSON file not found: {path}");
         return ErrorHelper.Check(NativeBindings.pl_scan_ndjson(path));
     }
+    public static LazyFrameHandle ScanNdjson(
+        string path,
+        Dictionary<string, DataTypeHandle>? schema,
+        Dictionary<string, DataTypeHandle>? schemaOverwrite,
+        PlNdjsonReadArgs args)
+    {
+        return WithSchemaHandle(schema, schemaHandle =>
+            WithSchemaHandle(schemaOverwrite, overwriteHandle =>
+                ErrorHelper.Check(NativeBindings.pl_scan_ndjson_with_options(path, schemaHandle, overwriteHandle, args))));
+    }
+    public static LazyFrameHandle ScanNdjson(string[] paths, string? includeFilePaths)
+    {
+        return UseUtf8StringArray(paths, ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_scan_ndjson_multi(ptrs, (UIntPtr)ptrs.Length, includeFilePaths)));
+    }
     public static void SinkJson(LazyFrameHandle lf, string path)
     {
         NativeBindings.pl_lazy_sink_json(lf, path);
         lf.TransferOwnership();
         ErrorHelper.CheckVoid();
     }
+    public static void SinkNdjson(LazyFrameHandle lf, string path)
+    {
+        NativeBindings.pl_lazy_sink_ndjson(lf, path);
+        lf.TransferOwnership();
+        ErrorHelper.CheckVoid();
+    }
     public static DataFrameHandle ReadIpc(string path)
     {
         if (!File.Exists(path)) throw new FileNotFoundException($"IPC file not found: {path}");
         return ErrorHelper.Check(NativeBindings.pl_read_ipc(path));
     }
+    public static DataFrameHandle ReadIpc(string path, bool memoryMap)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"IPC file not found: {path}");
+        return ErrorHelper.Check(NativeBindings.pl_read_ipc_with_args(path, memoryMap));
+    }
 
     public static LazyFrameHandle ScanIpc(string path)
     {
         if (!File.Exists(path)) throw new FileNotFoundException($"IPC file not found: {path}");
         return ErrorHelper.Check(NativeBindings.pl_scan_ipc(path));
     }
+    public static LazyFrameHandle ScanIpc(string[] paths, string? includeFilePaths)
+    {
+        return UseUtf8StringArray(paths, ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_scan_ipc_multi(ptrs, (UIntPtr)ptrs.Length, includeFilePaths)));
+    }
 
     public static void SinkIpc(LazyFrameHandle lf, string path)
     {
@@ -146,6 +427,12 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         ErrorHelper.CheckVoid();
     }
+    public static void SinkIpc(LazyFrameHandle lf, string path, PlIpcCompression compression)
+    {
+        NativeBindings.pl_lazy_sink_ipc_with_compression(lf, path, compression);
+        lf.TransferOwnership();
+        ErrorHelper.CheckVoid();
+    }
     public static unsafe DataFrameHandle FromArrow(RecordBatch batch)
     {
         // 1. 在栈上分配 C 结构体 (避免 GC 压力)
@@ -177,6 +464,15 @@ public static partial class PolarsWrapper
         var handle = NativeBindings.pl_lazy_frame_scan_stream(schema, callback,destroyCallback, userData);
         return ErrorHelper.Check(handle);
     }
+    public static unsafe LazyFrameHandle LazyFrameScanStreamPushdown(
+        CArrowSchema* schema,
+        delegate* unmanaged[Cdecl]<void*, byte**, UIntPtr, long, Arrow.CArrowArrayStream*> callback,
+        delegate* unmanaged[Cdecl]<void*, void> destroyCallback,
+        void* userData)
+    {
+        var handle = NativeBindings.pl_lazy_frame_scan_stream_pushdown(schema, callback, destroyCallback, userData);
+        return ErrorHelper.Check(handle);
+    }
     public static void ExportBatches(DataFrameHandle dfHandle, Action<Apache.Arrow.RecordBatch> onBatchReceived)
     {
         // 复用 PrepareSink 逻辑
@@ -214,4 +510,66 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         return ErrorHelper.Check(handle);
     }
+
+    /// <summary>
+    /// 流式执行并依次回调: 先交付输出 Schema，再按行序交付每个 batch，最后交付总行数。
+    /// 回调是同步的 (在调用线程上)，所以委托只需在本方法内保活。
+    /// </summary>
+    public static unsafe void SinkCallback(
+        LazyFrameHandle lf,
+        Action<Schema> onSchema,
+        Action<RecordBatch> onBatch,
+        Action<ulong> onFinish)
+    {
+        Schema? schema = null;
+
+        static int Fail(byte* errorMsg, Exception ex)
+        {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(ex.Message);
+            int len = Math.Min(bytes.Length, 1023);
+            Marshal.Copy(bytes, 0, (IntPtr)errorMsg, len);
+            errorMsg[len] = 0;
+            return 1;
+        }
+
+        ArrowStreamInterop.SinkSchemaCallback schemaCallback = (_, cSchema, errorMsg) =>
+        {
+            try
+            {
+                // 只复制，结构体由 Rust 释放
+                schema = CArrowSchemaImporter.ImportSchema(cSchema);
+                onSchema(schema);
+                return 0;
+            }
+            catch (Exception ex) { return Fail(errorMsg, ex); }
+        };
+        ArrowStreamInterop.SinkBatchCallback batchCallback = (_, cArray, errorMsg) =>
+        {
+            try
+            {
+                // ImportRecordBatch 接管数组内容 (release 置空)，结构体本身仍由 Rust 释放
+                var batch = CArrowArrayImporter.ImportRecordBatch(cArray, schema!);
+                onBatch(batch);
+                return 0;
+            }
+            catch (Exception ex) { return Fail(errorMsg, ex); }
+        };
+        ArrowStreamInterop.SinkFinishCallback finishCallback = (_, rowCount, errorMsg) =>
+        {
+            try
+            {
+                onFinish(rowCount);
+                return 0;
+            }
+            catch (Exception ex) { return Fail(errorMsg, ex); }
+        };
+
+        NativeBindings.pl_lazy_sink_callback(lf, schemaCallback, batchCallback, finishCallback, IntPtr.Zero);
+        lf.TransferOwnership();
+
+        GC.KeepAlive(schemaCallback);
+        GC.KeepAlive(batchCallback);
+        GC.KeepAlive(finishCallback);
+        ErrorHelper.CheckVoid();
+    }
 }
\ No newline at end of file
@@ -1,3 +1,7 @@
+using System.Runtime.InteropServices;
+using Apache.Arrow;
+using Apache.Arrow.C;
+
 namespace Polars.NET.Core;
 
 public static partial class PolarsWrapper
@@ -49,6 +53,22 @@ public static partial class PolarsWrapper
         IntPtr ptr = NativeBindings.pl_lazy_explain(lf, optimized);
         return ErrorHelper.CheckString(ptr);
     }
+    public static string ExplainTree(LazyFrameHandle lf, bool optimized)
+    {
+        IntPtr ptr = NativeBindings.pl_lazy_explain_tree(lf, optimized);
+        return ErrorHelper.CheckString(ptr);
+    }
+    public static string ToDot(LazyFrameHandle lf, bool optimized)
+    {
+        IntPtr ptr = NativeBindings.pl_lazy_to_dot(lf, optimized);
+        return ErrorHelper.CheckString(ptr);
+    }
+    // 返回 JSON: {"iterations", "rows", "wall_ms": [...], "min_ms", "mean_ms", "max_ms", "peak_rss_bytes" | null}
+    public static string BenchCollect(LazyFrameHandle lf, ulong iterations)
+    {
+        IntPtr ptr = NativeBindings.pl_bench_collect(lf, (UIntPtr)iterations);
+        return ErrorHelper.CheckString(ptr);
+    }
     public static LazyFrameHandle LazySelect(LazyFrameHandle lf, ExprHandle[] exprs)
     {
         var rawExprs = HandlesToPtrs(exprs);
@@ -63,6 +83,71 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         return ErrorHelper.Check(df);
     }
+    public static (DataFrameHandle Result, DataFrameHandle Timings) LazyProfile(LazyFrameHandle lf)
+    {
+        NativeBindings.pl_lazy_profile(lf, out var result, out var timings);
+        lf.TransferOwnership();
+        ErrorHelper.CheckVoid();
+        return (result, timings);
+    }
+    public static ulong LazyCount(LazyFrameHandle lf)
+    {
+        NativeBindings.pl_lazy_count(lf, out var count);
+        lf.TransferOwnership();
+        ErrorHelper.CheckVoid();
+        return count;
+    }
+    public static BatchIterHandle LazyCollectBatches(LazyFrameHandle lf, ulong batchSize)
+    {
+        var h = NativeBindings.pl_lazy_collect_batches(lf, (UIntPtr)batchSize);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    // 返回 null 表示已经拉完
+    public static unsafe RecordBatch? BatchIterNext(BatchIterHandle iter)
+    {
+        var array = CArrowArray.Create();
+        var schema = CArrowSchema.Create();
+        bool ownershipTransferred = false;
+        try
+        {
+            if (!NativeBindings.pl_batch_iter_next(iter, array, schema))
+            {
+                ErrorHelper.CheckVoid();
+                return null;
+            }
+
+            var managedSchema = CArrowSchemaImporter.ImportSchema(schema);
+            var batch = CArrowArrayImporter.ImportRecordBatch(array, managedSchema);
+            ownershipTransferred = true;
+            return batch;
+        }
+        finally
+        {
+            // 导入成功后 array 归 batch 管，schema 是拷贝，总是释放
+            if (!ownershipTransferred)
+            {
+                CArrowArray.Free(array);
+            }
+            CArrowSchema.Free(schema);
+        }
+    }
+    public static DataFrameHandle[] LazyCollectAll(LazyFrameHandle[] lfs)
+    {
+        if (lfs.Length == 0) return Array.Empty<DataFrameHandle>();
+
+        var ptrs = HandlesToPtrs(lfs); // 转移所有权
+        var outPtrs = new IntPtr[ptrs.Length];
+        NativeBindings.pl_lazy_collect_all(ptrs, (UIntPtr)ptrs.Length, outPtrs);
+        ErrorHelper.CheckVoid();
+        return outPtrs.Select(p => new DataFrameHandle(p)).ToArray();
+    }
+    public static DataFrameHandle LazyFetch(LazyFrameHandle lf, ulong nRows)
+    {
+        var df = NativeBindings.pl_lazy_fetch(lf, nRows);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(df);
+    }
     public static LazyFrameHandle LazyFilter(LazyFrameHandle lf, ExprHandle expr)
     {
         var h = NativeBindings.pl_lazy_filter(lf, expr);
@@ -70,6 +155,20 @@ public static partial class PolarsWrapper
         expr.TransferOwnership(); 
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazyFillNull(LazyFrameHandle lf, ExprHandle value)
+    {
+        var h = NativeBindings.pl_lazy_fill_null(lf, value);
+        lf.TransferOwnership();
+        value.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyFillNan(LazyFrameHandle lf, ExprHandle value)
+    {
+        var h = NativeBindings.pl_lazy_fill_nan(lf, value);
+        lf.TransferOwnership();
+        value.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle LazySort(LazyFrameHandle lf, ExprHandle expr, bool desc)
     {
         var h = NativeBindings.pl_lazy_sort(lf, expr, desc);
@@ -104,6 +203,29 @@ public static partial class PolarsWrapper
             }
         }
     }
+    public static LazyFrameHandle LazySortExprs(
+        LazyFrameHandle lf, ExprHandle[] exprs,
+        bool[]? descending, bool[]? nullsLast,
+        bool maintainOrder, bool multithreaded)
+    {
+        var exprPtrs = HandlesToPtrs(exprs);
+        unsafe
+        {
+            // null 数组 fixed 出来是空指针，Rust 端按全部 false 处理
+            fixed (bool* descPtr = descending)
+            fixed (bool* nullsPtr = nullsLast)
+            {
+                var h = NativeBindings.pl_lazy_sort_exprs(
+                    lf,
+                    exprPtrs, (UIntPtr)exprPtrs.Length,
+                    descPtr, nullsPtr,
+                    maintainOrder, multithreaded
+                );
+                lf.TransferOwnership();
+                return ErrorHelper.Check(h);
+            }
+        }
+    }
 
     public static LazyFrameHandle LazyLimit(LazyFrameHandle lf, uint n)
     {
@@ -111,6 +233,31 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazySlice(LazyFrameHandle lf, long offset, uint len)
+    {
+        var h = NativeBindings.pl_lazy_slice(lf, offset, len);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyShift(LazyFrameHandle lf, long n, ExprHandle? fill)
+    {
+        var h = NativeBindings.pl_lazy_shift(lf, n, fill ?? new ExprHandle());
+        lf.TransferOwnership();
+        fill?.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyReverse(LazyFrameHandle lf)
+    {
+        var h = NativeBindings.pl_lazy_reverse(lf);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyGatherEvery(LazyFrameHandle lf, ulong n, ulong offset)
+    {
+        var h = NativeBindings.pl_lazy_gather_every(lf, (UIntPtr)n, (UIntPtr)offset);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle LazyGroupByAgg(LazyFrameHandle lf, ExprHandle[] keys, ExprHandle[] aggs)
     {
         var keyPtrs = HandlesToPtrs(keys);
@@ -126,6 +273,19 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazyGroupByAgg(LazyFrameHandle lf, ExprHandle[] keys, ExprHandle[] aggs, bool maintainOrder)
+    {
+        var keyPtrs = HandlesToPtrs(keys);
+        var aggPtrs = HandlesToPtrs(aggs);
+        var h = NativeBindings.pl_lazy_groupby_agg_with_args(
+            lf,
+            keyPtrs, (UIntPtr)keyPtrs.Length,
+            aggPtrs, (UIntPtr)aggPtrs.Length,
+            maintainOrder
+        );
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     /// <summary>
     /// Wrapper for Lazy GroupBy Dynamic.
     /// </summary>
@@ -161,6 +321,30 @@ public static partial class PolarsWrapper
 
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazyRolling(
+        LazyFrameHandle lf,
+        string indexCol,
+        string period,
+        string? offset,
+        PlClosedWindow closedWindow,
+        ExprHandle[] keys,
+        ExprHandle[] aggs)
+    {
+        var keyPtrs = HandlesToPtrs(keys);
+        var aggPtrs = HandlesToPtrs(aggs);
+        var h = NativeBindings.pl_lazy_rolling(
+            lf,
+            indexCol,
+            period,
+            offset,
+            (int)closedWindow,
+            keyPtrs, (UIntPtr)keys.Length,
+            aggPtrs, (UIntPtr)aggs.Length
+        );
+        lf.TransferOwnership();
+
+        return ErrorHelper.Check(h);
+    }
     
     public static LazyFrameHandle LazyWithColumns(LazyFrameHandle lf, ExprHandle[] handles)
     {
@@ -169,6 +353,19 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazyWithRollingAggregates(
+        LazyFrameHandle lf,
+        SelectorHandle selector,
+        PlRollingOp[] ops,
+        string window,
+        string? suffix)
+    {
+        var codes = ops.Select(o => (int)o).ToArray();
+        var h = NativeBindings.pl_lazy_with_rolling_aggregates(lf, selector, codes, (UIntPtr)codes.Length, window, suffix);
+        lf.TransferOwnership();
+        selector.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle LazyExplode(LazyFrameHandle lf, ExprHandle[] exprs)
     {
         var raw = HandlesToPtrs(exprs);
@@ -176,6 +373,82 @@ public static partial class PolarsWrapper
         lf.TransferOwnership(); // 链式调用消耗旧 LF
         return ErrorHelper.Check(newLf);
     }
+    public static LazyFrameHandle LazyCastColumns(LazyFrameHandle lf, string[] names, DataTypeHandle[] dtypes, bool strict)
+    {
+        if (names.Length != dtypes.Length)
+            throw new ArgumentException("Names and Types must have same length");
+
+        // dtypes 只借用，锁定即可
+        using var locker = new SafeHandleLock<DataTypeHandle>(dtypes);
+        var typePtrs = locker.Pointers;
+
+        return UseUtf8StringArray(names, namePtrs =>
+        {
+            var h = NativeBindings.pl_lazy_cast_columns(lf, namePtrs, typePtrs, (UIntPtr)names.Length, strict);
+            lf.TransferOwnership();
+            return ErrorHelper.Check(h);
+        });
+    }
+    public static LazyFrameHandle LazyRename(LazyFrameHandle lf, string[] oldNames, string[] newNames, bool strict)
+    {
+        if (oldNames.Length != newNames.Length)
+            throw new ArgumentException("Old and new names must have same length");
+
+        return UseUtf8StringArray(oldNames, oldPtrs =>
+            UseUtf8StringArray(newNames, newPtrs =>
+            {
+                var h = NativeBindings.pl_lazy_rename(lf, oldPtrs, newPtrs, (UIntPtr)oldNames.Length, strict);
+                lf.TransferOwnership();
+                return ErrorHelper.Check(h);
+            })
+        );
+    }
+    public static LazyFrameHandle LazyDrop(LazyFrameHandle lf, string[] names, bool strict)
+    {
+        return UseUtf8StringArray(names, ptrs =>
+        {
+            var h = NativeBindings.pl_lazy_drop(lf, ptrs, (UIntPtr)names.Length, strict);
+            lf.TransferOwnership();
+            return ErrorHelper.Check(h);
+        });
+    }
+    public static LazyFrameHandle LazyDrop(LazyFrameHandle lf, SelectorHandle selector)
+    {
+        var h = NativeBindings.pl_lazy_drop_selector(lf, selector);
+        lf.TransferOwnership();
+        selector.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyUnnest(LazyFrameHandle lf, string[] names)
+    {
+        return UseUtf8StringArray(names, ptrs =>
+        {
+            var h = NativeBindings.pl_lazy_unnest(lf, ptrs, (UIntPtr)names.Length);
+            lf.TransferOwnership();
+            return ErrorHelper.Check(h);
+        });
+    }
+    public static LazyFrameHandle LazyUnnest(LazyFrameHandle lf, SelectorHandle selector)
+    {
+        var h = NativeBindings.pl_lazy_unnest_selector(lf, selector);
+        lf.TransferOwnership();
+        selector.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyUnique(LazyFrameHandle lf, SelectorHandle? subset, PlUniqueKeep keep, bool maintainOrder)
+    {
+        var h = NativeBindings.pl_lazy_unique(lf, subset ?? new SelectorHandle(), keep, maintainOrder);
+        lf.TransferOwnership();
+        subset?.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyWithContext(LazyFrameHandle lf, LazyFrameHandle[] contexts)
+    {
+        var ptrs = HandlesToPtrs(contexts); // 转移所有权
+        var h = NativeBindings.pl_lazy_with_context(lf, ptrs, (UIntPtr)ptrs.Length);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle LazyUnpivot(LazyFrameHandle lf, string[] index, string[] on, string? variableName, string? valueName)
     {
         return UseUtf8StringArray(index, iPtrs =>
@@ -203,6 +476,23 @@ public static partial class PolarsWrapper
         }
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazyConcat(
+        LazyFrameHandle[] handles,
+        PlConcatType how,
+        bool rechunk,
+        bool parallel,
+        bool toSupertypes,
+        bool maintainOrder)
+    {
+        var ptrs = HandlesToPtrs(handles);
+        var h = NativeBindings.pl_lazy_concat_with_args(
+            ptrs, (UIntPtr)ptrs.Length, (int)how, rechunk, parallel, toSupertypes, maintainOrder);
+        foreach (var handle in handles)
+        {
+            handle.TransferOwnership();
+        }
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle Join(
         LazyFrameHandle left, LazyFrameHandle right, 
         ExprHandle[] leftOn, ExprHandle[] rightOn, 
@@ -224,6 +514,93 @@ public static partial class PolarsWrapper
         
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle JoinAllowParallel(
+        LazyFrameHandle left, LazyFrameHandle right, 
+        ExprHandle[] leftOn, ExprHandle[] rightOn, 
+        PlJoinType how,
+        bool allowParallel)
+    {
+        var lPtrs = HandlesToPtrs(leftOn);
+        var rPtrs = HandlesToPtrs(rightOn);
+        
+        var h = NativeBindings.pl_lazy_join_allow_parallel(
+            left, right, 
+            lPtrs, (UIntPtr)lPtrs.Length, 
+            rPtrs, (UIntPtr)rPtrs.Length, 
+            how,
+            allowParallel
+        );
+
+        left.TransferOwnership();
+        right.TransferOwnership();
+        
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle JoinWithArgs(
+        LazyFrameHandle left, LazyFrameHandle right, 
+        ExprHandle[] leftOn, ExprHandle[] rightOn, 
+        PlJoinType how,
+        string? suffix,
+        PlJoinValidation validation,
+        bool joinNulls,
+        PlJoinCoalesce coalesce)
+    {
+        var lPtrs = HandlesToPtrs(leftOn);
+        var rPtrs = HandlesToPtrs(rightOn);
+        
+        var h = NativeBindings.pl_lazy_join_with_args(
+            left, right, 
+            lPtrs, (UIntPtr)lPtrs.Length, 
+            rPtrs, (UIntPtr)rPtrs.Length, 
+            how, suffix, validation, joinNulls, coalesce
+        );
+
+        left.TransferOwnership();
+        right.TransferOwnership();
+        
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle JoinOn(
+        LazyFrameHandle left, LazyFrameHandle right,
+        ExprHandle[] on,
+        PlJoinType how,
+        string? suffix,
+        PlJoinValidation validation,
+        bool joinNulls,
+        PlJoinCoalesce coalesce)
+    {
+        var ptrs = HandlesToPtrs(on);
+        var h = NativeBindings.pl_lazy_join_on(
+            left, right,
+            ptrs, (UIntPtr)ptrs.Length,
+            how, suffix, validation, joinNulls, coalesce
+        );
+        left.TransferOwnership();
+        right.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle JoinWhere(LazyFrameHandle left, LazyFrameHandle right, ExprHandle[] predicates)
+    {
+        var ptrs = HandlesToPtrs(predicates);
+        var h = NativeBindings.pl_lazy_join_where(left, right, ptrs, (UIntPtr)ptrs.Length);
+        left.TransferOwnership();
+        right.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle CrossJoin(LazyFrameHandle left, LazyFrameHandle right, string? suffix)
+    {
+        var h = NativeBindings.pl_lazy_cross_join(left, right, suffix);
+        left.TransferOwnership();
+        right.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle MergeSorted(LazyFrameHandle left, LazyFrameHandle right, string key)
+    {
+        var h = NativeBindings.pl_lazy_merge_sorted(left, right, key);
+        left.TransferOwnership();
+        right.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle JoinAsOf(
         LazyFrameHandle left, LazyFrameHandle right,
         ExprHandle leftOn, ExprHandle rightOn,
@@ -256,13 +633,58 @@ public static partial class PolarsWrapper
     // [新增] Streaming Collect
     public static DataFrameHandle CollectStreaming(LazyFrameHandle lf)
     {
-        var df = NativeBindings.pl_lazy_collect_streaming(lf);
+        return CollectWithEngine(lf, PlEngine.Streaming);
+    }
+    public static DataFrameHandle CollectWithEngine(LazyFrameHandle lf, PlEngine engine)
+    {
+        var df = NativeBindings.pl_lazy_collect_engine(lf, engine);
         lf.TransferOwnership();
         return ErrorHelper.Check(df);
     }
+    public static LazyFrameHandle CollectSpooled(LazyFrameHandle lf, string spoolDir)
+    {
+        var h = NativeBindings.pl_lazy_collect_spooled(lf, spoolDir);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    // 静态委托，生命周期覆盖所有后台查询
+    private static readonly QueryCompleteCallback s_queryCompleteDelegate = QueryCompleteTrampoline;
+
+    private static void QueryCompleteTrampoline(IntPtr userData, IntPtr df, IntPtr error)
+    {
+        var gcHandle = GCHandle.FromIntPtr(userData);
+        var tcs = (TaskCompletionSource<DataFrameHandle>)gcHandle.Target!;
+        gcHandle.Free();
+
+        if (df != IntPtr.Zero)
+        {
+            tcs.TrySetResult(new DataFrameHandle(df));
+        }
+        else
+        {
+            string msg = Marshal.PtrToStringUTF8(error) ?? "Unknown Rust Error";
+            tcs.TrySetException(new Exception($"[Polars Async Error] {msg}"));
+        }
+    }
+
+    // 查询在 Rust 后台线程上执行，不占用线程池线程
     public static Task<DataFrameHandle> LazyCollectAsync(LazyFrameHandle handle)
     {        
-        return Task.Run(() => LazyCollect(handle));
+        // 续体不在 Rust 线程上同步执行
+        var tcs = new TaskCompletionSource<DataFrameHandle>(TaskCreationOptions.RunContinuationsAsynchronously);
+        var gcHandle = GCHandle.Alloc(tcs);
+        try
+        {
+            using var query = NativeBindings.pl_lazy_collect_async(handle, s_queryCompleteDelegate, GCHandle.ToIntPtr(gcHandle));
+            handle.TransferOwnership();
+            ErrorHelper.Check(query);
+        }
+        catch
+        {
+            if (gcHandle.IsAllocated) gcHandle.Free();
+            throw;
+        }
+        return tcs.Task;
     }
     // --- Clone Ops ---
     public static LazyFrameHandle LazyClone(LazyFrameHandle lf)
@@ -270,4 +692,17 @@ public static partial class PolarsWrapper
         // 注意：这里不需要 Invalidate lf，因为 Rust 侧只是借用
         return ErrorHelper.Check(NativeBindings.pl_lazy_clone(lf));
     }
+    // --- Shared Ops ---
+    public static SharedLazyFrameHandle LazyShare(LazyFrameHandle lf)
+    {
+        var h = NativeBindings.pl_lazy_share(lf);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static SharedLazyFrameHandle LazyShareRetain(SharedLazyFrameHandle shared)
+        => ErrorHelper.Check(NativeBindings.pl_lazy_share_retain(shared));
+    public static LazyFrameHandle LazyShareClonePlan(SharedLazyFrameHandle shared)
+        => ErrorHelper.Check(NativeBindings.pl_lazy_share_clone_plan(shared));
+    public static ulong LazyShareRefCount(SharedLazyFrameHandle shared)
+        => (ulong)NativeBindings.pl_lazy_share_ref_count(shared);
 }
\ No newline at end of file
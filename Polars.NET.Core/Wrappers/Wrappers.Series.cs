@@ -146,6 +146,18 @@ public static partial class PolarsWrapper
     
     public static void SeriesRename(SeriesHandle h, string name) => NativeBindings.pl_series_rename(h, name);
 
+    public static string? SeriesExtensionName(SeriesHandle h)
+    {
+        var ptr = NativeBindings.pl_series_extension_name(h);
+        return ptr == IntPtr.Zero ? null : ErrorHelper.CheckString(ptr);
+    }
+
+    public static string? SeriesExtensionMetadata(SeriesHandle h)
+    {
+        var ptr = NativeBindings.pl_series_extension_metadata(h);
+        return ptr == IntPtr.Zero ? null : ErrorHelper.CheckString(ptr);
+    }
+
     // --- DataFrame Conversion ---
     public static DataFrameHandle SeriesToFrame(SeriesHandle h) 
     {
@@ -325,6 +337,7 @@ public static partial class PolarsWrapper
     public static SeriesHandle SeriesIsNotNan(SeriesHandle s) => ErrorHelper.Check(NativeBindings.pl_series_is_not_nan(s));
     public static SeriesHandle SeriesIsFinite(SeriesHandle s) => ErrorHelper.Check(NativeBindings.pl_series_is_finite(s));
     public static SeriesHandle SeriesIsInfinite(SeriesHandle s) => ErrorHelper.Check(NativeBindings.pl_series_is_infinite(s));
+    public static SeriesHandle SeriesCatGetCategories(SeriesHandle s) => ErrorHelper.Check(NativeBindings.pl_series_cat_get_categories(s));
     public static long SeriesNullCount(SeriesHandle s)
     {
         return (long)NativeBindings.pl_series_null_count(s);
@@ -31,4 +31,14 @@ public static partial class PolarsWrapper
             );
         });
     }
+
+    // 将所有已注册表落盘为 IPC 文件，并写入 catalog.json 清单
+    public static void SqlSnapshot(SqlContextHandle ctx, string dir)
+    {
+        NativeBindings.pl_sql_context_snapshot(ctx, dir);
+        ErrorHelper.CheckVoid();
+    }
+
+    public static SqlContextHandle SqlRestore(string dir)
+        => ErrorHelper.Check(NativeBindings.pl_sql_context_restore(dir));
 }
\ No newline at end of file
@@ -29,9 +29,10 @@ public static partial class PolarsWrapper
     //     return Map(expr, func);
     // }
 
-    public static ExprHandle Map(ExprHandle expr, Func<IArrowArray, IArrowArray> func, DataTypeHandle outputType)
+    // 把 Arrow -> Arrow 的 C# 委托包装成 Native 回调 (map / map_elements 共用)
+    private static unsafe UdfCallback CreateUdfCallback(Func<IArrowArray, IArrowArray> func)
     {
-        unsafe int Trampoline(CArrowArray* inArr, CArrowSchema* inSch, CArrowArray* outArr, CArrowSchema* outSch, byte* msgBuf)
+        int Trampoline(CArrowArray* inArr, CArrowSchema* inSch, CArrowArray* outArr, CArrowSchema* outSch, byte* msgBuf)
         {
             try 
             {
@@ -79,9 +80,14 @@ public static partial class PolarsWrapper
             }
         }
 
+        return Trampoline;
+    }
+
+    public static ExprHandle Map(ExprHandle expr, Func<IArrowArray, IArrowArray> func, DataTypeHandle outputType)
+    {
         unsafe 
         {
-            UdfCallback callback = Trampoline;
+            UdfCallback callback = CreateUdfCallback(func);
             GCHandle gcHandle = GCHandle.Alloc(callback);
             IntPtr userData = GCHandle.ToIntPtr(gcHandle);
 
@@ -104,4 +110,150 @@ public static partial class PolarsWrapper
             }
         }
     }
+
+    // 逐 chunk 回调，输出长度必须与输入一致
+    public static ExprHandle MapElements(ExprHandle expr, Func<IArrowArray, IArrowArray> func, DataTypeHandle outputType)
+    {
+        UdfCallback callback = CreateUdfCallback(func);
+        GCHandle gcHandle = GCHandle.Alloc(callback);
+        IntPtr userData = GCHandle.ToIntPtr(gcHandle);
+
+        try
+        {
+            var h = NativeBindings.pl_expr_map_elements(expr, callback, s_cleanupDelegate, userData, outputType);
+            expr.TransferOwnership();
+            return ErrorHelper.Check(h);
+        }
+        catch
+        {
+            if (gcHandle.IsAllocated) gcHandle.Free();
+            throw;
+        }
+    }
+
+    // 整批回调 (输入先 rechunk)；isElementwise = false 时 group_by 里按组调用
+    public static ExprHandle MapBatches(ExprHandle expr, Func<IArrowArray, IArrowArray> func, DataTypeHandle outputType, bool isElementwise)
+    {
+        UdfCallback callback = CreateUdfCallback(func);
+        GCHandle gcHandle = GCHandle.Alloc(callback);
+        IntPtr userData = GCHandle.ToIntPtr(gcHandle);
+
+        try
+        {
+            var h = NativeBindings.pl_expr_map_batches(expr, callback, outputType, isElementwise, s_cleanupDelegate, userData);
+            expr.TransferOwnership();
+            return ErrorHelper.Check(h);
+        }
+        catch
+        {
+            if (gcHandle.IsAllocated) gcHandle.Free();
+            throw;
+        }
+    }
+
+    // 整个 DataFrame 以 RecordBatch 交给 C#，返回的 RecordBatch 接回计划
+    // outputSchema 为 null 时表示输出 schema 与输入相同
+    public static LazyFrameHandle LazyMapBatches(
+        LazyFrameHandle lf,
+        Func<RecordBatch, RecordBatch> func,
+        Dictionary<string, DataTypeHandle>? outputSchema,
+        bool validateOutput)
+    {
+        unsafe int Trampoline(CArrowArray* arr, CArrowSchema* sch, Arrow.CArrowArrayStream* outStream, byte* errorMsg)
+        {
+            try
+            {
+                // 1. 导入 (移走 array 内容；schema 只复制，结构体由 Rust 回收)
+                var schema = CArrowSchemaImporter.ImportSchema(sch);
+                var batch = CArrowArrayImporter.ImportRecordBatch(arr, schema);
+
+                // 2. 执行用户逻辑
+                var result = func(batch);
+
+                // 3. 导出为单批次的流，写入 Rust 提供的结构体
+                var exporter = new Arrow.ArrowStreamExporter(new[] { result }.AsEnumerable().GetEnumerator(), result.Schema);
+                exporter.Export(outStream);
+                return 0;
+            }
+            catch (Exception ex)
+            {
+                byte[] bytes = System.Text.Encoding.UTF8.GetBytes(ex.Message);
+                int copyLen = Math.Min(bytes.Length, 1023);
+                Marshal.Copy(bytes, 0, (IntPtr)errorMsg, copyLen);
+                errorMsg[copyLen] = 0;
+                return 1;
+            }
+        }
+
+        TransformCallback callback;
+        unsafe
+        {
+            callback = Trampoline;
+        }
+        GCHandle gcHandle = GCHandle.Alloc(callback);
+        IntPtr userData = GCHandle.ToIntPtr(gcHandle);
+
+        try
+        {
+            return WithSchemaHandle(outputSchema, schemaHandle =>
+            {
+                var h = NativeBindings.pl_lazy_map_batches_transform(
+                    lf, callback, s_cleanupDelegate, userData, schemaHandle, validateOutput);
+                lf.TransferOwnership();
+                return ErrorHelper.Check(h);
+            });
+        }
+        catch
+        {
+            if (gcHandle.IsAllocated) gcHandle.Free();
+            throw;
+        }
+    }
+
+    public static ExprHandle NameMap(ExprHandle expr, Func<string, string> func)
+    {
+        unsafe int Trampoline(byte* name, byte* outBuf, UIntPtr bufLen)
+        {
+            int maxLen = (int)bufLen - 1;
+            try
+            {
+                string input = Marshal.PtrToStringUTF8((IntPtr)name) ?? "";
+                byte[] bytes = System.Text.Encoding.UTF8.GetBytes(func(input));
+                // 不截断列名，超长直接报错
+                if (bytes.Length > maxLen)
+                    throw new ArgumentException($"Mapped column name exceeds {maxLen} bytes.");
+
+                Marshal.Copy(bytes, 0, (IntPtr)outBuf, bytes.Length);
+                outBuf[bytes.Length] = 0;
+                return 0;
+            }
+            catch (Exception ex)
+            {
+                byte[] bytes = System.Text.Encoding.UTF8.GetBytes(ex.Message);
+                int copyLen = Math.Min(bytes.Length, maxLen);
+                Marshal.Copy(bytes, 0, (IntPtr)outBuf, copyLen);
+                outBuf[copyLen] = 0;
+                return 1;
+            }
+        }
+
+        unsafe
+        {
+            NameMapCallback callback = Trampoline;
+            GCHandle gcHandle = GCHandle.Alloc(callback);
+            IntPtr userData = GCHandle.ToIntPtr(gcHandle);
+
+            try
+            {
+                var h = NativeBindings.pl_expr_name_map(expr, callback, s_cleanupDelegate, userData);
+                expr.TransferOwnership();
+                return ErrorHelper.Check(h);
+            }
+            catch
+            {
+                if (gcHandle.IsAllocated) gcHandle.Free();
+                throw;
+            }
+        }
+    }
 }
\ No newline at end of file
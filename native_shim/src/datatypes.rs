@@ -75,6 +75,7 @@ define_pl_datatype_kind! {
         // Categorical 逻辑较复杂，直接内联你的构造代码
         Categorical = 21 <=> DataType::Categorical(_, _) => DataType::Categorical(Categories::random(PlSmallStr::EMPTY, CategoricalPhysical::U32),Categories::random(PlSmallStr::EMPTY, CategoricalPhysical::U32).mapping()),
         Decimal     = 22 <=> DataType::Decimal(_, _)   => DataType::Decimal(None, None),
+        Array       = 23 <=> DataType::Array(_, _)     => DataType::Array(Box::new(DataType::Null), 0),
     }
 }
 // --- Constructors ---
@@ -141,6 +142,15 @@ pub extern "C" fn pl_datatype_new_list(inner_ptr: *mut DataTypeContext) -> *mut
     Box::into_raw(Box::new(DataTypeContext { dtype: list_dtype }))
 }
 
+// 定长数组 Array(inner, width)，inner 同样只 Clone 不消费
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_datatype_new_array(inner_ptr: *mut DataTypeContext, width: usize) -> *mut DataTypeContext {
+    assert!(!inner_ptr.is_null());
+    let inner_ctx = unsafe { &*inner_ptr };
+    let array_dtype = DataType::Array(Box::new(inner_ctx.dtype.clone()), width);
+    Box::into_raw(Box::new(DataTypeContext { dtype: array_dtype }))
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_datatype_new_duration(unit: i32) -> *mut DataTypeContext {
     let time_unit = match unit {
@@ -224,6 +234,10 @@ fn dtype_to_string_verbose(dt: &DataType) -> String {
         DataType::List(inner) => {
             format!("list[{}]", dtype_to_string_verbose(inner))
         },
+
+        DataType::Array(inner, width) => {
+            format!("array[{}, {}]", dtype_to_string_verbose(inner), width)
+        },
         
         // 其他类型：使用 Polars 默认的 Display
         _ => dt.to_string()
@@ -360,11 +374,11 @@ pub unsafe extern "C" fn pl_datatype_get_inner(ptr: *mut DataType) -> *mut DataT
         if ptr.is_null() { return std::ptr::null_mut(); }
         let dtype = unsafe {&*ptr};
         match dtype {
-            DataType::List(inner) => {
+            DataType::List(inner) | DataType::Array(inner, _) => {
                 // Clone inner type and box it
                 Box::into_raw(Box::new(*inner.clone())) 
             },
-            _ => std::ptr::null_mut() // Not a list
+            _ => std::ptr::null_mut() // Not a list / array
         }
     }));
     result.unwrap_or(std::ptr::null_mut())
@@ -402,4 +416,184 @@ pub unsafe extern "C" fn pl_datatype_get_struct_field(
             unsafe {*type_out = Box::into_raw(Box::new(field.dtype.clone()))};
         }
     }
-}
\ No newline at end of file
+}
+// ==========================================
+// 类型转换矩阵
+// ==========================================
+// 只看 Kind 不看参数 (Datetime 单位/时区、Decimal 精度、List 内部类型等)，
+// 供 C# 强类型层在调用 cast 之前判断能否转换、是否可能丢失信息。
+// Lossless 必须对目标 Kind 的所有参数 (例如任意 Decimal 精度、任意时间单位) 和所有取值都成立，
+// 即 cast 过去再 cast 回来能得到原值；否则只要 Polars 接受这个 cast 就是 Lossy。
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CastSupport {
+    Unsupported = 0,
+    Lossless = 1,
+    Lossy = 2,
+}
+
+// 整数类型 -> (是否有符号, 位宽)
+fn int_layout(kind: PlDataTypeKind) -> Option<(bool, u32)> {
+    use PlDataTypeKind::*;
+    match kind {
+        Int8 => Some((true, 8)),
+        Int16 => Some((true, 16)),
+        Int32 => Some((true, 32)),
+        Int64 => Some((true, 64)),
+        UInt8 => Some((false, 8)),
+        UInt16 => Some((false, 16)),
+        UInt32 => Some((false, 32)),
+        UInt64 => Some((false, 64)),
+        _ => None,
+    }
+}
+
+// 浮点数尾数位宽 (能精确表示的整数位数)
+fn float_mantissa_bits(kind: PlDataTypeKind) -> Option<u32> {
+    match kind {
+        PlDataTypeKind::Float32 => Some(24),
+        PlDataTypeKind::Float64 => Some(53),
+        _ => None,
+    }
+}
+
+fn is_temporal(kind: PlDataTypeKind) -> bool {
+    use PlDataTypeKind::*;
+    matches!(kind, Date | Datetime | Time | Duration)
+}
+
+// 时间类型按物理整数看待时的取值范围 (是否有符号, 有效位宽)
+// Time 是一天内的纳秒数，恒为非负且小于 2^47
+fn temporal_layout(kind: PlDataTypeKind) -> Option<(bool, u32)> {
+    use PlDataTypeKind::*;
+    match kind {
+        Date => Some((true, 32)),
+        Datetime | Duration => Some((true, 64)),
+        Time => Some((false, 47)),
+        _ => None,
+    }
+}
+
+// 有符号 -> 无符号 永远可能丢负数；其余按位宽判断
+fn int_widening((fs, fb): (bool, u32), (ts, tb): (bool, u32)) -> bool {
+    match (fs, ts) {
+        (true, false) => false,
+        (false, true) => tb > fb,
+        _ => tb >= fb,
+    }
+}
+
+// 整数 (或按整数看待的时间类型) -> 整数 / 浮点
+fn int_conversion((fs, fb): (bool, u32), to: PlDataTypeKind) -> CastSupport {
+    if let Some(to_layout) = int_layout(to) {
+        return if int_widening((fs, fb), to_layout) { CastSupport::Lossless } else { CastSupport::Lossy };
+    }
+    if let Some(mantissa) = float_mantissa_bits(to) {
+        let value_bits = if fs { fb - 1 } else { fb };
+        return if value_bits <= mantissa { CastSupport::Lossless } else { CastSupport::Lossy };
+    }
+    CastSupport::Unsupported
+}
+
+fn numeric_conversion(from: PlDataTypeKind, to: PlDataTypeKind) -> CastSupport {
+    use PlDataTypeKind::*;
+    if from == Boolean {
+        return CastSupport::Lossless;
+    }
+    if to == Boolean {
+        return CastSupport::Lossy;
+    }
+    if let Some(layout) = int_layout(from) {
+        return int_conversion(layout, to);
+    }
+
+    match (from, to) {
+        (Float32, Float64) => CastSupport::Lossless,
+        (Float64, Float32) => CastSupport::Lossy,
+        // 浮点 -> 整数：截断
+        (Float32 | Float64, _) if int_layout(to).is_some() => CastSupport::Lossy,
+        _ => CastSupport::Unsupported,
+    }
+}
+
+fn supported_conversion(from: PlDataTypeKind, to: PlDataTypeKind) -> CastSupport {
+    use PlDataTypeKind::*;
+
+    let is_int = |k: PlDataTypeKind| int_layout(k).is_some();
+    let is_float = |k: PlDataTypeKind| float_mantissa_bits(k).is_some();
+
+    if from == Unknown || to == Unknown {
+        return CastSupport::Unsupported;
+    }
+    if from == to || from == Null {
+        return CastSupport::Lossless;
+    }
+
+    match (from, to) {
+        // 转成 Null 会丢掉所有值；时间类型和 Categorical 不支持
+        (_, Null) if is_temporal(from) || from == Categorical => CastSupport::Unsupported,
+        (_, Null) => CastSupport::Lossy,
+
+        // 嵌套类型只能在同类之间转换 (由内部类型决定)
+        (List | Struct | Array, _) | (_, List | Struct | Array) => CastSupport::Unsupported,
+
+        // String / Binary
+        (Categorical, String) | (String, Categorical) => CastSupport::Lossless,
+        (String, Binary) => CastSupport::Lossless,
+        (Binary, String) => CastSupport::Lossy, // 非法 UTF-8
+        (Boolean, Binary) => CastSupport::Lossy,
+        (_, Binary) if is_int(from) || is_float(from) => CastSupport::Lossy,
+        (Binary, _) | (_, Binary) => CastSupport::Unsupported,
+        // 整数 / 浮点按最短往返格式输出，能原样解析回来；Boolean 输出 "true" / "false"
+        (_, String) if from == Boolean || is_int(from) || is_float(from) || from == Decimal => CastSupport::Lossless,
+        // Date 超出 chrono 范围、Datetime 的负时间戳、Time 的亚秒部分都无法原样往返
+        (Date | Datetime | Time, String) => CastSupport::Lossy,
+        (Duration, String) => CastSupport::Unsupported,
+        (String, Boolean) => CastSupport::Unsupported,
+        (String, _) => CastSupport::Lossy, // 解析可能失败
+
+        // Categorical <-> 数值：按物理编码转换，编码不等于值本身
+        (Categorical, _) if is_int(to) => CastSupport::Lossy,
+        (_, Categorical) if is_int(from) || is_float(from) || from == Decimal => CastSupport::Lossy,
+        (Categorical, _) | (_, Categorical) => CastSupport::Unsupported,
+
+        // Decimal：目标精度可以任意小，所以没有无损的来源
+        (Decimal, _) if to == Boolean || is_int(to) || is_float(to) => CastSupport::Lossy,
+        (_, Decimal) if is_int(from) || is_float(from) => CastSupport::Lossy,
+        (Decimal, _) | (_, Decimal) => CastSupport::Unsupported,
+
+        // 时间类型之间：时间单位可以更粗 / 范围更小
+        (Date, Datetime) | (Datetime, Date | Time) | (Time, Duration) => CastSupport::Lossy,
+        (_, _) if is_temporal(from) && is_temporal(to) => CastSupport::Unsupported,
+
+        // 时间类型 <-> 数值：按物理整数比较
+        (_, _) if is_temporal(from) => match temporal_layout(from) {
+            Some(layout) if is_int(to) || is_float(to) => int_conversion(layout, to),
+            _ => CastSupport::Unsupported,
+        },
+        // 负数 / 超过一天的值在 Time 里是非法的
+        (_, Time) if from == Boolean || is_int(from) || is_float(from) => CastSupport::Lossy,
+        (_, _) if is_temporal(to) => match (int_layout(from), temporal_layout(to)) {
+            (Some(from_layout), Some(to_layout)) => {
+                if int_widening(from_layout, to_layout) { CastSupport::Lossless } else { CastSupport::Lossy }
+            },
+            _ if from == Boolean || is_float(from) => CastSupport::Lossy,
+            _ => CastSupport::Unsupported,
+        },
+
+        _ => numeric_conversion(from, to),
+    }
+}
+
+// from_kind / to_kind 使用 PlDataTypeKind 的编码
+// 返回: 0 = 不支持, 1 = 无损, 2 = 可能有损；编码无效时返回 -1 并设置错误
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_supported_conversion(from_kind: i32, to_kind: i32) -> i32 {
+    match (PlDataTypeKind::from_i32(from_kind), PlDataTypeKind::from_i32(to_kind)) {
+        (Some(from), Some(to)) => supported_conversion(from, to) as i32,
+        _ => {
+            set_error(format!("Invalid data type kind code: from={}, to={}", from_kind, to_kind));
+            -1
+        }
+    }
+}
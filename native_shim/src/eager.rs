@@ -9,7 +9,7 @@ use crate::types::*;
 use polars::lazy::dsl::UnpivotArgsDSL;
 use polars::functions::{concat_df_horizontal,concat_df_diagonal};
 use polars::prelude::{Field as PolarsField};
-use crate::utils::{consume_exprs_array, map_jointype, ptr_to_str};
+use crate::utils::{consume_exprs_array, hll_estimate, map_jointype, ptr_to_str};
 
 // ==========================================
 // 0. Memory Safety
@@ -86,6 +86,7 @@ macro_rules! gen_eager_op_vec {
 
 // 生成 pl_filter
 gen_eager_op_single!(pl_filter, filter);
+gen_eager_op_single!(pl_dataframe_filter_expr, filter);
 
 // 生成 pl_select
 gen_eager_op_vec!(pl_select, select);
@@ -119,6 +120,40 @@ pub extern "C" fn pl_groupby_agg(
     })
 }
 
+// 快速统计每组行数: 返回 [by..., "count"]
+// sort=true 时按 count 降序 (同 count 时保持首次出现顺序)，否则按分组首次出现的顺序
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_group_sizes(
+    df_ptr: *mut DataFrameContext,
+    by_names: *const *const c_char,
+    by_len: usize,
+    sort: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let names = unsafe { std::slice::from_raw_parts(by_names, by_len) };
+        let by_exprs: Vec<Expr> = names.iter()
+            .map(|&p| col(ptr_to_str(p).unwrap()))
+            .collect();
+
+        let mut lf = ctx.df.clone().lazy()
+            .group_by_stable(by_exprs)
+            .agg([len().alias("count")]);
+
+        if sort {
+            lf = lf.sort(
+                ["count"],
+                SortMultipleOptions::default()
+                    .with_order_descending(true)
+                    .with_maintain_order(true)
+            );
+        }
+
+        let res_df = lf.collect()?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
 // ==========================================
 // Join (连接)
 // ==========================================
@@ -273,6 +308,57 @@ pub extern "C" fn pl_dataframe_schema(df_ptr: *mut DataFrameContext) -> *mut c_c
     CString::new(json).unwrap().into_raw()
 }
 
+// 基数报告：每列一行 (column, dtype, approx_n_unique, null_count)
+// approx_n_unique 使用 HyperLogLog 估计，适合大表快速探查
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_cardinality_report(
+    df_ptr: *mut DataFrameContext,
+    precision: u8
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let width = ctx.df.width();
+
+        let mut names = Vec::with_capacity(width);
+        let mut dtypes = Vec::with_capacity(width);
+        let mut estimates = Vec::with_capacity(width);
+        let mut null_counts = Vec::with_capacity(width);
+
+        for col in ctx.df.get_columns() {
+            names.push(col.name().to_string());
+            dtypes.push(col.dtype().to_string());
+            estimates.push(hll_estimate(col.as_materialized_series(), precision)?);
+            null_counts.push(col.null_count() as u64);
+        }
+
+        let report = df!(
+            "column" => names,
+            "dtype" => dtypes,
+            "approx_n_unique" => estimates,
+            "null_count" => null_counts
+        )?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: report })))
+    })
+}
+
+// 用布尔 Series 直接过滤 (不经过 Lazy)，mask 长度必须与 df 行数一致，Null 视为 false
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_filter_mask(
+    df_ptr: *mut DataFrameContext,
+    mask_ptr: *mut SeriesContext
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let mask_ctx = unsafe { &*mask_ptr };
+
+        let mask = mask_ctx.series.bool()?;
+        let new_df = ctx.df.filter(mask)?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
 // --- Convenience Ops ---
 
 #[unsafe(no_mangle)]
@@ -744,7 +830,7 @@ pub extern "C" fn pl_dataframe_get_column(
 
                 let s = column.as_materialized_series().clone();
                 
-                Ok(Box::into_raw(Box::new(SeriesContext { series: s })))
+                Ok(Box::into_raw(Box::new(SeriesContext { series: s, extension: None })))
             },
             Err(_) => Ok(std::ptr::null_mut())
         }
@@ -765,7 +851,7 @@ pub extern "C" fn pl_dataframe_get_column_at(
             Some(column) => {
                 // [修正] 同样需要从 Column 提取 Series
                 let s = column.as_materialized_series().clone();
-                Ok(Box::into_raw(Box::new(SeriesContext { series: s })))
+                Ok(Box::into_raw(Box::new(SeriesContext { series: s, extension: None })))
             },
             None => Ok(std::ptr::null_mut())
         }
@@ -821,6 +907,17 @@ pub extern "C" fn pl_dataframe_new(
     })
 }
 
+// 按 Schema 创建 0 行 DataFrame，dtype 完全保留 (包括 List / Struct 等嵌套类型)
+// schema 只借用
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_empty_from_schema(schema_ptr: *mut SchemaContext) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*schema_ptr };
+        let df = DataFrame::empty_with_schema(&ctx.schema);
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn pl_dataframe_new_from_stream(
     stream_ptr: *mut ArrowArrayStream,
@@ -1,8 +1,8 @@
 use polars::prelude::*;
 use std::{ffi::CStr, os::raw::c_char};
-use crate::types::{ExprContext,DataTypeContext};
+use crate::types::{ExprContext,DataTypeContext,SeriesContext};
 use std::ops::{Add, Sub, Mul, Div, Rem};
-use crate::utils::{consume_exprs_array, ptr_to_str};
+use crate::utils::{consume_exprs_array, hll_estimate, ptr_to_str};
 
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_free(ptr: *mut ExprContext) {
@@ -100,7 +100,7 @@ macro_rules! gen_namespace_unary {
     };
 }
 /// 模式 6: RollingWindow操作
-fn parse_fixed_window_size(s: &str) -> PolarsResult<usize> {
+pub(crate) fn parse_fixed_window_size(s: &str) -> PolarsResult<usize> {
     // 去掉可能的 "i" 后缀 (Polars 习惯 "3i" 代表 3 index/rows)
     let clean_s = s.trim().trim_end_matches('i');
     clean_s.parse::<usize>().map_err(|_| {
@@ -218,6 +218,8 @@ gen_unary_op!(pl_expr_is_not_null, is_not_null);
 // Math Ops
 gen_unary_op!(pl_expr_sqrt,sqrt);
 gen_unary_op!(pl_expr_exp,exp);
+// Null 的个数 (count 则是非 Null 的个数，见 Statistics)
+gen_unary_op!(pl_expr_null_count, null_count);
 
 // --- Group 4: 二元操作 ---
 gen_binary_op!(pl_expr_eq, eq); // ==
@@ -249,6 +251,8 @@ gen_namespace_unary!(pl_expr_dt_month, dt, month);
 gen_namespace_unary!(pl_expr_dt_day, dt, day);
 gen_namespace_unary!(pl_expr_dt_ordinal_day, dt, ordinal_day);
 gen_namespace_unary!(pl_expr_dt_weekday, dt, weekday);
+gen_namespace_unary!(pl_expr_dt_week, dt, week); // ISO 周数 (1-53)
+gen_namespace_unary!(pl_expr_dt_quarter, dt, quarter);
 gen_namespace_unary!(pl_expr_dt_hour, dt, hour);
 gen_namespace_unary!(pl_expr_dt_minute, dt, minute);
 gen_namespace_unary!(pl_expr_dt_second, dt, second);
@@ -258,16 +262,52 @@ gen_namespace_unary!(pl_expr_dt_nanosecond, dt, nanosecond);
 
 gen_namespace_unary!(pl_expr_dt_date, dt, date); // 转为 Date 类型
 gen_namespace_unary!(pl_expr_dt_time, dt, time); // 转为 Time 类型
+// 时区偏移 (Duration)：base_utc_offset 为标准偏移，dst_offset 为夏令时额外偏移
+gen_namespace_unary!(pl_expr_dt_base_utc_offset, dt, base_utc_offset);
+gen_namespace_unary!(pl_expr_dt_dst_offset, dt, dst_offset);
+// Duration -> 总量 (Int64，向零截断)
+gen_namespace_unary!(pl_expr_dt_total_days, dt, total_days);
+gen_namespace_unary!(pl_expr_dt_total_hours, dt, total_hours);
+gen_namespace_unary!(pl_expr_dt_total_minutes, dt, total_minutes);
+gen_namespace_unary!(pl_expr_dt_total_seconds, dt, total_seconds);
+gen_namespace_unary!(pl_expr_dt_total_milliseconds, dt, total_milliseconds);
+gen_namespace_unary!(pl_expr_dt_total_microseconds, dt, total_microseconds);
+gen_namespace_unary!(pl_expr_dt_total_nanoseconds, dt, total_nanoseconds);
 // String Namespace
 gen_namespace_unary!(pl_expr_str_to_uppercase, str, to_uppercase);
 gen_namespace_unary!(pl_expr_str_to_lowercase, str, to_lowercase);
 gen_namespace_unary!(pl_expr_str_len_bytes, str, len_bytes);
+gen_namespace_unary!(pl_expr_str_len_chars, str, len_chars);
+gen_namespace_unary!(pl_expr_str_reverse, str, reverse);
 // --- List Ops (list 命名空间) ---
 gen_namespace_unary!(pl_expr_list_first, list, first);
+gen_namespace_unary!(pl_expr_list_last, list, last);
 gen_namespace_unary!(pl_expr_list_sum, list, sum);
 gen_namespace_unary!(pl_expr_list_min, list, min);
 gen_namespace_unary!(pl_expr_list_max, list, max);
 gen_namespace_unary!(pl_expr_list_mean, list, mean);
+gen_namespace_unary!(pl_expr_list_arg_min, list, arg_min);
+gen_namespace_unary!(pl_expr_list_arg_max, list, arg_max);
+gen_namespace_unary!(pl_expr_list_reverse, list, reverse);
+gen_namespace_unary!(pl_expr_list_drop_nulls, list, drop_nulls);
+// --- Array Ops (arr 命名空间，定长数组) ---
+gen_namespace_unary!(pl_expr_arr_len, arr, len);
+gen_namespace_unary!(pl_expr_arr_sum, arr, sum);
+gen_namespace_unary!(pl_expr_arr_min, arr, min);
+gen_namespace_unary!(pl_expr_arr_max, arr, max);
+gen_namespace_unary!(pl_expr_arr_mean, arr, mean);
+gen_namespace_unary!(pl_expr_arr_to_list, arr, to_list);
+// --- Binary Ops (bin 命名空间) ---
+gen_namespace_unary!(pl_expr_bin_size, binary, size_bytes);
+// --- Categorical Ops (cat 命名空间) ---
+// 注：0.50 的 Categorical 重构后不再区分 local / global，to_local 已无意义，不提供
+gen_namespace_unary!(pl_expr_cat_get_categories, cat, get_categories);
+gen_namespace_unary!(pl_expr_cat_len_bytes, cat, len_bytes);
+gen_namespace_unary!(pl_expr_cat_len_chars, cat, len_chars);
+// --- Name Ops (name 命名空间) ---
+gen_namespace_unary!(pl_expr_name_keep, name, keep);
+gen_namespace_unary!(pl_expr_name_to_lowercase, name, to_lowercase);
+gen_namespace_unary!(pl_expr_name_to_uppercase, name, to_uppercase);
 // 
 gen_rolling_op!(pl_expr_rolling_mean, rolling_mean);
 gen_rolling_op!(pl_expr_rolling_sum, rolling_sum);
@@ -332,6 +372,24 @@ pub extern "C" fn pl_expr_str_slice(
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
+// head / tail: 按字符 (而非字节) 截取，n 为负数时表示去掉末尾/开头的 |n| 个字符
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_head(expr_ptr: *mut ExprContext, n: i64) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.str().head(lit(n));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_tail(expr_ptr: *mut ExprContext, n: i64) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.str().tail(lit(n));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
 // extract (正则提取)
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_str_extract(
@@ -349,6 +407,120 @@ pub extern "C" fn pl_expr_str_extract(
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
+
+// extract_all (提取所有匹配 -> List<String>)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_extract_all(
+    expr_ptr: *mut ExprContext,
+    pat_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let pat = ptr_to_str(pat_ptr).unwrap();
+        let new_expr = ctx.inner.str().extract_all(lit(pat));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// extract_groups (所有捕获组 -> Struct，命名组用组名作字段名)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_extract_groups(
+    expr_ptr: *mut ExprContext,
+    pat_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let pat = ptr_to_str(pat_ptr).unwrap();
+        // 正则不合法时这里会返回 Err
+        let new_expr = ctx.inner.str().extract_groups(pat)?;
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// count_matches (统计匹配次数 -> UInt32)
+// literal = true 时 pattern 按普通字符串处理
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_count_matches(
+    expr_ptr: *mut ExprContext,
+    pat_ptr: *const c_char,
+    literal: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let pat = ptr_to_str(pat_ptr).unwrap();
+        let new_expr = ctx.inner.str().count_matches(lit(pat), literal);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+// --- JSON ---
+// json_decode: 把 JSON 字符串列解析为强类型列 (通常是 Struct)
+// dtype_ptr 为 NULL 时自动推断类型；infer_schema_len = 0 表示扫描全部行来推断
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_json_decode(
+    expr_ptr: *mut ExprContext,
+    dtype_ptr: *mut DataTypeContext,
+    infer_schema_len: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let dtype = if dtype_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { (*dtype_ptr).dtype.clone() })
+        };
+        let infer_len = if infer_schema_len == 0 { None } else { Some(infer_schema_len) };
+
+        let new_expr = ctx.inner.str().json_decode(dtype, infer_len);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// json_path_match: 用 JSONPath (如 "$.a.b") 提取值，结果为 String，匹配不到返回 Null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_json_path_match(
+    expr_ptr: *mut ExprContext,
+    path_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let path = ptr_to_str(path_ptr).unwrap();
+        let new_expr = ctx.inner.str().json_path_match(lit(path));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+// --- Concatenation ---
+// concat_str: 水平拼接多列为一个字符串列 (常用于构造复合键)
+// ignore_nulls=false 时任一输入为 Null 则结果为 Null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_concat_str(
+    exprs_ptr: *const *mut ExprContext,
+    len: usize,
+    sep_ptr: *const c_char,
+    ignore_nulls: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let exprs = unsafe { consume_exprs_array(exprs_ptr, len) };
+        let sep = if sep_ptr.is_null() { "" } else { ptr_to_str(sep_ptr).unwrap() };
+        let new_expr = concat_str(exprs, sep, ignore_nulls);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// str.join: 纵向聚合，把一列 (或 group_by 中的每组) 拼成一个字符串
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_join(
+    expr_ptr: *mut ExprContext,
+    sep_ptr: *const c_char,
+    ignore_nulls: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let sep = if sep_ptr.is_null() { "" } else { ptr_to_str(sep_ptr).unwrap() };
+        let new_expr = ctx.inner.str().join(sep, ignore_nulls);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
 // 替换操作 (Replace All)
 // pat: 匹配模式, val: 替换值
 #[unsafe(no_mangle)]
@@ -374,13 +546,48 @@ pub extern "C" fn pl_expr_str_replace_all(
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_str_split(
     expr_ptr: *mut ExprContext, 
-    pat_ptr: *const c_char
+    pat_ptr: *const c_char,
+    inclusive: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let pat = ptr_to_str(pat_ptr).unwrap();
+        // inclusive=true 时分隔符保留在每段末尾
+        let new_expr = if inclusive {
+            ctx.inner.str().split_inclusive(lit(pat))
+        } else {
+            ctx.inner.str().split(lit(pat))
+        };
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// split_exact: 切 n 次，得到 n+1 个字段的 Struct (field_0, field_1, ...)，不足补 null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_split_exact(
+    expr_ptr: *mut ExprContext, 
+    pat_ptr: *const c_char,
+    n: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let pat = ptr_to_str(pat_ptr).unwrap();
+        let new_expr = ctx.inner.str().split_exact(lit(pat), n);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// splitn: 最多得到 n 个字段的 Struct，剩余部分保留在最后一个字段中
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_splitn(
+    expr_ptr: *mut ExprContext, 
+    pat_ptr: *const c_char,
+    n: usize
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
         let pat = ptr_to_str(pat_ptr).unwrap();
-        // by_lengths=false (也就是 split by pattern)
-        let new_expr = ctx.inner.str().split(lit(pat));
+        let new_expr = ctx.inner.str().splitn(lit(pat), n);
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
@@ -478,6 +685,89 @@ pub extern "C" fn pl_expr_str_strip_suffix(
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
+// Titlecase: 每个单词首字母大写，其余小写 (单词以非字母数字字符分隔)
+// Polars 的 to_titlecase 需要 nightly feature，这里手写一份
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_to_titlecase(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.map(
+            |c| {
+                let ca = c.str()?;
+                let out: StringChunked = ca.apply_values(|v| {
+                    let mut res = String::with_capacity(v.len());
+                    let mut next_upper = true;
+                    for ch in v.chars() {
+                        if ch.is_alphanumeric() {
+                            if next_upper {
+                                res.extend(ch.to_uppercase());
+                            } else {
+                                res.extend(ch.to_lowercase());
+                            }
+                            next_upper = false;
+                        } else {
+                            res.push(ch);
+                            next_upper = true;
+                        }
+                    }
+                    res.into()
+                });
+                Ok(Some(out.into_column()))
+            },
+            GetOutput::from_type(DataType::String)
+        );
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- Padding ---
+// fill_char 以 Unicode code point (u32) 传入，C# 端直接传 (uint)char
+fn fill_char_from_u32(code: u32) -> PolarsResult<char> {
+    char::from_u32(code).ok_or_else(|| {
+        PolarsError::ComputeError(format!("Invalid fill character code point: {}", code).into())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_pad_start(
+    expr_ptr: *mut ExprContext,
+    length: usize,
+    fill_char: u32
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let fill = fill_char_from_u32(fill_char)?;
+        let new_expr = ctx.inner.str().pad_start(lit(length as u64), fill);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_pad_end(
+    expr_ptr: *mut ExprContext,
+    length: usize,
+    fill_char: u32
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let fill = fill_char_from_u32(fill_char)?;
+        let new_expr = ctx.inner.str().pad_end(lit(length as u64), fill);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// zfill: 左侧补 '0'，但会保留开头的 +/- 符号
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_str_zfill(
+    expr_ptr: *mut ExprContext,
+    length: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.str().zfill(lit(length as u64));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
 // Anchors
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_str_starts_with(expr_ptr: *mut ExprContext, prefix: *const c_char) -> *mut ExprContext {
@@ -518,267 +808,924 @@ pub extern "C" fn pl_expr_str_to_date(expr_ptr: *mut ExprContext, format: *const
     Box::into_raw(Box::new(ExprContext { inner: new_expr }))
 }
 
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_str_to_datetime(expr_ptr: *mut ExprContext, format: *const c_char) -> *mut ExprContext {
-    let ctx = unsafe { Box::from_raw(expr_ptr) };
-    let fmt = unsafe { CStr::from_ptr(format).to_string_lossy() };
-    
-    let options = StrptimeOptions {
-        format: Some(fmt.into()),
-        strict: false,
-        exact: true,
-        ..Default::default()
+// format 可为 NULL，此时由 Polars 自动推断格式
+unsafe fn build_strptime_options(
+    format: *const c_char,
+    strict: bool,
+    exact: bool,
+    cache: bool
+) -> StrptimeOptions {
+    let format = if format.is_null() {
+        None
+    } else {
+        let s = unsafe { CStr::from_ptr(format).to_string_lossy() };
+        Some(PlSmallStr::from_str(s.as_ref()))
     };
-    
-    // 默认转为 Microseconds, 无时区
-    let new_expr = ctx.inner.str().to_datetime(Some(TimeUnit::Microseconds), None, options, lit("raise"));
-    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
+    StrptimeOptions { format, strict, exact, cache }
 }
 
-// ==========================================
-// 复用expr
-// ==========================================
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_clone(ptr: *mut ExprContext) -> *mut ExprContext {
-    let ctx = unsafe { &*ptr };
-    let new_expr = ctx.inner.clone();
-    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
-}
-// ==========================================
-// Temporal Ops
-// ==========================================
+// 时间单位: 0=ns, 1=us, 2=ms；时区: NULL = Naive
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_to_string(
+pub extern "C" fn pl_expr_str_to_datetime(
     expr_ptr: *mut ExprContext,
-    format_ptr: *const c_char // 必须传入格式字符串，如 "%Y-%m-%d"
+    format: *const c_char,
+    unit_code: i32,
+    tz_ptr: *const c_char
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let format = ptr_to_str(format_ptr).unwrap();
-        
-        // Polars API: dt().to_string(format)
-        let new_expr = ctx.inner.dt().to_string(format);
-        
-        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
-    })
-}
-
-// Truncate (Floor)
-// every: e.g. "1h", "1d"
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_truncate(expr_ptr: *mut ExprContext, every: *const c_char) -> *mut ExprContext {
-    ffi_try!({
-        let ctx = unsafe { Box::from_raw(expr_ptr) }; // Move
-        let every_str = unsafe { CStr::from_ptr(every).to_string_lossy() };
-        
-        // dt().truncate(every)
-        let new_expr = ctx.inner.dt().truncate(lit(every_str.as_ref()));
-        
-        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
-    })
-}
+        // 与 to_date 保持一致：解析失败返回 Null
+        let options = unsafe { build_strptime_options(format, false, true, true) };
 
-// Round
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_round(expr_ptr: *mut ExprContext, every: *const c_char) -> *mut ExprContext {
-    ffi_try!({
-        let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let every_str = unsafe { CStr::from_ptr(every).to_string_lossy() };
-        
-        let new_expr = ctx.inner.dt().round(lit(every_str.as_ref()));
-        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
-    })
-}
+        let unit = match unit_code {
+            0 => TimeUnit::Nanoseconds,
+            1 => TimeUnit::Microseconds,
+            2 => TimeUnit::Milliseconds,
+            _ => TimeUnit::Microseconds,
+        };
+        let tz = if tz_ptr.is_null() {
+            None
+        } else {
+            let s = unsafe { CStr::from_ptr(tz_ptr).to_string_lossy() };
+            // opt_try_new 会校验时区名，非法时区直接报错而不是拖到执行期
+            TimeZone::opt_try_new(Some(s.as_ref()))?
+        };
 
-// Offset By (Add Duration)
-// by: Duration Expr (e.g. lit("1d") or col("duration"))
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_offset_by(expr_ptr: *mut ExprContext, by_ptr: *mut ExprContext) -> *mut ExprContext {
-    ffi_try!({
-        let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let by_ctx = unsafe { Box::from_raw(by_ptr) };
-        
-        // dt().offset_by(expr)
-        let new_expr = ctx.inner.dt().offset_by(by_ctx.inner);
+        let new_expr = ctx.inner.str().to_datetime(Some(unit), tz, options, lit("raise"));
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
 
-// Timestamp (to Int64)
-// unit: 0=ns, 1=us, 2=ms
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_timestamp(expr_ptr: *mut ExprContext, unit_code: i32) -> *mut ExprContext {
+pub extern "C" fn pl_expr_str_to_time(expr_ptr: *mut ExprContext, format: *const c_char) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let unit = match unit_code {
-            0 => TimeUnit::Nanoseconds,
-            1 => TimeUnit::Microseconds,
-            2 => TimeUnit::Milliseconds,
-            _ => TimeUnit::Microseconds,
-        };
-        
-        let new_expr = ctx.inner.dt().timestamp(unit);
+        let options = unsafe { build_strptime_options(format, false, true, true) };
+        let new_expr = ctx.inner.str().to_time(options);
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
 
-// 辅助函数：String -> NonExistent Enum
-fn parse_non_existent(s: &str) -> NonExistent {
-    match s {
-        "null" => NonExistent::Null,
-        "raise" => NonExistent::Raise,
-        // Polars 可能不支持其他策略用于 NonExistent，或者有 Time/RollForward 等
-        // 根据 0.50 源码，通常是 raise 或 null。
-        _ => NonExistent::Raise, // 默认报错
-    }
-}
-// Convert Time Zone (Physical value changes, Wall time changes)
+// 通用 strptime：目标类型由 DataTypeContext 指定 (Date / Datetime / Time)
+// strict: 解析失败时报错 (false 则返回 Null)
+// exact:  格式必须完整匹配 (false 则允许在字符串中搜索匹配片段)
+// cache:  对重复值缓存解析结果
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_convert_time_zone(
+pub extern "C" fn pl_expr_str_strptime(
     expr_ptr: *mut ExprContext,
-    tz_ptr: *const c_char
+    dtype_ptr: *mut DataTypeContext,
+    format: *const c_char,
+    strict: bool,
+    exact: bool,
+    cache: bool
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let tz_str = unsafe { CStr::from_ptr(tz_ptr).to_string_lossy() };
-        
-        // [修复] 构造 TimeZone 结构体
-        // Polars 0.50+ TimeZone::new(str)
-        let tz = unsafe{ TimeZone::new_unchecked(tz_str.as_ref()) };
-        
-        let new_expr = ctx.inner.dt().convert_time_zone(tz);
-        
+        let dtype = unsafe { &(*dtype_ptr).dtype };
+        match dtype {
+            DataType::Date | DataType::Datetime(_, _) | DataType::Time => {},
+            other => return Err(PolarsError::ComputeError(
+                format!("strptime target must be Date, Datetime or Time, got {}", other).into()
+            )),
+        }
+
+        let options = unsafe { build_strptime_options(format, strict, exact, cache) };
+        let new_expr = ctx.inner.str().strptime(dtype.clone(), options, lit("raise"));
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
 
-// Replace Time Zone (Physical value stays, Wall time changes or meta changes)
+// ==========================================
+// Temporal Ranges
+// ==========================================
+// interval: Duration 字符串 (e.g. "1d", "1mo", "15m")
+// closed: "both" (默认), "left", "right", "none"
+unsafe fn parse_range_args(
+    interval_ptr: *const c_char,
+    closed_ptr: *const c_char
+) -> PolarsResult<(Duration, ClosedWindow)> {
+    let interval = Duration::try_parse(ptr_to_str(interval_ptr).unwrap())?;
+    let closed = if closed_ptr.is_null() {
+        ClosedWindow::Both
+    } else {
+        map_closed_window(ptr_to_str(closed_ptr).unwrap())
+    };
+    Ok((interval, closed))
+}
+
+/// date_range / date_ranges / time_range / time_ranges: (start, end, interval, closed)
+/// *_range 生成一列，*_ranges 对每行的 start/end 生成一个 List
+macro_rules! gen_temporal_range {
+    ($func_name:ident, $polars_fn:path) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $func_name(
+            start_ptr: *mut ExprContext,
+            end_ptr: *mut ExprContext,
+            interval_ptr: *const c_char,
+            closed_ptr: *const c_char
+        ) -> *mut ExprContext {
+            ffi_try!({
+                let start = unsafe { Box::from_raw(start_ptr) };
+                let end = unsafe { Box::from_raw(end_ptr) };
+                let (interval, closed) = unsafe { parse_range_args(interval_ptr, closed_ptr)? };
+
+                let new_expr = $polars_fn(start.inner, end.inner, interval, closed);
+                Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+            })
+        }
+    };
+}
+
+gen_temporal_range!(pl_date_range, polars::lazy::dsl::date_range);
+gen_temporal_range!(pl_date_ranges, polars::lazy::dsl::date_ranges);
+gen_temporal_range!(pl_time_range, polars::lazy::dsl::time_range);
+gen_temporal_range!(pl_time_ranges, polars::lazy::dsl::time_ranges);
+
+/// datetime_range / datetime_ranges 额外接受时间单位和时区
+/// unit_code: 0=ns, 1=us, 2=ms, 其他 = 由输入推断；tz_ptr: NULL = 由输入推断
+macro_rules! gen_datetime_range {
+    ($func_name:ident, $polars_fn:path) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $func_name(
+            start_ptr: *mut ExprContext,
+            end_ptr: *mut ExprContext,
+            interval_ptr: *const c_char,
+            closed_ptr: *const c_char,
+            unit_code: i32,
+            tz_ptr: *const c_char
+        ) -> *mut ExprContext {
+            ffi_try!({
+                let start = unsafe { Box::from_raw(start_ptr) };
+                let end = unsafe { Box::from_raw(end_ptr) };
+                let (interval, closed) = unsafe { parse_range_args(interval_ptr, closed_ptr)? };
+
+                let time_unit = match unit_code {
+                    0 => Some(TimeUnit::Nanoseconds),
+                    1 => Some(TimeUnit::Microseconds),
+                    2 => Some(TimeUnit::Milliseconds),
+                    _ => None,
+                };
+                let time_zone = if tz_ptr.is_null() {
+                    None
+                } else {
+                    TimeZone::opt_try_new(Some(ptr_to_str(tz_ptr).unwrap()))?
+                };
+
+                let new_expr = $polars_fn(start.inner, end.inner, interval, closed, time_unit, time_zone);
+                Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+            })
+        }
+    };
+}
+
+gen_datetime_range!(pl_datetime_range, polars::lazy::dsl::datetime_range);
+gen_datetime_range!(pl_datetime_ranges, polars::lazy::dsl::datetime_ranges);
+
+// ==========================================
+// Horizontal (逐行跨列聚合)
+// ==========================================
+// polars::lazy::dsl 里 min/max/sum/mean_horizontal 被 glob 导出了两次，
+// 直接调用会触发 ambiguous_glob_imports，所以这里直接构造对应的 FunctionExpr
+fn horizontal_expr(function: polars::lazy::dsl::FunctionExpr, exprs: Vec<Expr>) -> PolarsResult<Expr> {
+    if exprs.is_empty() {
+        return Err(PolarsError::ComputeError(
+            "horizontal aggregation requires at least one expression".into()
+        ));
+    }
+    Ok(Expr::n_ary(function, exprs))
+}
+
+/// min / max / any / all_horizontal: (exprs, len)
+macro_rules! gen_horizontal_op {
+    ($func_name:ident, $function:expr) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $func_name(
+            exprs_ptr: *const *mut ExprContext,
+            len: usize
+        ) -> *mut ExprContext {
+            ffi_try!({
+                let exprs = unsafe { consume_exprs_array(exprs_ptr, len) };
+                let new_expr = horizontal_expr($function, exprs)?;
+                Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+            })
+        }
+    };
+}
+
+/// sum / mean_horizontal: (exprs, len, ignore_nulls)
+/// ignore_nulls: true 时跳过 Null；false 时一行中有任何 Null 结果即为 Null
+macro_rules! gen_horizontal_nulls_op {
+    ($func_name:ident, $variant:ident) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $func_name(
+            exprs_ptr: *const *mut ExprContext,
+            len: usize,
+            ignore_nulls: bool
+        ) -> *mut ExprContext {
+            ffi_try!({
+                let exprs = unsafe { consume_exprs_array(exprs_ptr, len) };
+                let new_expr = horizontal_expr(polars::lazy::dsl::FunctionExpr::$variant { ignore_nulls }, exprs)?;
+                Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+            })
+        }
+    };
+}
+
+gen_horizontal_op!(pl_min_horizontal, polars::lazy::dsl::FunctionExpr::MinHorizontal);
+gen_horizontal_op!(pl_max_horizontal, polars::lazy::dsl::FunctionExpr::MaxHorizontal);
+gen_horizontal_op!(pl_any_horizontal, BooleanFunction::AnyHorizontal.into());
+gen_horizontal_op!(pl_all_horizontal, BooleanFunction::AllHorizontal.into());
+gen_horizontal_nulls_op!(pl_sum_horizontal, SumHorizontal);
+gen_horizontal_nulls_op!(pl_mean_horizontal, MeanHorizontal);
+
+// 每行取第一个非 Null 的值
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_coalesce(
+    exprs_ptr: *const *mut ExprContext,
+    len: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let exprs = unsafe { consume_exprs_array(exprs_ptr, len) };
+        let new_expr = coalesce(&exprs);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// ==========================================
+// Correlation (列间相关性 / 协方差)
+// ==========================================
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_pearson_corr(a_ptr: *mut ExprContext, b_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let a = unsafe { Box::from_raw(a_ptr) };
+        let b = unsafe { Box::from_raw(b_ptr) };
+        let new_expr = pearson_corr(a.inner, b.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// propagate_nans: true 时任一列含 NaN 结果即为 NaN；false 则在排名时忽略 NaN
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_spearman_rank_corr(
+    a_ptr: *mut ExprContext,
+    b_ptr: *mut ExprContext,
+    propagate_nans: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let a = unsafe { Box::from_raw(a_ptr) };
+        let b = unsafe { Box::from_raw(b_ptr) };
+        let new_expr = spearman_rank_corr(a.inner, b.inner, propagate_nans);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_cov(a_ptr: *mut ExprContext, b_ptr: *mut ExprContext, ddof: u8) -> *mut ExprContext {
+    ffi_try!({
+        let a = unsafe { Box::from_raw(a_ptr) };
+        let b = unsafe { Box::from_raw(b_ptr) };
+        let new_expr = cov(a.inner, b.inner, ddof);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+/// rolling_corr / rolling_cov: (x, y, window_size, min_periods, ddof)
+macro_rules! gen_rolling_corr_cov {
+    ($func_name:ident, $polars_fn:ident) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $func_name(
+            x_ptr: *mut ExprContext,
+            y_ptr: *mut ExprContext,
+            window_size: u32,
+            min_periods: u32,
+            ddof: u8
+        ) -> *mut ExprContext {
+            ffi_try!({
+                let x = unsafe { Box::from_raw(x_ptr) };
+                let y = unsafe { Box::from_raw(y_ptr) };
+                let options = RollingCovOptions {
+                    window_size: window_size as IdxSize,
+                    min_periods: min_periods as IdxSize,
+                    ddof,
+                };
+                let new_expr = $polars_fn(x.inner, y.inner, options);
+                Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+            })
+        }
+    };
+}
+
+gen_rolling_corr_cov!(pl_rolling_corr, rolling_corr);
+gen_rolling_corr_cov!(pl_rolling_cov, rolling_cov);
+
+// ==========================================
+// 常量列 / 填充
+// ==========================================
+// 把 value 重复 n 次 (列名 "repeat")
+// dtype_ptr 可为 NULL (沿用 value 的类型)，否则先把 value cast 成该类型；只借用
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_repeat(
+    value_ptr: *mut ExprContext,
+    n_ptr: *mut ExprContext,
+    dtype_ptr: *mut DataTypeContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let value = unsafe { Box::from_raw(value_ptr) };
+        let n = unsafe { Box::from_raw(n_ptr) };
+        let value = if dtype_ptr.is_null() {
+            value.inner
+        } else {
+            value.inner.cast(unsafe { (*dtype_ptr).dtype.clone() })
+        };
+        let new_expr = repeat(value, n.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 在末尾追加 n 个 value (value 可以是 Null)，用于把较短的列补齐到指定长度
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_extend_constant(
+    expr_ptr: *mut ExprContext,
+    value_ptr: *mut ExprContext,
+    n_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let value = unsafe { Box::from_raw(value_ptr) };
+        let n = unsafe { Box::from_raw(n_ptr) };
+        let new_expr = ctx.inner.extend_constant(value.inner, n.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// ==========================================
+// 复用expr
+// ==========================================
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_clone(ptr: *mut ExprContext) -> *mut ExprContext {
+    let ctx = unsafe { &*ptr };
+    let new_expr = ctx.inner.clone();
+    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
+}
+// ==========================================
+// Temporal Ops
+// ==========================================
+// format: chrono 格式字符串，如 "%Y-%m-%d"；NULL 或 "iso" 输出 ISO 8601 ("iso:strict" 用 'T' 分隔日期和时间)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_to_string(
+    expr_ptr: *mut ExprContext,
+    format_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let format = if format_ptr.is_null() { "iso" } else { ptr_to_str(format_ptr).unwrap() };
+        
+        // Polars API: dt().to_string(format)
+        let new_expr = ctx.inner.dt().to_string(format);
+        
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// strftime: 与 Python dt.strftime 同名，等价于 to_string
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_strftime(
+    expr_ptr: *mut ExprContext,
+    format_ptr: *const c_char
+) -> *mut ExprContext {
+    pl_expr_dt_to_string(expr_ptr, format_ptr)
+}
+
+// Truncate (Floor)
+// every: e.g. "1h", "1d"
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_truncate(expr_ptr: *mut ExprContext, every_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) }; // Move
+        let every_ctx = unsafe { Box::from_raw(every_ptr) };
+        
+        // every: Duration 字符串 Expr (e.g. lit("15m") 或 col("bucket"))
+        let new_expr = ctx.inner.dt().truncate(every_ctx.inner);
+        
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// Round
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_round(expr_ptr: *mut ExprContext, every_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let every_ctx = unsafe { Box::from_raw(every_ptr) };
+        
+        let new_expr = ctx.inner.dt().round(every_ctx.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// Offset By (Add Duration)
+// by: Duration Expr (e.g. lit("1d") or col("duration"))
+// 支持日历单位: "1mo" / "1y" / "1q" / "3d12h" / "-2w" 等。
+// 按月/年偏移时会钳制到月末 (1 月 31 日 + "1mo" = 2 月 28/29 日)，这是 C# AddMonths 在计划里做不到的
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_offset_by(expr_ptr: *mut ExprContext, by_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let by_ctx = unsafe { Box::from_raw(by_ptr) };
+        
+        // dt().offset_by(expr)
+        let new_expr = ctx.inner.dt().offset_by(by_ctx.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 字符串版本：构建表达式时就校验 Duration 语法，避免错误拖到 collect 才暴露
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_offset_by_str(expr_ptr: *mut ExprContext, offset_ptr: *const c_char) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let offset = ptr_to_str(offset_ptr).unwrap();
+        Duration::try_parse(offset)?;
+
+        let new_expr = ctx.inner.dt().offset_by(lit(offset));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// Timestamp (to Int64)
+// unit: 0=ns, 1=us, 2=ms
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_timestamp(expr_ptr: *mut ExprContext, unit_code: i32) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let unit = match unit_code {
+            0 => TimeUnit::Nanoseconds,
+            1 => TimeUnit::Microseconds,
+            2 => TimeUnit::Milliseconds,
+            _ => TimeUnit::Microseconds,
+        };
+        
+        let new_expr = ctx.inner.dt().timestamp(unit);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 辅助函数：String -> NonExistent Enum
+fn parse_non_existent(s: &str) -> NonExistent {
+    match s {
+        "null" => NonExistent::Null,
+        "raise" => NonExistent::Raise,
+        // Polars 可能不支持其他策略用于 NonExistent，或者有 Time/RollForward 等
+        // 根据 0.50 源码，通常是 raise 或 null。
+        _ => NonExistent::Raise, // 默认报错
+    }
+}
+// Convert Time Zone (Physical value changes, Wall time changes)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dt_convert_time_zone(
+    expr_ptr: *mut ExprContext,
+    tz_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let tz_str = unsafe { CStr::from_ptr(tz_ptr).to_string_lossy() };
+        
+        // [修复] 构造 TimeZone 结构体
+        // Polars 0.50+ TimeZone::new(str)
+        let tz = unsafe{ TimeZone::new_unchecked(tz_str.as_ref()) };
+        
+        let new_expr = ctx.inner.dt().convert_time_zone(tz);
+        
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// Replace Time Zone (Physical value stays, Wall time changes or meta changes)
 // tz_ptr: NULL means "unset" (make naive), otherwise "set" (make aware)
 // ambiguous_ptr: "raise", "earliest", "latest", "null", etc.
 // 2. Replace Time Zone (参数升级)
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_dt_replace_time_zone(
+pub extern "C" fn pl_expr_dt_replace_time_zone(
+    expr_ptr: *mut ExprContext,
+    tz_ptr: *const c_char,          // TimeZone (Option)
+    ambiguous_ptr: *const c_char,   // Ambiguous (Expr string, e.g. "raise")
+    non_existent_ptr: *const c_char // NonExistent (Enum string, e.g. "raise")
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        
+        // A. 构造 Option<TimeZone>
+        let tz = if tz_ptr.is_null() {
+            None
+        } else {
+            let s = unsafe { CStr::from_ptr(tz_ptr).to_string_lossy() };
+            unsafe { Some(TimeZone::new_unchecked(s.as_ref())) }
+        };
+
+        // B. 构造 Ambiguous Expr
+        // 这里的 ambiguous 是 Expr 类型，通常传 lit("raise") 或 lit("earliest")
+        let amb_str = if ambiguous_ptr.is_null() {
+            "raise"
+        } else {
+            unsafe { CStr::from_ptr(ambiguous_ptr).to_str().unwrap() }
+        };
+        let ambiguous_expr = lit(amb_str);
+
+        // C. 构造 NonExistent Enum
+        // 这里必须解析字符串为 Rust 枚举
+        let ne_str = if non_existent_ptr.is_null() {
+            "raise"
+        } else {
+            unsafe { CStr::from_ptr(non_existent_ptr).to_str().unwrap() }
+        };
+        let non_existent = parse_non_existent(ne_str);
+
+        // D. 调用
+        let new_expr = ctx.inner.dt().replace_time_zone(tz, ambiguous_expr, non_existent);
+        
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+// ==========================================
+// Intervals
+// ==========================================
+// --- IsBetween ---
+// 这是一个三元操作: expr.is_between(lower, upper)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_is_between(
+    expr_ptr: *mut ExprContext,
+    lower_ptr: *mut ExprContext,
+    upper_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let lower = unsafe { Box::from_raw(lower_ptr) };
+        let upper = unsafe { Box::from_raw(upper_ptr) };
+
+        // 默认 behavior 是 ClosedInterval::Both (闭区间 [])
+        // 如果想暴露给 C#，可以传个 int 进来映射
+        let new_expr = ctx.inner.is_between(lower.inner, upper.inner, ClosedInterval::Both);
+        
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- DateTime Literal ---
+// 接收一个 i64 (微秒时间戳)，返回一个 Datetime 类型的 Expr
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_lit_datetime(
+    micros: i64
+) -> *mut ExprContext {
+    ffi_try!({
+        // 1. 先造一个 Int64 字面量
+        let lit_expr = lit(micros);
+        // 2. Cast 成 Datetime (Microseconds)
+        let dt_expr = lit_expr.cast(DataType::Datetime(TimeUnit::Microseconds, None));
+        
+        Ok(Box::into_raw(Box::new(ExprContext { inner: dt_expr })))
+    })
+}
+
+// --- Typed Literals ---
+// 直接构造带类型的标量，不经过 cast / 字符串解析
+// 时间单位: 0=ns, 1=us, 2=ms
+
+// value: 对应单位下距 epoch 的时间戳；tz: NULL = Naive
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_datetime(
+    value: i64,
+    unit_code: i32,
+    tz_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let unit = match unit_code {
+            0 => TimeUnit::Nanoseconds,
+            1 => TimeUnit::Microseconds,
+            2 => TimeUnit::Milliseconds,
+            _ => TimeUnit::Microseconds,
+        };
+        let tz = if tz_ptr.is_null() {
+            None
+        } else {
+            TimeZone::opt_try_new(Some(ptr_to_str(tz_ptr).unwrap()))?
+        };
+        let dtype = DataType::Datetime(unit, tz.clone());
+        let value = AnyValue::DatetimeOwned(value, unit, tz.map(Arc::new));
+        let expr = lit(Scalar::new(dtype, value));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+// days: 距 1970-01-01 的天数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_date(days: i32) -> *mut ExprContext {
+    ffi_try!({
+        let expr = lit(Scalar::new(DataType::Date, AnyValue::Date(days)));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+// nanos: 距午夜的纳秒数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_time(nanos: i64) -> *mut ExprContext {
+    ffi_try!({
+        let expr = lit(Scalar::new(DataType::Time, AnyValue::Time(nanos)));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_duration(value: i64, unit_code: i32) -> *mut ExprContext {
+    ffi_try!({
+        let unit = match unit_code {
+            0 => TimeUnit::Nanoseconds,
+            1 => TimeUnit::Microseconds,
+            2 => TimeUnit::Milliseconds,
+            _ => TimeUnit::Microseconds,
+        };
+        let expr = lit(Scalar::new(DataType::Duration(unit), AnyValue::Duration(value, unit)));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+// value_ptr: 指向放大 10^scale 后的整数 (与 pl_series_get_decimal 的约定一致)
+// i128 按值传递在不同平台 / 编译器版本间的 ABI 不一致，所以和其他 i128 接口一样走指针
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_decimal(value_ptr: *const i128, scale: usize) -> *mut ExprContext {
+    ffi_try!({
+        if value_ptr.is_null() {
+            return Err(PolarsError::ComputeError("Null decimal value pointer".into()));
+        }
+        let value = unsafe { *value_ptr };
+        let dtype = DataType::Decimal(None, Some(scale));
+        let expr = lit(Scalar::new(dtype, AnyValue::Decimal(value, scale)));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+// 复制一份字节，C# 端的缓冲区调用后即可释放
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_binary(ptr: *const u8, len: usize) -> *mut ExprContext {
+    ffi_try!({
+        let bytes = if len == 0 {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(ptr, len) }.to_vec()
+        };
+        let expr = lit(bytes);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+// 把内存中的 Series 整个嵌进表达式 (只借用 series，Series 是 COW 的，不复制数据)
+// 典型用法: col.is_in(pl_lit_series(allow_list).implode())
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_series(series_ptr: *mut SeriesContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { &*series_ptr };
+        let expr = lit(ctx.series.clone());
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+
+// other 应为 List (例如 implode 后的 pl_lit_series)；同类型的扁平列在 Polars 中已弃用
+// nulls_equal: true 时 Null 也参与匹配
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_is_in(
+    expr_ptr: *mut ExprContext,
+    other_ptr: *mut ExprContext,
+    nulls_equal: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let other = unsafe { Box::from_raw(other_ptr) };
+        let new_expr = ctx.inner.is_in(other.inner, nulls_equal);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 带类型的 Null (dtype 只借用)，避免 Null 字面量参与运算时类型推断成 Null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lit_null_typed(dtype_ptr: *mut DataTypeContext) -> *mut ExprContext {
+    ffi_try!({
+        let dtype = unsafe { (*dtype_ptr).dtype.clone() };
+        let expr = lit(Scalar::null(dtype));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
+    })
+}
+// ==========================================
+// List Ops
+// ==========================================
+// list.get(index)
+// index: Expr (支持负数从末尾取，也可以是逐行不同的索引列)
+// null_on_oob: 越界时返回 Null；false 则越界直接报错
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_get(
+    expr_ptr: *mut ExprContext, 
+    index_ptr: *mut ExprContext,
+    null_on_oob: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let index = unsafe { Box::from_raw(index_ptr) };
+        let new_expr = ctx.inner.list().get(index.inner, null_on_oob);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_sort(
+    expr_ptr: *mut ExprContext,
+    descending: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let options = SortOptions {
+            descending,
+            ..Default::default()
+        };
+        let new_expr = ctx.inner.list().sort(options);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 3. list.contains(item)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_contains(
+    expr_ptr: *mut ExprContext,
+    item_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let item = unsafe { Box::from_raw(item_ptr) };
+
+        // list().contains 逐行判断 item 是否在该行的 list 中
+        let new_expr = ctx.inner.list().contains(item.inner, true);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// ==========================================
+// Binary Ops
+// ==========================================
+fn check_binary_encoding(encoding: &str) -> PolarsResult<()> {
+    match encoding {
+        "hex" | "base64" => Ok(()),
+        _ => Err(PolarsError::ComputeError(
+            format!("Unknown binary encoding: '{}', expected 'hex' or 'base64'", encoding).into()
+        )),
+    }
+}
+
+// Binary -> String (hex / base64)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_bin_encode(
+    expr_ptr: *mut ExprContext,
+    encoding_ptr: *const c_char
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let encoding = ptr_to_str(encoding_ptr).unwrap();
+        check_binary_encoding(encoding)?;
+        let new_expr = match encoding {
+            "hex" => ctx.inner.binary().hex_encode(),
+            _ => ctx.inner.binary().base64_encode(),
+        };
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 编码后的 Binary -> 原始 Binary
+// strict: true 遇到非法编码直接报错，false 则返回 Null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_bin_decode(
     expr_ptr: *mut ExprContext,
-    tz_ptr: *const c_char,          // TimeZone (Option)
-    ambiguous_ptr: *const c_char,   // Ambiguous (Expr string, e.g. "raise")
-    non_existent_ptr: *const c_char // NonExistent (Enum string, e.g. "raise")
+    encoding_ptr: *const c_char,
+    strict: bool
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        
-        // A. 构造 Option<TimeZone>
-        let tz = if tz_ptr.is_null() {
-            None
-        } else {
-            let s = unsafe { CStr::from_ptr(tz_ptr).to_string_lossy() };
-            unsafe { Some(TimeZone::new_unchecked(s.as_ref())) }
+        let encoding = ptr_to_str(encoding_ptr).unwrap();
+        check_binary_encoding(encoding)?;
+        let new_expr = match encoding {
+            "hex" => ctx.inner.binary().hex_decode(strict),
+            _ => ctx.inner.binary().base64_decode(strict),
         };
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
 
-        // B. 构造 Ambiguous Expr
-        // 这里的 ambiguous 是 Expr 类型，通常传 lit("raise") 或 lit("earliest")
-        let amb_str = if ambiguous_ptr.is_null() {
-            "raise"
-        } else {
-            unsafe { CStr::from_ptr(ambiguous_ptr).to_str().unwrap() }
-        };
-        let ambiguous_expr = lit(amb_str);
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_bin_contains(
+    expr_ptr: *mut ExprContext,
+    pat_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let pat = unsafe { Box::from_raw(pat_ptr) };
+        let new_expr = ctx.inner.binary().contains_literal(pat.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
 
-        // C. 构造 NonExistent Enum
-        // 这里必须解析字符串为 Rust 枚举
-        let ne_str = if non_existent_ptr.is_null() {
-            "raise"
-        } else {
-            unsafe { CStr::from_ptr(non_existent_ptr).to_str().unwrap() }
-        };
-        let non_existent = parse_non_existent(ne_str);
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_bin_starts_with(
+    expr_ptr: *mut ExprContext,
+    prefix_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let prefix = unsafe { Box::from_raw(prefix_ptr) };
+        let new_expr = ctx.inner.binary().starts_with(prefix.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
 
-        // D. 调用
-        let new_expr = ctx.inner.dt().replace_time_zone(tz, ambiguous_expr, non_existent);
-        
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_bin_ends_with(
+    expr_ptr: *mut ExprContext,
+    suffix_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let suffix = unsafe { Box::from_raw(suffix_ptr) };
+        let new_expr = ctx.inner.binary().ends_with(suffix.inner);
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
+
 // ==========================================
-// Intervals
+// Categorical Ops
 // ==========================================
-// --- IsBetween ---
-// 这是一个三元操作: expr.is_between(lower, upper)
+// 以下函数只在类别字典上计算一次，再按物理编码广播回每一行，比先 cast 成 String 快
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_is_between(
+pub extern "C" fn pl_expr_cat_starts_with(
     expr_ptr: *mut ExprContext,
-    lower_ptr: *mut ExprContext,
-    upper_ptr: *mut ExprContext
+    prefix_ptr: *const c_char
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let lower = unsafe { Box::from_raw(lower_ptr) };
-        let upper = unsafe { Box::from_raw(upper_ptr) };
-
-        // 默认 behavior 是 ClosedInterval::Both (闭区间 [])
-        // 如果想暴露给 C#，可以传个 int 进来映射
-        let new_expr = ctx.inner.is_between(lower.inner, upper.inner, ClosedInterval::Both);
-        
+        let prefix = ptr_to_str(prefix_ptr).unwrap();
+        let new_expr = ctx.inner.cat().starts_with(prefix.to_string());
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
 
-// --- DateTime Literal ---
-// 接收一个 i64 (微秒时间戳)，返回一个 Datetime 类型的 Expr
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_lit_datetime(
-    micros: i64
+pub extern "C" fn pl_expr_cat_ends_with(
+    expr_ptr: *mut ExprContext,
+    suffix_ptr: *const c_char
 ) -> *mut ExprContext {
     ffi_try!({
-        // 1. 先造一个 Int64 字面量
-        let lit_expr = lit(micros);
-        // 2. Cast 成 Datetime (Microseconds)
-        let dt_expr = lit_expr.cast(DataType::Datetime(TimeUnit::Microseconds, None));
-        
-        Ok(Box::into_raw(Box::new(ExprContext { inner: dt_expr })))
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let suffix = ptr_to_str(suffix_ptr).unwrap();
+        let new_expr = ctx.inner.cat().ends_with(suffix.to_string());
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
-// ==========================================
-// List Ops
-// ==========================================
-// list.get(index)
+
+// length < 0 表示一直取到末尾
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_list_get(
-    expr_ptr: *mut ExprContext, 
-    index: i64
+pub extern "C" fn pl_expr_cat_slice(
+    expr_ptr: *mut ExprContext,
+    offset: i64,
+    length: i64
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let new_expr = ctx.inner.list().get(lit(index),true);
+        let length = if length < 0 { None } else { Some(length as usize) };
+        let new_expr = ctx.inner.cat().slice(offset, length);
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
+
+// ==========================================
+// Array Ops
+// ==========================================
+// arr.get(index)，语义同 list.get
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_list_sort(
+pub extern "C" fn pl_expr_arr_get(
     expr_ptr: *mut ExprContext,
-    descending: bool
+    index_ptr: *mut ExprContext,
+    null_on_oob: bool
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
-        let options = SortOptions {
-            descending,
-            ..Default::default()
-        };
-        let new_expr = ctx.inner.list().sort(options);
+        let index = unsafe { Box::from_raw(index_ptr) };
+        let new_expr = ctx.inner.arr().get(index.inner, null_on_oob);
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
 
-// 3. list.contains(item)
+// arr.contains(item)，逐行判断
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_list_contains(
+pub extern "C" fn pl_expr_arr_contains(
     expr_ptr: *mut ExprContext,
     item_ptr: *mut ExprContext
 ) -> *mut ExprContext {
     ffi_try!({
         let ctx = unsafe { Box::from_raw(expr_ptr) };
         let item = unsafe { Box::from_raw(item_ptr) };
-
-        let new_expr = item.inner.is_in(ctx.inner, true);
+        let new_expr = ctx.inner.arr().contains(item.inner, true);
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
@@ -823,6 +1770,40 @@ pub unsafe extern "C" fn pl_expr_implode(expr: *mut Expr) -> *mut Expr {
     })
 }
 
+// 与 explode 相同，List 展开成多行 (对应 Python 的 Expr.flatten)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_flatten(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.flatten();
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_drop_nulls(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.drop_nulls();
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 只保留 predicate 为 true 的元素，结果长度会变化
+// 主要用于 group_by.agg 里的条件聚合，例如 col("x").filter(col("y") > 0).sum()
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_filter(
+    expr_ptr: *mut ExprContext,
+    predicate_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let predicate = unsafe { Box::from_raw(predicate_ptr) };
+        let new_expr = ctx.inner.filter(predicate.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_list_join(
     expr_ptr: *mut ExprContext,
@@ -846,35 +1827,262 @@ pub extern "C" fn pl_expr_list_len(expr_ptr: *mut ExprContext) -> *mut ExprConte
     })
 }
 
-// ==========================================
-// Math
-// ==========================================
+// list 集合运算 (逐行比较两列 list)
+// op: 0=union, 1=intersection, 2=difference (left - right), 3=symmetric_difference
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_set_operation(
+    left_ptr: *mut ExprContext,
+    right_ptr: *mut ExprContext,
+    op: i32
+) -> *mut ExprContext {
+    ffi_try!({
+        let left = unsafe { Box::from_raw(left_ptr) };
+        let right = unsafe { Box::from_raw(right_ptr) };
+        let list = left.inner.list();
+        let new_expr = match op {
+            0 => list.union(right.inner),
+            1 => list.set_intersection(right.inner),
+            2 => list.set_difference(right.inner),
+            3 => list.set_symmetric_difference(right.inner),
+            _ => return Err(PolarsError::ComputeError(
+                format!("Unknown list set operation code: {}", op).into()
+            )),
+        };
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// list.std(ddof)
+// ddof: 自由度修正，样本标准差为 1
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_std(
+    expr_ptr: *mut ExprContext,
+    ddof: u8
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = ctx.inner.list().std(ddof);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// list.unique()
+// maintain_order: true 保持首次出现的顺序 (unique_stable)，false 更快但顺序不定
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_unique(
+    expr_ptr: *mut ExprContext,
+    maintain_order: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = if maintain_order {
+            ctx.inner.list().unique_stable()
+        } else {
+            ctx.inner.list().unique()
+        };
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// list.slice(offset, length)
+// offset/length 都是 Expr，可以逐行不同；length 传 NULL 表示一直取到末尾
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_slice(
+    expr_ptr: *mut ExprContext,
+    offset_ptr: *mut ExprContext,
+    length_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let offset = unsafe { Box::from_raw(offset_ptr) };
+        let length = if length_ptr.is_null() {
+            lit(NULL)
+        } else {
+            unsafe { Box::from_raw(length_ptr) }.inner
+        };
+        let new_expr = ctx.inner.list().slice(offset.inner, length);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// list.head(n) / list.tail(n)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_head(
+    expr_ptr: *mut ExprContext,
+    n_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let n = unsafe { Box::from_raw(n_ptr) };
+        let new_expr = ctx.inner.list().head(n.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_list_tail(
+    expr_ptr: *mut ExprContext,
+    n_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let n = unsafe { Box::from_raw(n_ptr) };
+        let new_expr = ctx.inner.list().tail(n.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// ==========================================
+// Math
+// ==========================================
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_log(
+    expr_ptr: *mut ExprContext, 
+    base: f64 // <--- 这里是 f64，不是 *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        // Polars API: log(base: f64)
+        let new_expr = ctx.inner.log(base); 
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_round(
+    expr_ptr: *mut ExprContext, 
+    decimals: u32
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        // round 默认行为
+        let new_expr = ctx.inner.round(decimals, RoundMode::HalfAwayFromZero); 
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// ==========================================
+// Expr Meta (内省)
+// ==========================================
+// 以下函数只借用 expr，C# 端仍然持有句柄
+
+// 输出列名；表达式没有确定的输出名 (如 cols("*")) 时返回 NULL 并设置错误
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_meta_output_name(expr_ptr: *mut ExprContext) -> *mut c_char {
+    ffi_try!({
+        let ctx = unsafe { &*expr_ptr };
+        let name = ctx.inner.clone().meta().output_name()?;
+        Ok(std::ffi::CString::new(name.as_str()).unwrap().into_raw())
+    })
+}
+
+// 表达式引用的所有根列名
+// 返回字符串指针数组，长度写入 out_len；用 pl_free_string_array 释放
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_meta_root_names(
+    expr_ptr: *mut ExprContext,
+    out_len: *mut usize
+) -> *mut *mut c_char {
+    ffi_try!({
+        let ctx = unsafe { &*expr_ptr };
+        let names: Box<[*mut c_char]> = ctx.inner.clone().meta().root_names()
+            .iter()
+            .map(|n| std::ffi::CString::new(n.as_str()).unwrap().into_raw())
+            .collect();
+        unsafe { *out_len = names.len() };
+        Ok(Box::into_raw(names) as *mut *mut c_char)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_free_string_array(ptr: *mut *mut c_char, len: usize) {
+    if ptr.is_null() { return; }
+    let names = unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)) };
+    for &p in names.iter() {
+        if !p.is_null() {
+            unsafe { let _ = std::ffi::CString::from_raw(p); }
+        }
+    }
+}
+
+// 树形结构的文本表示；as_dot = true 时输出 Graphviz dot 格式
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_meta_tree_format(expr_ptr: *mut ExprContext, as_dot: bool) -> *mut c_char {
+    ffi_try!({
+        let ctx = unsafe { &*expr_ptr };
+        let tree = ctx.inner.clone().meta().into_tree_formatter(as_dot, None)?.to_string();
+        Ok(std::ffi::CString::new(tree).unwrap().into_raw())
+    })
+}
+
+// 结构相等 (同样的树、同样的参数)，用于 C# 端做表达式缓存的 key
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_meta_eq(a_ptr: *mut ExprContext, b_ptr: *mut ExprContext) -> bool {
+    let a = unsafe { &*a_ptr };
+    let b = unsafe { &*b_ptr };
+    a.inner == b.inner
+}
+
+// ==========================================
+// Expr 序列化
+// ==========================================
+// format: 0 = 二进制 (Polars 内部格式，紧凑但只保证同版本 Polars 可读), 1 = JSON (可读，方便存进作业配置)
+// 注意：包含 C# UDF (pl_expr_map 等) 的表达式无法序列化
+fn serialize_expr(expr: &Expr, format: i32) -> PolarsResult<Vec<u8>> {
+    match format {
+        0 => {
+            let mut buf = Vec::new();
+            polars::polars_utils::pl_serialize::serialize_into_writer::<_, _, true>(&mut buf, expr)?;
+            Ok(buf)
+        },
+        1 => serde_json::to_vec(expr).map_err(|e| PolarsError::ComputeError(e.to_string().into())),
+        _ => Err(PolarsError::ComputeError(format!("Unknown serialization format: {}", format).into())),
+    }
+}
+
+fn deserialize_expr(bytes: &[u8], format: i32) -> PolarsResult<Expr> {
+    match format {
+        0 => polars::polars_utils::pl_serialize::deserialize_from_reader::<_, _, true>(bytes),
+        1 => serde_json::from_slice(bytes).map_err(|e| PolarsError::ComputeError(e.to_string().into())),
+        _ => Err(PolarsError::ComputeError(format!("Unknown serialization format: {}", format).into())),
+    }
+}
+
+// 借用 expr；返回字节数组，长度写入 out_len，用 pl_free_bytes 释放
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_log(
-    expr_ptr: *mut ExprContext, 
-    base: f64 // <--- 这里是 f64，不是 *mut ExprContext
-) -> *mut ExprContext {
+pub extern "C" fn pl_expr_serialize(
+    expr_ptr: *mut ExprContext,
+    format: i32,
+    out_len: *mut usize
+) -> *mut u8 {
     ffi_try!({
-        let ctx = unsafe { Box::from_raw(expr_ptr) };
-        // Polars API: log(base: f64)
-        let new_expr = ctx.inner.log(base); 
-        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+        let ctx = unsafe { &*expr_ptr };
+        let bytes = serialize_expr(&ctx.inner, format)?.into_boxed_slice();
+        unsafe { *out_len = bytes.len() };
+        Ok(Box::into_raw(bytes) as *mut u8)
     })
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_expr_round(
-    expr_ptr: *mut ExprContext, 
-    decimals: u32
+pub extern "C" fn pl_expr_deserialize(
+    bytes_ptr: *const u8,
+    len: usize,
+    format: i32
 ) -> *mut ExprContext {
     ffi_try!({
-        let ctx = unsafe { Box::from_raw(expr_ptr) };
-        // round 默认行为
-        let new_expr = ctx.inner.round(decimals, RoundMode::HalfAwayFromZero); 
-        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+        let bytes = unsafe { std::slice::from_raw_parts(bytes_ptr, len) };
+        let expr = deserialize_expr(bytes, format)?;
+        Ok(Box::into_raw(Box::new(ExprContext { inner: expr })))
     })
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_free_bytes(ptr: *mut u8, len: usize) {
+    if !ptr.is_null() {
+        unsafe { let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)); }
+    }
+}
+
 // ==========================================
 // Meta Data
 // ==========================================
@@ -887,6 +2095,12 @@ pub extern "C" fn pl_expr_len() -> *mut ExprContext {
     })
 }
 
+// pl_expr_len 的别名 (对应 Python 的 pl.len())：当前上下文的行数，group_by 中即为每组大小 (列名 "len")
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_len() -> *mut ExprContext {
+    pl_expr_len()
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_prefix(
     expr_ptr: *mut ExprContext, 
@@ -913,6 +2127,23 @@ pub extern "C" fn pl_expr_suffix(
     })
 }
 
+// name 命名空间下的别名，与 keep / to_lowercase 等保持同一前缀
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_name_prefix(
+    expr_ptr: *mut ExprContext,
+    prefix_ptr: *const c_char
+) -> *mut ExprContext {
+    pl_expr_prefix(expr_ptr, prefix_ptr)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_name_suffix(
+    expr_ptr: *mut ExprContext,
+    suffix_ptr: *const c_char
+) -> *mut ExprContext {
+    pl_expr_suffix(expr_ptr, suffix_ptr)
+}
+
 // --- Struct Ops (struct 命名空间) ---
 
 // 1. as_struct(exprs) -> Expr (构造结构体)
@@ -981,6 +2212,57 @@ pub unsafe extern "C" fn pl_expr_struct_rename_fields(
     Box::into_raw(Box::new(new_expr))
 }
 
+// 3. 一次取出多个字段 (展开成多列，支持 "*" 取全部字段)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_struct_field(
+    expr_ptr: *mut ExprContext,
+    names_ptr: *const *const c_char,
+    len: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let slice = unsafe { std::slice::from_raw_parts(names_ptr, len) };
+        let names: Vec<&str> = slice.iter().map(|&p| ptr_to_str(p).unwrap()).collect();
+        let new_expr = ctx.inner.struct_().field_by_names(names);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 4. 添加 / 替换字段 (同名字段会被覆盖)
+// field_exprs 中可以用 pl_expr_field 引用当前 struct 已有的字段
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_struct_with_fields(
+    expr_ptr: *mut ExprContext,
+    field_exprs_ptr: *const *mut ExprContext,
+    len: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let fields = unsafe { consume_exprs_array(field_exprs_ptr, len) };
+        let new_expr = ctx.inner.struct_().with_fields(fields);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// pl.field(name)：只在 struct.with_fields 内部有意义，指向当前 struct 的字段
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_field(name_ptr: *const c_char) -> *mut ExprContext {
+    ffi_try!({
+        let name = ptr_to_str(name_ptr).unwrap();
+        let new_expr = Expr::Field(Arc::from([PlSmallStr::from_str(name)]));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 与 pl_concat_str / pl_date_range 等自由函数保持同样的命名
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_as_struct(
+    exprs_ptr: *const *mut ExprContext,
+    len: usize
+) -> *mut ExprContext {
+    pl_expr_as_struct(exprs_ptr, len)
+}
+
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn pl_expr_struct_json_encode(
     expr: *mut Expr
@@ -991,6 +2273,21 @@ pub unsafe extern "C" fn pl_expr_struct_json_encode(
     Box::into_raw(Box::new(new_expr))
 }
 
+// 把多列打包成一行 JSON 字符串: {"col_a": ..., "col_b": ...}
+// 等价于 as_struct(exprs).struct.json_encode()，用于生成消息总线的事件 payload 列
+// 输出列名沿用第一个表达式的名字，需要时由调用方 alias
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_row_json_encode(
+    exprs_ptr: *const *mut ExprContext,
+    len: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let exprs = unsafe { consume_exprs_array(exprs_ptr, len) };
+        let new_expr = as_struct(exprs).struct_().json_encode();
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_over(
     expr_ptr: *mut ExprContext,
@@ -1031,6 +2328,33 @@ pub extern "C" fn pl_expr_cast(
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
 }
+
+// 与 pl_expr_cast 相同 (借用 expr)，额外控制溢出行为：
+// wrap_numerical: 数值溢出时按位截断回绕 (如 300 -> u8 得 44)，优先于 strict
+// strict: 转换失败时报错；false 则返回 Null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_cast_opts(
+    expr_ptr: *mut ExprContext,
+    dtype_ptr: *mut DataTypeContext,
+    strict: bool,
+    wrap_numerical: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { &*expr_ptr };
+        let target_dtype = unsafe { &(*dtype_ptr).dtype };
+
+        let options = if wrap_numerical {
+            polars_core::chunked_array::cast::CastOptions::Overflowing
+        } else if strict {
+            polars_core::chunked_array::cast::CastOptions::Strict
+        } else {
+            polars_core::chunked_array::cast::CastOptions::NonStrict
+        };
+        let new_expr = ctx.inner.clone().cast_with_options(target_dtype.clone(), options);
+
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
 // --- Time Series: Shift / Diff ---
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_shift(
@@ -1129,6 +2453,14 @@ pub extern "C" fn pl_expr_count(expr_ptr: *mut ExprContext) -> *mut ExprContext
     Box::into_raw(Box::new(ExprContext { inner: new_expr }))
 }
 
+// 是否存在 Null -> Boolean
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_has_nulls(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    let ctx = unsafe { Box::from_raw(expr_ptr) };
+    let new_expr = ctx.inner.null_count().gt(lit(0));
+    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_std(expr_ptr: *mut ExprContext, ddof: u8) -> *mut ExprContext {
     let ctx = unsafe { Box::from_raw(expr_ptr)};
@@ -1151,6 +2483,35 @@ pub extern "C" fn pl_expr_median(expr_ptr: *mut ExprContext) -> *mut ExprContext
     Box::into_raw(Box::new(ExprContext { inner: new_expr }))
 }
 
+// approx_n_unique (HyperLogLog)，precision 控制寄存器个数 (2^precision)
+// precision 越大越精确，标准误差约为 1.04 / sqrt(2^precision)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_approx_n_unique_hll(
+    expr_ptr: *mut ExprContext,
+    precision: u8
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        // 提前校验，避免错误延迟到 collect 时才暴露
+        if !(4..=18).contains(&precision) {
+            return Err(PolarsError::ComputeError(
+                format!("HyperLogLog precision must be between 4 and 18, got {}", precision).into()
+            ));
+        }
+
+        let new_expr = ctx.inner.agg_with_fmt_str(
+            move |c: Column| {
+                let s = c.as_materialized_series();
+                let n = hll_estimate(s, precision)?;
+                Ok(Some(Column::new(s.name().clone(), [n])))
+            },
+            GetOutput::from_type(DataType::UInt64),
+            "approx_n_unique_hll"
+        );
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
 // quantile(quantile, interpolation)
 // interpolation: "nearest", "higher", "lower", "midpoint", "linear"
 #[unsafe(no_mangle)]
@@ -1175,6 +2536,273 @@ pub extern "C" fn pl_expr_quantile(
     Box::into_raw(Box::new(ExprContext { inner: new_expr }))
 }
 
+// 偏度；bias=false 时做样本偏差修正
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_skew(expr_ptr: *mut ExprContext, bias: bool) -> *mut ExprContext {
+    let ctx = unsafe { Box::from_raw(expr_ptr) };
+    let new_expr = ctx.inner.skew(bias);
+    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
+}
+
+// 峰度；fisher=true 时减去 3.0 (正态分布为 0)，bias=false 时做样本偏差修正
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_kurtosis(expr_ptr: *mut ExprContext, fisher: bool, bias: bool) -> *mut ExprContext {
+    let ctx = unsafe { Box::from_raw(expr_ptr) };
+    let new_expr = ctx.inner.kurtosis(fisher, bias);
+    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
+}
+
+// 信息熵 -sum(pk * log(pk))；normalize=true 时先把值归一化为概率
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_entropy(expr_ptr: *mut ExprContext, base: f64, normalize: bool) -> *mut ExprContext {
+    let ctx = unsafe { Box::from_raw(expr_ptr) };
+    let new_expr = ctx.inner.entropy(base, normalize);
+    Box::into_raw(Box::new(ExprContext { inner: new_expr }))
+}
+
+// --- Distinct ---
+gen_unary_op!(pl_expr_n_unique, n_unique);
+gen_unary_op!(pl_expr_arg_unique, arg_unique);
+// 每个不同值出现的次数 (按首次出现的顺序)
+gen_unary_op!(pl_expr_unique_counts, unique_counts);
+gen_unary_op!(pl_expr_is_unique, is_unique);
+gen_unary_op!(pl_expr_is_duplicated, is_duplicated);
+// 每个值第一次 / 最后一次出现的位置为 true，用于标记重复行而不删除数据
+gen_unary_op!(pl_expr_is_first_distinct, is_first_distinct);
+gen_unary_op!(pl_expr_is_last_distinct, is_last_distinct);
+
+// maintain_order: 保持首次出现的顺序 (更慢)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_unique(expr_ptr: *mut ExprContext, maintain_order: bool) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let new_expr = if maintain_order {
+            ctx.inner.unique_stable()
+        } else {
+            ctx.inner.unique()
+        };
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- Weighted ---
+// 加权平均: sum(x * w) / sum(w)，x 为 Null 的行其权重不计入分母
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_weighted_mean(
+    expr_ptr: *mut ExprContext,
+    weights_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let w = unsafe { Box::from_raw(weights_ptr) };
+        let e = ctx.inner;
+
+        let numerator = (e.clone() * w.inner.clone()).sum();
+        let denominator = w.inner.filter(e.is_not_null()).sum();
+        let new_expr = numerator.cast(DataType::Float64) / denominator.cast(DataType::Float64);
+
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 加权求和: sum(x * w)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_weighted_sum(
+    expr_ptr: *mut ExprContext,
+    weights_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let w = unsafe { Box::from_raw(weights_ptr) };
+        let new_expr = (ctx.inner * w.inner).sum();
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 点积: sum(a * b)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_dot(
+    expr_ptr: *mut ExprContext,
+    other_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let other = unsafe { Box::from_raw(other_ptr) };
+        let new_expr = ctx.inner.dot(other.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- Share ---
+// 占比: x / sum(x) over(partition)；partition 为空时按全表求和
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_fraction_of_total(
+    expr_ptr: *mut ExprContext,
+    partition_by_ptr: *const *mut ExprContext,
+    len: usize
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let partition_by = unsafe { consume_exprs_array(partition_by_ptr, len) };
+        let e = ctx.inner;
+
+        let total = if partition_by.is_empty() {
+            e.clone().sum()
+        } else {
+            e.clone().sum().over(partition_by)
+        };
+        let new_expr = e.cast(DataType::Float64) / total.cast(DataType::Float64);
+
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- Streaks ---
+// 连续满足条件的行数: 条件为 true 时返回当前连续段内的序号 (1, 2, 3...)，否则为 0
+// 实现: rle_id 给每个连续段编号，段内 cum_count 即为序号
+fn streak_length_expr(cond: Expr) -> Expr {
+    let run_id = cond.clone().rle_id();
+    let pos = cond.clone().cum_count(false).over([run_id]);
+    when(cond.fill_null(lit(false)))
+        .then(pos.cast(DataType::UInt32))
+        .otherwise(lit(0u32))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_streak_length(cond_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(cond_ptr) };
+        let new_expr = streak_length_expr(ctx.inner);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 连续上涨次数: 与上一行相比增大时计入，持平/下降/Null 时归零
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_is_increasing_streak(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let increased = ctx.inner.diff(lit(1), Default::default()).gt(lit(0));
+        let new_expr = streak_length_expr(increased);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 连续下跌次数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_is_decreasing_streak(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let decreased = ctx.inner.diff(lit(1), Default::default()).lt(lit(0));
+        let new_expr = streak_length_expr(decreased);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- Outliers ---
+// IQR 规则: x < Q1 - k*IQR 或 x > Q3 + k*IQR 时为 true (k 通常取 1.5)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_is_outlier_iqr(expr_ptr: *mut ExprContext, k: f64) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let e = ctx.inner;
+
+        let q1 = e.clone().quantile(lit(0.25), QuantileMethod::Linear);
+        let q3 = e.clone().quantile(lit(0.75), QuantileMethod::Linear);
+        let iqr = q3.clone() - q1.clone();
+
+        let lower = q1 - lit(k) * iqr.clone();
+        let upper = q3 + lit(k) * iqr;
+        let new_expr = e.clone().lt(lower).or(e.gt(upper));
+
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// z-score 标准化: (x - mean) / std (样本标准差, ddof=1)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_zscore(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let e = ctx.inner;
+        let new_expr = (e.clone() - e.clone().mean()) / e.std(1);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// --- Binning ---
+// labels 可为 NULL (自动生成 "(a, b]" 形式的标签)，否则个数必须是 区间数 (breaks + 1)
+unsafe fn read_bin_labels(labels_ptr: *const *const c_char, labels_len: usize) -> Option<Vec<PlSmallStr>> {
+    if labels_ptr.is_null() {
+        return None;
+    }
+    let slice = unsafe { std::slice::from_raw_parts(labels_ptr, labels_len) };
+    Some(slice.iter().map(|&p| PlSmallStr::from_str(ptr_to_str(p).unwrap())).collect())
+}
+
+// 按给定断点分箱 -> Categorical
+// left_closed: 区间为 [a, b) 而不是 (a, b]
+// include_breaks: 输出 Struct { breakpoint, category }
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_cut(
+    expr_ptr: *mut ExprContext,
+    breaks_ptr: *const f64,
+    breaks_len: usize,
+    labels_ptr: *const *const c_char,
+    labels_len: usize,
+    left_closed: bool,
+    include_breaks: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let breaks = unsafe { std::slice::from_raw_parts(breaks_ptr, breaks_len) }.to_vec();
+        let labels = unsafe { read_bin_labels(labels_ptr, labels_len) };
+        let new_expr = ctx.inner.cut(breaks, labels, left_closed, include_breaks);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 按分位数分箱 (如 [0.1, 0.2, ..., 0.9] 即十分位)
+// allow_duplicates: 分位点重复时合并区间，否则报错
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_qcut(
+    expr_ptr: *mut ExprContext,
+    quantiles_ptr: *const f64,
+    quantiles_len: usize,
+    labels_ptr: *const *const c_char,
+    labels_len: usize,
+    left_closed: bool,
+    allow_duplicates: bool,
+    include_breaks: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let probs = unsafe { std::slice::from_raw_parts(quantiles_ptr, quantiles_len) }.to_vec();
+        let labels = unsafe { read_bin_labels(labels_ptr, labels_len) };
+        let new_expr = ctx.inner.qcut(probs, labels, left_closed, allow_duplicates, include_breaks);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// 等频分成 n_bins 份，等价于 qcut([1/n, 2/n, ...])
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_qcut_uniform(
+    expr_ptr: *mut ExprContext,
+    n_bins: usize,
+    labels_ptr: *const *const c_char,
+    labels_len: usize,
+    left_closed: bool,
+    allow_duplicates: bool,
+    include_breaks: bool
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let labels = unsafe { read_bin_labels(labels_ptr, labels_len) };
+        let new_expr = ctx.inner.qcut_uniform(n_bins, labels, left_closed, allow_duplicates, include_breaks);
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_expr_fill_nan(
     expr: *mut ExprContext,
@@ -0,0 +1,237 @@
+use polars::prelude::*;
+use crate::types::{DataFrameContext, SchemaContext};
+
+// ==========================================
+// 按 Schema 生成随机测试数据
+// ==========================================
+// 给 C# 集成测试 / Benchmark 造 fixture 用，不追求统计意义上的随机性，
+// 只要求同一个 seed 生成完全相同的数据，所以用自带的 SplitMix64 而不是引入 rand。
+
+// 2000-01-01 / 2029-12-31 距 epoch 的天数
+const DATE_MIN_DAYS: i64 = 10_957;
+const DATE_MAX_DAYS: i64 = 21_914;
+const SECONDS_PER_DAY: i64 = 86_400;
+const NS_PER_DAY: i64 = SECONDS_PER_DAY * 1_000_000_000;
+// 数值列的取值范围，避免生成一堆难以阅读的大数
+const NUMERIC_ABS_MAX: i64 = 1_000;
+// List 每行的最大长度
+const MAX_LIST_LEN: i64 = 3;
+const STRING_LEN: usize = 8;
+const DEFAULT_CATEGORY_COUNT: usize = 10;
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // [lo, hi] 闭区间
+    fn range_i64(&mut self, lo: i64, hi: i64) -> i64 {
+        let span = (hi - lo) as u64 + 1;
+        lo + (self.next_u64() % span) as i64
+    }
+}
+
+struct Generator {
+    rng: SplitMix64,
+    null_ratio: f64,
+}
+
+impl Generator {
+    fn is_null(&mut self) -> bool {
+        self.null_ratio > 0.0 && self.rng.next_f64() < self.null_ratio
+    }
+
+    fn ints(&mut self, n: usize, lo: i64, hi: i64) -> Vec<Option<i64>> {
+        (0..n)
+            .map(|_| if self.is_null() { None } else { Some(self.rng.range_i64(lo, hi)) })
+            .collect()
+    }
+
+    fn floats(&mut self, n: usize) -> Vec<Option<f64>> {
+        let span = (NUMERIC_ABS_MAX * 2) as f64;
+        (0..n)
+            .map(|_| {
+                if self.is_null() { None } else { Some(self.rng.next_f64() * span - NUMERIC_ABS_MAX as f64) }
+            })
+            .collect()
+    }
+
+    fn strings(&mut self, n: usize) -> Vec<Option<String>> {
+        (0..n)
+            .map(|_| {
+                if self.is_null() {
+                    return None;
+                }
+                let s = (0..STRING_LEN)
+                    .map(|_| ALPHANUMERIC[(self.rng.next_u64() % ALPHANUMERIC.len() as u64) as usize] as char)
+                    .collect();
+                Some(s)
+            })
+            .collect()
+    }
+
+    fn pick<'a>(&mut self, n: usize, choices: &[&'a str]) -> Vec<Option<&'a str>> {
+        (0..n)
+            .map(|_| {
+                if self.is_null() || choices.is_empty() {
+                    None
+                } else {
+                    Some(choices[(self.rng.next_u64() % choices.len() as u64) as usize])
+                }
+            })
+            .collect()
+    }
+
+    // 整数类型：[-1000, 1000] 与类型取值范围求交集
+    fn int_bounds(dtype: &DataType) -> (i64, i64) {
+        let (type_min, type_max) = match dtype {
+            DataType::Int8 => (i8::MIN as i64, i8::MAX as i64),
+            DataType::Int16 => (i16::MIN as i64, i16::MAX as i64),
+            DataType::UInt8 => (0, u8::MAX as i64),
+            DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => (0, i64::MAX),
+            _ => (i64::MIN, i64::MAX),
+        };
+        (type_min.max(-NUMERIC_ABS_MAX), type_max.min(NUMERIC_ABS_MAX))
+    }
+
+    // 每行随机长度的 List：先生成扁平的内部列，再按行切片
+    fn list_rows(&mut self, name: PlSmallStr, inner: &DataType, lengths: Vec<i64>) -> PolarsResult<Series> {
+        let total = lengths.iter().sum::<i64>() as usize;
+        let flat = self.column(name.clone(), inner, total)?;
+
+        let mut offset = 0i64;
+        let rows: Vec<Series> = lengths
+            .into_iter()
+            .map(|len| {
+                let row = flat.slice(offset, len as usize);
+                offset += len;
+                row
+            })
+            .collect();
+        Ok(Series::new(name, rows))
+    }
+
+    fn column(&mut self, name: PlSmallStr, dtype: &DataType, n: usize) -> PolarsResult<Series> {
+        if n == 0 {
+            return Ok(Series::new_empty(name, dtype));
+        }
+
+        let s = match dtype {
+            DataType::Null => Series::full_null(name, n, dtype),
+            DataType::Boolean => {
+                let values: Vec<Option<bool>> = (0..n)
+                    .map(|_| if self.is_null() { None } else { Some(self.rng.next_u64() & 1 == 1) })
+                    .collect();
+                Series::new(name, values)
+            },
+            dt if dt.is_integer() => {
+                let (lo, hi) = Self::int_bounds(dt);
+                Series::new(name, self.ints(n, lo, hi)).cast(dt)?
+            },
+            dt if dt.is_float() => Series::new(name, self.floats(n)).cast(dt)?,
+            DataType::Decimal(_, _) => Series::new(name, self.floats(n)).cast(dtype)?,
+            DataType::String => Series::new(name, self.strings(n)),
+            DataType::Binary => Series::new(name, self.strings(n)).cast(dtype)?,
+            DataType::Date => {
+                Series::new(name, self.ints(n, DATE_MIN_DAYS, DATE_MAX_DAYS))
+                    .cast(&DataType::Int32)?
+                    .cast(dtype)?
+            },
+            DataType::Datetime(unit, _) | DataType::Duration(unit) => {
+                let per_second = match unit {
+                    TimeUnit::Nanoseconds => 1_000_000_000,
+                    TimeUnit::Microseconds => 1_000_000,
+                    TimeUnit::Milliseconds => 1_000,
+                };
+                // Datetime: 2000 ~ 2029 之间的任意时刻；Duration: 0 ~ 1 天
+                let (lo, hi) = if matches!(dtype, DataType::Datetime(_, _)) {
+                    (DATE_MIN_DAYS * SECONDS_PER_DAY * per_second, (DATE_MAX_DAYS + 1) * SECONDS_PER_DAY * per_second - 1)
+                } else {
+                    (0, SECONDS_PER_DAY * per_second)
+                };
+                Series::new(name, self.ints(n, lo, hi)).cast(dtype)?
+            },
+            DataType::Time => Series::new(name, self.ints(n, 0, NS_PER_DAY - 1)).cast(dtype)?,
+            DataType::Categorical(cats, _) => {
+                // 已有类别时从中抽样，否则生成 cat_0 ~ cat_9
+                let mapping = cats.mapping();
+                let existing: Vec<&str> = (0..mapping.num_cats_upper_bound())
+                    .filter_map(|i| mapping.cat_to_str(i as CatSize))
+                    .collect();
+                let defaults: Vec<String> = (0..DEFAULT_CATEGORY_COUNT).map(|i| format!("cat_{}", i)).collect();
+                let choices: Vec<&str> = if existing.is_empty() {
+                    defaults.iter().map(|s| s.as_str()).collect()
+                } else {
+                    existing
+                };
+                Series::new(name, self.pick(n, &choices)).cast(dtype)?
+            },
+            DataType::Enum(fcats, _) => {
+                let choices: Vec<&str> = fcats.categories().values_iter().collect();
+                Series::new(name, self.pick(n, &choices)).cast(dtype)?
+            },
+            DataType::List(inner) => {
+                let lengths = (0..n).map(|_| self.rng.range_i64(0, MAX_LIST_LEN)).collect();
+                self.list_rows(name, inner, lengths)?.cast(dtype)?
+            },
+            DataType::Array(inner, width) => {
+                let lengths = vec![*width as i64; n];
+                self.list_rows(name, inner, lengths)?.cast(dtype)?
+            },
+            DataType::Struct(fields) => {
+                let children = fields
+                    .iter()
+                    .map(|f| self.column(f.name.clone(), &f.dtype, n))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructChunked::from_series(name, n, children.iter())?.into_series()
+            },
+            dt => {
+                return Err(PolarsError::ComputeError(
+                    format!("Cannot generate data for dtype: {}", dt).into()
+                ));
+            },
+        };
+        Ok(s)
+    }
+}
+
+// schema 只借用
+// seed: 相同 seed + schema 生成完全相同的数据
+// null_ratio: 每个值为 Null 的概率 (0.0 ~ 1.0)，嵌套类型作用于叶子节点
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_generate(
+    schema_ptr: *mut SchemaContext,
+    n_rows: usize,
+    seed: u64,
+    null_ratio: f64
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*schema_ptr };
+        if !(0.0..=1.0).contains(&null_ratio) {
+            return Err(PolarsError::ComputeError(
+                format!("null_ratio must be between 0 and 1, got {}", null_ratio).into()
+            ));
+        }
+
+        let mut generator = Generator { rng: SplitMix64(seed), null_ratio };
+        let columns = ctx.schema
+            .iter()
+            .map(|(name, dtype)| generator.column(name.clone(), dtype, n_rows).map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let df = DataFrame::new(columns)?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
@@ -0,0 +1,136 @@
+use polars::prelude::*;
+use crate::types::ExprContext;
+
+// ==========================================
+// WKB (Well-Known Binary) Point 辅助函数
+// ==========================================
+// SQL Server / PostGIS 导出的位置数据通常是 WKB 二进制，这里只处理最常见的 Point：
+//   [byte order: 1 字节][geometry type: u32][x: f64][y: f64]
+// 兼容 EWKB (带 SRID 标志位) 和 ISO Z/M/ZM 变体 (只取 x/y)。
+// 非 Point 或格式错误的值返回 Null，而不是让整列失败。
+
+const WKB_POINT: u32 = 1;
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+fn read_u32(bytes: &[u8], offset: usize, little_endian: bool) -> Option<u32> {
+    let b: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+    Some(if little_endian { u32::from_le_bytes(b) } else { u32::from_be_bytes(b) })
+}
+
+fn read_f64(bytes: &[u8], offset: usize, little_endian: bool) -> Option<f64> {
+    let b: [u8; 8] = bytes.get(offset..offset + 8)?.try_into().ok()?;
+    Some(if little_endian { f64::from_le_bytes(b) } else { f64::from_be_bytes(b) })
+}
+
+fn parse_wkb_point(bytes: &[u8]) -> Option<(f64, f64)> {
+    let little_endian = match *bytes.first()? {
+        0 => false,
+        1 => true,
+        _ => return None,
+    };
+    let raw_type = read_u32(bytes, 1, little_endian)?;
+
+    // EWKB: 高位标志 + 可选 SRID；ISO: 1001/2001/3001 表示 Z/M/ZM
+    let mut offset = 5;
+    if raw_type & EWKB_SRID_FLAG != 0 {
+        offset += 4;
+    }
+    let base_type = (raw_type & !(EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG)) % 1000;
+    if base_type != WKB_POINT {
+        return None;
+    }
+
+    let x = read_f64(bytes, offset, little_endian)?;
+    let y = read_f64(bytes, offset + 8, little_endian)?;
+    Some((x, y))
+}
+
+fn encode_wkb_point(x: f64, y: f64) -> Vec<u8> {
+    // 统一输出 little-endian 的 2D Point
+    let mut buf = Vec::with_capacity(21);
+    buf.push(1u8);
+    buf.extend_from_slice(&WKB_POINT.to_le_bytes());
+    buf.extend_from_slice(&x.to_le_bytes());
+    buf.extend_from_slice(&y.to_le_bytes());
+    buf
+}
+
+// Binary (WKB Point) -> Struct { x: f64, y: f64 }
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_bin_wkb_to_xy(expr_ptr: *mut ExprContext) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let output_type = DataType::Struct(vec![
+            Field::new("x".into(), DataType::Float64),
+            Field::new("y".into(), DataType::Float64),
+        ]);
+
+        let new_expr = ctx.inner.map(
+            |c| {
+                let ca = c.binary()?;
+                let len = ca.len();
+                let mut xs: Vec<Option<f64>> = Vec::with_capacity(len);
+                let mut ys: Vec<Option<f64>> = Vec::with_capacity(len);
+
+                for opt in ca.into_iter() {
+                    match opt.and_then(parse_wkb_point) {
+                        Some((x, y)) => { xs.push(Some(x)); ys.push(Some(y)); },
+                        None => { xs.push(None); ys.push(None); },
+                    }
+                }
+
+                let x = Series::new("x".into(), xs);
+                let y = Series::new("y".into(), ys);
+                let out = StructChunked::from_series(c.name().clone(), len, [x, y].iter())?;
+                Ok(Some(out.into_series().into_column()))
+            },
+            GetOutput::from_type(output_type)
+        );
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+
+// (x, y) -> Binary (WKB Point)；任一坐标为 Null 时结果为 Null
+// 长度为 1 的一侧 (如 Lit(0.0)) 广播到另一侧的长度，其余长度不一致时报 ShapeMismatch
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_xy_to_wkb(
+    x_ptr: *mut ExprContext,
+    y_ptr: *mut ExprContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let x_ctx = unsafe { Box::from_raw(x_ptr) };
+        let y_ctx = unsafe { Box::from_raw(y_ptr) };
+
+        let new_expr = x_ctx.inner.map_many(
+            |cols| {
+                let mut x = cols[0].cast(&DataType::Float64)?;
+                let mut y = cols[1].cast(&DataType::Float64)?;
+                if x.len() != y.len() {
+                    if x.len() == 1 {
+                        x = x.new_from_index(0, y.len());
+                    } else if y.len() == 1 {
+                        y = y.new_from_index(0, x.len());
+                    } else {
+                        return Err(PolarsError::ShapeMismatch(
+                            format!("xy_to_wkb: x has {} values but y has {}", x.len(), y.len()).into()
+                        ));
+                    }
+                }
+                let out: BinaryChunked = x.f64()?
+                    .into_iter()
+                    .zip(y.f64()?.into_iter())
+                    .map(|(x, y)| match (x, y) {
+                        (Some(x), Some(y)) => Some(encode_wkb_point(x, y)),
+                        _ => None,
+                    })
+                    .collect();
+                Ok(Some(out.with_name(cols[0].name().clone()).into_column()))
+            },
+            &[y_ctx.inner],
+            GetOutput::from_type(DataType::Binary)
+        );
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
@@ -3,10 +3,17 @@ use polars_arrow::ffi::{self, ArrowArray, ArrowSchema, export_array_to_c, export
 use polars_arrow::array::StructArray;
 use polars_arrow::datatypes::{ArrowDataType, Field};
 use polars_core::prelude::CompatLevel;
-use std::ffi::{CStr, c_void};
-use std::io::BufReader;
+use polars_io::RowIndex;
+use polars_io::avro::{AvroCompression, AvroReader, AvroWriter};
+use polars_io::parquet::write::KeyValueMetadata;
+use std::ffi::{CStr, CString, c_void};
+use std::io::{BufReader, Cursor};
+use std::num::NonZeroUsize;
 use std::os::raw::c_char;
 use std::fs::File;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 use crate::types::{DataFrameContext, LazyFrameContext, SchemaContext};
 use crate::utils::ptr_to_str;
 
@@ -91,6 +98,169 @@ pub extern "C" fn pl_scan_csv(
     })
 }
 // ==========================================
+// CSV 完整读取选项
+// ==========================================
+// C# 端按同样的字段顺序定义 [StructLayout(LayoutKind.Sequential)] 结构体，按指针传入
+#[repr(C)]
+pub struct CsvReadArgs {
+    pub has_header: bool,
+    pub separator: u8,
+    pub quote_char: u8,                    // 0 = 不处理引号
+    pub eol_char: u8,
+    pub skip_rows: usize,
+    pub n_rows: i64,                       // < 0 = 读取全部
+    pub infer_schema_length: i64,          // < 0 = 扫描全部行推断类型
+    pub comment_prefix: *const c_char,     // NULL = 无注释行
+    pub null_values: *const *const c_char, // 这些字符串在所有列中都视为 Null
+    pub null_values_len: usize,
+    pub encoding: i32,                     // 0 = Utf8, 1 = LossyUtf8 (非法字节替换为 U+FFFD)
+    pub try_parse_dates: bool,
+    pub ignore_errors: bool,
+    pub truncate_ragged_lines: bool,
+    pub low_memory: bool,
+    pub decimal_comma: bool,
+    pub missing_is_null: bool,
+}
+
+impl CsvReadArgs {
+    fn quote_char(&self) -> Option<u8> {
+        (self.quote_char != 0).then_some(self.quote_char)
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        (self.n_rows >= 0).then_some(self.n_rows as usize)
+    }
+
+    fn infer_schema_length(&self) -> Option<usize> {
+        (self.infer_schema_length >= 0).then_some(self.infer_schema_length as usize)
+    }
+
+    fn comment_prefix(&self) -> Option<PlSmallStr> {
+        if self.comment_prefix.is_null() {
+            None
+        } else {
+            Some(ptr_to_str(self.comment_prefix).unwrap().into())
+        }
+    }
+
+    fn null_values(&self) -> Option<NullValues> {
+        if self.null_values.is_null() || self.null_values_len == 0 {
+            return None;
+        }
+        let slice = unsafe { std::slice::from_raw_parts(self.null_values, self.null_values_len) };
+        let values = slice.iter().map(|&p| ptr_to_str(p).unwrap().into()).collect();
+        Some(NullValues::AllColumns(values))
+    }
+
+    fn encoding(&self) -> PolarsResult<CsvEncoding> {
+        match self.encoding {
+            0 => Ok(CsvEncoding::Utf8),
+            1 => Ok(CsvEncoding::LossyUtf8),
+            _ => Err(PolarsError::ComputeError(format!("Unknown csv encoding code: {}", self.encoding).into())),
+        }
+    }
+
+    // 文件 / 内存缓冲区的 eager 读取共用
+    fn to_read_options(&self, schema: Option<SchemaRef>) -> PolarsResult<CsvReadOptions> {
+        let parse_options = CsvParseOptions::default()
+            .with_separator(self.separator)
+            .with_quote_char(self.quote_char())
+            .with_eol_char(self.eol_char)
+            .with_comment_prefix(self.comment_prefix().as_deref())
+            .with_null_values(self.null_values())
+            .with_encoding(self.encoding()?)
+            .with_try_parse_dates(self.try_parse_dates)
+            .with_truncate_ragged_lines(self.truncate_ragged_lines)
+            .with_decimal_comma(self.decimal_comma)
+            .with_missing_is_null(self.missing_is_null);
+
+        Ok(CsvReadOptions::default()
+            .with_has_header(self.has_header)
+            .with_skip_rows(self.skip_rows)
+            .with_n_rows(self.n_rows())
+            .with_infer_schema_length(self.infer_schema_length())
+            .with_ignore_errors(self.ignore_errors)
+            .with_low_memory(self.low_memory)
+            .with_parse_options(parse_options)
+            .with_schema_overwrite(schema))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_csv_with_options(
+    path: *const c_char,
+    schema_ptr: *mut SchemaContext,
+    args_ptr: *const CsvReadArgs
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
+        let args = unsafe { &*args_ptr };
+
+        let schema = if schema_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { &*schema_ptr }.schema.clone())
+        };
+
+        let options = args.to_read_options(schema)?;
+
+        let df = options
+            .try_into_reader_with_file_path(Some(p.into_owned().into()))?
+            .finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_csv_with_options(
+    path: *const c_char,
+    schema_ptr: *mut SchemaContext,
+    args_ptr: *const CsvReadArgs
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
+        let args = unsafe { &*args_ptr };
+
+        let schema = if schema_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { &*schema_ptr }.schema.clone())
+        };
+
+        let reader = apply_csv_args(LazyCsvReader::new(PlPath::new(&p)), args, schema)?;
+
+        let inner = reader.finish()?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner })))
+    })
+}
+
+// 单文件 / 多文件 scan 共用同一套 CsvReadArgs
+fn apply_csv_args(
+    reader: LazyCsvReader,
+    args: &CsvReadArgs,
+    schema: Option<SchemaRef>
+) -> PolarsResult<LazyCsvReader> {
+    Ok(reader
+        .with_has_header(args.has_header)
+        .with_separator(args.separator)
+        .with_quote_char(args.quote_char())
+        .with_eol_char(args.eol_char)
+        .with_comment_prefix(args.comment_prefix())
+        .with_null_values(args.null_values())
+        .with_encoding(args.encoding()?)
+        .with_skip_rows(args.skip_rows)
+        .with_n_rows(args.n_rows())
+        .with_infer_schema_length(args.infer_schema_length())
+        .with_try_parse_dates(args.try_parse_dates)
+        .with_ignore_errors(args.ignore_errors)
+        .with_truncate_ragged_lines(args.truncate_ragged_lines)
+        .with_low_memory(args.low_memory)
+        .with_decimal_comma(args.decimal_comma)
+        .with_missing_is_null(args.missing_is_null)
+        .with_dtype_overwrite(schema))
+}
+// ==========================================
 // 读取 Parquet
 // ==========================================
 #[unsafe(no_mangle)]
@@ -123,6 +293,124 @@ pub extern "C" fn pl_scan_parquet(path_ptr: *const c_char) -> *mut LazyFrameCont
     })
 }
 
+// ==========================================
+// Parquet 读取选项
+// ==========================================
+// C# 端按同样的字段顺序定义 [StructLayout(LayoutKind.Sequential)] 结构体，按指针传入
+// 约定：columns 为 NULL 读全部列；n_rows < 0 读全部行；row_index_name 为 NULL 不加行号列
+#[repr(C)]
+pub struct ParquetReadArgs {
+    pub columns: *const *const c_char,
+    pub columns_len: usize,
+    pub n_rows: i64,
+    pub row_index_name: *const c_char,
+    pub row_index_offset: u32,
+    pub parallel: i32,          // 0 Auto, 1 None, 2 Columns, 3 RowGroups, 4 Prefiltered
+    pub use_statistics: bool,   // 用 row group 统计信息跳过不满足谓词的 row group (仅 scan)
+    pub low_memory: bool,
+    pub rechunk: bool,
+    pub cache: bool,            // 仅 scan
+    pub glob: bool,             // 仅 scan
+}
+
+impl ParquetReadArgs {
+    fn columns(&self) -> Option<Vec<String>> {
+        if self.columns.is_null() {
+            return None;
+        }
+        let slice = unsafe { std::slice::from_raw_parts(self.columns, self.columns_len) };
+        Some(slice.iter().map(|&p| ptr_to_str(p).unwrap().to_string()).collect())
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        (self.n_rows >= 0).then_some(self.n_rows as usize)
+    }
+
+    fn row_index(&self) -> Option<RowIndex> {
+        if self.row_index_name.is_null() {
+            None
+        } else {
+            Some(RowIndex {
+                name: ptr_to_str(self.row_index_name).unwrap().into(),
+                offset: self.row_index_offset as IdxSize,
+            })
+        }
+    }
+
+    fn parallel(&self) -> PolarsResult<ParallelStrategy> {
+        match self.parallel {
+            0 => Ok(ParallelStrategy::Auto),
+            1 => Ok(ParallelStrategy::None),
+            2 => Ok(ParallelStrategy::Columns),
+            3 => Ok(ParallelStrategy::RowGroups),
+            4 => Ok(ParallelStrategy::Prefiltered),
+            _ => Err(PolarsError::ComputeError(format!("Unknown parallel strategy code: {}", self.parallel).into())),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_parquet_with_options(
+    path_ptr: *const c_char,
+    args_ptr: *const ParquetReadArgs
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let args = unsafe { &*args_ptr };
+
+        let file = File::open(path)
+            .map_err(|e| PolarsError::ComputeError(format!("File not found: {}", e).into()))?;
+
+        let df = ParquetReader::new(file)
+            .with_columns(args.columns())
+            .with_slice(args.n_rows().map(|n| (0, n)))
+            .with_row_index(args.row_index())
+            .read_parallel(args.parallel()?)
+            .set_low_memory(args.low_memory)
+            .set_rechunk(args.rechunk)
+            .finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_parquet_with_options(
+    path_ptr: *const c_char,
+    args_ptr: *const ParquetReadArgs
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let args = unsafe { &*args_ptr };
+
+        let scan_args = ScanArgsParquet {
+            n_rows: args.n_rows(),
+            parallel: args.parallel()?,
+            row_index: args.row_index(),
+            use_statistics: args.use_statistics,
+            low_memory: args.low_memory,
+            rechunk: args.rechunk,
+            cache: args.cache,
+            glob: args.glob,
+            ..Default::default()
+        };
+        let mut lf = LazyFrame::scan_parquet(PlPath::new(path), scan_args)?;
+
+        // 列投影用 select 表达，优化器会下推到 reader；行号列要一并保留
+        if let Some(columns) = args.columns() {
+            let mut names: Vec<PlSmallStr> = columns.into_iter().map(PlSmallStr::from).collect();
+            if let Some(ri) = args.row_index() {
+                names.insert(0, ri.name);
+            }
+            lf = lf.select(names.into_iter().map(col).collect::<Vec<_>>());
+        }
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
+
 // ==========================================
 // 读取 JSON
 // ==========================================
@@ -153,6 +441,46 @@ pub extern "C" fn pl_scan_ndjson(path_ptr: *const c_char) -> *mut LazyFrameConte
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
     })
 }
+
+// C# 端按同样的字段顺序定义顺序布局结构体
+#[repr(C)]
+pub struct NdJsonReadArgs {
+    pub infer_schema_length: i64,   // <= 0 = 扫描全部行推断 schema
+    pub batch_size: i64,            // <= 0 = 默认
+    pub n_rows: i64,                // < 0 = 读全部行
+    pub ignore_errors: bool,        // 解析失败的行置为 Null，而不是让整个 scan 失败
+    pub low_memory: bool,
+}
+
+// schema_ptr: 完整 schema，跳过推断；schema_overwrite_ptr: 只覆盖推断出的部分列类型。均可为 NULL
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_ndjson_with_options(
+    path_ptr: *const c_char,
+    schema_ptr: *mut SchemaContext,
+    schema_overwrite_ptr: *mut SchemaContext,
+    args_ptr: *const NdJsonReadArgs
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let args = unsafe { &*args_ptr };
+
+        let schema = (!schema_ptr.is_null()).then(|| unsafe { &*schema_ptr }.schema.clone());
+        let schema_overwrite = (!schema_overwrite_ptr.is_null()).then(|| unsafe { &*schema_overwrite_ptr }.schema.clone());
+        let non_zero = |v: i64| NonZeroUsize::new(v.max(0) as usize);
+
+        let lf = LazyJsonLineReader::new(PlPath::new(path))
+            .with_schema(schema)
+            .with_schema_overwrite(schema_overwrite)
+            .with_infer_schema_length(non_zero(args.infer_schema_length))
+            .with_batch_size(non_zero(args.batch_size))
+            .with_n_rows((args.n_rows >= 0).then_some(args.n_rows as usize))
+            .with_ignore_errors(args.ignore_errors)
+            .low_memory(args.low_memory)
+            .finish()?;
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
 // ==========================================
 // IPC
 // ==========================================
@@ -167,6 +495,28 @@ pub extern "C" fn pl_read_ipc(path_ptr: *const c_char) -> *mut DataFrameContext
         Ok(Box::into_raw(Box::new(DataFrameContext { df })))
     })
 }
+
+// memory_map: 把文件映射进地址空间，列缓冲区直接指向映射区域，不再整体拷进内存。
+// 仅对未压缩的 IPC 文件生效；压缩文件会自动退回普通读取。
+// 注意：映射期间文件不能被覆盖或截断，否则读到的数据未定义。
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_ipc_with_args(
+    path_ptr: *const c_char,
+    memory_map: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr).unwrap();
+        let file = File::open(path).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let df = IpcReader::new(file)
+            .memory_mapped(memory_map.then(|| path.into()))
+            .finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// scan 本地文件时引擎本身就是通过内存映射按需读取的，无需额外开关
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_scan_ipc(path_ptr: *const c_char) -> *mut LazyFrameContext {
     ffi_try!({
@@ -182,13 +532,36 @@ pub extern "C" fn pl_scan_ipc(path_ptr: *const c_char) -> *mut LazyFrameContext
 pub extern "C" fn pl_lazy_sink_ipc(
     lf_ptr: *mut LazyFrameContext,
     path_ptr: *const c_char
+) {
+    // 默认不压缩，与 IpcWriterOptions::default() 一致
+    pl_lazy_sink_ipc_with_compression(lf_ptr, path_ptr, 0)
+}
+
+// IPC 压缩编码：0 = 不压缩, 1 = LZ4, 2 = ZSTD
+fn map_ipc_compression(code: i32) -> PolarsResult<Option<IpcCompression>> {
+    match code {
+        0 => Ok(None),
+        1 => Ok(Some(IpcCompression::LZ4)),
+        2 => Ok(Some(IpcCompression::ZSTD)),
+        _ => Err(PolarsError::ComputeError(format!("Unknown ipc compression code: {}", code).into())),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_ipc_with_compression(
+    lf_ptr: *mut LazyFrameContext,
+    path_ptr: *const c_char,
+    compression: i32
 ) {
     ffi_try_void!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
         let path = ptr_to_str(path_ptr).unwrap();
 
         // 1. 准备选项
-        let writer_options = IpcWriterOptions::default();
+        let writer_options = IpcWriterOptions {
+            compression: map_ipc_compression(compression)?,
+            ..Default::default()
+        };
         let sink_options = SinkOptions::default();
 
         // 2. 构造 Target (使用 PlPath::new 自动处理本地/云路径)
@@ -212,6 +585,213 @@ pub extern "C" fn pl_lazy_sink_ipc(
     })
 }
 
+// ==========================================
+// 多文件 / Glob 扫描
+// ==========================================
+// 单路径的 scan 默认已开启 glob，"data/*.parquet" 或 "data/**/*.parquet" 直接可用；
+// 这里再提供显式路径列表的版本，所有文件合并成一个 LazyFrame。
+// Hive 分区目录 (year=2024/month=01/...) 会自动识别为分区列。
+fn read_paths(paths_ptr: *const *const c_char, len: usize) -> PolarsResult<Arc<[PlPath]>> {
+    if len == 0 {
+        return Err(PolarsError::ComputeError("At least one path is required".into()));
+    }
+    let slice = unsafe { std::slice::from_raw_parts(paths_ptr, len) };
+    Ok(slice.iter().map(|&p| PlPath::new(ptr_to_str(p).unwrap())).collect())
+}
+
+fn include_file_paths(ptr: *const c_char) -> Option<PlSmallStr> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(ptr_to_str(ptr).unwrap().into())
+    }
+}
+
+// allow_missing_columns: 后面的文件缺列时填 Null，而不是报 schema 不一致
+// include_file_paths_ptr: 非空时追加一列记录每行来自哪个文件
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_parquet_multi(
+    paths_ptr: *const *const c_char,
+    len: usize,
+    allow_missing_columns: bool,
+    include_file_paths_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let paths = read_paths(paths_ptr, len)?;
+        let args = ScanArgsParquet {
+            allow_missing_columns,
+            include_file_paths: include_file_paths(include_file_paths_ptr),
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_parquet_files(paths, args)?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_ipc_multi(
+    paths_ptr: *const *const c_char,
+    len: usize,
+    include_file_paths_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let paths = read_paths(paths_ptr, len)?;
+        let args = ScanArgsIpc {
+            include_file_paths: include_file_paths(include_file_paths_ptr),
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_ipc_files(paths, args)?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
+
+// schema_ptr / args_ptr 与 pl_scan_csv_with_options 相同；所有文件按第一个文件的 schema 解析
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_csv_multi(
+    paths_ptr: *const *const c_char,
+    len: usize,
+    schema_ptr: *mut SchemaContext,
+    args_ptr: *const CsvReadArgs,
+    include_file_paths_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let paths = read_paths(paths_ptr, len)?;
+        let args = unsafe { &*args_ptr };
+
+        let schema = if schema_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { &*schema_ptr }.schema.clone())
+        };
+
+        let inner = apply_csv_args(LazyCsvReader::new_paths(paths), args, schema)?
+            .with_include_file_paths(include_file_paths(include_file_paths_ptr))
+            .finish()?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_ndjson_multi(
+    paths_ptr: *const *const c_char,
+    len: usize,
+    include_file_paths_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let paths = read_paths(paths_ptr, len)?;
+        let lf = LazyJsonLineReader::new_paths(paths)
+            .with_include_file_paths(include_file_paths(include_file_paths_ptr))
+            .finish()?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
+
+// ==========================================
+// 从内存缓冲区读取
+// ==========================================
+// 数据已经在 C# 的 byte[] 里 (HTTP 下载、数据库 blob 等) 时不必先落临时文件。
+// 缓冲区由调用方持有，只需在调用期间保持固定 (fixed / pinned)；返回的 DataFrame 不再引用它。
+fn byte_cursor<'a>(ptr: *const u8, len: usize) -> PolarsResult<Cursor<&'a [u8]>> {
+    if ptr.is_null() {
+        return Err(PolarsError::ComputeError("Null buffer pointer".into()));
+    }
+    Ok(Cursor::new(unsafe { std::slice::from_raw_parts(ptr, len) }))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_csv_bytes(
+    ptr: *const u8,
+    len: usize,
+    schema_ptr: *mut SchemaContext,
+    args_ptr: *const CsvReadArgs
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let cursor = byte_cursor(ptr, len)?;
+        let args = unsafe { &*args_ptr };
+
+        let schema = if schema_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { &*schema_ptr }.schema.clone())
+        };
+
+        let df = args.to_read_options(schema)?
+            .into_reader_with_file_handle(cursor)
+            .finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_parquet_bytes(ptr: *const u8, len: usize) -> *mut DataFrameContext {
+    ffi_try!({
+        let df = ParquetReader::new(byte_cursor(ptr, len)?).finish()?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_ipc_bytes(ptr: *const u8, len: usize) -> *mut DataFrameContext {
+    ffi_try!({
+        let df = IpcReader::new(byte_cursor(ptr, len)?).finish()?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// 与 pl_read_json 一样按 JSON 数组解析
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_json_bytes(ptr: *const u8, len: usize) -> *mut DataFrameContext {
+    ffi_try!({
+        let df = JsonReader::new(byte_cursor(ptr, len)?).finish()?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// ==========================================
+// 结果落盘 (Spooling)
+// ==========================================
+// 执行查询并把结果流式写入 spool_dir 下的临时 IPC 文件，返回对该文件的 scan。
+// 结果不会驻留在托管堆/原生堆中，后续查询通过内存映射按需读取。
+// 注意：文件不会自动删除，由调用方负责清理 spool_dir。
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect_spooled(
+    lf_ptr: *mut LazyFrameContext,
+    spool_dir_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        static SPOOL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let spool_dir = ptr_to_str(spool_dir_ptr).unwrap();
+        std::fs::create_dir_all(spool_dir)?;
+
+        // 进程号 + 时间戳 + 计数器，保证同一目录下多进程/多线程不冲突
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let seq = SPOOL_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let file_name = format!("polars_spool_{}_{}_{}.arrow", std::process::id(), nanos, seq);
+        let path = Path::new(spool_dir).join(file_name);
+        let path_str = path.to_string_lossy().to_string();
+
+        // 不压缩，确保后续 scan 可以零拷贝内存映射
+        let writer_options = IpcWriterOptions {
+            compression: None,
+            ..Default::default()
+        };
+        let target = SinkTarget::Path(PlPath::new(&path_str));
+        lf_ctx.inner
+            .sink_ipc(target, writer_options, None, SinkOptions::default())?
+            .with_new_streaming(true)
+            .collect()?;
+
+        let lf = LazyFrame::scan_ipc(PlPath::new(&path_str), ScanArgsIpc::default())?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_dataframe_from_arrow_record_batch(
     c_array_ptr: *mut ffi::ArrowArray, 
@@ -272,39 +852,333 @@ pub extern "C" fn pl_dataframe_from_arrow_record_batch(
 // ==========================================
 
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_write_csv(df_ptr: *mut DataFrameContext, path_ptr: *const c_char) {
+pub extern "C" fn pl_write_csv(df_ptr: *mut DataFrameContext, path_ptr: *const c_char) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let mut file = File::create(path)
+            .map_err(|e| PolarsError::ComputeError(format!("Could not create file: {}", e).into()))?;
+
+        CsvWriter::new(&mut file)
+            .finish(&mut ctx.df)?;
+        
+        Ok(())
+    })
+}
+
+// ==========================================
+// CSV 写出选项
+// ==========================================
+// 与 CsvReadArgs 一样，C# 端按相同字段顺序定义顺序布局结构体
+#[repr(C)]
+pub struct CsvWriteArgs {
+    pub separator: u8,
+    pub quote_char: u8,
+    pub quote_style: i32,                 // 0 = Necessary, 1 = Always, 2 = NonNumeric, 3 = Never
+    pub line_terminator: *const c_char,   // NULL = "\n"
+    pub date_format: *const c_char,       // NULL = 默认格式 (chrono)
+    pub time_format: *const c_char,
+    pub datetime_format: *const c_char,
+    pub float_precision: i32,             // < 0 = 不固定小数位
+    pub null_value: *const c_char,        // NULL = 空字符串
+    pub include_header: bool,
+    pub include_bom: bool,
+}
+
+impl CsvWriteArgs {
+    fn to_writer_options(&self) -> PolarsResult<CsvWriterOptions> {
+        let opt_str = |p: *const c_char| (!p.is_null()).then(|| ptr_to_str(p).unwrap().to_string());
+
+        let quote_style = match self.quote_style {
+            0 => QuoteStyle::Necessary,
+            1 => QuoteStyle::Always,
+            2 => QuoteStyle::NonNumeric,
+            3 => QuoteStyle::Never,
+            _ => return Err(PolarsError::ComputeError(
+                format!("Unknown csv quote style code: {}", self.quote_style).into()
+            )),
+        };
+
+        let mut options = CsvWriterOptions {
+            include_bom: self.include_bom,
+            include_header: self.include_header,
+            ..Default::default()
+        };
+        let so = &mut options.serialize_options;
+        so.separator = self.separator;
+        so.quote_char = self.quote_char;
+        so.quote_style = quote_style;
+        so.date_format = opt_str(self.date_format);
+        so.time_format = opt_str(self.time_format);
+        so.datetime_format = opt_str(self.datetime_format);
+        so.float_precision = (self.float_precision >= 0).then_some(self.float_precision as usize);
+        if let Some(t) = opt_str(self.line_terminator) {
+            so.line_terminator = t;
+        }
+        if let Some(n) = opt_str(self.null_value) {
+            so.null = n;
+        }
+        Ok(options)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_csv_with_options(
+    df_ptr: *mut DataFrameContext,
+    path_ptr: *const c_char,
+    args_ptr: *const CsvWriteArgs
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let options = unsafe { &*args_ptr }.to_writer_options()?;
+        let so = options.serialize_options;
+
+        let mut file = File::create(path)
+            .map_err(|e| PolarsError::ComputeError(format!("Could not create file: {}", e).into()))?;
+
+        CsvWriter::new(&mut file)
+            .include_bom(options.include_bom)
+            .include_header(options.include_header)
+            .with_separator(so.separator)
+            .with_quote_char(so.quote_char)
+            .with_quote_style(so.quote_style)
+            .with_line_terminator(so.line_terminator)
+            .with_null_value(so.null)
+            .with_date_format(so.date_format)
+            .with_time_format(so.time_format)
+            .with_datetime_format(so.datetime_format)
+            .with_float_precision(so.float_precision)
+            .finish(&mut ctx.df)?;
+
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_parquet(df_ptr: *mut DataFrameContext, path_ptr: *const c_char) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let file = File::create(path)
+            .map_err(|e| PolarsError::ComputeError(format!("Could not create file: {}", e).into()))?;
+
+        ParquetWriter::new(file)
+            .finish(&mut ctx.df)?;
+            
+        Ok(())
+    })
+}
+
+// ==========================================
+// Parquet 写出选项
+// ==========================================
+// C# 端按相同字段顺序定义顺序布局结构体；未设置的数值一律传 -1 走 Polars 默认值
+#[repr(C)]
+pub struct ParquetWriteArgs {
+    pub compression: i32,        // 0 = Uncompressed, 1 = Snappy, 2 = Gzip, 3 = Brotli, 4 = Zstd, 5 = Lz4Raw
+    pub compression_level: i32,  // < 0 = 编码器默认级别；仅 Gzip(0-9) / Brotli(0-11) / Zstd(1-22) 有效
+    pub statistics: i32,         // 0 = 默认 (min/max/null_count), 1 = 全部 (含 distinct_count), 2 = 不写
+    pub row_group_size: i64,     // < 0 = 默认
+    pub data_page_size: i64,     // < 0 = 默认 (1MB)
+}
+
+impl ParquetWriteArgs {
+    fn to_write_options(&self) -> PolarsResult<ParquetWriteOptions> {
+        let level = (self.compression_level >= 0).then_some(self.compression_level);
+        fn level_err(e: impl std::fmt::Display) -> PolarsError {
+            PolarsError::ComputeError(format!("Invalid compression level: {}", e).into())
+        }
+
+        let compression = match self.compression {
+            0 => ParquetCompression::Uncompressed,
+            1 => ParquetCompression::Snappy,
+            // 先做无损的整数转换，越界直接报错，不能截断 (例如 265 as u8 == 9)
+            2 => ParquetCompression::Gzip(level.map(|l| {
+                u8::try_from(l).map_err(level_err).and_then(|l| GzipLevel::try_new(l).map_err(level_err))
+            }).transpose()?),
+            3 => ParquetCompression::Brotli(level.map(|l| {
+                u32::try_from(l).map_err(level_err).and_then(|l| BrotliLevel::try_new(l).map_err(level_err))
+            }).transpose()?),
+            4 => ParquetCompression::Zstd(level.map(ZstdLevel::try_new).transpose().map_err(level_err)?),
+            5 => ParquetCompression::Lz4Raw,
+            _ => return Err(PolarsError::ComputeError(
+                format!("Unknown parquet compression code: {}", self.compression).into()
+            )),
+        };
+
+        let statistics = match self.statistics {
+            0 => StatisticsOptions::default(),
+            1 => StatisticsOptions::full(),
+            2 => StatisticsOptions::empty(),
+            _ => return Err(PolarsError::ComputeError(
+                format!("Unknown parquet statistics code: {}", self.statistics).into()
+            )),
+        };
+
+        Ok(ParquetWriteOptions {
+            compression,
+            statistics,
+            row_group_size: (self.row_group_size >= 0).then_some(self.row_group_size as usize),
+            data_page_size: (self.data_page_size >= 0).then_some(self.data_page_size as usize),
+            ..Default::default()
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_parquet_with_options(
+    df_ptr: *mut DataFrameContext,
+    path_ptr: *const c_char,
+    args_ptr: *const ParquetWriteArgs
+) {
     ffi_try_void!({
         let ctx = unsafe { &mut *df_ptr };
         let path = ptr_to_str(path_ptr)
             .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let options = unsafe { &*args_ptr }.to_write_options()?;
 
-        let mut file = File::create(path)
+        let file = File::create(path)
             .map_err(|e| PolarsError::ComputeError(format!("Could not create file: {}", e).into()))?;
 
-        CsvWriter::new(&mut file)
+        ParquetWriter::new(file)
+            .with_compression(options.compression)
+            .with_statistics(options.statistics)
+            .with_row_group_size(options.row_group_size)
+            .with_data_page_size(options.data_page_size)
             .finish(&mut ctx.df)?;
-        
+
         Ok(())
     })
 }
 
+// ==========================================
+// Parquet 描述信息 (key_value_metadata)
+// ==========================================
+// 表级描述和列级描述统一写进文件级 key_value_metadata：
+// - TABLE_DESCRIPTION_KEY  => 表描述字符串
+// - COLUMN_DESCRIPTIONS_KEY => JSON 对象 {"列名": "描述"}
+const TABLE_DESCRIPTION_KEY: &str = "polars_net.description";
+const COLUMN_DESCRIPTIONS_KEY: &str = "polars_net.column_descriptions";
+
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_write_parquet(df_ptr: *mut DataFrameContext, path_ptr: *const c_char) {
+pub extern "C" fn pl_write_parquet_with_descriptions(
+    df_ptr: *mut DataFrameContext,
+    path_ptr: *const c_char,
+    table_desc_ptr: *const c_char,        // NULL = 不写表描述
+    col_names_ptr: *const *const c_char,
+    col_descs_ptr: *const *const c_char,
+    len: usize
+) {
     ffi_try_void!({
         let ctx = unsafe { &mut *df_ptr };
         let path = ptr_to_str(path_ptr)
             .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
 
+        let mut kv: Vec<(String, String)> = Vec::new();
+        if !table_desc_ptr.is_null() {
+            kv.push((TABLE_DESCRIPTION_KEY.to_string(), ptr_to_str(table_desc_ptr).unwrap().to_string()));
+        }
+
+        if len > 0 {
+            let names = unsafe { std::slice::from_raw_parts(col_names_ptr, len) };
+            let descs = unsafe { std::slice::from_raw_parts(col_descs_ptr, len) };
+
+            let mut map = serde_json::Map::with_capacity(len);
+            for (&n, &d) in names.iter().zip(descs.iter()) {
+                let name = ptr_to_str(n).unwrap();
+                // 描述的列必须存在，否则读回来时对不上
+                if ctx.df.column(name).is_err() {
+                    return Err(PolarsError::ColumnNotFound(format!("Cannot describe missing column: {}", name).into()));
+                }
+                map.insert(name.to_string(), serde_json::Value::String(ptr_to_str(d).unwrap().to_string()));
+            }
+            let json = serde_json::to_string(&map)
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            kv.push((COLUMN_DESCRIPTIONS_KEY.to_string(), json));
+        }
+
         let file = File::create(path)
             .map_err(|e| PolarsError::ComputeError(format!("Could not create file: {}", e).into()))?;
 
         ParquetWriter::new(file)
+            .with_key_value_metadata(Some(KeyValueMetadata::from_static(kv)))
             .finish(&mut ctx.df)?;
-            
+
         Ok(())
     })
 }
 
+// 读取 Parquet 文件级 key_value_metadata，返回 JSON: {"key": "value", ...}
+// value 为空的条目返回 null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_parquet_key_value_metadata(path_ptr: *const c_char) -> *mut c_char {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let file = File::open(path)
+            .map_err(|e| PolarsError::ComputeError(format!("File not found: {}", e).into()))?;
+
+        let mut reader = ParquetReader::new(file);
+        let metadata = reader.get_metadata()?;
+
+        let mut map = serde_json::Map::new();
+        if let Some(kvs) = metadata.key_value_metadata() {
+            for kv in kvs {
+                let value = match &kv.value {
+                    Some(v) => serde_json::Value::String(v.clone()),
+                    None => serde_json::Value::Null,
+                };
+                map.insert(kv.key.clone(), value);
+            }
+        }
+
+        let json = serde_json::to_string(&map)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        Ok(CString::new(json).unwrap().into_raw())
+    })
+}
+
+// 读回 pl_write_parquet_with_descriptions 写入的描述
+// 返回 JSON: {"table": "..." | null, "columns": {"列名": "描述"}}
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_parquet_descriptions(path_ptr: *const c_char) -> *mut c_char {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let file = File::open(path)
+            .map_err(|e| PolarsError::ComputeError(format!("File not found: {}", e).into()))?;
+
+        let mut reader = ParquetReader::new(file);
+        let metadata = reader.get_metadata()?;
+
+        let mut table = serde_json::Value::Null;
+        let mut columns = serde_json::Value::Object(serde_json::Map::new());
+        if let Some(kvs) = metadata.key_value_metadata() {
+            for kv in kvs {
+                match (kv.key.as_str(), &kv.value) {
+                    (TABLE_DESCRIPTION_KEY, Some(v)) => table = serde_json::Value::String(v.clone()),
+                    (COLUMN_DESCRIPTIONS_KEY, Some(v)) => {
+                        columns = serde_json::from_str(v)
+                            .map_err(|e| PolarsError::ComputeError(format!("Invalid column descriptions: {}", e).into()))?;
+                    },
+                    _ => {}
+                }
+            }
+        }
+
+        let json = serde_json::json!({ "table": table, "columns": columns }).to_string();
+        Ok(CString::new(json).unwrap().into_raw())
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_dataframe_write_ipc(df_ptr: *mut DataFrameContext, path: *const c_char) {
     ffi_try_void!({
@@ -318,6 +1192,101 @@ pub extern "C" fn pl_dataframe_write_ipc(df_ptr: *mut DataFrameContext, path: *c
     })
 }
 
+// compression 编码同 map_ipc_compression
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_write_ipc_with_compression(
+    df_ptr: *mut DataFrameContext,
+    path: *const c_char,
+    compression: i32
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
+
+        let file = File::create(p.as_ref()).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        IpcWriter::new(file)
+            .with_compression(map_ipc_compression(compression)?)
+            .finish(&mut ctx.df)
+    })
+}
+
+// ==========================================
+// Arrow IPC Stream 格式
+// ==========================================
+// 与 IPC File (Feather v2) 不同，Stream 格式没有文件尾的 footer，
+// 可以边写边读，适合通过管道 / socket 传给 Arrow Flight 一类的消费端。
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_ipc_stream(
+    df_ptr: *mut DataFrameContext,
+    path: *const c_char,
+    compression: i32
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
+
+        let file = File::create(p.as_ref()).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        IpcStreamWriter::new(file)
+            .with_compression(map_ipc_compression(compression)?)
+            .finish(&mut ctx.df)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_ipc_stream(path_ptr: *const c_char) -> *mut DataFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr).unwrap();
+        let file = File::open(path).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let df = IpcStreamReader::new(file).finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// ==========================================
+// Avro
+// ==========================================
+// compression: 0 = 不压缩, 1 = Deflate, 2 = Snappy
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_avro(
+    df_ptr: *mut DataFrameContext,
+    path: *const c_char,
+    compression: i32
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
+
+        let compression = match compression {
+            0 => None,
+            1 => Some(AvroCompression::Deflate),
+            2 => Some(AvroCompression::Snappy),
+            _ => return Err(PolarsError::ComputeError(format!("Unknown avro compression code: {}", compression).into())),
+        };
+
+        let file = File::create(p.as_ref()).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        AvroWriter::new(file)
+            .with_compression(compression)
+            .finish(&mut ctx.df)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_avro(path_ptr: *const c_char) -> *mut DataFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr).unwrap();
+        let file = File::open(path).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let df = AvroReader::new(file).finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_dataframe_write_json(df_ptr: *mut DataFrameContext, path: *const c_char) {
     ffi_try_void!({
@@ -333,6 +1302,21 @@ pub extern "C" fn pl_dataframe_write_json(df_ptr: *mut DataFrameContext, path: *
     })
 }
 
+// NDJSON：每行一个 JSON 对象，适合日志采集类系统逐行消费
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_write_ndjson(df_ptr: *mut DataFrameContext, path: *const c_char) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
+
+        let file = File::create(p.as_ref()).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        JsonWriter::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut ctx.df)
+    })
+}
+
 
 // ==========================================
 // 3. 内存与转换操作
@@ -361,26 +1345,10 @@ pub extern "C" fn pl_to_arrow(
         }
         
         let ctx = unsafe { &mut *ctx_ptr };
-        let df = &mut ctx.df;
-
-        let columns = df.get_columns()
-            .iter()
-            .map(|s| s.clone().rechunk_to_arrow(CompatLevel::newest()))
-            .collect::<Vec<_>>();
-
-        let arrow_schema = df.schema().to_arrow(CompatLevel::newest());
-        let fields: Vec<Field> = arrow_schema.iter_values().cloned().collect();
-
-        let struct_array = StructArray::new(
-            ArrowDataType::Struct(fields.clone()), 
-            df.height(),
-            columns,
-            None
-        );
+        let (struct_array, root_field) = dataframe_to_struct_array(&ctx.df);
 
         unsafe {
             *out_chunk = export_array_to_c(Box::new(struct_array));
-            let root_field = Field::new("".into(), ArrowDataType::Struct(fields), false);
             *out_schema = export_field_to_c(&root_field);
         }
         
@@ -388,6 +1356,26 @@ pub extern "C" fn pl_to_arrow(
     })
 }
 
+// DataFrame -> 单个 StructArray (每列先 rechunk)，附带根 Field
+fn dataframe_to_struct_array(df: &DataFrame) -> (StructArray, Field) {
+    let columns = df.get_columns()
+        .iter()
+        .map(|s| s.clone().rechunk_to_arrow(CompatLevel::newest()))
+        .collect::<Vec<_>>();
+
+    let arrow_schema = df.schema().to_arrow(CompatLevel::newest());
+    let fields: Vec<Field> = arrow_schema.iter_values().cloned().collect();
+
+    let struct_array = StructArray::new(
+        ArrowDataType::Struct(fields.clone()), 
+        df.height(),
+        columns,
+        None
+    );
+    let root_field = Field::new("".into(), ArrowDataType::Struct(fields), false);
+    (struct_array, root_field)
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_sink_parquet(
     lf_ptr: *mut LazyFrameContext,
@@ -420,10 +1408,46 @@ pub extern "C" fn pl_lazy_sink_parquet(
     })
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_parquet_with_options(
+    lf_ptr: *mut LazyFrameContext,
+    path_ptr: *const c_char,
+    args_ptr: *const ParquetWriteArgs
+) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let path_str = ptr_to_str(path_ptr).unwrap();
+        let write_options = unsafe { &*args_ptr }.to_write_options()?;
+
+        let target = SinkTarget::Path(PlPath::new(path_str));
+        let sink_lf = lf_ctx.inner.sink_parquet(
+            target,
+            write_options,
+            None,
+            SinkOptions::default()
+        )?;
+
+        let _ = sink_lf
+        .with_new_streaming(true)
+        .collect()?;
+
+        Ok(())
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_sink_json(
     lf_ptr: *mut LazyFrameContext,
     path_ptr: *const c_char
+) {
+    pl_lazy_sink_ndjson(lf_ptr, path_ptr)
+}
+
+// 流式 JSON sink 只支持逐行输出 (JSON Lines)，无法流式写出一个完整的 JSON 数组
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_ndjson(
+    lf_ptr: *mut LazyFrameContext,
+    path_ptr: *const c_char
 ) {
     ffi_try_void!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
@@ -477,6 +1501,33 @@ pub extern "C" fn pl_lazy_sink_csv(
         Ok(())
     })
 }
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_csv_with_options(
+    lf_ptr: *mut LazyFrameContext,
+    path_ptr: *const c_char,
+    args_ptr: *const CsvWriteArgs
+) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let path_str = ptr_to_str(path_ptr).unwrap();
+        let writer_options = unsafe { &*args_ptr }.to_writer_options()?;
+
+        let target = SinkTarget::Path(PlPath::new(path_str));
+        let sink_lf = lf_ctx.inner.sink_csv(
+            target,
+            writer_options,
+            None,
+            SinkOptions::default()
+        )?;
+
+        let _ = sink_lf
+        .with_new_streaming(true)
+        .collect()?;
+
+        Ok(())
+    })
+}
 // ==========================================
 // Streaming Sink to DataBase
 // ==========================================
@@ -605,6 +1656,126 @@ pub extern "C" fn pl_lazy_map_batches(
     })
 }
 
+// ==========================================
+// map_batches (双向：C# 变换后把结果交回计划)
+// ==========================================
+// 参数 1/2 (Input): 整个 DataFrame 导出的 StructArray + Schema
+// 参数 3 (Output): 空的 ArrowArrayStream，由 C# 导出结果流填入
+// 参数 4 (Error): 错误信息缓冲区 (1KB)
+// 返回: 0 = Ok, 非 0 = Error
+// 所有权：三个结构体都在 Rust 栈上，指针只在回调期间有效，C# 不分配也不释放结构体本身。
+//   - 输入的 array / schema: C# 导入时移走内容；没移走 (导入前就出错) 的由 Rust 在回调返回后 release
+//   - 输出 stream: Rust 读完后 release，C# 的导出器随之释放自己的状态
+type TransformCallback = extern "C" fn(
+    *mut ffi::ArrowArray,
+    *mut ffi::ArrowSchema,
+    *mut ffi::ArrowArrayStream,
+    *mut std::os::raw::c_char
+) -> i32;
+
+struct CSharpTransformUdf {
+    callback: TransformCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void, // GCHandle
+    // validate_output 时的期望 schema；None 表示不校验
+    expected_schema: Option<SchemaRef>,
+}
+
+unsafe impl Send for CSharpTransformUdf {}
+unsafe impl Sync for CSharpTransformUdf {}
+
+impl Drop for CSharpTransformUdf {
+    fn drop(&mut self) {
+        (self.cleanup)(self.user_data);
+    }
+}
+
+impl CSharpTransformUdf {
+    fn call(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let (struct_array, root_field) = dataframe_to_struct_array(&df);
+        let mut c_array = export_array_to_c(Box::new(struct_array));
+        let mut c_schema = export_field_to_c(&root_field);
+        let mut c_stream = ffi::ArrowArrayStream::empty();
+
+        let mut error_msg_buf = [0u8; 1024];
+        let error_ptr = error_msg_buf.as_mut_ptr() as *mut std::os::raw::c_char;
+
+        let status = (self.callback)(&mut c_array, &mut c_schema, &mut c_stream, error_ptr);
+        // C# 已接管的结构体 release 为空，drop 是空操作；否则在这里释放
+        drop(c_array);
+        drop(c_schema);
+        if status != 0 {
+            let msg = unsafe { CStr::from_ptr(error_ptr).to_string_lossy().into_owned() };
+            return Err(PolarsError::ComputeError(format!("C# map_batches failed: {}", msg).into()));
+        }
+
+        // 复用 Eager 的流导入逻辑；c_stream 离开作用域时释放 (已 release 则为空操作)
+        let ctx_ptr = unsafe { crate::eager::pl_dataframe_new_from_stream(&mut c_stream) };
+        if ctx_ptr.is_null() {
+            return Err(PolarsError::ComputeError("Failed to consume stream returned by C# map_batches".into()));
+        }
+        let out = unsafe { Box::from_raw(ctx_ptr) }.df;
+
+        if let Some(expected) = &self.expected_schema
+            && out.schema().as_ref() != expected.as_ref() {
+            return Err(PolarsError::SchemaMismatch(format!(
+                "C# map_batches output schema {:?} does not match expected {:?}",
+                out.schema(), expected
+            ).into()));
+        }
+        Ok(out)
+    }
+}
+
+// output_schema: 可为 NULL，表示输出 schema 与输入相同 (借用，不消费)
+// validate_output: 执行时检查 C# 返回的 schema 是否与声明一致
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_map_batches_transform(
+    lf_ptr: *mut LazyFrameContext,
+    callback: TransformCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void,
+    output_schema: *mut SchemaContext,
+    validate_output: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let mut inner = lf_ctx.inner;
+
+        let output_schema = if output_schema.is_null() {
+            None
+        } else {
+            Some(unsafe { &*output_schema }.schema.clone())
+        };
+
+        // 校验需要知道期望 schema；没给 output_schema 时就是输入 schema
+        let expected_schema = if validate_output {
+            match &output_schema {
+                Some(s) => Some(s.clone()),
+                None => Some(inner.collect_schema()?),
+            }
+        } else {
+            None
+        };
+
+        let udf = Arc::new(CSharpTransformUdf { callback, cleanup, user_data, expected_schema });
+
+        let schema_fn: Option<Arc<dyn UdfSchema>> = output_schema.map(|s| {
+            Arc::new(move |_: &Schema| Ok(s.clone())) as Arc<dyn UdfSchema>
+        });
+
+        // C# 函数可能增删改列，谓词 / 投影都不能穿过它下推
+        let new_lf = inner.map(
+            move |df| udf.call(df),
+            AllowedOptimizations::empty(),
+            schema_fn,
+            Some("csharp_map_batches"),
+        );
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_dataframe_export_batches(
     df_ptr: *mut DataFrameContext,
@@ -654,4 +1825,71 @@ pub extern "C" fn pl_dataframe_export_batches(
         // 运行结束，udf 离开作用域，自动触发 cleanup
         Ok(())
     })
-}
\ No newline at end of file
+}
+// ==========================================
+// Callback Sink (schema -> batches -> finish)
+// ==========================================
+// 查询由流式引擎的 IPC sink 节点驱动 (见 lazy::spawn_batch_iter)，行序和背压由引擎保证；
+// 所有回调在调用线程上串行触发，按行序交付；返回非 0 表示 C# 端失败，错误信息写入缓冲区 (1KB)
+// 所有权：ArrowSchema / ArrowArray 结构体归 Rust，指针只在回调期间有效。
+//   - schema: C# 只复制 (CArrowSchemaImporter.ImportSchema)，回调返回后 Rust 释放
+//   - batch: C# 可在回调内移走内容 (CArrowArrayImporter.ImportRecordBatch 会把 release 置空)；
+//     回调返回后 Rust 释放结构体，若 release 仍非空 (C# 没有接管，例如导入前就出错) 由 Rust 调用 release
+//   成功和失败路径一致，C# 任何情况下都不需要、也不能释放这两个指针
+// schema 回调: (user_data, ArrowSchema, error)
+type SinkSchemaCallback = extern "C" fn(*mut c_void, *mut ffi::ArrowSchema, *mut c_char) -> i32;
+// batch 回调: (user_data, ArrowArray (StructArray), error)，schema 与上面一致
+type SinkBatchCallback = extern "C" fn(*mut c_void, *mut ffi::ArrowArray, *mut c_char) -> i32;
+// finish 回调: (user_data, 总行数, error)
+type SinkFinishCallback = extern "C" fn(*mut c_void, u64, *mut c_char) -> i32;
+
+fn check_sink_status(status: i32, stage: &str, error_buf: &[u8; 1024]) -> PolarsResult<()> {
+    if status != 0 {
+        let msg = unsafe { CStr::from_ptr(error_buf.as_ptr() as *const c_char).to_string_lossy().into_owned() };
+        return Err(PolarsError::ComputeError(format!("C# sink {} callback failed: {}", stage, msg).into()));
+    }
+    Ok(())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_callback(
+    lf_ptr: *mut LazyFrameContext,
+    schema_callback: SinkSchemaCallback,
+    batch_callback: SinkBatchCallback,
+    finish_callback: SinkFinishCallback,
+    user_data: *mut c_void
+) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let mut lf = lf_ctx.inner;
+
+        let mut error_msg_buf = [0u8; 1024];
+        let error_ptr = error_msg_buf.as_mut_ptr() as *mut c_char;
+
+        // 1. 先交付输出 schema (例如让 C# 建表 / 配置 SqlBulkCopy 列映射)
+        let schema = lf.collect_schema()?;
+        let fields: Vec<Field> = schema.to_arrow(CompatLevel::newest()).iter_values().cloned().collect();
+        let root_field = Field::new("".into(), ArrowDataType::Struct(fields), false);
+        let mut c_schema = export_field_to_c(&root_field);
+        let status = schema_callback(user_data, &mut c_schema, error_ptr);
+        drop(c_schema);
+        check_sink_status(status, "schema", &error_msg_buf)?;
+
+        // 2. 流式引擎执行，按 sink 写出的 record batch 交付
+        let mut batches = crate::lazy::spawn_batch_iter(lf, 0)?;
+        let mut row_count: u64 = 0;
+        while let Some(df) = batches.next_batch()? {
+            row_count += df.height() as u64;
+            let (struct_array, _) = dataframe_to_struct_array(&df);
+            let mut c_array = export_array_to_c(Box::new(struct_array));
+            let status = batch_callback(user_data, &mut c_array, error_ptr);
+            // C# 已接管时 release 为空，drop 是空操作；否则在这里释放
+            drop(c_array);
+            check_sink_status(status, "batch", &error_msg_buf)?;
+        }
+
+        // 3. 收尾
+        check_sink_status(finish_callback(user_data, row_count, error_ptr), "finish", &error_msg_buf)?;
+        Ok(())
+    })
+}
@@ -4,6 +4,7 @@ use polars::prelude::*;
 use crate::types::*;
 use polars::lazy::dsl::UnpivotArgsDSL;
 use crate::utils::{consume_exprs_array, map_jointype, ptr_to_str};
+use crate::expr::parse_fixed_window_size;
 
 // ==========================================
 // 宏定义
@@ -87,12 +88,234 @@ gen_lazy_vec_op!(pl_lazy_with_columns, with_columns);
 // --- Filter ---
 gen_lazy_single_expr_op!(pl_lazy_filter, filter);
 
+// --- Fill (作用于所有列) ---
+// fill_nan 只影响浮点列
+gen_lazy_single_expr_op!(pl_lazy_fill_null, fill_null);
+gen_lazy_single_expr_op!(pl_lazy_fill_nan, fill_nan);
+
 // --- Limit ---
 // limit 在 Polars 中通常接受 IdxSize (u32)
 gen_lazy_scalar_op!(pl_lazy_limit, limit, u32);
 // 也可以加个 tail
 gen_lazy_scalar_op!(pl_lazy_tail, tail, u32);
 
+// OFFSET / LIMIT 分页；offset 为负数时从末尾倒数。slice 会下推到 scan
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_slice(
+    lf_ptr: *mut LazyFrameContext,
+    offset: i64,
+    len: u32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let new_lf = lf_ctx.inner.slice(offset, len);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Shift / Reverse / Gather Every (作用于所有列)
+// ==========================================
+// n > 0 向下移动，n < 0 向上移动；fill_ptr 为 NULL 时空出的位置填 Null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_shift(
+    lf_ptr: *mut LazyFrameContext,
+    n: i64,
+    fill_ptr: *mut ExprContext
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let new_lf = if fill_ptr.is_null() {
+            lf_ctx.inner.shift(lit(n))
+        } else {
+            let fill = unsafe { Box::from_raw(fill_ptr) };
+            lf_ctx.inner.shift_and_fill(lit(n), fill.inner)
+        };
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_reverse(lf_ptr: *mut LazyFrameContext) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let new_lf = lf_ctx.inner.reverse();
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 从 offset 开始每隔 n 行取一行 (降采样)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_gather_every(
+    lf_ptr: *mut LazyFrameContext,
+    n: usize,
+    offset: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        if n == 0 {
+            return Err(PolarsError::ComputeError("gather_every n must be greater than 0".into()));
+        }
+        let new_lf = lf_ctx.inner.select([all().as_expr().gather_every(n, offset)]);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// With Context
+// ==========================================
+// 让表达式可以引用辅助 LazyFrame 中的列 (如只有一行的参数表)，无需显式 Join
+// lf 与 contexts 全部被消费
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_with_context(
+    lf_ptr: *mut LazyFrameContext,
+    contexts_ptr: *const *mut LazyFrameContext,
+    len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let slice = unsafe { std::slice::from_raw_parts(contexts_ptr, len) };
+        let contexts: Vec<LazyFrame> = slice
+            .iter()
+            .map(|&p| unsafe { Box::from_raw(p) }.inner)
+            .collect();
+
+        let new_lf = lf_ctx.inner.with_context(contexts);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Rename / Drop
+// ==========================================
+// 直接修改 schema，不用拼完整的 select 列表，也不会阻断投影下推
+fn read_names(names_ptr: *const *const c_char, len: usize) -> Vec<PlSmallStr> {
+    let slice = unsafe { std::slice::from_raw_parts(names_ptr, len) };
+    slice.iter().map(|&p| PlSmallStr::from_str(ptr_to_str(p).unwrap())).collect()
+}
+
+// old/new 一一对应；strict: 旧列名不存在时报错，false 则忽略
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_rename(
+    lf_ptr: *mut LazyFrameContext,
+    old_ptr: *const *const c_char,
+    new_ptr: *const *const c_char,
+    len: usize,
+    strict: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let existing = read_names(old_ptr, len);
+        let new = read_names(new_ptr, len);
+        let new_lf = lf_ctx.inner.rename(existing, new, strict);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// strict: 列不存在时报错，false 则忽略
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_drop(
+    lf_ptr: *mut LazyFrameContext,
+    names_ptr: *const *const c_char,
+    len: usize,
+    strict: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let names = read_names(names_ptr, len);
+        let selector = Selector::ByName { names: names.into(), strict };
+        let new_lf = lf_ctx.inner.drop(selector);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 按 selector 删除列 (如 cs.numeric())，selector 被消费
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_drop_selector(
+    lf_ptr: *mut LazyFrameContext,
+    sel_ptr: *mut SelectorContext
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let sel_ctx = unsafe { Box::from_raw(sel_ptr) };
+        let new_lf = lf_ctx.inner.drop(sel_ctx.inner);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Unnest
+// ==========================================
+// 把 Struct 列展开成顶层列 (例如 str.json_decode 的结果)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_unnest(
+    lf_ptr: *mut LazyFrameContext,
+    names_ptr: *const *const c_char,
+    len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let names = read_names(names_ptr, len);
+        let selector = Selector::ByName { names: names.into(), strict: true };
+        let new_lf = lf_ctx.inner.unnest(selector);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 按 selector 展开 (如 cs.by_dtype(Struct))，selector 被消费
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_unnest_selector(
+    lf_ptr: *mut LazyFrameContext,
+    sel_ptr: *mut SelectorContext
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let sel_ctx = unsafe { Box::from_raw(sel_ptr) };
+        let new_lf = lf_ctx.inner.unnest(sel_ctx.inner);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Unique (去重)
+// ==========================================
+// subset_ptr: 参与比较的列，NULL 表示全部列；selector 被消费
+// keep_code: 0 = First, 1 = Last, 2 = Any (最快，不保证保留哪一行), 3 = None (重复的行全部丢弃)
+// maintain_order: 结果保持原始行顺序 (更慢)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_unique(
+    lf_ptr: *mut LazyFrameContext,
+    subset_ptr: *mut SelectorContext,
+    keep_code: i32,
+    maintain_order: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let subset = if subset_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Box::from_raw(subset_ptr) }.inner)
+        };
+
+        let keep = match keep_code {
+            0 => UniqueKeepStrategy::First,
+            1 => UniqueKeepStrategy::Last,
+            2 => UniqueKeepStrategy::Any,
+            3 => UniqueKeepStrategy::None,
+            _ => return Err(PolarsError::ComputeError(
+                format!("Unknown unique keep code: {}", keep_code).into()
+            )),
+        };
+
+        let new_lf = if maintain_order {
+            lf_ctx.inner.unique_stable(subset, keep)
+        } else {
+            lf_ctx.inner.unique(subset, keep)
+        };
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
 // ==========================================
 // Sort
 // ==========================================
@@ -162,6 +385,42 @@ pub extern "C" fn pl_lazy_sort_multiple(
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner:res_lf })))
     })
 }
+
+// 多键排序，每个键可以单独指定方向和 Null 的位置
+// descending_ptr / nulls_last_ptr: 长度均为 len 的 bool 数组；传 NULL 表示全部为 false
+// maintain_order: 相等的行保持原有顺序 (稳定排序)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sort_exprs(
+    lf_ptr: *mut LazyFrameContext,
+    exprs_ptr: *const *mut ExprContext,
+    len: usize,
+    descending_ptr: *const bool,
+    nulls_last_ptr: *const bool,
+    maintain_order: bool,
+    multithreaded: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(lf_ptr) };
+        let exprs = unsafe { consume_exprs_array(exprs_ptr, len) };
+
+        let read_flags = |ptr: *const bool| -> Vec<bool> {
+            if ptr.is_null() {
+                vec![false; len]
+            } else {
+                unsafe { std::slice::from_raw_parts(ptr, len) }.to_vec()
+            }
+        };
+
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi(read_flags(descending_ptr))
+            .with_nulls_last_multi(read_flags(nulls_last_ptr))
+            .with_maintain_order(maintain_order)
+            .with_multithreaded(multithreaded);
+
+        let res_lf = ctx.inner.sort_by_exprs(exprs, options);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: res_lf })))
+    })
+}
 // ==========================================
 // GroupBy
 // ==========================================
@@ -170,6 +429,18 @@ pub extern "C" fn pl_lazy_groupby_agg(
     lf_ptr: *mut LazyFrameContext,
     keys_ptr: *const *mut ExprContext, keys_len: usize,
     aggs_ptr: *const *mut ExprContext, aggs_len: usize
+) -> *mut LazyFrameContext {
+    // 旧接口一直是稳定分组
+    pl_lazy_groupby_agg_with_args(lf_ptr, keys_ptr, keys_len, aggs_ptr, aggs_len, true)
+}
+
+// maintain_order: true = 组按首次出现的顺序输出 (group_by_stable)，false = 顺序不定但更快
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_groupby_agg_with_args(
+    lf_ptr: *mut LazyFrameContext,
+    keys_ptr: *const *mut ExprContext, keys_len: usize,
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize,
+    maintain_order: bool
 ) -> *mut LazyFrameContext {
     ffi_try!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
@@ -177,7 +448,12 @@ pub extern "C" fn pl_lazy_groupby_agg(
         let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
 
         // 链式调用
-        let new_lf = lf_ctx.inner.group_by_stable(keys).agg(aggs);
+        let gb = if maintain_order {
+            lf_ctx.inner.group_by_stable(keys)
+        } else {
+            lf_ctx.inner.group_by(keys)
+        };
+        let new_lf = gb.agg(aggs);
         
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
     })
@@ -272,6 +548,60 @@ pub unsafe extern "C" fn pl_lazy_group_by_dynamic(
     })
 }
 
+// 以每一行的 index 值为窗口终点做聚合 (group_by_rolling)，与 group_by_dynamic 的固定窗口互补
+// index_col: 时间或整数列 (需已排序)
+// period: 窗口长度，如 "2d" / "3i"
+// offset: NULL 表示默认的 -period，即窗口为 (t - period, t]
+// closed_window_idx: 0=Left, 1=Right, 2=Both, 3=None
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pl_lazy_rolling(
+    lf_ptr: *mut LazyFrameContext,
+    index_col: *const c_char,
+    period: *const c_char,
+    offset: *const c_char,
+    closed_window_idx: i32,
+    keys_ptr: *const *mut ExprContext, keys_len: usize,
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+
+        let index_col_str = ptr_to_str(index_col).unwrap();
+        let period = Duration::parse(ptr_to_str(period).unwrap());
+        let offset = if offset.is_null() {
+            -period
+        } else {
+            Duration::parse(ptr_to_str(offset).unwrap())
+        };
+
+        let closed_window = match closed_window_idx {
+            0 => ClosedWindow::Left,
+            1 => ClosedWindow::Right,
+            2 => ClosedWindow::Both,
+            3 => ClosedWindow::None,
+            _ => return Err(PolarsError::ComputeError(
+                format!("Unknown closed window code: {}", closed_window_idx).into()
+            )),
+        };
+
+        let options = RollingGroupOptions {
+            index_column: PlSmallStr::from_str(index_col_str),
+            period,
+            offset,
+            closed_window,
+        };
+
+        let keys = unsafe { consume_exprs_array(keys_ptr, keys_len) };
+        let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
+
+        let new_lf = lf_ctx.inner
+            .rolling(col(index_col_str), keys, options)
+            .agg(aggs);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_explode(
     lf_ptr: *mut LazyFrameContext,
@@ -309,37 +639,579 @@ pub extern "C" fn pl_lazy_explode(
 }
 
 // ==========================================
-// Collect (出口：LazyFrame -> DataFrame)
+// Collect (出口：LazyFrame -> DataFrame)
+// ==========================================
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect(lf_ptr: *mut LazyFrameContext) -> *mut DataFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        
+        // 去掉了 println!，保持库函数的纯洁性。
+        // 如果想看日志，可以在 F# 端调用 explain 或者 check schema。
+        // 这里的 ? 会捕获 PolarsError 并转给 ffi_try
+        let df = lf_ctx.inner.collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// 兼容旧接口，等价于 pl_lazy_collect_engine(lf, 2)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect_streaming(lf_ptr: *mut LazyFrameContext) -> *mut DataFrameContext {
+    pl_lazy_collect_engine(lf_ptr, 2)
+}
+
+// 0=Auto, 1=InMemory, 2=Streaming, 3=Gpu
+// Gpu 在 Rust 端没有独立执行器，由 Polars 内部回退到 InMemory
+fn map_engine(code: i32) -> PolarsResult<Engine> {
+    match code {
+        0 => Ok(Engine::Auto),
+        1 => Ok(Engine::InMemory),
+        2 => Ok(Engine::Streaming),
+        3 => Ok(Engine::Gpu),
+        _ => Err(PolarsError::ComputeError(format!("Unknown engine code: {}", code).into())),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect_engine(lf_ptr: *mut LazyFrameContext, engine_code: i32) -> *mut DataFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let engine = map_engine(engine_code)?;
+
+        let df = lf_ctx.inner.collect_with_engine(engine)?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// 行数统计：select(len()) 只需要行数，Parquet / IPC / CSV 扫描可直接走元数据 / 快速计数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_count(lf_ptr: *mut LazyFrameContext, out_count: *mut u64) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let df = lf_ctx.inner.select([len()]).collect()?;
+
+        let count = df.get_columns()[0]
+            .cast(&DataType::UInt64)?
+            .u64()?
+            .get(0)
+            .unwrap_or(0);
+        unsafe { *out_count = count };
+        Ok(())
+    })
+}
+
+// 执行查询并返回各节点耗时
+// out_result: 查询结果; out_timings: 列 [node, start, end]，单位微秒
+// 出错时两个输出都不写，C# 端通过错误信息判断
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_profile(
+    lf_ptr: *mut LazyFrameContext,
+    out_result: *mut *mut DataFrameContext,
+    out_timings: *mut *mut DataFrameContext,
+) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let (df, timings) = lf_ctx.inner.profile()?;
+
+        unsafe {
+            *out_result = Box::into_raw(Box::new(DataFrameContext { df }));
+            *out_timings = Box::into_raw(Box::new(DataFrameContext { df: timings }));
+        }
+        Ok(())
+    })
+}
+
+// 一次性执行多个 LazyFrame
+// 所有计划合并成一个整体优化，公共子计划 (例如同一个昂贵的 scan) 只执行一次
+// out_dfs_ptr: C# 端分配的长度为 len 的指针数组，按输入顺序写入结果
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect_all(
+    lfs_ptr: *const *mut LazyFrameContext,
+    len: usize,
+    out_dfs_ptr: *mut *mut DataFrameContext,
+) {
+    ffi_try_void!({
+        // 1. 消费所有 LazyFrame
+        let slice = unsafe { std::slice::from_raw_parts(lfs_ptr, len) };
+        let lfs: Vec<LazyFrame> = slice
+            .iter()
+            .map(|&p| unsafe { Box::from_raw(p) }.inner)
+            .collect();
+
+        if lfs.is_empty() {
+            return Ok(());
+        }
+
+        // 2. 优化选项沿用第一个 LazyFrame 的设置
+        let opt_state = lfs[0].get_current_optimizations();
+        let plans = lfs.into_iter().map(|lf| lf.logical_plan).collect();
+
+        let dfs = LazyFrame::collect_all_with_engine(plans, Engine::InMemory, opt_state)?;
+
+        // 3. 写回输出数组
+        let out = unsafe { std::slice::from_raw_parts_mut(out_dfs_ptr, len) };
+        for (slot, df) in out.iter_mut().zip(dfs) {
+            *slot = Box::into_raw(Box::new(DataFrameContext { df }));
+        }
+        Ok(())
+    })
+}
+
+// ==========================================
+// Async Collect (后台执行 + 完成回调)
+// ==========================================
+// 完成回调: (user_data, df, error)
+// 成功时 df 非空、error 为空，df 所有权交给 C#；
+// 失败时 df 为空，error 只在回调期间有效，C# 需要自行拷贝
+// 回调在 Rust 后台线程上触发，C# 端一般在里面 SetResult 到 TaskCompletionSource
+type QueryCompleteCallback = extern "C" fn(
+    *mut std::ffi::c_void,
+    *mut DataFrameContext,
+    *const c_char,
+);
+
+const QUERY_RUNNING: i32 = 0;
+const QUERY_COMPLETED: i32 = 1;
+const QUERY_FAILED: i32 = 2;
+
+pub struct QueryHandle {
+    status: Arc<std::sync::atomic::AtomicI32>,
+}
+
+// user_data 是 C# 的 GCHandle，只是原样传回，跨线程没有问题
+struct UserData(*mut std::ffi::c_void);
+unsafe impl Send for UserData {}
+
+impl UserData {
+    fn get(&self) -> *mut std::ffi::c_void {
+        self.0
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect_async(
+    lf_ptr: *mut LazyFrameContext,
+    on_complete: QueryCompleteCallback,
+    user_data: *mut std::ffi::c_void,
+) -> *mut QueryHandle {
+    ffi_try!({
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::sync::atomic::{AtomicI32, Ordering};
+
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let status = Arc::new(AtomicI32::new(QUERY_RUNNING));
+        let thread_status = status.clone();
+        let user_data = UserData(user_data);
+
+        std::thread::Builder::new()
+            .name("polars-collect-async".into())
+            .spawn(move || {
+                let result = catch_unwind(AssertUnwindSafe(|| lf_ctx.inner.collect()));
+                // 先更新状态再回调，保证回调里 poll 到的一定是最终状态
+                match result {
+                    Ok(Ok(df)) => {
+                        thread_status.store(QUERY_COMPLETED, Ordering::Release);
+                        let df_ptr = Box::into_raw(Box::new(DataFrameContext { df }));
+                        on_complete(user_data.get(), df_ptr, std::ptr::null());
+                    }
+                    Ok(Err(e)) => {
+                        thread_status.store(QUERY_FAILED, Ordering::Release);
+                        let msg = std::ffi::CString::new(e.to_string()).unwrap_or_default();
+                        on_complete(user_data.get(), std::ptr::null_mut(), msg.as_ptr());
+                    }
+                    Err(_) => {
+                        thread_status.store(QUERY_FAILED, Ordering::Release);
+                        let msg = std::ffi::CString::new("Rust Panic occurred during async collect").unwrap();
+                        on_complete(user_data.get(), std::ptr::null_mut(), msg.as_ptr());
+                    }
+                }
+            })?;
+
+        Ok(Box::into_raw(Box::new(QueryHandle { status })))
+    })
+}
+
+// 0 = 运行中, 1 = 成功, 2 = 失败
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_query_poll(handle: *mut QueryHandle) -> i32 {
+    let handle = unsafe { &*handle };
+    handle.status.load(std::sync::atomic::Ordering::Acquire)
+}
+
+// 只释放句柄本身；若查询仍在运行，后台线程照常执行完并触发回调
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_query_free(handle: *mut QueryHandle) {
+    ffi_try_void!({
+        if !handle.is_null() {
+            unsafe { let _ = Box::from_raw(handle); }
+        }
+        Ok(())
+    })
+}
+
+// ==========================================
+// Batch Iterator (流式拉取)
+// ==========================================
+// 后台线程用流式引擎把查询 sink 成 Arrow IPC，写入目标是一个有界管道 (SinkTarget::Dyn)，
+// C# 端每次 next 从管道另一端解码出一批。行序 (maintain_order) 和背压都由引擎的 sink 节点负责：
+// 管道满了写端阻塞，上游 pipeline 随之停下，内存占用不会随结果集增长。
+// 代价是每批多一次 IPC 编解码 (不压缩，基本是 memcpy)，换来不改写用户的计划 (不插行号、不插 map 节点)。
+const BATCH_PIPE_CAPACITY: usize = 64; // 管道中最多缓冲的写入块数 (BufWriter 每块 8KB，大 buffer 整块写入)
+
+type PipeReceiver = std::sync::Arc<std::sync::Mutex<std::sync::mpsc::Receiver<Vec<u8>>>>;
+
+struct PipeWriter {
+    tx: std::sync::mpsc::SyncSender<Vec<u8>>,
+}
+
+impl std::io::Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // 接收端已经被 free：返回错误让 sink 中止查询
+        self.tx.send(buf.to_vec()).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Batch iterator was dropped")
+        })?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl polars_io::utils::file::DynWriteable for PipeWriter {
+    fn as_dyn_write(&self) -> &(dyn std::io::Write + Send + 'static) {
+        self as _
+    }
+    fn as_mut_dyn_write(&mut self) -> &mut (dyn std::io::Write + Send + 'static) {
+        self as _
+    }
+    // drop 发送端即关闭管道，读端随后读到 EOF
+    fn close(self: Box<Self>) -> std::io::Result<()> {
+        Ok(())
+    }
+    fn sync_on_close(&mut self, _: polars_io::utils::sync_on_close::SyncOnCloseType) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct PipeReader {
+    rx: PipeReceiver,
+    chunk: Vec<u8>,
+    pos: usize,
+}
+
+impl std::io::Read for PipeReader {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        while self.pos == self.chunk.len() {
+            let next = self.rx.lock().unwrap_or_else(|e| e.into_inner()).recv();
+            match next {
+                Ok(chunk) => {
+                    self.chunk = chunk;
+                    self.pos = 0;
+                },
+                // 所有发送端都已断开 = EOF
+                Err(_) => return Ok(0),
+            }
+        }
+        let n = out.len().min(self.chunk.len() - self.pos);
+        out[..n].copy_from_slice(&self.chunk[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+pub struct BatchIterHandle {
+    rx: PipeReceiver,
+    // 第一次 next 时才读 IPC 头，避免构造时阻塞
+    pipe: Option<PipeReader>,
+    reader: Option<polars_arrow::io::ipc::read::StreamReader<PipeReader>>,
+    query: Option<std::thread::JoinHandle<PolarsResult<()>>>,
+    batch_size: usize,
+    buffer: Option<DataFrame>,
+    finished: bool,
+}
+
+impl BatchIterHandle {
+    pub(crate) fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            // 1. 凑够一批就切出来；batch_size = 0 表示按 sink 写出的 record batch 原样返回
+            if let Some(buf) = self.buffer.take() {
+                let height = buf.height();
+                if self.batch_size == 0 || height >= self.batch_size || self.finished {
+                    let take = if self.batch_size == 0 { height } else { height.min(self.batch_size) };
+                    if take < height {
+                        self.buffer = Some(buf.slice(take as i64, height - take));
+                    }
+                    return Ok(Some(buf.slice(0, take)));
+                }
+                self.buffer = Some(buf);
+            }
+
+            if self.finished {
+                return Ok(None);
+            }
+
+            // 2. 从管道解码下一个 record batch；读完或出错后等后台查询结束，优先报告查询本身的错误
+            match self.read_record_batch() {
+                Ok(Some(df)) => {
+                    if df.height() == 0 {
+                        continue;
+                    }
+                    match self.buffer.as_mut() {
+                        Some(buf) => { buf.vstack_mut_owned(df)?; },
+                        None => self.buffer = Some(df),
+                    }
+                },
+                Ok(None) => {
+                    self.finished = true;
+                    self.finish_query()?;
+                },
+                Err(e) => {
+                    self.finished = true;
+                    return Err(self.finish_query().err().unwrap_or(e));
+                },
+            }
+        }
+    }
+
+    fn read_record_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        use polars_arrow::io::ipc::read::{StreamReader, StreamState, read_stream_metadata};
+        use std::io::Read;
+
+        if self.reader.is_none() {
+            let Some(mut pipe) = self.pipe.take() else { return Ok(None) };
+            // IPC 文件格式 = 8 字节 magic + 以 EOS 结尾的流格式 + footer，跳过 magic 后按流格式逐批解码
+            let mut magic = [0u8; 8];
+            if pipe.read_exact(&mut magic).is_err() {
+                // 查询在打开 sink 之前就结束了，结果 (多半是错误) 由 finish_query 给出
+                return Ok(None);
+            }
+            let metadata = read_stream_metadata(&mut pipe)?;
+            self.reader = Some(StreamReader::new(pipe, metadata, None));
+        }
+
+        match self.reader.as_mut().and_then(|r| r.next()) {
+            Some(Ok(StreamState::Some(batch))) => Ok(Some(DataFrame::from(batch))),
+            Some(Ok(StreamState::Waiting)) | None => Ok(None),
+            Some(Err(e)) => Err(e),
+        }
+    }
+
+    // EOS 之后写端还要写 footer，读完丢弃，否则管道满了写端会一直阻塞；然后等后台线程退出
+    fn finish_query(&mut self) -> PolarsResult<()> {
+        self.reader = None;
+        self.pipe = None;
+        self.rx.lock().unwrap_or_else(|e| e.into_inner()).iter().for_each(drop);
+        match self.query.take() {
+            Some(query) => query.join().unwrap_or_else(|_| {
+                Err(PolarsError::ComputeError("Rust Panic occurred during batch collect".into()))
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_collect_batches(
+    lf_ptr: *mut LazyFrameContext,
+    batch_size: usize,
+) -> *mut BatchIterHandle {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let handle = spawn_batch_iter(lf_ctx.inner, batch_size)?;
+        Ok(Box::into_raw(Box::new(handle)))
+    })
+}
+
+// 启动后台流式查询，返回按行序拉取的迭代器 (pl_lazy_sink_callback 也复用这里)
+pub(crate) fn spawn_batch_iter(lf: LazyFrame, batch_size: usize) -> PolarsResult<BatchIterHandle> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let (tx, rx) = std::sync::mpsc::sync_channel::<Vec<u8>>(BATCH_PIPE_CAPACITY);
+    let rx: PipeReceiver = std::sync::Arc::new(std::sync::Mutex::new(rx));
+
+    let writer: Box<dyn polars_io::utils::file::DynWriteable> = Box::new(PipeWriter { tx });
+    let target = SinkTarget::Dyn(SpecialEq::new(Arc::new(std::sync::Mutex::new(Some(writer)))));
+    let mut options = IpcWriterOptions::default();
+    // 让 sink 直接按 batch_size 切 record batch，消费端的切分只做兜底
+    if batch_size > 0 {
+        options.chunk_size = IdxSize::try_from(batch_size).unwrap_or(IdxSize::MAX);
+    }
+    let lf = lf
+        .sink_ipc(target, options, None, SinkOptions::default())?
+        .with_new_streaming(true);
+
+    // 写端在计划里：sink 打开后由它关闭；查询提前失败时随计划一起 drop，读端同样会读到 EOF
+    let query = std::thread::Builder::new()
+        .name("polars-collect-batches".into())
+        .spawn(move || match catch_unwind(AssertUnwindSafe(|| lf.collect())) {
+            Ok(result) => result.map(|_| ()),
+            Err(_) => Err(PolarsError::ComputeError("Rust Panic occurred during batch collect".into())),
+        })?;
+
+    Ok(BatchIterHandle {
+        pipe: Some(PipeReader { rx: rx.clone(), chunk: Vec::new(), pos: 0 }),
+        rx,
+        reader: None,
+        query: Some(query),
+        batch_size,
+        buffer: None,
+        finished: false,
+    })
+}
+
+// 拉取下一批，写成 Arrow C Data Interface (StructArray + Schema，与 pl_to_arrow 一致)
+// 返回 true = 拿到一批; false = 已经结束或出错 (出错时 pl_get_last_error 可取到信息)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_batch_iter_next(
+    handle: *mut BatchIterHandle,
+    out_chunk: *mut polars_arrow::ffi::ArrowArray,
+    out_schema: *mut polars_arrow::ffi::ArrowSchema,
+) -> bool {
+    let handle = unsafe { &mut *handle };
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handle.next_batch()));
+    match result {
+        Ok(Ok(Some(df))) => {
+            let ctx = Box::into_raw(Box::new(DataFrameContext { df }));
+            crate::io::pl_to_arrow(ctx, out_chunk, out_schema);
+            unsafe { let _ = Box::from_raw(ctx); }
+            true
+        },
+        Ok(Ok(None)) => false,
+        Ok(Err(e)) => {
+            crate::error::set_error(e.to_string());
+            false
+        },
+        Err(_) => {
+            crate::error::set_error("Panic in pl_batch_iter_next".to_string());
+            false
+        },
+    }
+}
+
+// 提前释放时，后台查询在下一次发送时发现接收端已关闭并自行中止
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_batch_iter_free(handle: *mut BatchIterHandle) {
+    ffi_try_void!({
+        if !handle.is_null() {
+            unsafe { let _ = Box::from_raw(handle); }
+        }
+        Ok(())
+    })
+}
+// ==========================================
+// Fetch (调试用)
 // ==========================================
+// 把计划中的每个数据源 (文件 scan / 内存 DataFrame) 都截断为前 n 行。
+// 和在末尾 limit 不同，源头直接少读数据；但 filter / join / 聚合的结果会因此不完整，只适合调试
+fn limit_scans(plan: &mut DslPlan, n: IdxSize) {
+    match plan {
+        DslPlan::Scan { .. } | DslPlan::DataFrameScan { .. } => {
+            *plan = DslPlan::Slice { input: Arc::new(plan.clone()), offset: 0, len: n };
+        },
+        // 已经转换过的计划：退回原始 DSL 再处理，避免复用缓存的 IR
+        DslPlan::IR { dsl, .. } => {
+            *plan = (**dsl).clone();
+            limit_scans(plan, n);
+        },
+        DslPlan::Filter { input, .. }
+        | DslPlan::Cache { input }
+        | DslPlan::Select { input, .. }
+        | DslPlan::GroupBy { input, .. }
+        | DslPlan::HStack { input, .. }
+        | DslPlan::MatchToSchema { input, .. }
+        | DslPlan::Distinct { input, .. }
+        | DslPlan::Sort { input, .. }
+        | DslPlan::Slice { input, .. }
+        | DslPlan::MapFunction { input, .. }
+        | DslPlan::Sink { input, .. } => limit_scans(Arc::make_mut(input), n),
+        DslPlan::Join { input_left, input_right, .. }
+        | DslPlan::MergeSorted { input_left, input_right, .. } => {
+            limit_scans(Arc::make_mut(input_left), n);
+            limit_scans(Arc::make_mut(input_right), n);
+        },
+        DslPlan::ExtContext { input, contexts } => {
+            limit_scans(Arc::make_mut(input), n);
+            contexts.iter_mut().for_each(|p| limit_scans(p, n));
+        },
+        DslPlan::Union { inputs, .. }
+        | DslPlan::HConcat { inputs, .. }
+        | DslPlan::SinkMultiple { inputs } => inputs.iter_mut().for_each(|p| limit_scans(p, n)),
+    }
+}
 
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_lazy_collect(lf_ptr: *mut LazyFrameContext) -> *mut DataFrameContext {
+pub extern "C" fn pl_lazy_fetch(lf_ptr: *mut LazyFrameContext, n_rows: u64) -> *mut DataFrameContext {
     ffi_try!({
-        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
-        
-        // 去掉了 println!，保持库函数的纯洁性。
-        // 如果想看日志，可以在 F# 端调用 explain 或者 check schema。
-        // 这里的 ? 会捕获 PolarsError 并转给 ffi_try
-        let df = lf_ctx.inner.collect()?;
-
+        let mut lf = unsafe { Box::from_raw(lf_ptr) }.inner;
+        // IdxSize 在 bigidx 下是 u64，默认是 u32；超出范围时报错而不是截断
+        let n_rows = IdxSize::try_from(n_rows).map_err(|_| PolarsError::ComputeError(
+            format!("n_rows {} exceeds the maximum row index of this build", n_rows).into()
+        ))?;
+        limit_scans(&mut lf.logical_plan, n_rows);
+        let df = lf.collect()?;
         Ok(Box::into_raw(Box::new(DataFrameContext { df })))
     })
 }
+// ==========================================
+// Benchmark
+// ==========================================
+// 进程峰值常驻内存 (VmHWM)。只有 Linux 能拿到，其他平台返回 None
+fn peak_rss_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
 
+// 对同一个计划重复 collect，返回 JSON:
+// { "iterations", "rows", "wall_ms": [...], "min_ms", "mean_ms", "max_ms", "peak_rss_bytes" }
+// 借用 lf (每次 collect 都 clone 计划)，C# 端仍然持有句柄；返回的字符串用 pl_free_string 释放
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_lazy_collect_streaming(lf_ptr: *mut LazyFrameContext) -> *mut DataFrameContext {
+pub extern "C" fn pl_bench_collect(lf_ptr: *mut LazyFrameContext, iterations: usize) -> *mut c_char {
     ffi_try!({
-        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
-        
-        // Polars 0.50+ 写法: with_streaming(true).collect()
-        let df = lf_ctx.inner
-            .with_new_streaming(true)
-            .collect()?;
-            
-        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+        let lf_ctx = unsafe { &*lf_ptr };
+        if iterations == 0 {
+            return Err(PolarsError::ComputeError("iterations must be greater than 0".into()));
+        }
+
+        let mut wall_ms = Vec::with_capacity(iterations);
+        let mut rows = 0;
+        for _ in 0..iterations {
+            let start = std::time::Instant::now();
+            let df = lf_ctx.inner.clone().collect()?;
+            wall_ms.push(start.elapsed().as_secs_f64() * 1000.0);
+            rows = df.height();
+        }
+
+        let min = wall_ms.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = wall_ms.iter().cloned().fold(0.0, f64::max);
+        let mean = wall_ms.iter().sum::<f64>() / iterations as f64;
+
+        let report = serde_json::json!({
+            "iterations": iterations,
+            "rows": rows,
+            "wall_ms": wall_ms,
+            "min_ms": min,
+            "mean_ms": mean,
+            "max_ms": max,
+            "peak_rss_bytes": peak_rss_bytes(),
+        });
+        Ok(std::ffi::CString::new(report.to_string()).unwrap().into_raw())
     })
 }
 // ==========================================
+// Group By 分区预聚合开关
+// ==========================================
+// 不提供按查询的开关：Polars 只在内存引擎的 group_by 执行器里读取
+// POLARS_FORCE_PARTITION / POLARS_NO_PARTITION 环境变量来决定是否分区预聚合，
+// 物理计划构建时也没有可传入的选项 (流式引擎则完全不看这两个变量)。
+// 在 .NET 宿主里修改环境变量必然和 GC / JIT 等线程读取环境变量形成数据竞争，所以不做封装。
+// ==========================================
 // Unpivot
 // ==========================================
 #[unsafe(no_mangle)]
@@ -414,6 +1286,23 @@ pub extern "C" fn pl_lazy_concat(
     how: i32,        // 0=Vert, 1=Horz, 2=Diag
     rechunk: bool,   // 统一传给 UnionArgs
     parallel: bool   // 统一传给 UnionArgs
+) -> *mut LazyFrameContext {
+    // 旧接口：保持 UnionArgs 默认的 to_supertypes = false, maintain_order = true
+    pl_lazy_concat_with_args(lfs_ptr, len, how, rechunk, parallel, false, true)
+}
+
+// how: 0=Vertical, 1=Horizontal, 2=Diagonal, 3=DiagonalRelaxed (Diagonal + 自动提升为公共超类型)
+// to_supertypes: 列类型不一致时提升为公共超类型 (如 Int32 + Int64 -> Int64)，而不是报错
+// maintain_order: 结果按输入顺序排列；关掉后允许并行执行时乱序，通常更快
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_concat_with_args(
+    lfs_ptr: *const *mut LazyFrameContext,
+    len: usize,
+    how: i32,
+    rechunk: bool,
+    parallel: bool,
+    to_supertypes: bool,
+    maintain_order: bool
 ) -> *mut LazyFrameContext {
     ffi_try!({
         // 1. 消费所有 LazyFrame
@@ -434,6 +1323,8 @@ pub extern "C" fn pl_lazy_concat(
         let args = UnionArgs {
             rechunk,
             parallel,
+            to_supertypes,
+            maintain_order,
             ..Default::default()
         };
 
@@ -449,6 +1340,9 @@ pub extern "C" fn pl_lazy_concat(
             // Diagonal
             2 => concat_lf_diagonal(lfs, args)?,
 
+            // Diagonal Relaxed
+            3 => concat_lf_diagonal(lfs, UnionArgs { to_supertypes: true, ..args })?,
+
             _ => return Err(PolarsError::ComputeError("Invalid lazy concat strategy".into())),
         };
         
@@ -456,6 +1350,25 @@ pub extern "C" fn pl_lazy_concat(
     })
 }
 
+// 两个已按 key 升序排好的 LazyFrame 归并成一个有序结果，不需要 concat 后再整体排序
+// 两边 schema 必须一致；输入没排好序时结果无意义 (Polars 不做检查)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_merge_sorted(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    key_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+        let key = ptr_to_str(key_ptr).unwrap();
+
+        let new_lf = left_ctx.inner.merge_sorted(right_ctx.inner, key)?;
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
 // ==========================================
 // Join & Join As of
 // ==========================================
@@ -486,6 +1399,191 @@ pub extern "C" fn pl_lazy_join(
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
     })
 }
+// allow_parallel = true : 默认行为，两侧输入并行物化
+// allow_parallel = false: 依次物化两侧输入，倾斜数据下内存占用更可控
+// Polars 没有可供外部强制选择的 broadcast / sort-merge join 物理算子，这里只暴露这一个开关
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_join_allow_parallel(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    left_on_ptr: *const *mut ExprContext, left_on_len: usize,
+    right_on_ptr: *const *mut ExprContext, right_on_len: usize,
+    how_code: i32,
+    allow_parallel: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+
+        let left_on = unsafe { consume_exprs_array(left_on_ptr, left_on_len) };
+        let right_on = unsafe { consume_exprs_array(right_on_ptr, right_on_len) };
+
+        let how = map_jointype(how_code);
+
+        let new_lf = left_ctx.inner
+            .join_builder()
+            .with(right_ctx.inner)
+            .left_on(left_on)
+            .right_on(right_on)
+            .how(how)
+            .allow_parallel(allow_parallel)
+            .finish();
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+// 完整 Join 参数:
+// suffix: 右表重名列的后缀，NULL 表示默认的 "_right"
+// validation_code: 0 = m:m (不检查), 1 = 1:1, 2 = 1:m, 3 = m:1；键不满足唯一性时 collect 报错
+// join_nulls: Null 键是否视为相等
+// coalesce_code: 0 = 由 Join 类型决定 (与 Python 默认一致), 1 = 合并左右键列, 2 = 保留两侧键列
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_join_with_args(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    left_on_ptr: *const *mut ExprContext, left_on_len: usize,
+    right_on_ptr: *const *mut ExprContext, right_on_len: usize,
+    how_code: i32,
+    suffix_ptr: *const c_char,
+    validation_code: i32,
+    join_nulls: bool,
+    coalesce_code: i32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+
+        let left_on = unsafe { consume_exprs_array(left_on_ptr, left_on_len) };
+        let right_on = unsafe { consume_exprs_array(right_on_ptr, right_on_len) };
+
+        let new_lf = build_join(
+            left_ctx.inner, right_ctx.inner, left_on, right_on,
+            how_code, suffix_ptr, validation_code, join_nulls, coalesce_code
+        )?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 与 Python join(on=...) 对应：同一组键同时用于左右两侧，其余参数同 pl_lazy_join_with_args
+// how_code 4 / 5 即 Semi / Anti Join
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_join_on(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    on_ptr: *const *mut ExprContext, on_len: usize,
+    how_code: i32,
+    suffix_ptr: *const c_char,
+    validation_code: i32,
+    join_nulls: bool,
+    coalesce_code: i32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+
+        let on = unsafe { consume_exprs_array(on_ptr, on_len) };
+
+        let new_lf = build_join(
+            left_ctx.inner, right_ctx.inner, on.clone(), on,
+            how_code, suffix_ptr, validation_code, join_nulls, coalesce_code
+        )?;
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_join(
+    left: LazyFrame,
+    right: LazyFrame,
+    left_on: Vec<Expr>,
+    right_on: Vec<Expr>,
+    how_code: i32,
+    suffix_ptr: *const c_char,
+    validation_code: i32,
+    join_nulls: bool,
+    coalesce_code: i32
+) -> PolarsResult<LazyFrame> {
+    let validation = match validation_code {
+        0 => JoinValidation::ManyToMany,
+        1 => JoinValidation::OneToOne,
+        2 => JoinValidation::OneToMany,
+        3 => JoinValidation::ManyToOne,
+        _ => return Err(PolarsError::ComputeError(
+            format!("Unknown join validation code: {}", validation_code).into()
+        )),
+    };
+    let coalesce = match coalesce_code {
+        0 => JoinCoalesce::JoinSpecific,
+        1 => JoinCoalesce::CoalesceColumns,
+        2 => JoinCoalesce::KeepColumns,
+        _ => return Err(PolarsError::ComputeError(
+            format!("Unknown join coalesce code: {}", coalesce_code).into()
+        )),
+    };
+
+    let mut builder = left
+        .join_builder()
+        .with(right)
+        .left_on(left_on)
+        .right_on(right_on)
+        .how(map_jointype(how_code))
+        .validate(validation)
+        .join_nulls(join_nulls)
+        .coalesce(coalesce);
+    if !suffix_ptr.is_null() {
+        builder = builder.suffix(ptr_to_str(suffix_ptr).unwrap());
+    }
+
+    Ok(builder.finish())
+}
+// 笛卡尔积，不需要连接键；suffix 为 NULL 时使用默认的 "_right"
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_cross_join(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    suffix_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+        let suffix = if suffix_ptr.is_null() {
+            None
+        } else {
+            Some(PlSmallStr::from_str(ptr_to_str(suffix_ptr).unwrap()))
+        };
+
+        let new_lf = left_ctx.inner.cross_join(right_ctx.inner, suffix);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+// 非等值 Join (IEJoin)：predicates 之间是 AND 关系，
+// 例如 col("time") >= col("start"), col("time") < col("end")
+// 右表中与左表重名的列加 "_right" 后缀，谓词里可直接用该名字引用右表列
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_join_where(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    predicates_ptr: *const *mut ExprContext,
+    len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+        let predicates = unsafe { consume_exprs_array(predicates_ptr, len) };
+        if predicates.is_empty() {
+            return Err(PolarsError::ComputeError(
+                "join_where requires at least one predicate".into()
+            ));
+        }
+
+        let new_lf = left_ctx.inner
+            .join_builder()
+            .with(right_ctx.inner)
+            .join_where(predicates);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
 fn exprs_to_names(exprs: &[Expr]) -> PolarsResult<Vec<PlSmallStr>> {
     let mut names = Vec::new();
     for e in exprs {
@@ -568,6 +1666,91 @@ pub extern "C" fn pl_lazy_join_asof(
     })
 }
 // ==========================================
+// Rolling Aggregates (批量)
+// ==========================================
+// 对 selector 选中的每一列、每一种聚合生成一个 rolling 列，输出列名: {列名}{suffix}_{op}
+// ops_codes: 0=mean, 1=sum, 2=min, 3=max, 4=std, 5=var, 6=median
+// window: 固定行数窗口 (e.g. "3" 或 "3i")
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_with_rolling_aggregates(
+    lf_ptr: *mut LazyFrameContext,
+    sel_ptr: *mut SelectorContext,
+    ops_codes: *const i32,
+    ops_len: usize,
+    window_ptr: *const c_char,
+    suffix_ptr: *const c_char
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let sel_ctx = unsafe { Box::from_raw(sel_ptr) };
+        let codes = unsafe { std::slice::from_raw_parts(ops_codes, ops_len) };
+        let window_size = parse_fixed_window_size(ptr_to_str(window_ptr).unwrap())?;
+        let suffix = if suffix_ptr.is_null() { "_rolling" } else { ptr_to_str(suffix_ptr).unwrap() };
+
+        let base: Expr = sel_ctx.inner.into();
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+
+        let mut exprs = Vec::with_capacity(codes.len());
+        for &code in codes {
+            let (expr, op_name) = match code {
+                0 => (base.clone().rolling_mean(options.clone()), "mean"),
+                1 => (base.clone().rolling_sum(options.clone()), "sum"),
+                2 => (base.clone().rolling_min(options.clone()), "min"),
+                3 => (base.clone().rolling_max(options.clone()), "max"),
+                4 => (base.clone().rolling_std(options.clone()), "std"),
+                5 => (base.clone().rolling_var(options.clone()), "var"),
+                6 => (base.clone().rolling_median(options.clone()), "median"),
+                _ => return Err(PolarsError::ComputeError(
+                    format!("Unknown rolling op code: {}", code).into()
+                )),
+            };
+            exprs.push(expr.name().suffix(&format!("{}_{}", suffix, op_name)));
+        }
+
+        let new_lf = lf_ctx.inner.with_columns(exprs);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+// ==========================================
+// 批量 Cast
+// ==========================================
+// 一次 FFI 调用生成整个 with_columns(cast...) 计划，宽表统一类型时不用逐列往返
+// dtypes 只借用，C# 端继续持有并负责释放
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_cast_columns(
+    lf_ptr: *mut LazyFrameContext,
+    names_ptr: *const *const c_char,
+    dtypes_ptr: *const *mut DataTypeContext,
+    len: usize,
+    strict: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let names = unsafe { std::slice::from_raw_parts(names_ptr, len) };
+        let dtypes = unsafe { std::slice::from_raw_parts(dtypes_ptr, len) };
+
+        let exprs: Vec<Expr> = names.iter()
+            .zip(dtypes.iter())
+            .map(|(&name_ptr, &dtype_ptr)| {
+                let name = ptr_to_str(name_ptr).unwrap();
+                let dtype = unsafe { (*dtype_ptr).dtype.clone() };
+                if strict {
+                    col(name).strict_cast(dtype)
+                } else {
+                    col(name).cast(dtype)
+                }
+            })
+            .collect();
+
+        let new_lf = lf_ctx.inner.with_columns(exprs);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+// ==========================================
 // 5. 实用功能
 // ==========================================
 #[unsafe(no_mangle)]
@@ -612,7 +1795,35 @@ pub extern "C" fn pl_lazy_explain(lf_ptr: *mut LazyFrameContext, optimized: bool
     })
 }
 
-// 释放字符串 (配合 pl_lazy_explain 使用)
+// 树形格式的执行计划 (与 Python 的 explain(format="tree") 一致)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_explain_tree(lf_ptr: *mut LazyFrameContext, optimized: bool) -> *mut c_char {
+    ffi_try!({
+        let ctx = unsafe { &*lf_ptr };
+
+        let plan_str = if optimized {
+            ctx.inner.describe_optimized_plan_tree()?
+        } else {
+            ctx.inner.describe_plan_tree()?
+        };
+
+        Ok(std::ffi::CString::new(plan_str).unwrap().into_raw())
+    })
+}
+
+// Graphviz DOT 格式的执行计划，C# 端可直接交给 dot 渲染
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_to_dot(lf_ptr: *mut LazyFrameContext, optimized: bool) -> *mut c_char {
+    ffi_try!({
+        let ctx = unsafe { &*lf_ptr };
+
+        let dot = ctx.inner.to_dot(optimized)?;
+
+        Ok(std::ffi::CString::new(dot).unwrap().into_raw())
+    })
+}
+
+// 释放字符串 (配合 pl_lazy_explain / explain_tree / to_dot 使用)
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_free_string(ptr: *mut std::os::raw::c_char) {
     if !ptr.is_null() {
@@ -642,14 +1853,87 @@ pub extern "C" fn pl_lazy_frame_free(ptr: *mut LazyFrameContext) {
     })
 }
 
+// ==========================================
+// Shared LazyFrame (引用计数句柄)
+// ==========================================
+// 普通 LazyFrameContext 是单一所有者，链式调用会消费句柄，多个线程同时拿同一个句柄组合查询是不安全的。
+// 共享句柄只读：每个线程 retain 一份自己的句柄，各自 clone_plan 出独立的 LazyFrame 再继续构建。
+
+// 编译期确认 LazyFrame 可以跨线程共享
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<LazyFrame>();
+};
+
+// 消费 lf，返回引用计数为 1 的共享句柄
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_share(lf_ptr: *mut LazyFrameContext) -> *mut SharedLazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        Ok(Box::into_raw(Box::new(SharedLazyFrameContext { inner: Arc::new(lf_ctx.inner) })))
+    })
+}
+
+// 引用计数 +1，返回一个新句柄 (每个句柄都要单独 free)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_share_retain(ptr: *mut SharedLazyFrameContext) -> *mut SharedLazyFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*ptr };
+        Ok(Box::into_raw(Box::new(SharedLazyFrameContext { inner: Arc::clone(&ctx.inner) })))
+    })
+}
+
+// 从共享句柄复制出一个独占的 LazyFrame (只复制计划，不复制数据)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_share_clone_plan(ptr: *mut SharedLazyFrameContext) -> *mut LazyFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*ptr };
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: ctx.inner.as_ref().clone() })))
+    })
+}
+
+// 当前存活的共享句柄数量 (调试用)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_share_ref_count(ptr: *mut SharedLazyFrameContext) -> usize {
+    if ptr.is_null() { return 0; }
+    let ctx = unsafe { &*ptr };
+    Arc::strong_count(&ctx.inner)
+}
+
+// 引用计数 -1，最后一个句柄释放时计划才真正析构
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_share_free(ptr: *mut SharedLazyFrameContext) {
+    ffi_try_void!({
+        if !ptr.is_null() {
+            unsafe { let _ = Box::from_raw(ptr); }
+        }
+        Ok(())
+    })
+}
+
 // 定义回调函数签名：C# 返回一个 ArrowArrayStream 指针
 type StreamFactoryCallback = unsafe extern "C" fn(*mut core::ffi::c_void) -> *mut polars_arrow::ffi::ArrowArrayStream;
 type DestroyUserDataCallback = unsafe extern "C" fn(*mut core::ffi::c_void); // [新增]
+// 带下推信息的版本：C# 数据源据此只取需要的部分
+// 参数: user_data, 列名数组 (NULL = 全部列，只在回调期间有效), 列数, n_rows (-1 = 不限)
+// 不下推谓词：Polars Expr 的序列化格式是内部格式，跨版本不稳定，不能作为对外协议；过滤统一在 Rust 端做
+type PushdownStreamFactoryCallback = unsafe extern "C" fn(
+    *mut core::ffi::c_void,
+    *const *const c_char,
+    usize,
+    i64,
+) -> *mut polars_arrow::ffi::ArrowArrayStream;
+
+enum StreamFactory {
+    Plain(StreamFactoryCallback),
+    Pushdown(PushdownStreamFactoryCallback),
+}
+
 // 1. 定义扫描器结构体
 // 这个结构体会被 Polars 的 Logical Plan 持有，直到执行时
 struct CSharpStreamScanner {
     schema: SchemaRef,
-    callback: StreamFactoryCallback,
+    callback: StreamFactory,
     destroy_callback: Option<DestroyUserDataCallback>,
     user_data: *mut core::ffi::c_void, // 指向 C# 端保持上下文的对象 (GCHandle)
 }
@@ -675,10 +1959,27 @@ impl AnonymousScan for CSharpStreamScanner {
         self
     }
     // 核心：当 Polars 需要数据时，会调用这个 scan 方法
-    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
-        unsafe {
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let df = unsafe {
             // A. 回调 C# 获取新的流指针
-            let stream_ptr = (self.callback)(self.user_data);
+            let stream_ptr = match self.callback {
+                StreamFactory::Plain(callback) => callback(self.user_data),
+                StreamFactory::Pushdown(callback) => {
+                    let columns: Option<Vec<std::ffi::CString>> = scan_opts.with_columns.as_ref().map(|cols| {
+                        cols.iter().map(|c| std::ffi::CString::new(c.as_str()).unwrap()).collect()
+                    });
+                    let column_ptrs: Option<Vec<*const c_char>> = columns
+                        .as_ref()
+                        .map(|cols| cols.iter().map(|c| c.as_ptr()).collect());
+                    let (cols_ptr, cols_len) = match &column_ptrs {
+                        Some(ptrs) => (ptrs.as_ptr(), ptrs.len()),
+                        None => (std::ptr::null(), 0),
+                    };
+                    let n_rows = scan_opts.n_rows.map(|n| n as i64).unwrap_or(-1);
+
+                    callback(self.user_data, cols_ptr, cols_len, n_rows)
+                },
+            };
             
             if stream_ptr.is_null() {
                 return Err(PolarsError::ComputeError("C# callback returned null stream".into()));
@@ -693,8 +1994,19 @@ impl AnonymousScan for CSharpStreamScanner {
             }
 
             let ctx = Box::from_raw(ctx_ptr);
-            Ok(ctx.df) 
+            ctx.df
+        };
+
+        // C. 下推信息对 C# 只是提示，这里统一补做一遍，保证结果和 schema 一致
+        // 空流读出来没有列，补上完整 schema
+        let mut df = if df.width() == 0 { DataFrame::empty_with_schema(&self.schema) } else { df };
+        if let Some(columns) = &scan_opts.with_columns {
+            df = df.select(columns.iter().cloned())?;
+        }
+        if let Some(n_rows) = scan_opts.n_rows {
+            df = df.head(Some(n_rows));
         }
+        Ok(df)
     }
 
     // 告诉 Polars 数据的结构
@@ -724,41 +2036,65 @@ pub unsafe extern "C" fn pl_lazy_frame_scan_stream(
     user_data: *mut core::ffi::c_void,
 ) -> *mut LazyFrameContext {
     ffi_try!({
-        // 解析 C Schema
-        let field = unsafe { polars_arrow::ffi::import_field_from_c(&*ptr_schema)? };
-        
-        // Arrow Field -> Polars Schema
-        let arrow_dtype = field.dtype; // 注意：字段名可能是 data_type 而不是 dtype，视版本而定
-        
-        let schema = match arrow_dtype {
-            ArrowDataType::Struct(fields) => {
-                // [修复 2] Schema::new() 不存在，改用 Schema::with_capacity
-                let mut schema = Schema::with_capacity(fields.len());
-                for f in fields {
-                    let p_field = PolarsField::from(&f);
-                    // [修复 3] Schema 通常使用 insert 方法添加字段，而不是 with_column
-                    schema.insert(p_field.name, p_field.dtype);
-                }
-                Arc::new(schema)
-            },
-            _ => return Err(PolarsError::ComputeError("Schema must be a Struct".into())),
-        };
-
-        // 创建扫描器
-        let scanner = CSharpStreamScanner {
-            schema,
-            callback,
-            destroy_callback: Some(destroy_callback),
-            user_data,
-        };
-
-        // 创建 LazyFrame
-        let lf = LazyFrame::anonymous_scan(
-            std::sync::Arc::new(scanner),
-            ScanArgsAnonymous::default()
-        )?;
+        let lf = unsafe { scan_stream_lazy(ptr_schema, StreamFactory::Plain(callback), destroy_callback, user_data)? };
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
 
+// 同上，但回调会收到列裁剪 / 行数限制信息
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pl_lazy_frame_scan_stream_pushdown(
+    ptr_schema: *mut polars_arrow::ffi::ArrowSchema,
+    callback: PushdownStreamFactoryCallback,
+    destroy_callback: DestroyUserDataCallback,
+    user_data: *mut core::ffi::c_void,
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf = unsafe { scan_stream_lazy(ptr_schema, StreamFactory::Pushdown(callback), destroy_callback, user_data)? };
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
     })
 }
 
+unsafe fn scan_stream_lazy(
+    ptr_schema: *mut polars_arrow::ffi::ArrowSchema,
+    callback: StreamFactory,
+    destroy_callback: DestroyUserDataCallback,
+    user_data: *mut core::ffi::c_void,
+) -> PolarsResult<LazyFrame> {
+    // 解析 C Schema
+    let field = unsafe { polars_arrow::ffi::import_field_from_c(&*ptr_schema)? };
+    
+    // Arrow Field -> Polars Schema
+    let arrow_dtype = field.dtype; // 注意：字段名可能是 data_type 而不是 dtype，视版本而定
+    
+    let schema = match arrow_dtype {
+        ArrowDataType::Struct(fields) => {
+            // [修复 2] Schema::new() 不存在，改用 Schema::with_capacity
+            let mut schema = Schema::with_capacity(fields.len());
+            for f in fields {
+                let p_field = PolarsField::from(&f);
+                // [修复 3] Schema 通常使用 insert 方法添加字段，而不是 with_column
+                schema.insert(p_field.name, p_field.dtype);
+            }
+            Arc::new(schema)
+        },
+        _ => return Err(PolarsError::ComputeError("Schema must be a Struct".into())),
+    };
+
+    // 创建扫描器
+    let scanner = CSharpStreamScanner {
+        schema,
+        callback,
+        destroy_callback: Some(destroy_callback),
+        user_data,
+    };
+
+    // 创建 LazyFrame
+    let lf = LazyFrame::anonymous_scan(
+        std::sync::Arc::new(scanner),
+        ScanArgsAnonymous::default()
+    )?;
+
+    Ok(lf)
+}
+
@@ -12,7 +12,10 @@ mod sql;
 mod series;
 mod datatypes;
 mod schema;
+mod registry;
+mod geo;
 
 
 
 
+mod generate;
@@ -0,0 +1,64 @@
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::os::raw::c_char;
+use std::sync::{Mutex, OnceLock};
+use crate::types::DataFrameContext;
+use crate::utils::ptr_to_str;
+
+// ==========================================
+// 进程级 DataFrame 注册表
+// ==========================================
+// 不同的 .NET 组件 (插件 / AppDomain) 之间按名字交换 DataFrame，无需传递句柄。
+// DataFrame 是 COW 的，put/get 只是 clone 一份 Arc，不会复制数据。
+
+fn registry() -> &'static Mutex<HashMap<String, DataFrame>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, DataFrame>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn lock_registry() -> std::sync::MutexGuard<'static, HashMap<String, DataFrame>> {
+    // 锁中毒只说明之前有线程 panic，Map 本身仍然可用
+    registry().lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// 清空注册表，返回被释放的名字 (供 pl_shutdown 使用)
+pub(crate) fn clear_registry() -> Vec<String> {
+    lock_registry().drain().map(|(name, _)| name).collect()
+}
+
+// 借用 df (C# 端仍然持有自己的句柄)，同名覆盖
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_registry_put(name_ptr: *const c_char, df_ptr: *mut DataFrameContext) {
+    ffi_try_void!({
+        let name = ptr_to_str(name_ptr).unwrap();
+        let ctx = unsafe { &*df_ptr };
+        lock_registry().insert(name.to_string(), ctx.df.clone());
+        Ok(())
+    })
+}
+
+// 返回一个新句柄，C# 负责释放；不存在时返回 NULL 并设置错误
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_registry_get(name_ptr: *const c_char) -> *mut DataFrameContext {
+    ffi_try!({
+        let name = ptr_to_str(name_ptr).unwrap();
+        let df = lock_registry()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| PolarsError::ComputeError(
+                format!("No DataFrame registered under name '{}'", name).into()
+            ))?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// out_removed: 是否确实删除了条目；名字非法时设置错误，out_removed 保持不变
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_registry_remove(name_ptr: *const c_char, out_removed: *mut bool) {
+    ffi_try_void!({
+        let name = ptr_to_str(name_ptr).unwrap();
+        let removed = lock_registry().remove(name).is_some();
+        unsafe { *out_removed = removed };
+        Ok(())
+    })
+}
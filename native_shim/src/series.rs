@@ -30,7 +30,7 @@ pub extern "C" fn pl_series_new_i32(
             Series::new(name.into(), &opts)
         };
 
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -55,7 +55,7 @@ pub extern "C" fn pl_series_new_i64(
             Series::new(name.into(), &opts)
         };
 
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -80,7 +80,7 @@ pub extern "C" fn pl_series_new_f64(
             Series::new(name.into(), &opts)
         };
 
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -105,7 +105,7 @@ pub extern "C" fn pl_series_new_bool(
             Series::new(name.into(), &opts)
         };
 
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -130,7 +130,7 @@ pub extern "C" fn pl_series_new_str(
             .collect();
 
         let series = Series::new(name.into(), &vec_opts);
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -165,7 +165,7 @@ pub extern "C" fn pl_series_new_decimal(
             .map_err(|e| PolarsError::ComputeError(format!("Decimal creation failed: {}", e).into()))?
             .into_series();
 
-        Ok(Box::into_raw(Box::new(SeriesContext { series: decimal_series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: decimal_series, extension: None })))
     })
 }
 
@@ -231,7 +231,7 @@ pub extern "C" fn pl_series_to_arrow(ptr: *mut SeriesContext) -> *mut ArrowArray
         let ctx = unsafe { &*ptr };
         let contiguous_series = ctx.series.rechunk();
         let arr = contiguous_series.to_arrow(0, CompatLevel::newest());
-        Ok(Box::into_raw(Box::new(ArrowArrayContext { array: arr })))
+        Ok(Box::into_raw(Box::new(ArrowArrayContext { array: arr, extension: ctx.extension.clone() })))
     })
 }
 
@@ -372,8 +372,18 @@ pub unsafe extern "C" fn pl_arrow_to_series(
         let name_str = unsafe { CStr::from_ptr(name).to_str().unwrap() };
         let field = unsafe { polars_arrow::ffi::import_field_from_c(&*ptr_schema)? };
         // println!("Imported DataType: {:?}", field.dtype);
+
+        // 扩展类型 (arrow.uuid / arrow.json / geoarrow.*)：按存储类型导入，扩展信息单独保存
+        let (storage_dtype, extension) = match field.dtype {
+            ArrowDataType::Extension(ext) => {
+                let ext = *ext;
+                (ext.inner.clone(), Some(ext))
+            },
+            other => (other, None),
+        };
+
         let array_val = unsafe { std::ptr::read(ptr_array) };
-        let mut array = unsafe { polars_arrow::ffi::import_array_from_c(array_val, field.dtype)? };
+        let mut array = unsafe { polars_arrow::ffi::import_array_from_c(array_val, storage_dtype)? };
        
         // =============================================================
         // 🔧 调用我们手写的升级函数
@@ -381,10 +391,30 @@ pub unsafe extern "C" fn pl_arrow_to_series(
         array = upgrade_to_large_list(array);
 
         let series = Series::from_arrow(name_str.into(), array)?;
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension })))
     })
 }
 
+// 返回 Arrow 扩展类型名 (如 "arrow.uuid")，没有扩展类型时返回 NULL
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_extension_name(ptr: *mut SeriesContext) -> *mut c_char {
+    let ctx = unsafe { &*ptr };
+    match &ctx.extension {
+        Some(ext) => CString::new(ext.name.as_str()).unwrap().into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+// 返回扩展类型的 metadata 字符串 (原样透传)，没有时返回 NULL
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_extension_metadata(ptr: *mut SeriesContext) -> *mut c_char {
+    let ctx = unsafe { &*ptr };
+    match ctx.extension.as_ref().and_then(|ext| ext.metadata.as_ref()) {
+        Some(meta) => CString::new(meta.as_str()).unwrap().into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_series_cast(
     ptr: *mut SeriesContext, 
@@ -395,7 +425,20 @@ pub extern "C" fn pl_series_cast(
         let target_dtype = unsafe { &(*dtype_ptr).dtype };
         
         let s = ctx.series.cast(target_dtype)?;
-        Ok(Box::into_raw(Box::new(SeriesContext { series: s })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: s, extension: None })))
+    })
+}
+
+// Categorical / Enum Series 的类别列表 (String Series)，不需要先 cast 成 String 再 unique
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_cat_get_categories(ptr: *mut SeriesContext) -> *mut SeriesContext {
+    ffi_try!({
+        let ctx = unsafe { &*ptr };
+        let mapping = ctx.series.dtype().cat_mapping()?;
+        let ca = unsafe {
+            StringChunked::from_chunks(ctx.series.name().clone(), vec![mapping.to_arrow(true)])
+        };
+        Ok(Box::into_raw(Box::new(SeriesContext { series: ca.into_series(), extension: None })))
     })
 }
 
@@ -404,7 +447,7 @@ pub extern "C" fn pl_series_is_null(s_ptr: *mut SeriesContext) -> *mut SeriesCon
     ffi_try!({
         let ctx = unsafe { &*s_ptr };
         let series = ctx.series.is_null().into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -413,7 +456,7 @@ pub extern "C" fn pl_series_is_not_null(s_ptr: *mut SeriesContext) -> *mut Serie
     ffi_try!({
         let ctx = unsafe { &*s_ptr };
         let series = ctx.series.is_not_null().into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series, extension: None })))
     })
 }
 
@@ -563,7 +606,7 @@ pub extern "C" fn pl_series_add(s1: *mut SeriesContext, s2: *mut SeriesContext)
         let s2 = unsafe { &(*s2).series };
         // 运算符重载可能会 panic (例如形状极度不匹配且无法广播)
         let res = s1 + s2; 
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res? })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res?, extension: None })))
     })
 }
 
@@ -573,7 +616,7 @@ pub extern "C" fn pl_series_sub(s1: *mut SeriesContext, s2: *mut SeriesContext)
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1 - s2;
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res? })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res?, extension: None })))
     })
 }
 
@@ -583,7 +626,7 @@ pub extern "C" fn pl_series_mul(s1: *mut SeriesContext, s2: *mut SeriesContext)
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1 * s2;
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res? })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res?, extension: None })))
     })
 }
 
@@ -593,7 +636,7 @@ pub extern "C" fn pl_series_div(s1: *mut SeriesContext, s2: *mut SeriesContext)
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1 / s2;
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res? })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res?, extension: None })))
     })
 }
 
@@ -608,7 +651,7 @@ pub extern "C" fn pl_series_eq(s1: *mut SeriesContext, s2: *mut SeriesContext) -
         let s2 = unsafe { &(*s2).series };
         // [修复] 去掉 unwrap(), 使用 ? 传播错误
         let res = s1.equal(s2).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -618,7 +661,7 @@ pub extern "C" fn pl_series_neq(s1: *mut SeriesContext, s2: *mut SeriesContext)
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1.not_equal(s2).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -628,7 +671,7 @@ pub extern "C" fn pl_series_gt(s1: *mut SeriesContext, s2: *mut SeriesContext) -
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1.gt(s2).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -638,7 +681,7 @@ pub extern "C" fn pl_series_gt_eq(s1: *mut SeriesContext, s2: *mut SeriesContext
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1.gt_eq(s2).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -648,7 +691,7 @@ pub extern "C" fn pl_series_lt(s1: *mut SeriesContext, s2: *mut SeriesContext) -
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1.lt(s2).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -658,7 +701,7 @@ pub extern "C" fn pl_series_lt_eq(s1: *mut SeriesContext, s2: *mut SeriesContext
         let s1 = unsafe { &(*s1).series };
         let s2 = unsafe { &(*s2).series };
         let res = s1.lt_eq(s2).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 // ==========================================
@@ -671,7 +714,7 @@ pub extern "C" fn pl_series_sum(s_ptr: *mut SeriesContext) -> *mut SeriesContext
         let s = unsafe { &(*s_ptr).series };
         // [修复] 使用 sum_reduce() 获取 Scalar，再转回 Series
         let res = s.sum_reduce()?.into_series(s.name().clone());
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -681,7 +724,7 @@ pub extern "C" fn pl_series_mean(s_ptr: *mut SeriesContext) -> *mut SeriesContex
         let s = unsafe { &(*s_ptr).series };
         let mean_val = s.mean();
         let res = Series::new(s.name().clone(), &[mean_val]);
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -696,7 +739,7 @@ pub extern "C" fn pl_series_min(s_ptr: *mut SeriesContext) -> *mut SeriesContext
         // 2. 将 Scalar 转回 Series (需要传入列名)
         let res = scalar.into_series(s.name().clone());
         
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -711,7 +754,7 @@ pub extern "C" fn pl_series_max(s_ptr: *mut SeriesContext) -> *mut SeriesContext
         // 2. 将 Scalar 转回 Series
         let res = scalar.into_series(s.name().clone());
         
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -721,7 +764,7 @@ pub extern "C" fn pl_series_is_nan(s_ptr: *mut SeriesContext) -> *mut SeriesCont
         let ctx = unsafe { &*s_ptr };
         // is_nan() 返回 Result<BooleanChunked> -> ? 解包 -> into_series()
         let res = ctx.series.is_nan()?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -730,7 +773,7 @@ pub extern "C" fn pl_series_is_not_nan(s_ptr: *mut SeriesContext) -> *mut Series
     ffi_try!({
         let ctx = unsafe { &*s_ptr };
         let res = ctx.series.is_not_nan()?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -739,7 +782,7 @@ pub extern "C" fn pl_series_is_finite(s_ptr: *mut SeriesContext) -> *mut SeriesC
     ffi_try!({
         let ctx = unsafe { &*s_ptr };
         let res = ctx.series.is_finite()?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
@@ -748,7 +791,7 @@ pub extern "C" fn pl_series_is_infinite(s_ptr: *mut SeriesContext) -> *mut Serie
     ffi_try!({
         let ctx = unsafe { &*s_ptr };
         let res = ctx.series.is_infinite()?.into_series();
-        Ok(Box::into_raw(Box::new(SeriesContext { series: res })))
+        Ok(Box::into_raw(Box::new(SeriesContext { series: res, extension: None })))
     })
 }
 
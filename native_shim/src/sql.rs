@@ -1,6 +1,7 @@
 use polars::prelude::*;
 use polars::sql::SQLContext;
 use std::os::raw::c_char;
+use std::path::Path;
 use crate::{types::LazyFrameContext, utils::ptr_to_str};
 
 // 定义 Context 容器
@@ -62,4 +63,87 @@ pub extern "C" fn pl_sql_context_execute(
         
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
     })
-}
\ No newline at end of file
+}
+// ==========================================
+// Snapshot / Restore
+// ==========================================
+// 目录结构:
+//   <dir>/catalog.json      清单: { "version": 1, "tables": [ { "name": "...", "file": "table_0.arrow" } ] }
+//   <dir>/table_<i>.arrow   每张表一个 IPC 文件 (文件名用序号，避免表名中的非法字符)
+const CATALOG_MANIFEST: &str = "catalog.json";
+const CATALOG_VERSION: u64 = 1;
+
+// 执行所有已注册表的查询计划并落盘为 IPC，同时写入清单
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_sql_context_snapshot(
+    ctx_ptr: *mut SqlContextWrapper,
+    dir_ptr: *const c_char
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &*ctx_ptr };
+        let dir = Path::new(ptr_to_str(dir_ptr).unwrap());
+        std::fs::create_dir_all(dir)?;
+
+        let mut tables = ctx.inner.get_table_map().into_iter().collect::<Vec<_>>();
+        // 排序保证清单稳定，便于 diff
+        tables.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut entries = Vec::with_capacity(tables.len());
+        for (i, (name, lf)) in tables.into_iter().enumerate() {
+            let file_name = format!("table_{}.arrow", i);
+            let path = dir.join(&file_name);
+            let target = SinkTarget::Path(PlPath::new(&path.to_string_lossy()));
+
+            lf.sink_ipc(target, IpcWriterOptions::default(), None, SinkOptions::default())?
+                .with_new_streaming(true)
+                .collect()?;
+
+            entries.push(serde_json::json!({ "name": name, "file": file_name }));
+        }
+
+        let manifest = serde_json::json!({
+            "version": CATALOG_VERSION,
+            "tables": entries,
+        });
+        let json = serde_json::to_string_pretty(&manifest)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        std::fs::write(dir.join(CATALOG_MANIFEST), json)?;
+
+        Ok(())
+    })
+}
+
+// 读取清单，以 scan_ipc (内存映射) 的方式重新注册所有表，返回新的 Context
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_sql_context_restore(dir_ptr: *const c_char) -> *mut SqlContextWrapper {
+    ffi_try!({
+        let dir = Path::new(ptr_to_str(dir_ptr).unwrap());
+        let raw = std::fs::read_to_string(dir.join(CATALOG_MANIFEST))?;
+        let manifest: serde_json::Value = serde_json::from_str(&raw)
+            .map_err(|e| PolarsError::ComputeError(format!("Invalid catalog manifest: {}", e).into()))?;
+
+        let version = manifest["version"].as_u64().unwrap_or(0);
+        if version != CATALOG_VERSION {
+            return Err(PolarsError::ComputeError(
+                format!("Unsupported catalog manifest version: {}", version).into()
+            ));
+        }
+
+        let tables = manifest["tables"].as_array()
+            .ok_or_else(|| PolarsError::ComputeError("Catalog manifest is missing 'tables'".into()))?;
+
+        let mut ctx = SQLContext::new();
+        for entry in tables {
+            let (Some(name), Some(file)) = (entry["name"].as_str(), entry["file"].as_str()) else {
+                return Err(PolarsError::ComputeError(
+                    format!("Malformed catalog entry: {}", entry).into()
+                ));
+            };
+            let path = dir.join(file);
+            let lf = LazyFrame::scan_ipc(PlPath::new(&path.to_string_lossy()), ScanArgsIpc::default())?;
+            ctx.register(name, lf);
+        }
+
+        Ok(Box::into_raw(Box::new(SqlContextWrapper { inner: ctx })))
+    })
+}
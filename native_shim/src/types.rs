@@ -20,9 +20,18 @@ pub struct LazyFrameContext {
     pub inner: LazyFrame,
 }
 
+// 可跨线程共享的 LazyFrame：计划只读，每个句柄持有一份 Arc 引用计数
+// 需要继续链式构建时通过 pl_lazy_share_clone_plan 拿一个独占的 LazyFrameContext
+pub struct SharedLazyFrameContext {
+    pub inner: Arc<LazyFrame>,
+}
+
 // 包装结构体
+// extension: 从 Arrow 导入时携带的扩展类型 (如 arrow.uuid)。Polars 本身不保存扩展元数据，
+// 这里单独保存，导出时重新包回去；经过计算产生的新 Series 不再携带
 pub struct SeriesContext {
     pub series: Series,
+    pub extension: Option<polars_arrow::datatypes::ExtensionType>,
 }
 
 // 包装 DataType，因为我们需要传递它给 cast 函数
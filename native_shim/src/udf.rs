@@ -3,7 +3,7 @@ use polars_arrow::ffi;
 use crate::types::{ExprContext,DataTypeContext};
 use std::sync::Arc;
 use polars_arrow::datatypes::Field as ArrowField;
-use std::ffi::{CStr,c_void};
+use std::ffi::{CStr,CString,c_void};
 
 // 1. 定义清理回调的签名
 // 参数: user_data (这里我们将传入 C# GCHandle 的 IntPtr)
@@ -48,7 +48,16 @@ impl CSharpUdf {
     fn call(&self, s: Series) -> PolarsResult<Option<Series>> {
         // A. 准备输入数据
         let array = s.to_arrow(0, CompatLevel::newest());
+        let out_array = self.call_array(array)?;
+
+        // E. 重建 Series
+        let out_series = Series::try_from((s.name().clone(), out_array))?;
         
+        Ok(Some(out_series))
+    }
+
+    // 把单个 Arrow 数组交给 C#，再把 C# 填好的结果导回来
+    fn call_array(&self, array: ArrayRef) -> PolarsResult<ArrayRef> {
         // [修复 1 & 2]
         // 1. 使用 .dtype() 而不是 .data_type()
         // 2. 使用 ArrowField (polars_arrow::datatypes::Field)
@@ -78,12 +87,24 @@ impl CSharpUdf {
         // D. 导回结果
         // import_field_from_c 返回的就是 ArrowField，所以这里的 field 类型是对的
         let out_field = unsafe { ffi::import_field_from_c(&c_schema_out).map_err(|e| PolarsError::ComputeError(e.to_string().into()))? };
-        let out_array = unsafe { ffi::import_array_from_c(c_array_out, out_field.dtype.clone()).map_err(|e| PolarsError::ComputeError(e.to_string().into()))? };
+        unsafe { ffi::import_array_from_c(c_array_out, out_field.dtype.clone()).map_err(|e| PolarsError::ComputeError(e.to_string().into())) }
+    }
 
-        // E. 重建 Series
-        let out_series = Series::try_from((s.name().clone(), out_array))?;
-        
-        Ok(Some(out_series))
+    // 逐 chunk 调用 C#：每个 chunk 的输出长度必须和输入一致 (逐元素语义)
+    fn call_elements(&self, s: Series) -> PolarsResult<Series> {
+        let mut chunks = Vec::with_capacity(s.n_chunks());
+        for i in 0..s.n_chunks() {
+            let array = s.to_arrow(i, CompatLevel::newest());
+            let in_len = array.len();
+            let out_array = self.call_array(array)?;
+            if out_array.len() != in_len {
+                return Err(PolarsError::ShapeMismatch(
+                    format!("C# map_elements returned {} values for a chunk of {}", out_array.len(), in_len).into()
+                ));
+            }
+            chunks.push(out_array);
+        }
+        Series::try_from((s.name().clone(), chunks))
     }
 }
 
@@ -120,4 +141,145 @@ pub extern "C" fn pl_expr_map(
 
         Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
     })
-}
\ No newline at end of file
+}
+// ==========================================
+// map_batches (整批 C# UDF)
+// ==========================================
+// 和 pl_lazy_map_batches (只消费数据) 不同，这里 C# 返回新的 ArrowArray 并回到计划中继续参与计算
+// 输入会先 rechunk 成单个连续数组，C# 一次拿到整批数据
+// is_elementwise:
+//   true  -> 结果只依赖每行自身，group_by 里直接作用于整列，优化器可以安全地做谓词下推等
+//   false -> 结果依赖整批数据 (如归一化、排名)，group_by 里按组分别调用
+// output_type_ptr 只借用；Unknown 表示输出类型与输入相同
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_map_batches(
+    expr_ptr: *mut ExprContext,
+    callback: UdfCallback,
+    output_type_ptr: *mut DataTypeContext,
+    is_elementwise: bool,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let udf = Arc::new(CSharpUdf { callback, cleanup, user_data });
+        let target_dtype = unsafe { &(*output_type_ptr).dtype };
+        let output_type = match target_dtype {
+            DataType::Unknown(UnknownKind::Any) => GetOutput::map_field(|f| Ok(f.clone())),
+            _ => GetOutput::from_type(target_dtype.clone()),
+        };
+
+        let function = move |c: Column| {
+            let s = c.take_materialized_series().rechunk();
+            let res_series = udf.call(s)?;
+            Ok(res_series.map(|s| s.into_column()))
+        };
+        let new_expr = if is_elementwise {
+            ctx.inner.map_with_fmt_str(function, output_type, "map_batches")
+        } else {
+            ctx.inner.apply_with_fmt_str(function, output_type, "map_batches")
+        };
+
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+// ==========================================
+// map_elements (逐元素 C# UDF)
+// ==========================================
+// 和 pl_expr_map 共用同一个 Arrow 回调签名，区别在于：
+// 1. 每个 chunk 单独回调一次，C# 端一次只需要处理一段连续数据
+// 2. 输出长度必须与输入一致，否则报 ShapeMismatch
+// 在 group_by().agg() 里也按元素作用，而不是把整个组当作一个 Series
+// output_type_ptr 只借用；Unknown 表示输出类型与输入相同
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_map_elements(
+    expr_ptr: *mut ExprContext,
+    callback: UdfCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void,
+    output_type_ptr: *mut DataTypeContext
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let udf = Arc::new(CSharpUdf { callback, cleanup, user_data });
+        let target_dtype = unsafe { &(*output_type_ptr).dtype };
+        let output_type = match target_dtype {
+            DataType::Unknown(UnknownKind::Any) => GetOutput::map_field(|f| Ok(f.clone())),
+            _ => GetOutput::from_type(target_dtype.clone()),
+        };
+
+        let new_expr = ctx.inner.map(
+            move |c| {
+                let s = c.take_materialized_series();
+                Ok(Some(udf.call_elements(s)?.into_column()))
+            },
+            output_type
+        );
+
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
+// ==========================================
+// name.map (C# 回调重命名)
+// ==========================================
+// 参数 1 (输入): 原列名 (UTF-8, \0 结尾)
+// 参数 2 (输出): 新列名缓冲区，由 C# 写入 \0 结尾的 UTF-8；失败时写入错误信息
+// 参数 3: 缓冲区长度
+// 返回值: i32 (0=Ok, 1=Error)
+type NameMapCallback = extern "C" fn(
+    *const std::os::raw::c_char,
+    *mut std::os::raw::c_char,
+    usize
+) -> i32;
+
+const NAME_BUF_LEN: usize = 1024;
+
+struct CSharpNameMapper {
+    callback: NameMapCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void,
+}
+
+unsafe impl Send for CSharpNameMapper {}
+unsafe impl Sync for CSharpNameMapper {}
+
+impl Drop for CSharpNameMapper {
+    fn drop(&mut self) {
+        (self.cleanup)(self.user_data);
+    }
+}
+
+impl CSharpNameMapper {
+    fn call(&self, name: &PlSmallStr) -> PolarsResult<PlSmallStr> {
+        let c_name = CString::new(name.as_str())
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let mut buf = [0u8; NAME_BUF_LEN];
+        let buf_ptr = buf.as_mut_ptr() as *mut std::os::raw::c_char;
+
+        let status = (self.callback)(c_name.as_ptr(), buf_ptr, NAME_BUF_LEN);
+        // 防止 C# 写满缓冲区没留 \0
+        unsafe { *buf_ptr.add(NAME_BUF_LEN - 1) = 0 };
+        let out = unsafe { CStr::from_ptr(buf_ptr).to_string_lossy().into_owned() };
+
+        if status != 0 {
+            return Err(PolarsError::ComputeError(format!("C# name mapper failed: {}", out).into()));
+        }
+        Ok(out.into())
+    }
+}
+
+// 对表达式的输出列名调用 C# 回调重命名，配合 selector 批量处理多列时使用
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_expr_name_map(
+    expr_ptr: *mut ExprContext,
+    callback: NameMapCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void
+) -> *mut ExprContext {
+    ffi_try!({
+        let ctx = unsafe { Box::from_raw(expr_ptr) };
+        let mapper = Arc::new(CSharpNameMapper { callback, cleanup, user_data });
+        let new_expr = ctx.inner.name().map(move |name| mapper.call(name));
+        Ok(Box::into_raw(Box::new(ExprContext { inner: new_expr })))
+    })
+}
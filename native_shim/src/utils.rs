@@ -2,13 +2,15 @@ use std::ffi::{CStr, c_char};
 
 use polars_arrow::ffi::ArrowArray;
 use polars_arrow::ffi::{export_array_to_c,export_field_to_c};
-use polars::prelude::{ArrowSchema, Expr, JoinType};
-use polars_arrow::datatypes::Field;
+use polars::prelude::{ArrowSchema, Expr, JoinType, PlSeedableRandomStateQuality, PolarsError, PolarsResult, Series};
+use polars_arrow::datatypes::{ArrowDataType, ExtensionType, Field};
 
 use crate::types::ExprContext;
 
 pub struct ArrowArrayContext {
     pub array: Box<dyn polars_arrow::array::Array>, 
+    // 导出 Schema 时包在存储类型外面的扩展类型
+    pub extension: Option<polars_arrow::datatypes::ExtensionType>,
 }
 
 #[unsafe(no_mangle)]
@@ -51,8 +53,15 @@ pub extern "C" fn pl_arrow_schema_export(
 
     let ctx = unsafe { &*ptr };
     
-    // 1. 获取 Array 的 DataType
-    let dtype = ctx.array.dtype().clone();
+    // 1. 获取 Array 的 DataType (有扩展类型时包回 Extension，保留 name + metadata)
+    let dtype = match &ctx.extension {
+        Some(ext) => ArrowDataType::Extension(Box::new(ExtensionType {
+            name: ext.name.clone(),
+            inner: ctx.array.dtype().clone(),
+            metadata: ext.metadata.clone(),
+        })),
+        None => ctx.array.dtype().clone(),
+    };
 
     // 2. 构造一个 Field (名字随意，因为 ImportArray 主要看类型，Series 名字通常在上层管理)
     // 但为了调试方便，我们叫它 "exported"
@@ -106,4 +115,72 @@ pub(crate) fn map_jointype(code: i32) -> JoinType {
         5 => JoinType::Anti,
         _ => JoinType::Inner, // 默认
     }
-}
\ No newline at end of file
+}
+/// HyperLogLog 基数估计 (precision = 寄存器位数，寄存器个数 m = 2^precision)
+/// Polars 自带的 approx_n_unique 精度固定，这里自己实现一份以便调用方控制精度/内存
+pub(crate) fn hll_estimate(s: &Series, precision: u8) -> PolarsResult<u64> {
+    if !(4..=18).contains(&precision) {
+        return Err(PolarsError::ComputeError(
+            format!("HyperLogLog precision must be between 4 and 18, got {}", precision).into()
+        ));
+    }
+
+    // 1. 计算每个值的 64 位哈希 (fixed seed，保证结果可复现)
+    let mut hashes = Vec::with_capacity(s.len());
+    s.vec_hash(PlSeedableRandomStateQuality::fixed(), &mut hashes)?;
+
+    // 2. 填充寄存器：高 p 位选寄存器，剩余位的前导零个数 + 1 为 rank
+    let p = precision as u32;
+    let m = 1usize << p;
+    let mut registers = vec![0u8; m];
+    for h in hashes {
+        let idx = (h >> (64 - p)) as usize;
+        let w = (h << p) | (1u64 << (p - 1));
+        let rank = (w.leading_zeros() + 1) as u8;
+        if rank > registers[idx] {
+            registers[idx] = rank;
+        }
+    }
+
+    // 3. 调和平均估计 + 小基数修正 (Linear Counting)
+    let m_f = m as f64;
+    let alpha = match m {
+        16 => 0.673,
+        32 => 0.697,
+        64 => 0.709,
+        _ => 0.7213 / (1.0 + 1.079 / m_f),
+    };
+    let sum: f64 = registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+    let mut estimate = alpha * m_f * m_f / sum;
+
+    let zeros = registers.iter().filter(|&&r| r == 0).count();
+    if estimate <= 2.5 * m_f && zeros > 0 {
+        estimate = m_f * (m_f / zeros as f64).ln();
+    }
+
+    Ok(estimate.round() as u64)
+}
+
+// ==========================================
+// 进程级清理
+// ==========================================
+// .NET 宿主卸载 AssemblyLoadContext 前调用。
+// 释放 shim 自己持有的全局状态 (DataFrame 注册表)，并向 Polars 线程池投递一个空任务等它跑完，
+// 之前从外部提交、还在排队的任务会先出队。
+// Polars 的 rayon 线程池和异步 IO 运行时是进程级静态对象，无法销毁，只能保证调用后它们处于空闲状态。
+// 返回关闭时仍然存活的注册表条目数；Debug 构建下非 0 时额外设置错误信息，方便测试断言泄漏。
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_shutdown() -> usize {
+    // 全局注入队列是 FIFO 的，空任务完成时之前排队的任务都已开始执行
+    polars_core::POOL.install(|| {});
+
+    let leaked = crate::registry::clear_registry();
+    if cfg!(debug_assertions) && !leaked.is_empty() {
+        crate::error::set_error(format!(
+            "pl_shutdown: {} DataFrame(s) still registered: {}",
+            leaked.len(),
+            leaked.join(", ")
+        ));
+    }
+    leaked.len()
+}